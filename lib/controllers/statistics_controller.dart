@@ -10,6 +10,7 @@ class StatisticsController extends ChangeNotifier {
   static const int lossWindowSize = 100;
 
   Statistics? _statistics;
+  List<PeerStatistics> _peers = const [];
   final ListQueue<int> _lossWindow = ListQueue<int>(lossWindowSize)
     ..addAll(List<int>.filled(lossWindowSize, 0));
 
@@ -28,10 +29,14 @@ class StatisticsController extends ChangeNotifier {
 
   double get outputLevel => _statistics?.outputLevel ?? 0;
 
-  String get upload => formatBandwidth(_statistics?.uploadBandwidth.toInt());
+  String get upload =>
+      '${formatBandwidth(_statistics?.uploadBandwidth.toInt())}/s';
 
   String get download =>
-      formatBandwidth(_statistics?.downloadBandwidth.toInt());
+      '${formatBandwidth(_statistics?.downloadBandwidth.toInt())}/s';
+
+  /// the statistics of each room participant, empty outside of rooms
+  List<PeerStatistics> get peers => _peers;
 
   /// called when the backend has updated statistics
   void setStatistics(Statistics statistics) {
@@ -53,4 +58,10 @@ class StatisticsController extends ChangeNotifier {
     _statistics = statistics;
     notifyListeners();
   }
+
+  /// called when the backend has updated the statistics of room participants
+  void setPeerStatistics(List<PeerStatistics> peers) {
+    _peers = peers;
+    notifyListeners();
+  }
 }
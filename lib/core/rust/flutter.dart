@@ -28,7 +28,11 @@ abstract class FlutterCallbacks implements RustOpaqueInterface {
           required FutureOr<void> Function(ChatMessage) messageReceived,
           required FutureOr<void> Function(ManagerState) managerActive,
           required FutureOr<void> Function((FrontendNotify, bool))
-              screenshareStarted}) =>
+              screenshareStarted,
+          required FutureOr<void> Function(RecordingState) recordingState,
+          required FutureOr<void> Function(DeviceEvent) deviceEvent,
+          required FutureOr<void> Function(List<PeerStatistics>)
+              peerStatistics}) =>
       RustLib.instance.api.crateFlutterFlutterCallbacksNew(
           acceptCall: acceptCall,
           getContact: getContact,
@@ -38,7 +42,10 @@ abstract class FlutterCallbacks implements RustOpaqueInterface {
           statistics: statistics,
           messageReceived: messageReceived,
           managerActive: managerActive,
-          screenshareStarted: screenshareStarted);
+          screenshareStarted: screenshareStarted,
+          recordingState: recordingState,
+          deviceEvent: deviceEvent,
+          peerStatistics: peerStatistics);
 }
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PreparedIdentitySwitch>>
@@ -65,6 +72,10 @@ abstract class Telepathy implements RustOpaqueInterface {
   Future<void> endCall();
 
   /// The only entry point into participating in a room.
+  ///
+  /// Rooms ignore the codec config: every member decodes the same stream, so
+  /// rooms always send mono SEA at 5 residual bits, which every client
+  /// (including web) supports. Rooms do not adapt the bitrate to loss.
   Future<void> joinRoom(
       {required List<String> memberStrings, required StartOperation operation});
 
@@ -102,6 +113,9 @@ abstract class Telepathy implements RustOpaqueInterface {
   /// Sends a chat message
   Future<void> sendChat({required ChatMessage message});
 
+  /// Auto gain is set on the processor; the current call is not reconfigured.
+  void setAutoGain({required bool enabled});
+
   void setContactOutputVolume({required Contact contact});
 
   void setDeafened({required bool deafened});
@@ -109,6 +123,9 @@ abstract class Telepathy implements RustOpaqueInterface {
   /// Denoise is set on the processor; the current call is not reconfigured.
   void setDenoise({required bool denoise});
 
+  /// Echo cancellation is set on the processors; the current call is not reconfigured.
+  void setEchoCancellation({required bool enabled});
+
   void setEfficiencyMode({required bool enabled});
 
   /// Sets the signing key (called when the profile changes)
@@ -118,6 +135,9 @@ abstract class Telepathy implements RustOpaqueInterface {
 
   void setInputVolume({required double decibel});
 
+  /// The bounds are read when a call or room connects.
+  void setJitterBufferBounds({required int minMs, required int maxMs});
+
   Future<void> setModel({Uint8List? model});
 
   void setMuted({required bool muted});
@@ -128,10 +148,16 @@ abstract class Telepathy implements RustOpaqueInterface {
 
   void setPlayCustomRingtones({required bool play});
 
+  /// Read when a call or room connects.
+  void setRedundancy({required bool enabled});
+
   void setRmsThreshold({required double decimal});
 
   void setSendCustomRingtone({required bool send});
 
+  /// The voice gate is set on the processor; the current call is not reconfigured.
+  void setVoiceGate({required bool enabled});
+
   /// shuts down the entire rust backend
   Future<void> shutdown();
 
@@ -146,11 +172,18 @@ abstract class Telepathy implements RustOpaqueInterface {
   /// prevents silent reintroduction of blocking semantics.
   Future<void> startManager();
 
+  Future<void> startRecording(
+      {required String path,
+      required RecordingFormat format,
+      required RecordingLayout layout});
+
   Future<void> startScreenshare({required Contact contact});
 
   /// Tries to start a session for a contact
   Future<void> startSession({required Contact contact});
 
+  Future<void> stopRecording();
+
   /// Stops a specific session (called when a contact is deleted)
   Future<void> stopSession({required Contact contact});
 }
@@ -113,11 +113,21 @@ abstract class RustLibApi extends BaseApi {
 
   String crateTypesChatMessageTime({required ChatMessage that});
 
+  int crateTypesCodecConfigChannels({required CodecConfig that});
+
+  AudioCodec crateTypesCodecConfigCodec({required CodecConfig that});
+
   Future<CodecConfig> crateTypesCodecConfigDefault();
 
   CodecConfig crateTypesCodecConfigNew(
       {required bool enabled, required bool vbr, required double residualBits});
 
+  void crateTypesCodecConfigSetChannels(
+      {required CodecConfig that, required int channels});
+
+  void crateTypesCodecConfigSetCodec(
+      {required CodecConfig that, required AudioCodec codec});
+
   void crateTypesCodecConfigSetEnabled(
       {required CodecConfig that, required bool enabled});
 
@@ -170,10 +180,38 @@ abstract class RustLibApi extends BaseApi {
       required FutureOr<void> Function(ChatMessage) messageReceived,
       required FutureOr<void> Function(ManagerState) managerActive,
       required FutureOr<void> Function((FrontendNotify, bool))
-          screenshareStarted});
+          screenshareStarted,
+      required FutureOr<void> Function(RecordingState) recordingState,
+      required FutureOr<void> Function(DeviceEvent) deviceEvent,
+      required FutureOr<void> Function(List<PeerStatistics>) peerStatistics});
 
   void cratePlayerFlutterSoundHandleCancel({required FlutterSoundHandle that});
 
+  BigInt cratePlayerFlutterSoundHandleDurationMs(
+      {required FlutterSoundHandle that});
+
+  bool cratePlayerFlutterSoundHandleIsLooping(
+      {required FlutterSoundHandle that});
+
+  bool cratePlayerFlutterSoundHandleIsPaused(
+      {required FlutterSoundHandle that});
+
+  void cratePlayerFlutterSoundHandlePause({required FlutterSoundHandle that});
+
+  BigInt cratePlayerFlutterSoundHandlePositionMs(
+      {required FlutterSoundHandle that});
+
+  void cratePlayerFlutterSoundHandleResume({required FlutterSoundHandle that});
+
+  void cratePlayerFlutterSoundHandleSeek(
+      {required FlutterSoundHandle that, required BigInt positionMs});
+
+  void cratePlayerFlutterSoundHandleSetLooping(
+      {required FlutterSoundHandle that, required bool looping});
+
+  void cratePlayerFlutterSoundHandleSetVolume(
+      {required FlutterSoundHandle that, required double volume});
+
   Future<void> crateTypesFrontendNotifyNotified({required FrontendNotify that});
 
   void crateTypesFrontendNotifyNotify({required FrontendNotify that});
@@ -342,6 +380,9 @@ abstract class RustLibApi extends BaseApi {
   Future<void> crateFlutterTelepathySendChat(
       {required Telepathy that, required ChatMessage message});
 
+  void crateFlutterTelepathySetAutoGain(
+      {required Telepathy that, required bool enabled});
+
   void crateFlutterTelepathySetContactOutputVolume(
       {required Telepathy that, required Contact contact});
 
@@ -351,6 +392,9 @@ abstract class RustLibApi extends BaseApi {
   void crateFlutterTelepathySetDenoise(
       {required Telepathy that, required bool denoise});
 
+  void crateFlutterTelepathySetEchoCancellation(
+      {required Telepathy that, required bool enabled});
+
   void crateFlutterTelepathySetEfficiencyMode(
       {required Telepathy that, required bool enabled});
 
@@ -363,6 +407,9 @@ abstract class RustLibApi extends BaseApi {
   void crateFlutterTelepathySetInputVolume(
       {required Telepathy that, required double decibel});
 
+  void crateFlutterTelepathySetJitterBufferBounds(
+      {required Telepathy that, required int minMs, required int maxMs});
+
   Future<void> crateFlutterTelepathySetModel(
       {required Telepathy that, Uint8List? model});
 
@@ -378,12 +425,18 @@ abstract class RustLibApi extends BaseApi {
   void crateFlutterTelepathySetPlayCustomRingtones(
       {required Telepathy that, required bool play});
 
+  void crateFlutterTelepathySetRedundancy(
+      {required Telepathy that, required bool enabled});
+
   void crateFlutterTelepathySetRmsThreshold(
       {required Telepathy that, required double decimal});
 
   void crateFlutterTelepathySetSendCustomRingtone(
       {required Telepathy that, required bool send});
 
+  void crateFlutterTelepathySetVoiceGate(
+      {required Telepathy that, required bool enabled});
+
   Future<void> crateFlutterTelepathyShutdown({required Telepathy that});
 
   Future<void> crateFlutterTelepathyStartCall(
@@ -393,12 +446,20 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateFlutterTelepathyStartManager({required Telepathy that});
 
+  Future<void> crateFlutterTelepathyStartRecording(
+      {required Telepathy that,
+      required String path,
+      required RecordingFormat format,
+      required RecordingLayout layout});
+
   Future<void> crateFlutterTelepathyStartScreenshare(
       {required Telepathy that, required Contact contact});
 
   Future<void> crateFlutterTelepathyStartSession(
       {required Telepathy that, required Contact contact});
 
+  Future<void> crateFlutterTelepathyStopRecording({required Telepathy that});
+
   Future<void> crateFlutterTelepathyStopSession(
       {required Telepathy that, required Contact contact});
 
@@ -849,13 +910,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that'],
       );
 
+  @override
+  int crateTypesCodecConfigChannels({required CodecConfig that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_16,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTypesCodecConfigChannelsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTypesCodecConfigChannelsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'CodecConfig_channels',
+        argNames: ['that'],
+      );
+
+  @override
+  AudioCodec crateTypesCodecConfigCodec({required CodecConfig that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_audio_codec,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTypesCodecConfigCodecConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTypesCodecConfigCodecConstMeta =>
+      const TaskConstMeta(
+        debugName: 'CodecConfig_codec',
+        argNames: ['that'],
+      );
+
   @override
   Future<CodecConfig> crateTypesCodecConfigDefault() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
+            funcId: 14, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -885,7 +996,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_bool(enabled, serializer);
         sse_encode_bool(vbr, serializer);
         sse_encode_f_32(residualBits, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -903,6 +1014,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['enabled', 'vbr', 'residualBits'],
       );
 
+  @override
+  void crateTypesCodecConfigSetChannels(
+      {required CodecConfig that, required int channels}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
+            that, serializer);
+        sse_encode_u_16(channels, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTypesCodecConfigSetChannelsConstMeta,
+      argValues: [that, channels],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTypesCodecConfigSetChannelsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'CodecConfig_set_channels',
+        argNames: ['that', 'channels'],
+      );
+
+  @override
+  void crateTypesCodecConfigSetCodec(
+      {required CodecConfig that, required AudioCodec codec}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
+            that, serializer);
+        sse_encode_audio_codec(codec, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTypesCodecConfigSetCodecConstMeta,
+      argValues: [that, codec],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTypesCodecConfigSetCodecConstMeta =>
+      const TaskConstMeta(
+        debugName: 'CodecConfig_set_codec',
+        argNames: ['that', 'codec'],
+      );
+
   @override
   void crateTypesCodecConfigSetEnabled(
       {required CodecConfig that, required bool enabled}) {
@@ -912,7 +1077,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
             that, serializer);
         sse_encode_bool(enabled, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -939,7 +1104,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
             that, serializer);
         sse_encode_f_32(residualBits, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -966,7 +1131,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
             that, serializer);
         sse_encode_bool(vbr, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -992,7 +1157,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_record_bool_bool_f_32,
@@ -1023,7 +1188,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(nickname, serializer);
         sse_encode_String(peerId, serializer);
         sse_encode_f_32(outputVolume, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1049,7 +1214,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 19, port: port_);
+            funcId: 23, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1074,7 +1239,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1099,7 +1264,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
         sse_encode_list_prim_u_8_loose(id, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_bool,
@@ -1124,7 +1289,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(nickname, serializer);
         sse_encode_String(peerId, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1149,7 +1314,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1173,7 +1338,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_f_32,
@@ -1198,7 +1363,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1222,7 +1387,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1249,7 +1414,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
         sse_encode_String(nickname, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1276,7 +1441,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
         sse_encode_f_32(decibel, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1306,7 +1471,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       required FutureOr<void> Function(ChatMessage) messageReceived,
       required FutureOr<void> Function(ManagerState) managerActive,
       required FutureOr<void> Function((FrontendNotify, bool))
-          screenshareStarted}) {
+          screenshareStarted,
+      required FutureOr<void> Function(RecordingState) recordingState,
+      required FutureOr<void> Function(DeviceEvent) deviceEvent,
+      required FutureOr<void> Function(List<PeerStatistics>) peerStatistics}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -1328,7 +1496,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             managerActive, serializer);
         sse_encode_DartFn_Inputs_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_frontend_notify_bool_Output_unit_AnyhowException(
             screenshareStarted, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+        sse_encode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(
+            recordingState, serializer);
+        sse_encode_DartFn_Inputs_device_event_Output_unit_AnyhowException(
+            deviceEvent, serializer);
+        sse_encode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(
+            peerStatistics, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1345,7 +1519,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         statistics,
         messageReceived,
         managerActive,
-        screenshareStarted
+        screenshareStarted,
+        recordingState,
+        deviceEvent,
+        peerStatistics
       ],
       apiImpl: this,
     ));
@@ -1363,7 +1540,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           'statistics',
           'messageReceived',
           'managerActive',
-          'screenshareStarted'
+          'screenshareStarted',
+          'recordingState',
+          'deviceEvent',
+          'peerStatistics'
         ],
       );
 
@@ -1374,7 +1554,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1392,6 +1572,241 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that'],
       );
 
+  @override
+  BigInt cratePlayerFlutterSoundHandleDurationMs(
+      {required FlutterSoundHandle that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandleDurationMsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandleDurationMsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_duration_ms',
+        argNames: ['that'],
+      );
+
+  @override
+  bool cratePlayerFlutterSoundHandleIsLooping(
+      {required FlutterSoundHandle that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandleIsLoopingConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandleIsLoopingConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_is_looping',
+        argNames: ['that'],
+      );
+
+  @override
+  bool cratePlayerFlutterSoundHandleIsPaused(
+      {required FlutterSoundHandle that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandleIsPausedConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandleIsPausedConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_is_paused',
+        argNames: ['that'],
+      );
+
+  @override
+  void cratePlayerFlutterSoundHandlePause({required FlutterSoundHandle that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandlePauseConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandlePauseConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_pause',
+        argNames: ['that'],
+      );
+
+  @override
+  BigInt cratePlayerFlutterSoundHandlePositionMs(
+      {required FlutterSoundHandle that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandlePositionMsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandlePositionMsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_position_ms',
+        argNames: ['that'],
+      );
+
+  @override
+  void cratePlayerFlutterSoundHandleResume({required FlutterSoundHandle that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandleResumeConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandleResumeConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_resume',
+        argNames: ['that'],
+      );
+
+  @override
+  void cratePlayerFlutterSoundHandleSeek(
+      {required FlutterSoundHandle that, required BigInt positionMs}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        sse_encode_u_64(positionMs, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandleSeekConstMeta,
+      argValues: [that, positionMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandleSeekConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_seek',
+        argNames: ['that', 'positionMs'],
+      );
+
+  @override
+  void cratePlayerFlutterSoundHandleSetLooping(
+      {required FlutterSoundHandle that, required bool looping}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        sse_encode_bool(looping, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandleSetLoopingConstMeta,
+      argValues: [that, looping],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandleSetLoopingConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_set_looping',
+        argNames: ['that', 'looping'],
+      );
+
+  @override
+  void cratePlayerFlutterSoundHandleSetVolume(
+      {required FlutterSoundHandle that, required double volume}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterSoundHandle(
+            that, serializer);
+        sse_encode_f_32(volume, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCratePlayerFlutterSoundHandleSetVolumeConstMeta,
+      argValues: [that, volume],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCratePlayerFlutterSoundHandleSetVolumeConstMeta =>
+      const TaskConstMeta(
+        debugName: 'FlutterSoundHandle_set_volume',
+        argNames: ['that', 'volume'],
+      );
+
   @override
   Future<void> crateTypesFrontendNotifyNotified(
       {required FrontendNotify that}) {
@@ -1401,7 +1816,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFrontendNotify(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 31, port: port_);
+            funcId: 44, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1426,7 +1841,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFrontendNotify(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1450,7 +1865,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 33, port: port_);
+            funcId: 46, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1477,7 +1892,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_String,
@@ -1502,7 +1917,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_String,
@@ -1528,7 +1943,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_String,
@@ -1553,7 +1968,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_16,
@@ -1578,7 +1993,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_String,
@@ -1604,7 +2019,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_list_String,
@@ -1639,7 +2054,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_opt_String(dnsEndpoint, serializer);
         sse_encode_opt_String(dnsOriginDomain, serializer);
         sse_encode_opt_String(pkarrRelay, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1691,7 +2106,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_opt_String(dnsEndpoint, serializer);
         sse_encode_opt_String(dnsOriginDomain, serializer);
         sse_encode_opt_String(pkarrRelay, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1731,7 +2146,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 42, port: port_);
+            funcId: 55, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1757,7 +2172,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 43, port: port_);
+            funcId: 56, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1782,7 +2197,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 44, port: port_);
+            funcId: 57, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1807,7 +2222,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 45, port: port_);
+            funcId: 58, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1841,7 +2256,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_32(width, serializer);
         sse_encode_i_32(height, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 46, port: port_);
+            funcId: 59, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1881,7 +2296,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_32(backgroundColor, serializer);
         sse_encode_u_32(fontColor, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 47, port: port_);
+            funcId: 60, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1924,7 +2339,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_record_i_32_i_32,
@@ -1952,7 +2367,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_u_32(backgroundColor, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 49, port: port_);
+            funcId: 62, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1980,7 +2395,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_u_32(fontColor, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 50, port: port_);
+            funcId: 63, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2008,7 +2423,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_i_32(height, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 51, port: port_);
+            funcId: 64, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2034,7 +2449,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 52, port: port_);
+            funcId: 65, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2060,7 +2475,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedIdentitySwitch(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 53, port: port_);
+            funcId: 66, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2085,7 +2500,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -2110,7 +2525,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -2135,7 +2550,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -2160,7 +2575,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -2185,7 +2600,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
@@ -2212,7 +2627,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScreenshareConfig(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 59, port: port_);
+            funcId: 72, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2237,7 +2652,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 60, port: port_);
+            funcId: 73, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2264,7 +2679,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_list_prim_u_8_loose(buffer, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 61, port: port_);
+            funcId: 74, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2292,7 +2707,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScreenshareConfig(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 62, port: port_);
+            funcId: 75, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2319,7 +2734,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScreenshareConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -2356,7 +2771,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_32(framerate, serializer);
         sse_encode_opt_box_autoadd_u_32(height, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 64, port: port_);
+            funcId: 77, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2389,7 +2804,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSoundPlayer(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2413,7 +2828,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_f_32(outputVolume, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2441,7 +2856,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_list_prim_u_8_loose(bytes, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 67, port: port_);
+            funcId: 80, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2469,7 +2884,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_opt_String(deviceId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 68, port: port_);
+            funcId: 81, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2496,7 +2911,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSoundPlayer(
             that, serializer);
         sse_encode_f_32(volume, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2521,7 +2936,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStartOperation(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2547,7 +2962,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 71, port: port_);
+            funcId: 84, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2581,7 +2996,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(text, serializer);
         sse_encode_list_record_string_list_prim_u_8_strict(
             attachments, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2608,7 +3023,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 73, port: port_);
+            funcId: 86, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2640,7 +3055,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStartOperation(
             operation, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 74, port: port_);
+            funcId: 87, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2667,7 +3082,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 75, port: port_);
+            funcId: 88, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2709,7 +3124,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             codecConfig, serializer);
         sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFlutterCallbacks(
             callbacks, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2749,7 +3164,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2775,7 +3190,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2807,7 +3222,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             targetContacts, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 79, port: port_);
+            funcId: 92, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2834,7 +3249,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 80, port: port_);
+            funcId: 93, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2859,7 +3274,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2888,7 +3303,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChatMessage(
             message, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 82, port: port_);
+            funcId: 95, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2906,6 +3321,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that', 'message'],
       );
 
+  @override
+  void crateFlutterTelepathySetAutoGain(
+      {required Telepathy that, required bool enabled}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateFlutterTelepathySetAutoGainConstMeta,
+      argValues: [that, enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateFlutterTelepathySetAutoGainConstMeta =>
+      const TaskConstMeta(
+        debugName: 'Telepathy_set_auto_gain',
+        argNames: ['that', 'enabled'],
+      );
+
   @override
   void crateFlutterTelepathySetContactOutputVolume(
       {required Telepathy that, required Contact contact}) {
@@ -2916,7 +3358,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             contact, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2943,7 +3385,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(deafened, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2970,7 +3412,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(denoise, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2982,10 +3424,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     ));
   }
 
-  TaskConstMeta get kCrateFlutterTelepathySetDenoiseConstMeta =>
+  TaskConstMeta get kCrateFlutterTelepathySetDenoiseConstMeta =>
+      const TaskConstMeta(
+        debugName: 'Telepathy_set_denoise',
+        argNames: ['that', 'denoise'],
+      );
+
+  @override
+  void crateFlutterTelepathySetEchoCancellation(
+      {required Telepathy that, required bool enabled}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateFlutterTelepathySetEchoCancellationConstMeta,
+      argValues: [that, enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateFlutterTelepathySetEchoCancellationConstMeta =>
       const TaskConstMeta(
-        debugName: 'Telepathy_set_denoise',
-        argNames: ['that', 'denoise'],
+        debugName: 'Telepathy_set_echo_cancellation',
+        argNames: ['that', 'enabled'],
       );
 
   @override
@@ -2997,7 +3466,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(enabled, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3025,7 +3494,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 87, port: port_);
+            funcId: 102, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3053,7 +3522,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_opt_String(deviceId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 88, port: port_);
+            funcId: 103, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3080,7 +3549,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_f_32(decibel, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3098,6 +3567,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that', 'decibel'],
       );
 
+  @override
+  void crateFlutterTelepathySetJitterBufferBounds(
+      {required Telepathy that, required int minMs, required int maxMs}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_u_32(minMs, serializer);
+        sse_encode_u_32(maxMs, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateFlutterTelepathySetJitterBufferBoundsConstMeta,
+      argValues: [that, minMs, maxMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateFlutterTelepathySetJitterBufferBoundsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'Telepathy_set_jitter_buffer_bounds',
+        argNames: ['that', 'minMs', 'maxMs'],
+      );
+
   @override
   Future<void> crateFlutterTelepathySetModel(
       {required Telepathy that, Uint8List? model}) {
@@ -3108,7 +3605,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_opt_list_prim_u_8_strict(model, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 90, port: port_);
+            funcId: 106, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3135,7 +3632,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(muted, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3163,7 +3660,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_opt_String(deviceId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 92, port: port_);
+            funcId: 108, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3190,7 +3687,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_f_32(decibel, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3217,7 +3714,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(play, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3235,6 +3732,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that', 'play'],
       );
 
+  @override
+  void crateFlutterTelepathySetRedundancy(
+      {required Telepathy that, required bool enabled}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateFlutterTelepathySetRedundancyConstMeta,
+      argValues: [that, enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateFlutterTelepathySetRedundancyConstMeta =>
+      const TaskConstMeta(
+        debugName: 'Telepathy_set_redundancy',
+        argNames: ['that', 'enabled'],
+      );
+
   @override
   void crateFlutterTelepathySetRmsThreshold(
       {required Telepathy that, required double decimal}) {
@@ -3244,7 +3768,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_f_32(decimal, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3271,7 +3795,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(send, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3289,6 +3813,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that', 'send'],
       );
 
+  @override
+  void crateFlutterTelepathySetVoiceGate(
+      {required Telepathy that, required bool enabled}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateFlutterTelepathySetVoiceGateConstMeta,
+      argValues: [that, enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateFlutterTelepathySetVoiceGateConstMeta =>
+      const TaskConstMeta(
+        debugName: 'Telepathy_set_voice_gate',
+        argNames: ['that', 'enabled'],
+      );
+
   @override
   Future<void> crateFlutterTelepathyShutdown({required Telepathy that}) {
     return handler.executeNormal(NormalTask(
@@ -3297,7 +3848,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 97, port: port_);
+            funcId: 115, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3330,7 +3881,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStartOperation(
             operation, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 98, port: port_);
+            funcId: 116, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3356,7 +3907,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 99, port: port_);
+            funcId: 117, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3374,6 +3925,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that'],
       );
 
+  @override
+  Future<void> crateFlutterTelepathyStartRecording(
+      {required Telepathy that,
+      required String path,
+      required RecordingFormat format,
+      required RecordingLayout layout}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_recording_format(format, serializer);
+        sse_encode_recording_layout(layout, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 118, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_dart_error,
+      ),
+      constMeta: kCrateFlutterTelepathyStartRecordingConstMeta,
+      argValues: [that, path, format, layout],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateFlutterTelepathyStartRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: 'Telepathy_start_recording',
+        argNames: ['that', 'path', 'format', 'layout'],
+      );
+
   @override
   Future<void> crateFlutterTelepathyStartScreenshare(
       {required Telepathy that, required Contact contact}) {
@@ -3385,7 +3969,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             contact, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 100, port: port_);
+            funcId: 119, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3414,7 +3998,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             contact, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 101, port: port_);
+            funcId: 120, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3432,6 +4016,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['that', 'contact'],
       );
 
+  @override
+  Future<void> crateFlutterTelepathyStopRecording({required Telepathy that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 121, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_dart_error,
+      ),
+      constMeta: kCrateFlutterTelepathyStopRecordingConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateFlutterTelepathyStopRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: 'Telepathy_stop_recording',
+        argNames: ['that'],
+      );
+
   @override
   Future<void> crateFlutterTelepathyStopSession(
       {required Telepathy that, required Contact contact}) {
@@ -3443,7 +4053,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             contact, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 102, port: port_);
+            funcId: 122, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3468,7 +4078,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_StreamSink_String_Sse(s, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3492,7 +4102,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_record_string_list_prim_u_8_strict,
@@ -3517,7 +4127,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 105, port: port_);
+            funcId: 125, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3540,7 +4150,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_list_String(peers, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -3562,7 +4172,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3586,7 +4196,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 108, port: port_);
+            funcId: 128, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_bool,
@@ -3610,7 +4220,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 109, port: port_);
+            funcId: 129, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_statistics,
@@ -3634,7 +4244,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(peerId, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_bool,
@@ -3720,6 +4330,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     };
   }
 
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_device_event_Output_unit_AnyhowException(
+          FutureOr<void> Function(DeviceEvent) raw) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_device_event(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException('$e\n\n$s'));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(
+          FutureOr<void> Function(List<PeerStatistics>) raw) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_list_peer_statistics(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException('$e\n\n$s'));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
   Future<void> Function(int, dynamic)
       encode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
           FutureOr<Contact?> Function(Uint8List) raw) {
@@ -3890,6 +4566,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     };
   }
 
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(
+          FutureOr<void> Function(RecordingState) raw) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_recording_state(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException('$e\n\n$s'));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
   Future<void> Function(int, dynamic)
       encode_DartFn_Inputs_statistics_Output_unit_AnyhowException(
           FutureOr<void> Function(Statistics) raw) {
@@ -4389,6 +5098,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('');
   }
 
+  @protected
+  FutureOr<void> Function(DeviceEvent)
+      dco_decode_DartFn_Inputs_device_event_Output_unit_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(List<PeerStatistics>)
+      dco_decode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
   @protected
   FutureOr<Contact?> Function(Uint8List)
       dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
@@ -4429,6 +5154,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('');
   }
 
+  @protected
+  FutureOr<void> Function(RecordingState)
+      dco_decode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
   @protected
   FutureOr<void> Function(Statistics)
       dco_decode_DartFn_Inputs_statistics_Output_unit_AnyhowException(
@@ -4600,6 +5333,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as String;
   }
 
+  @protected
+  AudioCodec dco_decode_audio_codec(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioCodec.values[raw as int];
+  }
+
   @protected
   AudioDevice dco_decode_audio_device(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4636,6 +5375,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw);
   }
 
+  @protected
+  AudioDevice dco_decode_box_autoadd_audio_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_device(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4679,6 +5424,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  DeviceEvent dco_decode_device_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DeviceEvent_Added(
+          input: dco_decode_bool(raw[1]),
+          device: dco_decode_box_autoadd_audio_device(raw[2]),
+        );
+      case 1:
+        return DeviceEvent_Removed(
+          input: dco_decode_bool(raw[1]),
+          device: dco_decode_box_autoadd_audio_device(raw[2]),
+        );
+      case 2:
+        return DeviceEvent_DefaultChanged(
+          input: dco_decode_bool(raw[1]),
+          device: dco_decode_opt_box_autoadd_audio_device(raw[2]),
+        );
+      case 3:
+        return DeviceEvent_Switched(
+          input: dco_decode_bool(raw[1]),
+          device: dco_decode_opt_box_autoadd_audio_device(raw[2]),
+        );
+      default:
+        throw Exception('unreachable');
+    }
+  }
+
   @protected
   double dco_decode_f_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4720,6 +5494,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_audio_device).toList();
   }
 
+  @protected
+  List<PeerStatistics> dco_decode_list_peer_statistics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_statistics).toList();
+  }
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4793,6 +5573,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             raw);
   }
 
+  @protected
+  AudioDevice? dco_decode_opt_box_autoadd_audio_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_device(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4811,6 +5597,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
   }
 
+  @protected
+  PeerStatistics dco_decode_peer_statistics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PeerStatistics(
+      peer: dco_decode_String(arr[0]),
+      latency: dco_decode_usize(arr[1]),
+      packetLoss: dco_decode_f_32(arr[2]),
+      jitter: dco_decode_f_32(arr[3]),
+      outputLevel: dco_decode_f_32(arr[4]),
+      speaking: dco_decode_bool(arr[5]),
+    );
+  }
+
   @protected
   (
     FrontendNotify,
@@ -4917,6 +5719,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RecordingFormat dco_decode_recording_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecordingFormat.values[raw as int];
+  }
+
+  @protected
+  RecordingLayout dco_decode_recording_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecordingLayout.values[raw as int];
+  }
+
+  @protected
+  RecordingState dco_decode_recording_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return RecordingState_Started(
+          dco_decode_String(raw[1]),
+        );
+      case 1:
+        return RecordingState_Stopped(
+          dco_decode_list_String(raw[1]),
+        );
+      case 2:
+        return RecordingState_Failed(
+          dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception('unreachable');
+    }
+  }
+
   @protected
   SessionStatus dco_decode_session_status(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4941,15 +5776,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Statistics dco_decode_statistics(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
     return Statistics(
       inputLevel: dco_decode_f_32(arr[0]),
       outputLevel: dco_decode_f_32(arr[1]),
-      latency: dco_decode_usize(arr[2]),
-      uploadBandwidth: dco_decode_usize(arr[3]),
-      downloadBandwidth: dco_decode_usize(arr[4]),
-      loss: dco_decode_usize(arr[5]),
+      gain: dco_decode_f_32(arr[2]),
+      latency: dco_decode_usize(arr[3]),
+      uploadBandwidth: dco_decode_usize(arr[4]),
+      downloadBandwidth: dco_decode_usize(arr[5]),
+      loss: dco_decode_usize(arr[6]),
+      jitterDelay: dco_decode_usize(arr[7]),
+      packetLoss: dco_decode_f_32(arr[8]),
+      jitter: dco_decode_f_32(arr[9]),
+      relayed: dco_decode_bool(arr[10]),
+      pathChanges: dco_decode_usize(arr[11]),
+      congestionWindow: dco_decode_usize(arr[12]),
+      bytesSent: dco_decode_usize(arr[13]),
+      bytesReceived: dco_decode_usize(arr[14]),
     );
   }
 
@@ -4965,6 +5809,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as int;
   }
 
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
   @protected
   int dco_decode_u_8(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -5470,6 +6320,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  AudioCodec sse_decode_audio_codec(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AudioCodec.values[inner];
+  }
+
   @protected
   AudioDevice sse_decode_audio_device(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5502,6 +6359,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         deserializer));
   }
 
+  @protected
+  AudioDevice sse_decode_box_autoadd_audio_device(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_device(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5540,6 +6404,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return DartError(message: var_message);
   }
 
+  @protected
+  DeviceEvent sse_decode_device_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_input = sse_decode_bool(deserializer);
+        var var_device = sse_decode_box_autoadd_audio_device(deserializer);
+        return DeviceEvent_Added(input: var_input, device: var_device);
+      case 1:
+        var var_input = sse_decode_bool(deserializer);
+        var var_device = sse_decode_box_autoadd_audio_device(deserializer);
+        return DeviceEvent_Removed(input: var_input, device: var_device);
+      case 2:
+        var var_input = sse_decode_bool(deserializer);
+        var var_device = sse_decode_opt_box_autoadd_audio_device(deserializer);
+        return DeviceEvent_DefaultChanged(input: var_input, device: var_device);
+      case 3:
+        var var_input = sse_decode_bool(deserializer);
+        var var_device = sse_decode_opt_box_autoadd_audio_device(deserializer);
+        return DeviceEvent_Switched(input: var_input, device: var_device);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5598,6 +6489,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<PeerStatistics> sse_decode_list_peer_statistics(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerStatistics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_statistics(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5688,6 +6592,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AudioDevice? sse_decode_opt_box_autoadd_audio_device(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_device(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5721,6 +6637,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  PeerStatistics sse_decode_peer_statistics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peer = sse_decode_String(deserializer);
+    var var_latency = sse_decode_usize(deserializer);
+    var var_packetLoss = sse_decode_f_32(deserializer);
+    var var_jitter = sse_decode_f_32(deserializer);
+    var var_outputLevel = sse_decode_f_32(deserializer);
+    var var_speaking = sse_decode_bool(deserializer);
+    return PeerStatistics(
+        peer: var_peer,
+        latency: var_latency,
+        packetLoss: var_packetLoss,
+        jitter: var_jitter,
+        outputLevel: var_outputLevel,
+        speaking: var_speaking);
+  }
+
   @protected
   (
     FrontendNotify,
@@ -5797,6 +6731,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  RecordingFormat sse_decode_recording_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RecordingFormat.values[inner];
+  }
+
+  @protected
+  RecordingLayout sse_decode_recording_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RecordingLayout.values[inner];
+  }
+
+  @protected
+  RecordingState sse_decode_recording_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return RecordingState_Started(var_field0);
+      case 1:
+        var var_field0 = sse_decode_list_String(deserializer);
+        return RecordingState_Stopped(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return RecordingState_Failed(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   SessionStatus sse_decode_session_status(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5824,17 +6792,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_inputLevel = sse_decode_f_32(deserializer);
     var var_outputLevel = sse_decode_f_32(deserializer);
+    var var_gain = sse_decode_f_32(deserializer);
     var var_latency = sse_decode_usize(deserializer);
     var var_uploadBandwidth = sse_decode_usize(deserializer);
     var var_downloadBandwidth = sse_decode_usize(deserializer);
     var var_loss = sse_decode_usize(deserializer);
+    var var_jitterDelay = sse_decode_usize(deserializer);
+    var var_packetLoss = sse_decode_f_32(deserializer);
+    var var_jitter = sse_decode_f_32(deserializer);
+    var var_relayed = sse_decode_bool(deserializer);
+    var var_pathChanges = sse_decode_usize(deserializer);
+    var var_congestionWindow = sse_decode_usize(deserializer);
+    var var_bytesSent = sse_decode_usize(deserializer);
+    var var_bytesReceived = sse_decode_usize(deserializer);
     return Statistics(
         inputLevel: var_inputLevel,
         outputLevel: var_outputLevel,
+        gain: var_gain,
         latency: var_latency,
         uploadBandwidth: var_uploadBandwidth,
         downloadBandwidth: var_downloadBandwidth,
-        loss: var_loss);
+        loss: var_loss,
+        jitterDelay: var_jitterDelay,
+        packetLoss: var_packetLoss,
+        jitter: var_jitter,
+        relayed: var_relayed,
+        pathChanges: var_pathChanges,
+        congestionWindow: var_congestionWindow,
+        bytesSent: var_bytesSent,
+        bytesReceived: var_bytesReceived);
   }
 
   @protected
@@ -5849,6 +6835,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint32();
   }
 
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6220,6 +7212,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void sse_encode_DartFn_Inputs_device_event_Output_unit_AnyhowException(
+      FutureOr<void> Function(DeviceEvent) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_device_event_Output_unit_AnyhowException(self),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(
+          FutureOr<void> Function(List<PeerStatistics>) self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(self),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
@@ -6277,6 +7289,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void sse_encode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(
+      FutureOr<void> Function(RecordingState) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(self),
+        serializer);
+  }
+
   @protected
   void sse_encode_DartFn_Inputs_statistics_Output_unit_AnyhowException(
       FutureOr<void> Function(Statistics) self, SseSerializer serializer) {
@@ -6488,6 +7509,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_audio_codec(AudioCodec self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_audio_device(AudioDevice self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6519,6 +7546,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_audio_device(
+      AudioDevice self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_device(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6552,6 +7586,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_device_event(DeviceEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DeviceEvent_Added(input: final input, device: final device):
+        sse_encode_i_32(0, serializer);
+        sse_encode_bool(input, serializer);
+        sse_encode_box_autoadd_audio_device(device, serializer);
+      case DeviceEvent_Removed(input: final input, device: final device):
+        sse_encode_i_32(1, serializer);
+        sse_encode_bool(input, serializer);
+        sse_encode_box_autoadd_audio_device(device, serializer);
+      case DeviceEvent_DefaultChanged(input: final input, device: final device):
+        sse_encode_i_32(2, serializer);
+        sse_encode_bool(input, serializer);
+        sse_encode_opt_box_autoadd_audio_device(device, serializer);
+      case DeviceEvent_Switched(input: final input, device: final device):
+        sse_encode_i_32(3, serializer);
+        sse_encode_bool(input, serializer);
+        sse_encode_opt_box_autoadd_audio_device(device, serializer);
+    }
+  }
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6601,6 +7658,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_peer_statistics(
+      List<PeerStatistics> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_statistics(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_prim_u_8_loose(
       List<int> self, SseSerializer serializer) {
@@ -6685,6 +7752,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_audio_device(
+      AudioDevice? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_device(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6717,6 +7795,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_peer_statistics(
+      PeerStatistics self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.peer, serializer);
+    sse_encode_usize(self.latency, serializer);
+    sse_encode_f_32(self.packetLoss, serializer);
+    sse_encode_f_32(self.jitter, serializer);
+    sse_encode_f_32(self.outputLevel, serializer);
+    sse_encode_bool(self.speaking, serializer);
+  }
+
   @protected
   void
       sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_frontend_notify_bool(
@@ -6778,6 +7868,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_session_status(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_recording_format(RecordingFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_recording_layout(RecordingLayout self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_recording_state(
+      RecordingState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case RecordingState_Started(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case RecordingState_Stopped(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_String(field0, serializer);
+      case RecordingState_Failed(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
   @protected
   void sse_encode_session_status(SessionStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6803,10 +7922,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_f_32(self.inputLevel, serializer);
     sse_encode_f_32(self.outputLevel, serializer);
+    sse_encode_f_32(self.gain, serializer);
     sse_encode_usize(self.latency, serializer);
     sse_encode_usize(self.uploadBandwidth, serializer);
     sse_encode_usize(self.downloadBandwidth, serializer);
     sse_encode_usize(self.loss, serializer);
+    sse_encode_usize(self.jitterDelay, serializer);
+    sse_encode_f_32(self.packetLoss, serializer);
+    sse_encode_f_32(self.jitter, serializer);
+    sse_encode_bool(self.relayed, serializer);
+    sse_encode_usize(self.pathChanges, serializer);
+    sse_encode_usize(self.congestionWindow, serializer);
+    sse_encode_usize(self.bytesSent, serializer);
+    sse_encode_usize(self.bytesReceived, serializer);
   }
 
   @protected
@@ -6821,6 +7949,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint32(self);
   }
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6961,6 +8095,21 @@ class CodecConfigImpl extends RustOpaque implements CodecConfig {
         RustLib.instance.api.rust_arc_decrement_strong_count_CodecConfigPtr,
   );
 
+  /// the configured channel count, mono when it is not supported
+  int channels() => RustLib.instance.api.crateTypesCodecConfigChannels(
+        that: this,
+      );
+
+  AudioCodec codec() => RustLib.instance.api.crateTypesCodecConfigCodec(
+        that: this,
+      );
+
+  void setChannels({required int channels}) => RustLib.instance.api
+      .crateTypesCodecConfigSetChannels(that: this, channels: channels);
+
+  void setCodec({required AudioCodec codec}) => RustLib.instance.api
+      .crateTypesCodecConfigSetCodec(that: this, codec: codec);
+
   void setEnabled({required bool enabled}) => RustLib.instance.api
       .crateTypesCodecConfigSetEnabled(that: this, enabled: enabled);
 
@@ -7078,6 +8227,51 @@ class FlutterSoundHandleImpl extends RustOpaque implements FlutterSoundHandle {
   void cancel() => RustLib.instance.api.cratePlayerFlutterSoundHandleCancel(
         that: this,
       );
+
+  /// The duration of the sound in milliseconds.
+  BigInt durationMs() =>
+      RustLib.instance.api.cratePlayerFlutterSoundHandleDurationMs(
+        that: this,
+      );
+
+  /// Returns true while the sound loops.
+  bool isLooping() =>
+      RustLib.instance.api.cratePlayerFlutterSoundHandleIsLooping(
+        that: this,
+      );
+
+  /// Returns true while playback is paused.
+  bool isPaused() => RustLib.instance.api.cratePlayerFlutterSoundHandleIsPaused(
+        that: this,
+      );
+
+  /// Pauses playback without closing the output stream.
+  void pause() => RustLib.instance.api.cratePlayerFlutterSoundHandlePause(
+        that: this,
+      );
+
+  /// The playback position in milliseconds.
+  BigInt positionMs() =>
+      RustLib.instance.api.cratePlayerFlutterSoundHandlePositionMs(
+        that: this,
+      );
+
+  /// Resumes paused playback.
+  void resume() => RustLib.instance.api.cratePlayerFlutterSoundHandleResume(
+        that: this,
+      );
+
+  /// Jumps to a position in milliseconds, clamped to the duration.
+  void seek({required BigInt positionMs}) => RustLib.instance.api
+      .cratePlayerFlutterSoundHandleSeek(that: this, positionMs: positionMs);
+
+  /// Enables or disables looping.
+  void setLooping({required bool looping}) => RustLib.instance.api
+      .cratePlayerFlutterSoundHandleSetLooping(that: this, looping: looping);
+
+  /// Sets the volume of this sound in decibels, on top of the player volume.
+  void setVolume({required double volume}) => RustLib.instance.api
+      .cratePlayerFlutterSoundHandleSetVolume(that: this, volume: volume);
 }
 
 @sealed
@@ -7526,6 +8720,10 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
       );
 
   /// The only entry point into participating in a room.
+  ///
+  /// Rooms ignore the codec config: every member decodes the same stream, so
+  /// rooms always send mono SEA at 5 residual bits, which every client
+  /// (including web) supports. Rooms do not adapt the bitrate to loss.
   Future<void> joinRoom(
           {required List<String> memberStrings,
           required StartOperation operation}) =>
@@ -7570,6 +8768,10 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   Future<void> sendChat({required ChatMessage message}) => RustLib.instance.api
       .crateFlutterTelepathySendChat(that: this, message: message);
 
+  /// Auto gain is set on the processor; the current call is not reconfigured.
+  void setAutoGain({required bool enabled}) => RustLib.instance.api
+      .crateFlutterTelepathySetAutoGain(that: this, enabled: enabled);
+
   void setContactOutputVolume({required Contact contact}) =>
       RustLib.instance.api.crateFlutterTelepathySetContactOutputVolume(
           that: this, contact: contact);
@@ -7581,6 +8783,10 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   void setDenoise({required bool denoise}) => RustLib.instance.api
       .crateFlutterTelepathySetDenoise(that: this, denoise: denoise);
 
+  /// Echo cancellation is set on the processors; the current call is not reconfigured.
+  void setEchoCancellation({required bool enabled}) => RustLib.instance.api
+      .crateFlutterTelepathySetEchoCancellation(that: this, enabled: enabled);
+
   void setEfficiencyMode({required bool enabled}) => RustLib.instance.api
       .crateFlutterTelepathySetEfficiencyMode(that: this, enabled: enabled);
 
@@ -7594,6 +8800,11 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   void setInputVolume({required double decibel}) => RustLib.instance.api
       .crateFlutterTelepathySetInputVolume(that: this, decibel: decibel);
 
+  /// The bounds are read when a call or room connects.
+  void setJitterBufferBounds({required int minMs, required int maxMs}) =>
+      RustLib.instance.api.crateFlutterTelepathySetJitterBufferBounds(
+          that: this, minMs: minMs, maxMs: maxMs);
+
   Future<void> setModel({Uint8List? model}) => RustLib.instance.api
       .crateFlutterTelepathySetModel(that: this, model: model);
 
@@ -7609,12 +8820,20 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   void setPlayCustomRingtones({required bool play}) => RustLib.instance.api
       .crateFlutterTelepathySetPlayCustomRingtones(that: this, play: play);
 
+  /// Read when a call or room connects.
+  void setRedundancy({required bool enabled}) => RustLib.instance.api
+      .crateFlutterTelepathySetRedundancy(that: this, enabled: enabled);
+
   void setRmsThreshold({required double decimal}) => RustLib.instance.api
       .crateFlutterTelepathySetRmsThreshold(that: this, decimal: decimal);
 
   void setSendCustomRingtone({required bool send}) => RustLib.instance.api
       .crateFlutterTelepathySetSendCustomRingtone(that: this, send: send);
 
+  /// The voice gate is set on the processor; the current call is not reconfigured.
+  void setVoiceGate({required bool enabled}) => RustLib.instance.api
+      .crateFlutterTelepathySetVoiceGate(that: this, enabled: enabled);
+
   /// shuts down the entire rust backend
   Future<void> shutdown() => RustLib.instance.api.crateFlutterTelepathyShutdown(
         that: this,
@@ -7636,6 +8855,13 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
         that: this,
       );
 
+  Future<void> startRecording(
+          {required String path,
+          required RecordingFormat format,
+          required RecordingLayout layout}) =>
+      RustLib.instance.api.crateFlutterTelepathyStartRecording(
+          that: this, path: path, format: format, layout: layout);
+
   Future<void> startScreenshare({required Contact contact}) =>
       RustLib.instance.api
           .crateFlutterTelepathyStartScreenshare(that: this, contact: contact);
@@ -7644,6 +8870,11 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   Future<void> startSession({required Contact contact}) => RustLib.instance.api
       .crateFlutterTelepathyStartSession(that: this, contact: contact);
 
+  Future<void> stopRecording() =>
+      RustLib.instance.api.crateFlutterTelepathyStopRecording(
+        that: this,
+      );
+
   /// Stops a specific session (called when a contact is deleted)
   Future<void> stopSession({required Contact contact}) => RustLib.instance.api
       .crateFlutterTelepathyStopSession(that: this, contact: contact);
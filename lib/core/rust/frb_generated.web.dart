@@ -272,6 +272,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_DartFn_Inputs_call_state_Output_unit_AnyhowException(
           dynamic raw);
 
+  @protected
+  FutureOr<void> Function(DeviceEvent)
+      dco_decode_DartFn_Inputs_device_event_Output_unit_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<void> Function(List<PeerStatistics>)
+      dco_decode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(
+          dynamic raw);
+
   @protected
   FutureOr<Contact?> Function(Uint8List)
       dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
@@ -297,6 +307,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_DartFn_Inputs_record_string_session_status_Output_unit_AnyhowException(
           dynamic raw);
 
+  @protected
+  FutureOr<void> Function(RecordingState)
+      dco_decode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(
+          dynamic raw);
+
   @protected
   FutureOr<void> Function(Statistics)
       dco_decode_DartFn_Inputs_statistics_Output_unit_AnyhowException(
@@ -401,6 +416,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AudioCodec dco_decode_audio_codec(dynamic raw);
+
   @protected
   AudioDevice dco_decode_audio_device(dynamic raw);
 
@@ -417,6 +435,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           dynamic raw);
 
+  @protected
+  AudioDevice dco_decode_box_autoadd_audio_device(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
@@ -426,6 +447,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DartError dco_decode_dart_error(dynamic raw);
 
+  @protected
+  DeviceEvent dco_decode_device_event(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
@@ -446,6 +470,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<AudioDevice> dco_decode_list_audio_device(dynamic raw);
 
+  @protected
+  List<PeerStatistics> dco_decode_list_peer_statistics(dynamic raw);
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
@@ -478,6 +505,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           dynamic raw);
 
+  @protected
+  AudioDevice? dco_decode_opt_box_autoadd_audio_device(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
@@ -487,6 +517,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  PeerStatistics dco_decode_peer_statistics(dynamic raw);
+
   @protected
   (
     FrontendNotify,
@@ -519,6 +552,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, SessionStatus) dco_decode_record_string_session_status(dynamic raw);
 
+  @protected
+  RecordingFormat dco_decode_recording_format(dynamic raw);
+
+  @protected
+  RecordingLayout dco_decode_recording_layout(dynamic raw);
+
+  @protected
+  RecordingState dco_decode_recording_state(dynamic raw);
+
   @protected
   SessionStatus dco_decode_session_status(dynamic raw);
 
@@ -531,6 +573,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int dco_decode_u_32(dynamic raw);
 
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -808,6 +853,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AudioCodec sse_decode_audio_codec(SseDeserializer deserializer);
+
   @protected
   AudioDevice sse_decode_audio_device(SseDeserializer deserializer);
 
@@ -824,6 +872,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           SseDeserializer deserializer);
 
+  @protected
+  AudioDevice sse_decode_box_autoadd_audio_device(
+      SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -833,6 +885,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DartError sse_decode_dart_error(SseDeserializer deserializer);
 
+  @protected
+  DeviceEvent sse_decode_device_event(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer);
 
@@ -853,6 +908,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<AudioDevice> sse_decode_list_audio_device(SseDeserializer deserializer);
 
+  @protected
+  List<PeerStatistics> sse_decode_list_peer_statistics(
+      SseDeserializer deserializer);
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
@@ -887,6 +946,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           SseDeserializer deserializer);
 
+  @protected
+  AudioDevice? sse_decode_opt_box_autoadd_audio_device(
+      SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -896,6 +959,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  PeerStatistics sse_decode_peer_statistics(SseDeserializer deserializer);
+
   @protected
   (
     FrontendNotify,
@@ -931,6 +997,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   (String, SessionStatus) sse_decode_record_string_session_status(
       SseDeserializer deserializer);
 
+  @protected
+  RecordingFormat sse_decode_recording_format(SseDeserializer deserializer);
+
+  @protected
+  RecordingLayout sse_decode_recording_layout(SseDeserializer deserializer);
+
+  @protected
+  RecordingState sse_decode_recording_state(SseDeserializer deserializer);
+
   @protected
   SessionStatus sse_decode_session_status(SseDeserializer deserializer);
 
@@ -943,6 +1018,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
@@ -1135,6 +1213,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_DartFn_Inputs_call_state_Output_unit_AnyhowException(
       FutureOr<void> Function(CallState) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartFn_Inputs_device_event_Output_unit_AnyhowException(
+      FutureOr<void> Function(DeviceEvent) self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(
+          FutureOr<void> Function(List<PeerStatistics>) self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
@@ -1163,6 +1251,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
           FutureOr<void> Function((String, SessionStatus)) self,
           SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(
+      FutureOr<void> Function(RecordingState) self, SseSerializer serializer);
+
   @protected
   void sse_encode_DartFn_Inputs_statistics_Output_unit_AnyhowException(
       FutureOr<void> Function(Statistics) self, SseSerializer serializer);
@@ -1268,6 +1360,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_audio_codec(AudioCodec self, SseSerializer serializer);
+
   @protected
   void sse_encode_audio_device(AudioDevice self, SseSerializer serializer);
 
@@ -1284,6 +1379,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           RecordingConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_audio_device(
+      AudioDevice self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
@@ -1293,6 +1392,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_dart_error(DartError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_device_event(DeviceEvent self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
@@ -1314,6 +1416,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_audio_device(
       List<AudioDevice> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_peer_statistics(
+      List<PeerStatistics> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -1349,6 +1455,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           RecordingConfig? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_audio_device(
+      AudioDevice? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
@@ -1359,6 +1469,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_list_prim_u_8_strict(
       Uint8List? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_peer_statistics(
+      PeerStatistics self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_frontend_notify_bool(
@@ -1388,6 +1502,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_record_string_session_status(
       (String, SessionStatus) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_recording_format(RecordingFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_recording_layout(RecordingLayout self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_recording_state(
+      RecordingState self, SseSerializer serializer);
+
   @protected
   void sse_encode_session_status(SessionStatus self, SseSerializer serializer);
 
@@ -1400,6 +1524,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -32,6 +32,33 @@ abstract class FlutterSoundHandle implements RustOpaqueInterface {
   ///
   /// This triggers a graceful fade-out to prevent audio pops/clicks.
   void cancel();
+
+  /// The duration of the sound in milliseconds.
+  BigInt durationMs();
+
+  /// Returns true while the sound loops.
+  bool isLooping();
+
+  /// Returns true while playback is paused.
+  bool isPaused();
+
+  /// Pauses playback without closing the output stream.
+  void pause();
+
+  /// The playback position in milliseconds.
+  BigInt positionMs();
+
+  /// Resumes paused playback.
+  void resume();
+
+  /// Jumps to a position in milliseconds, clamped to the duration.
+  void seek({required BigInt positionMs});
+
+  /// Enables or disables looping.
+  void setLooping({required bool looping});
+
+  /// Sets the volume of this sound in decibels, on top of the player volume.
+  void setVolume({required double volume});
 }
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SoundPlayer>>
@@ -53,6 +53,15 @@ abstract class CodecConfig implements RustOpaqueInterface {
       RustLib.instance.api.crateTypesCodecConfigNew(
           enabled: enabled, vbr: vbr, residualBits: residualBits);
 
+  /// the configured channel count, mono when it is not supported
+  int channels();
+
+  AudioCodec codec();
+
+  void setChannels({required int channels});
+
+  void setCodec({required AudioCodec codec});
+
   void setEnabled({required bool enabled});
 
   void setResidualBits({required double residualBits});
@@ -200,6 +209,18 @@ abstract class ScreenshareConfig implements RustOpaqueInterface {
       int? height});
 }
 
+/// the compressed codec offered to peers when the codec is enabled
+enum AudioCodec {
+  sea,
+
+  /// falls back to sea when the peer does not support opus
+  opus,
+
+  /// bit exact audio, smaller than raw
+  lossless,
+  ;
+}
+
 @freezed
 sealed class CallState with _$CallState {
   const CallState._();
@@ -236,6 +257,34 @@ class DartError implements FrbException {
           message == other.message;
 }
 
+/// reported through `CoreCallbacks::device_event`
+@freezed
+sealed class DeviceEvent with _$DeviceEvent {
+  const DeviceEvent._();
+
+  /// a device was connected
+  const factory DeviceEvent.added({
+    required bool input,
+    required AudioDevice device,
+  }) = DeviceEvent_Added;
+  /// a device was disconnected
+  const factory DeviceEvent.removed({
+    required bool input,
+    required AudioDevice device,
+  }) = DeviceEvent_Removed;
+  /// the system default device changed, `None` when there is no default
+  const factory DeviceEvent.defaultChanged({
+    required bool input,
+    AudioDevice? device,
+  }) = DeviceEvent_DefaultChanged;
+  /// a call or room moved to another device after its device failed,
+  /// `None` is the system default
+  const factory DeviceEvent.switched({
+    required bool input,
+    AudioDevice? device,
+  }) = DeviceEvent_Switched;
+}
+
 enum ManagerState {
   stopped,
   starting,
@@ -313,6 +362,95 @@ class NetworkConfigUpdateError implements FrbException {
           message == other.message;
 }
 
+/// processed statistics of a single room participant for the frontend
+class PeerStatistics {
+  /// the peer id of the participant
+  final String peer;
+
+  /// the round trip time of the connection to the participant in milliseconds
+  final BigInt latency;
+
+  /// the share of the participant's audio datagrams missing in the interval
+  final double packetLoss;
+
+  /// the inter-arrival jitter of the participant's audio in milliseconds
+  final double jitter;
+
+  /// a percentage of the participant's max output volume in the window
+  final double outputLevel;
+
+  /// whether the participant was audible in the interval
+  final bool speaking;
+
+  const PeerStatistics({
+    required this.peer,
+    required this.latency,
+    required this.packetLoss,
+    required this.jitter,
+    required this.outputLevel,
+    required this.speaking,
+  });
+
+  @override
+  int get hashCode =>
+      peer.hashCode ^
+      latency.hashCode ^
+      packetLoss.hashCode ^
+      jitter.hashCode ^
+      outputLevel.hashCode ^
+      speaking.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerStatistics &&
+          runtimeType == other.runtimeType &&
+          peer == other.peer &&
+          latency == other.latency &&
+          packetLoss == other.packetLoss &&
+          jitter == other.jitter &&
+          outputLevel == other.outputLevel &&
+          speaking == other.speaking;
+}
+
+/// the file format of a call recording
+enum RecordingFormat {
+  /// 16-bit PCM wav
+  wav,
+
+  /// sea codec, playable like any other sea sound
+  sea,
+  ;
+}
+
+/// whether a call recording mixes everyone into one file
+enum RecordingLayout {
+  mixed,
+
+  /// one file for the local user and one for each peer
+  separateTracks,
+  ;
+}
+
+/// reported through `CoreCallbacks::recording_state`
+@freezed
+sealed class RecordingState with _$RecordingState {
+  const RecordingState._();
+
+  /// recording to the given path
+  const factory RecordingState.started(
+    String field0,
+  ) = RecordingState_Started;
+  /// the recording ended after writing these files
+  const factory RecordingState.stopped(
+    List<String> field0,
+  ) = RecordingState_Stopped;
+  /// the recording ended with an error
+  const factory RecordingState.failed(
+    String field0,
+  ) = RecordingState_Failed;
+}
+
 @freezed
 sealed class SessionStatus with _$SessionStatus {
   const SessionStatus._();
@@ -334,25 +472,62 @@ class Statistics {
   /// a percentage of the max output volume in the window
   final double outputLevel;
 
-  /// the current call latency
+  /// the linear gain applied by automatic gain control, 1.0 when it is disabled
+  final double gain;
+
+  /// the round trip time of the connection in milliseconds
   final BigInt latency;
 
-  /// the approximate upload bandwidth used by the current call
+  /// the bytes per second sent over the connection
   final BigInt uploadBandwidth;
 
-  /// the approximate download bandwidth used by the current call
+  /// the bytes per second received over the connection
   final BigInt downloadBandwidth;
 
   /// the number of output samples that were lost in the interval
   final BigInt loss;
 
+  /// the playout delay of the adaptive jitter buffer in milliseconds,
+  /// the deepest participant's in rooms
+  final BigInt jitterDelay;
+
+  /// the share of audio datagrams missing from the received sequence in the interval
+  final double packetLoss;
+
+  /// the inter-arrival jitter of received audio in milliseconds
+  final double jitter;
+
+  /// whether the connection goes through a relay instead of a direct path
+  final bool relayed;
+
+  /// how often the connection switched between a direct and a relayed path
+  final BigInt pathChanges;
+
+  /// the congestion window of the connection in bytes
+  final BigInt congestionWindow;
+
+  /// the total bytes sent over the connection
+  final BigInt bytesSent;
+
+  /// the total bytes received over the connection
+  final BigInt bytesReceived;
+
   const Statistics({
     required this.inputLevel,
     required this.outputLevel,
+    required this.gain,
     required this.latency,
     required this.uploadBandwidth,
     required this.downloadBandwidth,
     required this.loss,
+    required this.jitterDelay,
+    required this.packetLoss,
+    required this.jitter,
+    required this.relayed,
+    required this.pathChanges,
+    required this.congestionWindow,
+    required this.bytesSent,
+    required this.bytesReceived,
   });
 
   static Future<Statistics> default_() =>
@@ -362,10 +537,19 @@ class Statistics {
   int get hashCode =>
       inputLevel.hashCode ^
       outputLevel.hashCode ^
+      gain.hashCode ^
       latency.hashCode ^
       uploadBandwidth.hashCode ^
       downloadBandwidth.hashCode ^
-      loss.hashCode;
+      loss.hashCode ^
+      jitterDelay.hashCode ^
+      packetLoss.hashCode ^
+      jitter.hashCode ^
+      relayed.hashCode ^
+      pathChanges.hashCode ^
+      congestionWindow.hashCode ^
+      bytesSent.hashCode ^
+      bytesReceived.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -374,8 +558,17 @@ class Statistics {
           runtimeType == other.runtimeType &&
           inputLevel == other.inputLevel &&
           outputLevel == other.outputLevel &&
+          gain == other.gain &&
           latency == other.latency &&
           uploadBandwidth == other.uploadBandwidth &&
           downloadBandwidth == other.downloadBandwidth &&
-          loss == other.loss;
+          loss == other.loss &&
+          jitterDelay == other.jitterDelay &&
+          packetLoss == other.packetLoss &&
+          jitter == other.jitter &&
+          relayed == other.relayed &&
+          pathChanges == other.pathChanges &&
+          congestionWindow == other.congestionWindow &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived;
 }
@@ -498,6 +498,939 @@ class _$CallState_CallEndedCopyWithImpl<$Res>
   }
 }
 
+/// @nodoc
+mixin _$DeviceEvent {
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is DeviceEvent);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  String toString() {
+    return 'DeviceEvent()';
+  }
+}
+
+/// @nodoc
+class $DeviceEventCopyWith<$Res> {
+  $DeviceEventCopyWith(DeviceEvent _, $Res Function(DeviceEvent) __);
+}
+
+/// Adds pattern-matching-related methods to [DeviceEvent].
+extension DeviceEventPatterns on DeviceEvent {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DeviceEvent_Added value)? added,
+    TResult Function(DeviceEvent_Removed value)? removed,
+    TResult Function(DeviceEvent_DefaultChanged value)? defaultChanged,
+    TResult Function(DeviceEvent_Switched value)? switched,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added() when added != null:
+        return added(_that);
+      case DeviceEvent_Removed() when removed != null:
+        return removed(_that);
+      case DeviceEvent_DefaultChanged() when defaultChanged != null:
+        return defaultChanged(_that);
+      case DeviceEvent_Switched() when switched != null:
+        return switched(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DeviceEvent_Added value) added,
+    required TResult Function(DeviceEvent_Removed value) removed,
+    required TResult Function(DeviceEvent_DefaultChanged value) defaultChanged,
+    required TResult Function(DeviceEvent_Switched value) switched,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added():
+        return added(_that);
+      case DeviceEvent_Removed():
+        return removed(_that);
+      case DeviceEvent_DefaultChanged():
+        return defaultChanged(_that);
+      case DeviceEvent_Switched():
+        return switched(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DeviceEvent_Added value)? added,
+    TResult? Function(DeviceEvent_Removed value)? removed,
+    TResult? Function(DeviceEvent_DefaultChanged value)? defaultChanged,
+    TResult? Function(DeviceEvent_Switched value)? switched,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added() when added != null:
+        return added(_that);
+      case DeviceEvent_Removed() when removed != null:
+        return removed(_that);
+      case DeviceEvent_DefaultChanged() when defaultChanged != null:
+        return defaultChanged(_that);
+      case DeviceEvent_Switched() when switched != null:
+        return switched(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(bool input, AudioDevice device)? added,
+    TResult Function(bool input, AudioDevice device)? removed,
+    TResult Function(bool input, AudioDevice? device)? defaultChanged,
+    TResult Function(bool input, AudioDevice? device)? switched,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added() when added != null:
+        return added(_that.input, _that.device);
+      case DeviceEvent_Removed() when removed != null:
+        return removed(_that.input, _that.device);
+      case DeviceEvent_DefaultChanged() when defaultChanged != null:
+        return defaultChanged(_that.input, _that.device);
+      case DeviceEvent_Switched() when switched != null:
+        return switched(_that.input, _that.device);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(bool input, AudioDevice device) added,
+    required TResult Function(bool input, AudioDevice device) removed,
+    required TResult Function(bool input, AudioDevice? device) defaultChanged,
+    required TResult Function(bool input, AudioDevice? device) switched,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added():
+        return added(_that.input, _that.device);
+      case DeviceEvent_Removed():
+        return removed(_that.input, _that.device);
+      case DeviceEvent_DefaultChanged():
+        return defaultChanged(_that.input, _that.device);
+      case DeviceEvent_Switched():
+        return switched(_that.input, _that.device);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(bool input, AudioDevice device)? added,
+    TResult? Function(bool input, AudioDevice device)? removed,
+    TResult? Function(bool input, AudioDevice? device)? defaultChanged,
+    TResult? Function(bool input, AudioDevice? device)? switched,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added() when added != null:
+        return added(_that.input, _that.device);
+      case DeviceEvent_Removed() when removed != null:
+        return removed(_that.input, _that.device);
+      case DeviceEvent_DefaultChanged() when defaultChanged != null:
+        return defaultChanged(_that.input, _that.device);
+      case DeviceEvent_Switched() when switched != null:
+        return switched(_that.input, _that.device);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// a device was connected
+
+class DeviceEvent_Added extends DeviceEvent {
+  const DeviceEvent_Added({required this.input, required this.device})
+      : super._();
+
+  final bool input;
+  final AudioDevice device;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_AddedCopyWith<DeviceEvent_Added> get copyWith =>
+      _$DeviceEvent_AddedCopyWithImpl<DeviceEvent_Added>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_Added &&
+            (identical(other.input, input) || other.input == input) &&
+            (identical(other.device, device) || other.device == device));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, input, device);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.added(input: $input, device: $device)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_AddedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_AddedCopyWith(
+          DeviceEvent_Added value, $Res Function(DeviceEvent_Added) _then) =
+      _$DeviceEvent_AddedCopyWithImpl;
+  @useResult
+  $Res call({bool input, AudioDevice device});
+}
+
+/// @nodoc
+class _$DeviceEvent_AddedCopyWithImpl<$Res>
+    implements $DeviceEvent_AddedCopyWith<$Res> {
+  _$DeviceEvent_AddedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_Added _self;
+  final $Res Function(DeviceEvent_Added) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? input = null,
+    Object? device = null,
+  }) {
+    return _then(DeviceEvent_Added(
+      input: null == input
+          ? _self.input
+          : input // ignore: cast_nullable_to_non_nullable
+              as bool,
+      device: null == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as AudioDevice,
+    ));
+  }
+}
+
+/// a device was disconnected
+
+class DeviceEvent_Removed extends DeviceEvent {
+  const DeviceEvent_Removed({required this.input, required this.device})
+      : super._();
+
+  final bool input;
+  final AudioDevice device;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_RemovedCopyWith<DeviceEvent_Removed> get copyWith =>
+      _$DeviceEvent_RemovedCopyWithImpl<DeviceEvent_Removed>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_Removed &&
+            (identical(other.input, input) || other.input == input) &&
+            (identical(other.device, device) || other.device == device));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, input, device);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.removed(input: $input, device: $device)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_RemovedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_RemovedCopyWith(
+          DeviceEvent_Removed value, $Res Function(DeviceEvent_Removed) _then) =
+      _$DeviceEvent_RemovedCopyWithImpl;
+  @useResult
+  $Res call({bool input, AudioDevice device});
+}
+
+/// @nodoc
+class _$DeviceEvent_RemovedCopyWithImpl<$Res>
+    implements $DeviceEvent_RemovedCopyWith<$Res> {
+  _$DeviceEvent_RemovedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_Removed _self;
+  final $Res Function(DeviceEvent_Removed) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? input = null,
+    Object? device = null,
+  }) {
+    return _then(DeviceEvent_Removed(
+      input: null == input
+          ? _self.input
+          : input // ignore: cast_nullable_to_non_nullable
+              as bool,
+      device: null == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as AudioDevice,
+    ));
+  }
+}
+
+/// the system default device changed, `None` when there is no default
+
+class DeviceEvent_DefaultChanged extends DeviceEvent {
+  const DeviceEvent_DefaultChanged({required this.input, this.device})
+      : super._();
+
+  final bool input;
+  final AudioDevice? device;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_DefaultChangedCopyWith<DeviceEvent_DefaultChanged> get copyWith =>
+      _$DeviceEvent_DefaultChangedCopyWithImpl<DeviceEvent_DefaultChanged>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_DefaultChanged &&
+            (identical(other.input, input) || other.input == input) &&
+            (identical(other.device, device) || other.device == device));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, input, device);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.defaultChanged(input: $input, device: $device)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_DefaultChangedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_DefaultChangedCopyWith(DeviceEvent_DefaultChanged value,
+          $Res Function(DeviceEvent_DefaultChanged) _then) =
+      _$DeviceEvent_DefaultChangedCopyWithImpl;
+  @useResult
+  $Res call({bool input, AudioDevice? device});
+}
+
+/// @nodoc
+class _$DeviceEvent_DefaultChangedCopyWithImpl<$Res>
+    implements $DeviceEvent_DefaultChangedCopyWith<$Res> {
+  _$DeviceEvent_DefaultChangedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_DefaultChanged _self;
+  final $Res Function(DeviceEvent_DefaultChanged) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? input = null,
+    Object? device = freezed,
+  }) {
+    return _then(DeviceEvent_DefaultChanged(
+      input: null == input
+          ? _self.input
+          : input // ignore: cast_nullable_to_non_nullable
+              as bool,
+      device: freezed == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as AudioDevice?,
+    ));
+  }
+}
+
+/// a call or room moved to another device after its device failed,
+/// `None` is the system default
+
+class DeviceEvent_Switched extends DeviceEvent {
+  const DeviceEvent_Switched({required this.input, this.device}) : super._();
+
+  final bool input;
+  final AudioDevice? device;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_SwitchedCopyWith<DeviceEvent_Switched> get copyWith =>
+      _$DeviceEvent_SwitchedCopyWithImpl<DeviceEvent_Switched>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_Switched &&
+            (identical(other.input, input) || other.input == input) &&
+            (identical(other.device, device) || other.device == device));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, input, device);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.switched(input: $input, device: $device)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_SwitchedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_SwitchedCopyWith(DeviceEvent_Switched value,
+          $Res Function(DeviceEvent_Switched) _then) =
+      _$DeviceEvent_SwitchedCopyWithImpl;
+  @useResult
+  $Res call({bool input, AudioDevice? device});
+}
+
+/// @nodoc
+class _$DeviceEvent_SwitchedCopyWithImpl<$Res>
+    implements $DeviceEvent_SwitchedCopyWith<$Res> {
+  _$DeviceEvent_SwitchedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_Switched _self;
+  final $Res Function(DeviceEvent_Switched) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? input = null,
+    Object? device = freezed,
+  }) {
+    return _then(DeviceEvent_Switched(
+      input: null == input
+          ? _self.input
+          : input // ignore: cast_nullable_to_non_nullable
+              as bool,
+      device: freezed == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as AudioDevice?,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$RecordingState {
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is RecordingState);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  String toString() {
+    return 'RecordingState()';
+  }
+}
+
+/// @nodoc
+class $RecordingStateCopyWith<$Res> {
+  $RecordingStateCopyWith(RecordingState _, $Res Function(RecordingState) __);
+}
+
+/// Adds pattern-matching-related methods to [RecordingState].
+extension RecordingStatePatterns on RecordingState {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RecordingState_Started value)? started,
+    TResult Function(RecordingState_Stopped value)? stopped,
+    TResult Function(RecordingState_Failed value)? failed,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case RecordingState_Started() when started != null:
+        return started(_that);
+      case RecordingState_Stopped() when stopped != null:
+        return stopped(_that);
+      case RecordingState_Failed() when failed != null:
+        return failed(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RecordingState_Started value) started,
+    required TResult Function(RecordingState_Stopped value) stopped,
+    required TResult Function(RecordingState_Failed value) failed,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case RecordingState_Started():
+        return started(_that);
+      case RecordingState_Stopped():
+        return stopped(_that);
+      case RecordingState_Failed():
+        return failed(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RecordingState_Started value)? started,
+    TResult? Function(RecordingState_Stopped value)? stopped,
+    TResult? Function(RecordingState_Failed value)? failed,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case RecordingState_Started() when started != null:
+        return started(_that);
+      case RecordingState_Stopped() when stopped != null:
+        return stopped(_that);
+      case RecordingState_Failed() when failed != null:
+        return failed(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? started,
+    TResult Function(List<String> field0)? stopped,
+    TResult Function(String field0)? failed,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case RecordingState_Started() when started != null:
+        return started(_that.field0);
+      case RecordingState_Stopped() when stopped != null:
+        return stopped(_that.field0);
+      case RecordingState_Failed() when failed != null:
+        return failed(_that.field0);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) started,
+    required TResult Function(List<String> field0) stopped,
+    required TResult Function(String field0) failed,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case RecordingState_Started():
+        return started(_that.field0);
+      case RecordingState_Stopped():
+        return stopped(_that.field0);
+      case RecordingState_Failed():
+        return failed(_that.field0);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? started,
+    TResult? Function(List<String> field0)? stopped,
+    TResult? Function(String field0)? failed,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case RecordingState_Started() when started != null:
+        return started(_that.field0);
+      case RecordingState_Stopped() when stopped != null:
+        return stopped(_that.field0);
+      case RecordingState_Failed() when failed != null:
+        return failed(_that.field0);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// recording to the given path
+
+class RecordingState_Started extends RecordingState {
+  const RecordingState_Started(this.field0) : super._();
+
+  final String field0;
+
+  /// Create a copy of RecordingState
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $RecordingState_StartedCopyWith<RecordingState_Started> get copyWith =>
+      _$RecordingState_StartedCopyWithImpl<RecordingState_Started>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is RecordingState_Started &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'RecordingState.started(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $RecordingState_StartedCopyWith<$Res>
+    implements $RecordingStateCopyWith<$Res> {
+  factory $RecordingState_StartedCopyWith(RecordingState_Started value,
+          $Res Function(RecordingState_Started) _then) =
+      _$RecordingState_StartedCopyWithImpl;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class _$RecordingState_StartedCopyWithImpl<$Res>
+    implements $RecordingState_StartedCopyWith<$Res> {
+  _$RecordingState_StartedCopyWithImpl(this._self, this._then);
+
+  final RecordingState_Started _self;
+  final $Res Function(RecordingState_Started) _then;
+
+  /// Create a copy of RecordingState
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(RecordingState_Started(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// the recording ended after writing these files
+
+class RecordingState_Stopped extends RecordingState {
+  const RecordingState_Stopped(final List<String> field0)
+      : _field0 = field0,
+        super._();
+
+  final List<String> _field0;
+  List<String> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  /// Create a copy of RecordingState
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $RecordingState_StoppedCopyWith<RecordingState_Stopped> get copyWith =>
+      _$RecordingState_StoppedCopyWithImpl<RecordingState_Stopped>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is RecordingState_Stopped &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  @override
+  String toString() {
+    return 'RecordingState.stopped(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $RecordingState_StoppedCopyWith<$Res>
+    implements $RecordingStateCopyWith<$Res> {
+  factory $RecordingState_StoppedCopyWith(RecordingState_Stopped value,
+          $Res Function(RecordingState_Stopped) _then) =
+      _$RecordingState_StoppedCopyWithImpl;
+  @useResult
+  $Res call({List<String> field0});
+}
+
+/// @nodoc
+class _$RecordingState_StoppedCopyWithImpl<$Res>
+    implements $RecordingState_StoppedCopyWith<$Res> {
+  _$RecordingState_StoppedCopyWithImpl(this._self, this._then);
+
+  final RecordingState_Stopped _self;
+  final $Res Function(RecordingState_Stopped) _then;
+
+  /// Create a copy of RecordingState
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(RecordingState_Stopped(
+      null == field0
+          ? _self._field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// the recording ended with an error
+
+class RecordingState_Failed extends RecordingState {
+  const RecordingState_Failed(this.field0) : super._();
+
+  final String field0;
+
+  /// Create a copy of RecordingState
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $RecordingState_FailedCopyWith<RecordingState_Failed> get copyWith =>
+      _$RecordingState_FailedCopyWithImpl<RecordingState_Failed>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is RecordingState_Failed &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'RecordingState.failed(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $RecordingState_FailedCopyWith<$Res>
+    implements $RecordingStateCopyWith<$Res> {
+  factory $RecordingState_FailedCopyWith(RecordingState_Failed value,
+          $Res Function(RecordingState_Failed) _then) =
+      _$RecordingState_FailedCopyWithImpl;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class _$RecordingState_FailedCopyWithImpl<$Res>
+    implements $RecordingState_FailedCopyWith<$Res> {
+  _$RecordingState_FailedCopyWithImpl(this._self, this._then);
+
+  final RecordingState_Failed _self;
+  final $Res Function(RecordingState_Failed) _then;
+
+  /// Create a copy of RecordingState
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(RecordingState_Failed(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
 /// @nodoc
 mixin _$SessionStatus {
   @override
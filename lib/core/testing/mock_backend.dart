@@ -116,8 +116,35 @@ class MockSoundHandle implements FlutterSoundHandle {
   @override
   void dispose() {}
 
+  @override
+  BigInt durationMs() => BigInt.zero;
+
   @override
   bool get isDisposed => false;
+
+  @override
+  bool isLooping() => false;
+
+  @override
+  bool isPaused() => false;
+
+  @override
+  void pause() {}
+
+  @override
+  BigInt positionMs() => BigInt.zero;
+
+  @override
+  void resume() {}
+
+  @override
+  void seek({required BigInt positionMs}) {}
+
+  @override
+  void setLooping({required bool looping}) {}
+
+  @override
+  void setVolume({required double volume}) {}
 }
 
 class MockSoundPlayer implements SoundPlayer {
@@ -240,6 +267,9 @@ class MockTelepathy implements Telepathy {
   @override
   Future<void> sendChat({required ChatMessage message}) async {}
 
+  @override
+  void setAutoGain({required bool enabled}) {}
+
   @override
   void setContactOutputVolume({required Contact contact}) {}
 
@@ -249,6 +279,9 @@ class MockTelepathy implements Telepathy {
   @override
   void setDenoise({required bool denoise}) {}
 
+  @override
+  void setEchoCancellation({required bool enabled}) {}
+
   @override
   void setEfficiencyMode({required bool enabled}) {}
 
@@ -261,6 +294,9 @@ class MockTelepathy implements Telepathy {
   @override
   void setInputVolume({required double decibel}) {}
 
+  @override
+  void setJitterBufferBounds({required int minMs, required int maxMs}) {}
+
   @override
   Future<void> setModel({Uint8List? model}) async {}
 
@@ -276,12 +312,18 @@ class MockTelepathy implements Telepathy {
   @override
   void setPlayCustomRingtones({required bool play}) {}
 
+  @override
+  void setRedundancy({required bool enabled}) {}
+
   @override
   void setRmsThreshold({required double decimal}) {}
 
   @override
   void setSendCustomRingtone({required bool send}) {}
 
+  @override
+  void setVoiceGate({required bool enabled}) {}
+
   @override
   Future<void> shutdown() async {}
 
@@ -290,6 +332,15 @@ class MockTelepathy implements Telepathy {
     _stateController.setSessionManager(ManagerState.active);
   }
 
+  @override
+  Future<void> startRecording(
+      {required String path,
+      required RecordingFormat format,
+      required RecordingLayout layout}) async {}
+
+  @override
+  Future<void> stopRecording() async {}
+
   @override
   Future<void> startScreenshare({required Contact contact}) async {}
 
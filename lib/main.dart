@@ -242,6 +242,35 @@ Future<void> main(List<String> args) async {
     return profilesController.contacts.values.map((c) => c.pubClone()).toList();
   }
 
+  // created with the telepathy instance below, device events arrive after it
+  late final AudioDevices audioDevices;
+
+  /// called when an audio device is connected, removed or switched
+  void deviceEvent(DeviceEvent event) {
+    switch (event) {
+      case DeviceEvent_Added() ||
+            DeviceEvent_Removed() ||
+            DeviceEvent_DefaultChanged():
+        audioDevices.updateDevices();
+      case DeviceEvent_Switched():
+        final direction = event.input ? 'input' : 'output';
+        DebugConsole.warn('the $direction device failed, switched to '
+            '${event.device?.name ?? 'the default device'}');
+    }
+  }
+
+  /// called when a call recording starts, stops or fails
+  void recordingState(RecordingState state) {
+    switch (state) {
+      case RecordingState_Started():
+        DebugConsole.log('recording to ${state.field0}');
+      case RecordingState_Stopped():
+        DebugConsole.log('recording saved to ${state.field0.join(', ')}');
+      case RecordingState_Failed():
+        DebugConsole.error('recording failed: ${state.field0}');
+    }
+  }
+
   FlutterCallbacks callbacks = FlutterCallbacks(
       acceptCall: acceptCall,
       getContact: getContact,
@@ -251,7 +280,10 @@ Future<void> main(List<String> args) async {
       statistics: statisticsController.setStatistics,
       messageReceived: chatStateController.messageReceived,
       managerActive: stateController.setSessionManager,
-      screenshareStarted: stateController.screenshareStarted);
+      screenshareStarted: stateController.screenshareStarted,
+      recordingState: recordingState,
+      deviceEvent: deviceEvent,
+      peerStatistics: statisticsController.setPeerStatistics);
 
   final telepathy = Telepathy(
       host: host,
@@ -269,7 +301,7 @@ Future<void> main(List<String> args) async {
     telepathy.startSession(contact: contact);
   }
 
-  final audioDevices = AudioDevices(telepathy: telepathy);
+  audioDevices = AudioDevices(telepathy: telepathy);
 
   // apply options to the instance
   telepathy.setRmsThreshold(decimal: audioSettingsController.inputSensitivity);
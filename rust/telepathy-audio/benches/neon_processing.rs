@@ -0,0 +1,200 @@
+#![cfg(not(target_family = "wasm"))]
+
+use crate::common::{dummy_float_frame, dummy_int_frame};
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use telepathy_audio::internal::processing::*;
+
+mod common;
+
+/// Benchmarks for multiplication operations.
+/// Compares scalar, wide (auto-selecting), and NEON implementations.
+pub fn bench_multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("neon_multiplication");
+
+    let mut frame = dummy_float_frame();
+
+    group.bench_function("scalar_mul", |b| {
+        b.iter(|| scalar_mul(black_box(&mut frame), black_box(2_f32)))
+    });
+
+    group.bench_function("wide_mul", |b| {
+        b.iter(|| wide_mul(black_box(&mut frame), black_box(2_f32)))
+    });
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        unsafe {
+            group.bench_function("neon_mul", |b| {
+                b.iter(|| neon_mul(black_box(&mut frame), black_box(2_f32)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Benchmarks for mixing operations.
+/// Compares scalar, wide (auto-selecting), and NEON implementations.
+pub fn bench_mixing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("neon_mixing");
+
+    let frame = dummy_float_frame();
+    let mut mix = dummy_float_frame();
+
+    group.bench_function("scalar_mix", |b| {
+        b.iter(|| scalar_mix(black_box(&mut mix), black_box(&frame), black_box(0.5)))
+    });
+
+    group.bench_function("wide_mix", |b| {
+        b.iter(|| wide_mix(black_box(&mut mix), black_box(&frame), black_box(0.5)))
+    });
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        unsafe {
+            group.bench_function("neon_mix", |b| {
+                b.iter(|| neon_mix(black_box(&mut mix), black_box(&frame), black_box(0.5)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Benchmarks for i16 to f32 conversion operations.
+/// Compares scalar, wide (auto-selecting), and NEON implementations.
+pub fn bench_i16_to_f32_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("neon_i16_to_f32_conversion");
+
+    let mut pre_buf = [0_f32; 4096];
+    let frame = dummy_int_frame();
+    let scale = 1_f32 / i16::MAX as f32;
+
+    group.bench_function("i16_to_f32_scalar", |b| {
+        b.iter(|| i16_to_f32_scalar(black_box(&frame), black_box(&mut pre_buf), black_box(scale)))
+    });
+
+    group.bench_function("wide_i16_to_f32", |b| {
+        b.iter(|| wide_i16_to_f32(black_box(&frame), black_box(&mut pre_buf), black_box(scale)))
+    });
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        unsafe {
+            group.bench_function("i16_to_f32_neon", |b| {
+                b.iter(|| {
+                    i16_to_f32_neon(black_box(&frame), black_box(&mut pre_buf), black_box(scale))
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Benchmarks for float scaling operations.
+/// Compares scalar, wide (auto-selecting), and NEON implementations.
+pub fn bench_float_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("neon_float_scaling");
+
+    let mut pre_buf = dummy_float_frame();
+
+    group.bench_function("scalar_float_scaler", |b| {
+        b.iter(|| scalar_float_scaler(black_box(&mut pre_buf), black_box(i16::MAX as f32)))
+    });
+
+    group.bench_function("wide_float_scaler", |b| {
+        b.iter(|| wide_float_scaler(black_box(&mut pre_buf), black_box(i16::MAX as f32)))
+    });
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        unsafe {
+            group.bench_function("neon_float_scaler", |b| {
+                b.iter(|| neon_float_scaler(black_box(&mut pre_buf), black_box(i16::MAX as f32)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Benchmarks for f32 to i16 conversion operations.
+/// Compares scalar, wide (auto-selecting), and NEON implementations.
+pub fn bench_f32_to_i16_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("neon_f32_to_i16_conversion");
+
+    // Create f32 frame scaled to i16 range
+    let mut float_frame = dummy_float_frame();
+    for x in &mut float_frame {
+        *x = (*x * i16::MAX as f32).clamp(i16::MIN as f32, i16::MAX as f32);
+    }
+
+    let mut output = [0_i16; 4096];
+
+    group.bench_function("f32_to_i16_scalar", |b| {
+        b.iter(|| {
+            for (out, &f) in black_box(&mut output)
+                .iter_mut()
+                .zip(black_box(&float_frame).iter())
+            {
+                *out = f as i16;
+            }
+        })
+    });
+
+    group.bench_function("wide_f32_to_i16", |b| {
+        b.iter(|| wide_f32_to_i16(black_box(&float_frame), black_box(&mut output)))
+    });
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        unsafe {
+            group.bench_function("f32_to_i16_neon", |b| {
+                b.iter(|| f32_to_i16_neon(black_box(&float_frame), black_box(&mut output)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Benchmarks for RMS (Root Mean Square) calculation.
+/// Compares wide (auto-selecting) and NEON implementations.
+pub fn bench_rms_calculation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("neon_rms_calculation");
+
+    let frame = dummy_float_frame();
+
+    group.bench_function("calculate_rms_wide", |b| {
+        b.iter(|| calculate_rms(black_box(&frame)))
+    });
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        unsafe {
+            group.bench_function("calculate_rms_neon", |b| {
+                b.iter(|| {
+                    let data = black_box(&frame);
+                    let inv_len = 1.0 / data.len() as f32;
+                    let sum = calculate_rms_neon(data);
+                    (sum * inv_len).sqrt()
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_multiplication,
+    bench_mixing,
+    bench_i16_to_f32_conversion,
+    bench_float_scaling,
+    bench_f32_to_i16_conversion,
+    bench_rms_calculation
+);
+criterion_main!(benches);
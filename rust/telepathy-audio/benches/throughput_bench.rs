@@ -21,6 +21,7 @@ use std::hint::black_box;
 use std::thread;
 use std::time::Duration;
 use telepathy_audio::FRAME_SIZE;
+use telepathy_audio::codec::{AudioDecoder, AudioEncoder, RawDecoder, RawEncoder};
 use telepathy_audio::internal::NETWORK_FRAME;
 use telepathy_audio::internal::buffer_pool::PooledBuffer;
 use telepathy_audio::internal::processor::{input_processor, output_processor};
@@ -158,10 +159,13 @@ fn bench_input_throughput(c: &mut Criterion) {
                     };
                     let denoiser = config.denoise.then(DenoiseState::new);
 
-                    let encoder = if config.codec {
-                        SeaEncoder::new(1, output_rate as u32, EncoderSettings::default()).ok()
+                    let encoder: Box<dyn AudioEncoder> = if config.codec {
+                        Box::new(
+                            SeaEncoder::new(1, output_rate as u32, EncoderSettings::default())
+                                .unwrap(),
+                        )
                     } else {
-                        None
+                        Box::new(RawEncoder)
                     };
 
                     let processor_handle = thread::spawn(move || {
@@ -171,6 +175,10 @@ fn bench_input_throughput(c: &mut Criterion) {
                             input_rate,
                             output_rate,
                             denoiser,
+                            None,
+                            None,
+                            None,
+                            None,
                             state,
                             encoder,
                         )
@@ -249,17 +257,19 @@ fn bench_output_throughput(c: &mut Criterion) {
                     let input_rate = 48_000_usize;
                     let output_rate = if config.resample { 44_100 } else { input_rate };
 
-                    let decoder = if config.codec {
-                        SeaDecoder::new(SeaFileHeader {
-                            version: 1,
-                            channels: 1,
-                            chunk_size: 960,
-                            frames_per_chunk: 480,
-                            sample_rate: input_rate as u32,
-                        })
-                        .ok()
+                    let decoder: Box<dyn AudioDecoder> = if config.codec {
+                        Box::new(
+                            SeaDecoder::new(SeaFileHeader {
+                                version: 1,
+                                channels: 1,
+                                chunk_size: 960,
+                                frames_per_chunk: 480,
+                                sample_rate: input_rate as u32,
+                            })
+                            .unwrap(),
+                        )
                     } else {
-                        None
+                        Box::new(RawDecoder)
                     };
 
                     let handle = thread::spawn(move || {
@@ -270,6 +280,8 @@ fn bench_output_throughput(c: &mut Criterion) {
                             output_rate,
                             state,
                             decoder,
+                            None,
+                            None,
                         )
                     });
 
@@ -0,0 +1,448 @@
+//! Pluggable audio codecs.
+//!
+//! The processors encode and decode network frames through the
+//! [`AudioEncoder`] and [`AudioDecoder`] traits, so any implementation can be
+//! plugged into the builders. Codecs that peers negotiate during the call
+//! handshake also get a [`CodecId`].
+//!
+//! ## Implementations
+//!
+//! | Codec | Id | Encoder | Decoder |
+//! |-------|----|---------|---------|
+//! | Raw 16 bit PCM | [`CodecId::Raw`] | [`RawEncoder`] | [`RawDecoder`] |
+//! | SEA | [`CodecId::Sea`] | [`SeaEncoder`] | [`SeaDecoder`] |
+//! | Opus (not on WASM) | [`CodecId::Opus`] | `OpusEncoder` | `OpusDecoder` |
+//! | Lossless LPC + Rice | [`CodecId::Lossless`] | [`LosslessEncoder`] | [`LosslessDecoder`] |
+//!
+//! Every codec works on [`FRAME_SIZE`] interleaved samples per network frame,
+//! so a stereo frame holds `FRAME_SIZE / 2` samples per channel (see
+//! [`supports_channels`]). Opus only accepts the resulting frame length at some
+//! sample rates; check [`CodecId::is_supported`] before offering it to a peer.
+//!
+//! ## Builders
+//!
+//! The encoder sample rate is only known once the input device is open, so the
+//! builders take an [`EncoderFactory`] / [`DecoderFactory`] that is called with
+//! the stream's sample rate and channel count at build time:
+//!
+//! ```rust,no_run
+//! use telepathy_audio::FRAME_SIZE;
+//! use telepathy_audio::codec::SeaEncoder;
+//! use telepathy_audio::devices::CpalAudioHost;
+//! use telepathy_audio::io::AudioInputBuilder;
+//! use telepathy_audio::sea::encoder::EncoderSettings;
+//!
+//! let host = CpalAudioHost::new();
+//! let input = AudioInputBuilder::new()
+//!     .channels(2)
+//!     .encoder(|sample_rate, channels| {
+//!         let settings = EncoderSettings {
+//!             frames_per_chunk: FRAME_SIZE as u16 / channels,
+//!             ..Default::default()
+//!         };
+//!         Ok(SeaEncoder::new(channels as u8, sample_rate, settings)?)
+//!     })
+//!     .callback(|encoded| { /* send over network */ })
+//!     .build(&host)
+//!     .unwrap();
+//! ```
+
+use crate::constants::{FRAME_SIZE, MAX_CHANNELS};
+use crate::error::{ConfigError, Error, ProcessingError};
+use crate::internal::NETWORK_FRAME;
+use crate::sea::codec::file::DEFAULT_SCALE_FACTOR_FRAMES;
+use bytes::BytesMut;
+
+#[cfg(not(target_family = "wasm"))]
+pub mod opus;
+
+pub use crate::lossless::decoder::LosslessDecoder;
+pub use crate::lossless::encoder::LosslessEncoder;
+pub use crate::sea::decoder::SeaDecoder;
+pub use crate::sea::encoder::SeaEncoder;
+#[cfg(not(target_family = "wasm"))]
+pub use opus::{OpusDecoder, OpusEncoder, OpusSettings};
+
+/// Identifies a codec in the call handshake.
+///
+/// The discriminants are part of the wire format and must never change. `Raw`
+/// and `Sea` match the `false`/`true` encoding of the old `codec_enabled` flag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum CodecId {
+    /// Uncompressed 16 bit PCM.
+    #[default]
+    Raw = 0,
+    /// The SEA codec (see [`crate::sea`]).
+    Sea = 1,
+    /// Opus through libopus, unavailable on WASM.
+    Opus = 2,
+    /// Lossless LPC + Rice coding (see [`crate::lossless`]).
+    Lossless = 3,
+}
+
+impl CodecId {
+    /// Every known codec, in wire order.
+    pub const ALL: [CodecId; 4] = [CodecId::Raw, CodecId::Sea, CodecId::Opus, CodecId::Lossless];
+
+    /// The codec to try next when a peer does not support this one.
+    ///
+    /// Raw is supported everywhere and has no fallback.
+    pub fn fallback(self) -> Option<CodecId> {
+        match self {
+            CodecId::Raw => None,
+            CodecId::Sea | CodecId::Lossless => Some(CodecId::Raw),
+            CodecId::Opus => Some(CodecId::Sea),
+        }
+    }
+
+    /// Returns whether this build can encode and decode the codec at
+    /// `sample_rate` with `channels` interleaved channels.
+    pub fn is_supported(self, sample_rate: u32, channels: u16) -> bool {
+        if !supports_channels(channels) {
+            return false;
+        }
+
+        match self {
+            CodecId::Raw | CodecId::Sea | CodecId::Lossless => true,
+            #[cfg(not(target_family = "wasm"))]
+            CodecId::Opus => opus::supports_format(sample_rate, channels),
+            #[cfg(target_family = "wasm")]
+            CodecId::Opus => {
+                let _ = sample_rate;
+                false
+            }
+        }
+    }
+}
+
+/// Returns whether a network frame can carry `channels` interleaved channels.
+///
+/// The channel count must split [`FRAME_SIZE`] into whole SEA scale factor
+/// blocks, which allows 1, 2, 3, 4, 6 and 8 channels.
+pub fn supports_channels(channels: u16) -> bool {
+    (1..=MAX_CHANNELS).contains(&channels)
+        && FRAME_SIZE.is_multiple_of(channels as usize * DEFAULT_SCALE_FACTOR_FRAMES)
+}
+
+impl TryFrom<u8> for CodecId {
+    type Error = ConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Raw),
+            1 => Ok(Self::Sea),
+            2 => Ok(Self::Opus),
+            3 => Ok(Self::Lossless),
+            other => Err(ConfigError::UnknownCodec(other)),
+        }
+    }
+}
+
+impl From<CodecId> for u8 {
+    fn from(id: CodecId) -> Self {
+        id as u8
+    }
+}
+
+/// Compresses one frame of samples into a network payload.
+///
+/// Multichannel frames are interleaved; an encoder is created for a fixed
+/// channel count and always receives [`FRAME_SIZE`] samples in total.
+pub trait AudioEncoder: Send {
+    /// Encodes `frame` into `output`, resizing it to the payload length.
+    fn encode_frame(
+        &mut self,
+        frame: [i16; FRAME_SIZE],
+        output: &mut BytesMut,
+    ) -> Result<(), Error>;
+
+    /// Changes the residual bits of the following frames, see
+    /// [`EncoderSettings::residual_bits`](crate::sea::encoder::EncoderSettings).
+    ///
+    /// The peer's decoder keeps decoding across the change. Codecs without
+    /// the setting ignore it (the default).
+    fn set_residual_bits(&mut self, _residual_bits: f32) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Restores one frame of samples from a network payload.
+pub trait AudioDecoder: Send {
+    /// Decodes the payload in `frame` into `output`.
+    fn decode_frame(&mut self, frame: &[u8], output: &mut [i16; FRAME_SIZE]) -> Result<(), Error>;
+
+    /// Fills `output` with a codec-specific continuation of the previous frame.
+    ///
+    /// Used for packet loss concealment. Returns `false` (the default) when the
+    /// codec cannot extrapolate and generic concealment should be used instead.
+    fn conceal_frame(&mut self, _output: &mut [i16; FRAME_SIZE]) -> bool {
+        false
+    }
+
+    /// Rebuilds a lost frame from the forward error correction data carried
+    /// by `next`, the payload of the frame that follows it.
+    ///
+    /// `next` is decoded with [`Self::decode_frame`] afterwards as usual.
+    /// Returns `false` (the default) when the codec carries no such data and
+    /// the frame has to be concealed instead.
+    fn recover_frame(&mut self, _next: &[u8], _output: &mut [i16; FRAME_SIZE]) -> bool {
+        false
+    }
+}
+
+/// Creates an encoder for the given sample rate and channel count when an
+/// input is built.
+pub type EncoderFactory = Box<dyn FnOnce(u32, u16) -> Result<Box<dyn AudioEncoder>, Error> + Send>;
+
+/// Creates a decoder for the given sample rate and channel count when an
+/// output is built.
+pub type DecoderFactory = Box<dyn FnOnce(u32, u16) -> Result<Box<dyn AudioDecoder>, Error> + Send>;
+
+/// Sends samples uncompressed in native byte order.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RawEncoder;
+
+impl AudioEncoder for RawEncoder {
+    fn encode_frame(
+        &mut self,
+        frame: [i16; FRAME_SIZE],
+        output: &mut BytesMut,
+    ) -> Result<(), Error> {
+        output.resize(NETWORK_FRAME, 0);
+        output.copy_from_slice(unsafe {
+            std::slice::from_raw_parts(frame.as_ptr() as *const u8, NETWORK_FRAME)
+        });
+        Ok(())
+    }
+}
+
+/// Reads uncompressed samples in native byte order.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RawDecoder;
+
+impl AudioDecoder for RawDecoder {
+    fn decode_frame(&mut self, frame: &[u8], output: &mut [i16; FRAME_SIZE]) -> Result<(), Error> {
+        if frame.len() != NETWORK_FRAME {
+            return Err(Error::Processing(ProcessingError::FrameLength {
+                expected: NETWORK_FRAME,
+                actual: frame.len(),
+            }));
+        }
+
+        for (dst, src) in output.iter_mut().zip(frame.chunks_exact(2)) {
+            *dst = i16::from_ne_bytes([src[0], src[1]]);
+        }
+        Ok(())
+    }
+}
+
+impl AudioEncoder for SeaEncoder {
+    fn encode_frame(
+        &mut self,
+        frame: [i16; FRAME_SIZE],
+        output: &mut BytesMut,
+    ) -> Result<(), Error> {
+        Ok(SeaEncoder::encode_frame(self, frame, output)?)
+    }
+
+    fn set_residual_bits(&mut self, residual_bits: f32) -> Result<(), Error> {
+        Ok(SeaEncoder::set_residual_bits(self, residual_bits)?)
+    }
+}
+
+impl AudioDecoder for SeaDecoder {
+    fn decode_frame(&mut self, frame: &[u8], output: &mut [i16; FRAME_SIZE]) -> Result<(), Error> {
+        Ok(SeaDecoder::decode_frame(self, frame, output)?)
+    }
+
+    fn conceal_frame(&mut self, output: &mut [i16; FRAME_SIZE]) -> bool {
+        SeaDecoder::conceal_frame(self, output)
+    }
+}
+
+impl AudioEncoder for LosslessEncoder {
+    fn encode_frame(
+        &mut self,
+        frame: [i16; FRAME_SIZE],
+        output: &mut BytesMut,
+    ) -> Result<(), Error> {
+        Ok(LosslessEncoder::encode_frame(self, frame, output)?)
+    }
+}
+
+impl AudioDecoder for LosslessDecoder {
+    fn decode_frame(&mut self, frame: &[u8], output: &mut [i16; FRAME_SIZE]) -> Result<(), Error> {
+        Ok(LosslessDecoder::decode_frame(self, frame, output)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sea::codec::file::SeaFileHeader;
+    use crate::sea::encoder::EncoderSettings;
+
+    fn sine_frame() -> [i16; FRAME_SIZE] {
+        std::array::from_fn(|i| {
+            let t = i as f32 / 48_000.0;
+            ((t * 440.0 * std::f32::consts::TAU).sin() * 10_000.0) as i16
+        })
+    }
+
+    fn round_trip(encoder: &mut dyn AudioEncoder, decoder: &mut dyn AudioDecoder) -> i32 {
+        let input = sine_frame();
+        let mut encoded = BytesMut::new();
+        encoder.encode_frame(input, &mut encoded).unwrap();
+
+        let mut output = [0_i16; FRAME_SIZE];
+        decoder.decode_frame(&encoded, &mut output).unwrap();
+        input
+            .iter()
+            .zip(output)
+            .map(|(a, b)| (*a as i32 - b as i32).abs())
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn codec_id_round_trips_through_u8() {
+        for id in CodecId::ALL {
+            assert_eq!(CodecId::try_from(u8::from(id)).unwrap(), id);
+        }
+        assert!(matches!(
+            CodecId::try_from(200),
+            Err(ConfigError::UnknownCodec(200))
+        ));
+    }
+
+    #[test]
+    fn every_fallback_chain_ends_at_raw() {
+        for id in CodecId::ALL {
+            let mut current = id;
+            while let Some(next) = current.fallback() {
+                assert!(next < current);
+                current = next;
+            }
+            assert_eq!(current, CodecId::Raw);
+        }
+        assert!(CodecId::Raw.is_supported(44_100, 1));
+        assert!(CodecId::Sea.is_supported(44_100, 2));
+        assert!(!CodecId::Opus.is_supported(44_100, 1));
+        assert!(!CodecId::Sea.is_supported(48_000, 5));
+    }
+
+    #[test]
+    fn channel_counts_must_split_frames_into_whole_blocks() {
+        let supported: Vec<u16> = (0..=16).filter(|c| supports_channels(*c)).collect();
+        assert_eq!(supported, [1, 2, 3, 4, 6, 8]);
+    }
+
+    #[test]
+    fn raw_codec_is_lossless() {
+        let diff = round_trip(&mut RawEncoder, &mut RawDecoder);
+        assert_eq!(diff, 0);
+    }
+
+    #[test]
+    fn raw_decoder_rejects_wrong_length() {
+        let mut output = [0_i16; FRAME_SIZE];
+        let result = RawDecoder.decode_frame(&[0_u8; 10], &mut output);
+        assert!(matches!(
+            result,
+            Err(Error::Processing(ProcessingError::FrameLength {
+                expected: NETWORK_FRAME,
+                actual: 10
+            }))
+        ));
+        assert!(!RawDecoder.conceal_frame(&mut output));
+    }
+
+    #[test]
+    fn lossless_codec_is_lossless_and_smaller_than_raw() {
+        let mut encoded = BytesMut::new();
+        LosslessEncoder::new()
+            .encode_frame(sine_frame(), &mut encoded)
+            .unwrap();
+        assert!(encoded.len() < NETWORK_FRAME);
+
+        let diff = round_trip(&mut LosslessEncoder::new(), &mut LosslessDecoder::new());
+        assert_eq!(diff, 0);
+    }
+
+    #[test]
+    fn sea_codec_round_trips_through_traits() {
+        let mut encoder = SeaEncoder::new(1, 48_000, EncoderSettings::default()).unwrap();
+        // prime the encoder so the chunk size is known for the header
+        encoder
+            .encode_frame(sine_frame(), &mut BytesMut::new())
+            .unwrap();
+        let mut decoder = SeaDecoder::new(SeaFileHeader {
+            version: 1,
+            channels: 1,
+            chunk_size: encoder.chunk_size(),
+            frames_per_chunk: FRAME_SIZE as u16,
+            sample_rate: 48_000,
+        })
+        .unwrap();
+
+        assert!(round_trip(&mut encoder, &mut decoder) < 2_000);
+    }
+
+    #[test]
+    fn sea_decoder_follows_residual_bits_changes() {
+        let mut encoder = SeaEncoder::new(1, 48_000, EncoderSettings::default()).unwrap();
+        let mut decoder = SeaDecoder::new(SeaFileHeader {
+            version: 1,
+            channels: 1,
+            chunk_size: NETWORK_FRAME as u16,
+            frames_per_chunk: FRAME_SIZE as u16,
+            sample_rate: 48_000,
+        })
+        .unwrap();
+
+        let mut sizes = Vec::new();
+        for residual_bits in [3.0, 5.0, 2.0] {
+            AudioEncoder::set_residual_bits(&mut encoder, residual_bits).unwrap();
+            let diff = round_trip(&mut encoder, &mut decoder);
+            assert!(diff < 4_000, "{residual_bits} bits differ by {diff}");
+            sizes.push(encoder.chunk_size());
+        }
+        assert!(sizes[0] < sizes[1] && sizes[2] < sizes[0], "{sizes:?}");
+
+        // codecs without the setting ignore it
+        assert!(RawEncoder.set_residual_bits(2.0).is_ok());
+    }
+
+    #[test]
+    fn sea_codec_keeps_stereo_channels_apart() {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAME_SIZE as u16 / 2,
+            ..Default::default()
+        };
+        let mut encoder = SeaEncoder::new(2, 48_000, settings).unwrap();
+        // the left channel carries the sine, the right channel its inverse
+        let mono = sine_frame();
+        let input: [i16; FRAME_SIZE] = std::array::from_fn(|i| {
+            let sample = mono[i / 2];
+            if i % 2 == 0 { sample } else { -sample }
+        });
+
+        let mut encoded = BytesMut::new();
+        encoder.encode_frame(input, &mut encoded).unwrap();
+        let mut decoder = SeaDecoder::new(SeaFileHeader {
+            version: 1,
+            channels: 2,
+            chunk_size: encoder.chunk_size(),
+            frames_per_chunk: FRAME_SIZE as u16 / 2,
+            sample_rate: 48_000,
+        })
+        .unwrap();
+        let mut output = [0_i16; FRAME_SIZE];
+        decoder.decode_frame(&encoded, &mut output).unwrap();
+
+        for (expected, actual) in input.iter().zip(output) {
+            assert!((*expected as i32 - actual as i32).abs() < 2_000);
+        }
+    }
+}
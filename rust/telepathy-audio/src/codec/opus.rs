@@ -0,0 +1,340 @@
+//! Opus encoder and decoder built on libopus.
+//!
+//! Opus adds in-band forward error correction, discontinuous transmission and
+//! bitrate scaling on top of what SEA offers, at the cost of a native
+//! dependency. Frames stay at [`FRAME_SIZE`] interleaved samples, which is
+//! only a legal Opus frame duration at some sample rates; see
+//! [`supports_format`]. libopus handles mono and stereo only.
+
+use crate::codec::{AudioDecoder, AudioEncoder};
+use crate::constants::FRAME_SIZE;
+use crate::error::Error;
+use audiopus::coder::{Decoder, Encoder};
+use audiopus::packet::Packet;
+use audiopus::{Application, Bitrate, Channels, MutSignals, SampleRate};
+use bytes::BytesMut;
+
+/// The largest packet libopus produces for a single frame.
+const MAX_PACKET: usize = 1275;
+
+/// Returns whether [`FRAME_SIZE`] interleaved samples form a valid Opus frame
+/// at `sample_rate` with `channels` channels.
+///
+/// Opus accepts 2.5, 5, 10, 20, 40 and 60 ms frames, so mono frames of 480
+/// samples work at 48 kHz (10 ms), 24 kHz (20 ms), 12 kHz (40 ms) and 8 kHz
+/// (60 ms). Stereo frames are half as long, which rules out 8 kHz (30 ms).
+pub fn supports_format(sample_rate: u32, channels: u16) -> bool {
+    if !matches!(channels, 1 | 2) || !matches!(sample_rate, 8_000 | 12_000 | 24_000 | 48_000) {
+        return false;
+    }
+
+    // frame duration in half milliseconds
+    let duration = (FRAME_SIZE / channels as usize) as u32 * 2_000;
+    duration.is_multiple_of(sample_rate)
+        && matches!(duration / sample_rate, 5 | 10 | 20 | 40 | 80 | 120)
+}
+
+fn opus_format(sample_rate: u32, channels: u16) -> Result<(SampleRate, Channels), Error> {
+    if !supports_format(sample_rate, channels) {
+        let error = match channels {
+            1 | 2 => audiopus::Error::InvalidSampleRate(sample_rate as i32),
+            _ => audiopus::Error::InvalidChannels(channels as i32),
+        };
+        return Err(error.into());
+    }
+
+    Ok((
+        SampleRate::try_from(sample_rate as i32)?,
+        Channels::try_from(channels as i32)?,
+    ))
+}
+
+/// Encoder options for [`OpusEncoder`].
+#[derive(Clone, Copy, Debug)]
+pub struct OpusSettings {
+    /// Target bitrate in bits per second, or `None` to let libopus choose.
+    pub bitrate: Option<i32>,
+    /// Whether to use variable bitrate.
+    pub vbr: bool,
+    /// Whether to embed in-band forward error correction data.
+    pub fec: bool,
+    /// Expected packet loss in percent, tunes how much FEC data is sent.
+    pub expected_loss: u8,
+    /// Whether to send tiny packets during silence (discontinuous transmission).
+    pub dtx: bool,
+}
+
+impl Default for OpusSettings {
+    fn default() -> Self {
+        Self {
+            bitrate: None,
+            vbr: true,
+            fec: true,
+            expected_loss: 10,
+            dtx: true,
+        }
+    }
+}
+
+/// Mono or stereo Opus encoder tuned for voice.
+pub struct OpusEncoder {
+    encoder: Encoder,
+}
+
+impl OpusEncoder {
+    pub fn new(sample_rate: u32, channels: u16, settings: OpusSettings) -> Result<Self, Error> {
+        let (sample_rate, channels) = opus_format(sample_rate, channels)?;
+        let mut encoder = Encoder::new(sample_rate, channels, Application::Voip)?;
+
+        encoder.set_bitrate(match settings.bitrate {
+            Some(bits_per_second) => Bitrate::BitsPerSecond(bits_per_second),
+            None => Bitrate::Auto,
+        })?;
+        encoder.set_vbr(settings.vbr)?;
+        encoder.set_inband_fec(settings.fec)?;
+        encoder.set_packet_loss_perc(settings.expected_loss.min(100))?;
+        encoder.set_dtx(settings.dtx)?;
+
+        Ok(Self { encoder })
+    }
+}
+
+impl AudioEncoder for OpusEncoder {
+    fn encode_frame(
+        &mut self,
+        frame: [i16; FRAME_SIZE],
+        output: &mut BytesMut,
+    ) -> Result<(), Error> {
+        output.resize(MAX_PACKET, 0);
+        let len = self.encoder.encode(&frame, output)?;
+        output.truncate(len);
+        Ok(())
+    }
+}
+
+/// Mono or stereo Opus decoder.
+///
+/// A lost frame is rebuilt from the in-band FEC of the following packet when
+/// that has already arrived, and concealed by libopus itself otherwise.
+pub struct OpusDecoder {
+    decoder: Decoder,
+    channels: usize,
+    /// whether a frame has been decoded yet
+    primed: bool,
+}
+
+impl OpusDecoder {
+    pub fn new(sample_rate: u32, channels: u16) -> Result<Self, Error> {
+        let (sample_rate, opus_channels) = opus_format(sample_rate, channels)?;
+        Ok(Self {
+            decoder: Decoder::new(sample_rate, opus_channels)?,
+            channels: channels as usize,
+            primed: false,
+        })
+    }
+
+    /// Decodes `packet`, or the FEC data it carries for the frame before it
+    /// when `fec` is set.
+    fn decode(
+        &mut self,
+        packet: Option<Packet<'_>>,
+        output: &mut [i16; FRAME_SIZE],
+        fec: bool,
+    ) -> Result<(), Error> {
+        // libopus counts samples per channel
+        let samples = self
+            .decoder
+            .decode(packet, MutSignals::try_from(&mut output[..])?, fec)?
+            * self.channels;
+        output[samples.min(FRAME_SIZE)..].fill(0);
+        Ok(())
+    }
+}
+
+impl AudioDecoder for OpusDecoder {
+    fn decode_frame(&mut self, frame: &[u8], output: &mut [i16; FRAME_SIZE]) -> Result<(), Error> {
+        self.decode(Some(Packet::try_from(frame)?), output, false)?;
+        self.primed = true;
+        Ok(())
+    }
+
+    fn conceal_frame(&mut self, output: &mut [i16; FRAME_SIZE]) -> bool {
+        self.primed && self.decode(None, output, false).is_ok()
+    }
+
+    fn recover_frame(&mut self, next: &[u8], output: &mut [i16; FRAME_SIZE]) -> bool {
+        let Ok(packet) = Packet::try_from(next) else {
+            return false;
+        };
+        self.primed && self.decode(Some(packet), output, true).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ProcessingError;
+
+    fn sine_frames(sample_rate: u32, count: usize) -> Vec<[i16; FRAME_SIZE]> {
+        (0..count)
+            .map(|frame| {
+                std::array::from_fn(|i| {
+                    let t = (frame * FRAME_SIZE + i) as f32 / sample_rate as f32;
+                    ((t * 440.0 * std::f32::consts::TAU).sin() * 10_000.0) as i16
+                })
+            })
+            .collect()
+    }
+
+    fn rms(samples: &[i16]) -> f32 {
+        let sum: f32 = samples.iter().map(|s| (*s as f32).powi(2)).sum();
+        (sum / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn frame_size_limits_sample_rates() {
+        for rate in [8_000, 12_000, 24_000, 48_000] {
+            assert!(supports_format(rate, 1));
+            assert!(OpusEncoder::new(rate, 1, OpusSettings::default()).is_ok());
+        }
+        for rate in [16_000, 44_100, 96_000] {
+            assert!(!supports_format(rate, 1));
+            assert!(matches!(
+                OpusDecoder::new(rate, 1),
+                Err(Error::Processing(ProcessingError::Opus(
+                    audiopus::Error::InvalidSampleRate(_)
+                )))
+            ));
+        }
+    }
+
+    #[test]
+    fn stereo_frames_are_half_as_long() {
+        for rate in [12_000, 24_000, 48_000] {
+            assert!(supports_format(rate, 2));
+            assert!(OpusDecoder::new(rate, 2).is_ok());
+        }
+        // 240 samples per channel at 8 kHz are 30 ms
+        assert!(!supports_format(8_000, 2));
+        assert!(matches!(
+            OpusEncoder::new(48_000, 4, OpusSettings::default()),
+            Err(Error::Processing(ProcessingError::Opus(
+                audiopus::Error::InvalidChannels(4)
+            )))
+        ));
+    }
+
+    #[test]
+    fn round_trip_preserves_signal_energy() {
+        let mut encoder = OpusEncoder::new(48_000, 1, OpusSettings::default()).unwrap();
+        let mut decoder = OpusDecoder::new(48_000, 1).unwrap();
+        let frames = sine_frames(48_000, 20);
+
+        let mut encoded = BytesMut::new();
+        let mut output = [0_i16; FRAME_SIZE];
+        for frame in &frames {
+            encoder.encode_frame(*frame, &mut encoded).unwrap();
+            assert!(!encoded.is_empty() && encoded.len() < FRAME_SIZE * 2);
+            decoder.decode_frame(&encoded, &mut output).unwrap();
+        }
+
+        // the codec delay shifts the waveform, so compare levels instead of samples
+        let expected = rms(&frames[19]);
+        let actual = rms(&output);
+        assert!((actual - expected).abs() < expected * 0.25);
+    }
+
+    #[test]
+    fn conceals_only_after_the_first_frame() {
+        let mut encoder = OpusEncoder::new(48_000, 1, OpusSettings::default()).unwrap();
+        let mut decoder = OpusDecoder::new(48_000, 1).unwrap();
+        let mut output = [0_i16; FRAME_SIZE];
+        assert!(!decoder.conceal_frame(&mut output));
+
+        let mut encoded = BytesMut::new();
+        for frame in sine_frames(48_000, 5) {
+            encoder.encode_frame(frame, &mut encoded).unwrap();
+            decoder.decode_frame(&encoded, &mut output).unwrap();
+        }
+
+        let mut concealed = [0_i16; FRAME_SIZE];
+        assert!(decoder.conceal_frame(&mut concealed));
+        assert!(rms(&concealed) > 500.0);
+    }
+
+    #[test]
+    fn fec_recovers_a_lost_frame_better_than_concealment() {
+        let settings = OpusSettings {
+            // low enough for SILK, which carries the FEC data
+            bitrate: Some(32_000),
+            dtx: false,
+            ..Default::default()
+        };
+        let mut encoder = OpusEncoder::new(48_000, 1, settings).unwrap();
+        // the talker gets louder and higher right at the lost frame
+        let lost = 10;
+        let frames: Vec<[i16; FRAME_SIZE]> = (0..lost + 2)
+            .map(|frame| {
+                let (frequency, amplitude) = if frame < lost {
+                    (220.0, 2_000.0)
+                } else {
+                    (660.0, 12_000.0)
+                };
+                std::array::from_fn(|i| {
+                    let t = (frame * FRAME_SIZE + i) as f32 / 48_000.0;
+                    ((t * frequency * std::f32::consts::TAU).sin() * amplitude) as i16
+                })
+            })
+            .collect();
+        let packets: Vec<BytesMut> = frames
+            .iter()
+            .map(|frame| {
+                let mut packet = BytesMut::new();
+                encoder.encode_frame(*frame, &mut packet).unwrap();
+                packet
+            })
+            .collect();
+
+        let mut reference = OpusDecoder::new(48_000, 1).unwrap();
+        let mut recovered = OpusDecoder::new(48_000, 1).unwrap();
+        let mut concealed = OpusDecoder::new(48_000, 1).unwrap();
+        let mut output = [0_i16; FRAME_SIZE];
+        for packet in &packets[..lost] {
+            reference.decode_frame(packet, &mut output).unwrap();
+            recovered.decode_frame(packet, &mut output).unwrap();
+            concealed.decode_frame(packet, &mut output).unwrap();
+        }
+
+        let mut expected = [0_i16; FRAME_SIZE];
+        reference
+            .decode_frame(&packets[lost], &mut expected)
+            .unwrap();
+        let mut from_fec = [0_i16; FRAME_SIZE];
+        assert!(recovered.recover_frame(&packets[lost + 1], &mut from_fec));
+        let mut from_plc = [0_i16; FRAME_SIZE];
+        assert!(concealed.conceal_frame(&mut from_plc));
+
+        let error = |frame: &[i16; FRAME_SIZE]| {
+            let difference: Vec<i16> = frame
+                .iter()
+                .zip(&expected)
+                .map(|(a, b)| a.saturating_sub(*b))
+                .collect();
+            rms(&difference)
+        };
+        assert!(error(&from_fec) < error(&from_plc));
+    }
+
+    #[test]
+    fn recovery_needs_a_decoded_frame_first() {
+        let mut encoder = OpusEncoder::new(48_000, 1, OpusSettings::default()).unwrap();
+        let mut decoder = OpusDecoder::new(48_000, 1).unwrap();
+        let mut encoded = BytesMut::new();
+        encoder
+            .encode_frame(sine_frames(48_000, 1)[0], &mut encoded)
+            .unwrap();
+
+        let mut output = [0_i16; FRAME_SIZE];
+        assert!(!decoder.recover_frame(&encoded, &mut output));
+    }
+}
@@ -43,3 +43,13 @@ pub const MINIMUM_SILENCE_LENGTH: u16 = 40;
 /// create high-frequency artifacts perceived as clicks. The linear ramp
 /// spreads the energy change across multiple samples.
 pub(crate) const TRANSITION_LENGTH: usize = 48;
+
+/// Largest number of interleaved channels a stream may carry.
+///
+/// ## Rationale
+///
+/// Every network frame holds [`FRAME_SIZE`] interleaved samples, so a frame
+/// carries `FRAME_SIZE / channels` samples per channel. Eight channels (7.1)
+/// still leave 60 samples per channel, enough for the SEA scale factor blocks.
+/// See [`supports_channels`](crate::codec::supports_channels) for the exact rule.
+pub const MAX_CHANNELS: u16 = 8;
@@ -1,6 +1,12 @@
 use crate::constants::TRANSITION_LENGTH;
 use crate::devices::DeviceDirection;
-use crate::devices::{AudioDeviceInfo, AudioDeviceList, AudioHost, DeviceError, device_to_info};
+#[cfg(not(target_family = "wasm"))]
+use crate::devices::DeviceEvent;
+#[cfg(not(target_family = "wasm"))]
+use crate::devices::watch::poll_devices;
+use crate::devices::{
+    AudioDeviceInfo, AudioDeviceList, AudioHost, DeviceError, DeviceSnapshot, device_to_info,
+};
 #[cfg(not(target_family = "wasm"))]
 use crate::internal::traits::{AudioInput, RingBufferInput};
 use crate::internal::traits::{AudioOutput, CHANNEL_SIZE, RingBufferOutput};
@@ -16,6 +22,8 @@ use std::fmt;
 use std::sync::Arc;
 #[cfg(not(target_family = "wasm"))]
 use std::sync::Condvar;
+#[cfg(not(target_family = "wasm"))]
+use tokio::sync::mpsc::UnboundedReceiver;
 use tracing::error;
 
 /// CPAL-backed audio host for device management.
@@ -144,6 +152,7 @@ impl AudioHost for CpalAudioHost {
     fn open_input(
         &self,
         device_id: Option<&str>,
+        channels: u16,
         error_callback: Option<StreamErrorCallback>,
     ) -> Result<(impl AudioInput + Send + 'static, u32, Self::InputStream), DeviceError> {
         let input_device = self.get_input_device(device_id)?;
@@ -177,6 +186,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::I16 => build_input_stream_with_format::<i16>(
@@ -184,6 +194,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::I32 => build_input_stream_with_format::<i32>(
@@ -191,6 +202,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::I64 => build_input_stream_with_format_64::<i64>(
@@ -198,6 +210,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::U8 => build_input_stream_with_format::<u8>(
@@ -205,6 +218,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::U16 => build_input_stream_with_format::<u16>(
@@ -212,6 +226,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::U32 => build_input_stream_with_format::<u32>(
@@ -219,6 +234,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::U64 => build_input_stream_with_format_64::<u64>(
@@ -226,6 +242,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::F32 => build_input_stream_with_format::<f32>(
@@ -233,6 +250,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::F64 => build_input_stream_with_format_64::<f64>(
@@ -240,6 +258,7 @@ impl AudioHost for CpalAudioHost {
                 config.into(),
                 input_sender,
                 input_channels,
+                channels as usize,
                 error_callback,
             )?,
             _ => {
@@ -261,6 +280,7 @@ impl AudioHost for CpalAudioHost {
     fn open_output(
         &self,
         device_id: Option<&str>,
+        channels: u16,
         error_callback: Option<StreamErrorCallback>,
     ) -> Result<(impl AudioOutput + Send + 'static, u32, Self::OutputStream), DeviceError> {
         let output_device = self.get_output_device(device_id)?;
@@ -286,6 +306,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::I16 => build_output_stream_with_format::<i16>(
@@ -293,6 +314,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::I32 => build_output_stream_with_format::<i32>(
@@ -300,6 +322,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::I64 => build_output_stream_with_format::<i64>(
@@ -307,6 +330,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::U8 => build_output_stream_with_format::<u8>(
@@ -314,6 +338,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::U16 => build_output_stream_with_format::<u16>(
@@ -321,6 +346,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::U32 => build_output_stream_with_format::<u32>(
@@ -328,6 +354,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::U64 => build_output_stream_with_format::<u64>(
@@ -335,6 +362,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::F32 => build_output_stream_with_format::<f32>(
@@ -342,6 +370,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             SampleFormat::F64 => build_output_stream_with_format::<f64>(
@@ -349,6 +378,7 @@ impl AudioHost for CpalAudioHost {
                 output_config.into(),
                 output_consumer,
                 output_channels,
+                channels as usize,
                 error_callback,
             )?,
             _ => {
@@ -365,14 +395,41 @@ impl AudioHost for CpalAudioHost {
             source,
         })?;
         Ok((
-            RingBufferOutput::new(output_producer),
+            RingBufferOutput::new(output_producer).with_channels(channels),
             device_sample_rate,
             stream,
         ))
     }
+
+    /// cpal has no device change notifications, so the devices are polled
+    /// every [`DEVICE_POLL_INTERVAL`](crate::devices::DEVICE_POLL_INTERVAL).
+    #[cfg(not(target_family = "wasm"))]
+    fn watch_devices(&self) -> Result<UnboundedReceiver<DeviceEvent>, DeviceError> {
+        let initial = self.device_snapshot()?;
+        let host = self.clone();
+        Ok(poll_devices(initial, move || host.device_snapshot()))
+    }
 }
 
 impl CpalAudioHost {
+    /// Captures the current devices and system defaults.
+    pub fn device_snapshot(&self) -> Result<DeviceSnapshot, DeviceError> {
+        Ok(DeviceSnapshot {
+            input_devices: self.list_input_devices()?,
+            output_devices: self.list_output_devices()?,
+            default_input: self
+                .inner()
+                .default_input_device()
+                .as_ref()
+                .and_then(device_to_info),
+            default_output: self
+                .inner()
+                .default_output_device()
+                .as_ref()
+                .and_then(device_to_info),
+        })
+    }
+
     fn get_input_device(&self, device_id: Option<&str>) -> Result<Device, DeviceError> {
         self.get_device(device_id, DeviceDirection::Input, |host| {
             host.default_input_device()
@@ -443,6 +500,8 @@ impl CpalAudioHost {
 /// * `config` - Stream configuration (sample rate, channels, etc.)
 /// * `input_producer` - Ring buffer producer for f32 samples to the processor
 /// * `input_channels` - Number of input channels
+/// * `channels` - Number of interleaved channels sent to the processor; device
+///   channel `c % input_channels` feeds pipeline channel `c`
 /// * `error_callback` - Optional callback for stream errors
 #[cfg(not(target_family = "wasm"))]
 fn build_input_stream_with_format<T>(
@@ -450,6 +509,7 @@ fn build_input_stream_with_format<T>(
     config: StreamConfig,
     mut input_sender: RingBufferSender,
     input_channels: usize,
+    channels: usize,
     mut error_callback: Option<StreamErrorCallback>,
 ) -> Result<Stream, DeviceError>
 where
@@ -462,9 +522,11 @@ where
                 input_stream_helper(
                     &mut input_sender,
                     input_channels,
+                    channels,
                     data.len(),
-                    data.chunks(input_channels)
-                        .map(|frame| frame[0].to_float_sample()),
+                    data.chunks(input_channels).flat_map(|frame| {
+                        (0..channels).map(move |c| frame[c % frame.len()].to_float_sample())
+                    }),
                 );
             },
             move |err| {
@@ -494,6 +556,7 @@ fn build_input_stream_with_format_64<T>(
     config: StreamConfig,
     mut input_sender: RingBufferSender,
     input_channels: usize,
+    channels: usize,
     mut error_callback: Option<StreamErrorCallback>,
 ) -> Result<Stream, DeviceError>
 where
@@ -506,10 +569,13 @@ where
                 input_stream_helper(
                     &mut input_sender,
                     input_channels,
+                    channels,
                     data.len(),
-                    data.chunks(input_channels).map(|frame| {
-                        let sample_f64 = frame[0].to_float_sample();
-                        sample_f64 as f32
+                    data.chunks(input_channels).flat_map(|frame| {
+                        (0..channels).map(move |c| {
+                            let sample_f64 = frame[c % frame.len()].to_float_sample();
+                            sample_f64 as f32
+                        })
                     }),
                 );
             },
@@ -534,12 +600,13 @@ where
 fn input_stream_helper(
     input_sender: &mut RingBufferSender,
     input_channels: usize,
+    channels: usize,
     data_len: usize,
     sample_iter: impl Iterator<Item = f32>,
 ) {
     let Ok(chunk) = input_sender
         .producer
-        .write_chunk_uninit(data_len / input_channels)
+        .write_chunk_uninit(data_len / input_channels * channels)
     else {
         return;
     };
@@ -566,18 +633,23 @@ fn input_stream_helper(
 /// * `config` - Stream configuration (sample rate, channels, etc.)
 /// * `output_consumer` - Ring buffer consumer for f32 samples from the processor
 /// * `output_channels` - Number of output channels
+/// * `channels` - Number of interleaved channels produced by the processor;
+///   device channel `c` plays pipeline channel `c % channels`
 /// * `error_callback` - Optional callback for stream errors
 fn build_output_stream_with_format<T>(
     device: &Device,
     config: StreamConfig,
     mut output_consumer: Consumer<f32>,
     output_channels: usize,
+    channels: usize,
     mut error_callback: Option<StreamErrorCallback>,
 ) -> Result<SendStream, DeviceError>
 where
     T: Sample + SizedSample + FromSample<f32> + Send + 'static,
 {
-    let mut last_sample = 0_f32;
+    let channels = channels.max(1);
+    // the most recent pipeline frame, faded out on underrun
+    let mut last_frame = vec![0_f32; channels];
     let mut was_underrun = true;
     let mut was_missing = false;
 
@@ -588,7 +660,7 @@ where
                 debug_assert!(output_channels > 0);
 
                 let total_frames = data.len() / output_channels;
-                let available_frames = total_frames.min(output_consumer.slots());
+                let available_frames = total_frames.min(output_consumer.slots() / channels);
 
                 if was_missing && available_frames == 0 {
                     // shortcut for when the fade already occurred & we are playing silence
@@ -600,7 +672,7 @@ where
 
                 let mut pulled = 0;
                 if available_frames > 0 {
-                    match output_consumer.read_chunk(available_frames) {
+                    match output_consumer.read_chunk(available_frames * channels) {
                         Ok(chunk) => {
                             let mut samples = chunk.into_iter();
 
@@ -612,19 +684,23 @@ where
                                 0
                             };
 
-                            for i in 0..ramp_in_len {
+                            for _ in 0..available_frames {
                                 let Some(frame) = frames.next() else {
                                     break;
                                 };
-                                let Some(sample_f32) = samples.next() else {
-                                    break;
+                                for (sample, sample_f32) in last_frame.iter_mut().zip(&mut samples)
+                                {
+                                    *sample = sample_f32;
+                                }
+
+                                let g = if pulled < ramp_in_len {
+                                    hann_fade_in(pulled, ramp_in_len)
+                                } else {
+                                    1_f32
                                 };
-
-                                let g = hann_fade_in(i, ramp_in_len);
-                                let out = sample_f32 * g;
-
-                                last_sample = sample_f32;
-                                frame.fill(T::from_sample(out));
+                                for (c, out) in frame.iter_mut().enumerate() {
+                                    *out = T::from_sample(last_frame[c % channels] * g);
+                                }
                                 pulled += 1;
                             }
 
@@ -632,14 +708,6 @@ where
                                 was_underrun = false;
                                 was_missing = false;
                             }
-
-                            while let (Some(frame), Some(sample_f32)) =
-                                (frames.next(), samples.next())
-                            {
-                                last_sample = sample_f32;
-                                frame.fill(T::from_sample(sample_f32));
-                                pulled += 1;
-                            }
                         }
                         Err(ChunkError::TooFewSlots(_)) => {
                             // Shouldn't happen since we min() with slots(), but if it does:
@@ -655,20 +723,22 @@ where
 
                     let fade_len = TRANSITION_LENGTH.min(missing);
 
-                    // Smooth fade from last real sample -> 0
+                    // Smooth fade from last real frame -> 0
                     for i in 0..fade_len {
                         let Some(frame) = frames.next() else {
                             break;
                         };
                         let g = hann_fade_out(i, fade_len);
-                        frame.fill(T::from_sample(last_sample * g));
+                        for (c, out) in frame.iter_mut().enumerate() {
+                            *out = T::from_sample(last_frame[c % channels] * g);
+                        }
                     }
 
                     for frame in frames {
                         frame.fill(T::from_sample(0.0));
                     }
 
-                    last_sample = 0.0;
+                    last_frame.fill(0.0);
                 }
             },
             move |err| {
@@ -0,0 +1,503 @@
+//! [`AudioHost`] backed by audio files, for running without sound hardware.
+//!
+//! The input device plays a WAV or SEA file as microphone samples and the
+//! output device writes everything it receives to a 16-bit WAV file. Bots,
+//! regression tests and demos can run a whole call through it.
+use crate::Error;
+use crate::devices::{
+    AudioDeviceInfo, AudioDeviceList, AudioHost, DeviceDirection, DeviceError, DeviceEvent,
+};
+use crate::error::AudioFileError;
+use crate::internal::traits::{AudioInput, AudioOutput};
+use crate::io::StreamErrorCallback;
+use crate::player::{DecodedAudio, decode_audio};
+use crate::recorder::WavWriter;
+use std::fs::File;
+use std::io::BufWriter;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+use tracing::warn;
+
+const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+const FILE_DEVICE_ID: &str = "file";
+
+/// How fast the input device reads its file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilePacing {
+    /// Samples arrive at the rate a microphone would deliver them.
+    #[default]
+    RealTime,
+    /// Samples are read as fast as the input processor takes them.
+    AsFastAsPossible,
+}
+
+/// In-process [`AudioHost`] whose devices are audio files.
+///
+/// Without an input file the input device records silence, and without an
+/// output file received audio is discarded. Each host has one input and one
+/// output device, both with the id `"file"`.
+///
+/// ```rust,no_run
+/// use telepathy_audio::devices::{FileAudioHost, FilePacing};
+///
+/// let host = FileAudioHost::new()
+///     .input_file("greeting.wav")
+///     .unwrap()
+///     .output_file("received.wav")
+///     .unwrap()
+///     .pacing(FilePacing::AsFastAsPossible);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileAudioHost {
+    input: Option<Arc<InputFile>>,
+    pacing: FilePacing,
+    looping: bool,
+    output: Option<Arc<Mutex<OutputFile>>>,
+    output_rate: u32,
+}
+
+impl FileAudioHost {
+    /// Creates a host which records silence and discards its output.
+    pub fn new() -> Self {
+        Self {
+            input: None,
+            pacing: FilePacing::RealTime,
+            looping: false,
+            output: None,
+            output_rate: DEFAULT_SAMPLE_RATE,
+        }
+    }
+
+    /// Reads the microphone samples from a WAV or SEA file.
+    pub fn input_file(self, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let bytes =
+            std::fs::read(path).map_err(|error| Error::AudioFile(AudioFileError::Io(error)))?;
+        self.with_input(path.display().to_string(), bytes)
+    }
+
+    /// Reads the microphone samples from the bytes of a WAV or SEA file.
+    pub fn input_bytes(self, bytes: Vec<u8>) -> Result<Self, Error> {
+        self.with_input(String::from("File Input"), bytes)
+    }
+
+    /// Writes received audio to a new WAV file at `path`.
+    ///
+    /// The file is created right away, its format is set by the first output
+    /// stream. Later output streams append to the same file.
+    pub fn output_file(mut self, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file =
+            File::create(path).map_err(|error| Error::AudioFile(AudioFileError::Io(error)))?;
+        self.output = Some(Arc::new(Mutex::new(OutputFile {
+            path: path.to_path_buf(),
+            state: OutputState::Created(file),
+        })));
+        Ok(self)
+    }
+
+    /// Sets how fast the input file is read, [`FilePacing::RealTime`] by default.
+    pub fn pacing(mut self, pacing: FilePacing) -> Self {
+        self.pacing = pacing;
+        self
+    }
+
+    /// Restarts the input file when it ends. Otherwise the input stream ends
+    /// with the file.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Sets the sample rate of the output file, 48 kHz by default.
+    pub fn output_rate(mut self, sample_rate: u32) -> Self {
+        self.output_rate = sample_rate;
+        self
+    }
+
+    fn with_input(mut self, name: String, bytes: Vec<u8>) -> Result<Self, Error> {
+        self.input = Some(Arc::new(InputFile {
+            name,
+            audio: decode_audio(bytes)?,
+        }));
+        Ok(self)
+    }
+
+    /// Fails for every device but the file device.
+    fn check_device(
+        &self,
+        device_id: Option<&str>,
+        direction: DeviceDirection,
+    ) -> Result<(), DeviceError> {
+        match device_id {
+            Some(id) if id != FILE_DEVICE_ID => Err(DeviceError::DeviceNotFound {
+                direction,
+                id: id.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for FileAudioHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioHost for FileAudioHost {
+    type InputStream = ();
+    type OutputStream = ();
+
+    fn list_input_devices(&self) -> Result<Vec<AudioDeviceInfo>, DeviceError> {
+        let name = self
+            .input
+            .as_ref()
+            .map(|input| input.name.clone())
+            .unwrap_or_else(|| String::from("Silence"));
+        Ok(vec![AudioDeviceInfo {
+            name,
+            id: FILE_DEVICE_ID.to_string(),
+        }])
+    }
+
+    fn list_output_devices(&self) -> Result<Vec<AudioDeviceInfo>, DeviceError> {
+        let name = match &self.output {
+            Some(output) => output.lock().unwrap().path.display().to_string(),
+            None => String::from("Discard"),
+        };
+        Ok(vec![AudioDeviceInfo {
+            name,
+            id: FILE_DEVICE_ID.to_string(),
+        }])
+    }
+
+    fn list_all_devices(&self) -> Result<AudioDeviceList, DeviceError> {
+        Ok(AudioDeviceList {
+            input_devices: self.list_input_devices()?,
+            output_devices: self.list_output_devices()?,
+        })
+    }
+
+    fn input_sample_rate(&self, device_id: Option<&str>) -> Result<u32, DeviceError> {
+        self.check_device(device_id, DeviceDirection::Input)?;
+        Ok(self
+            .input
+            .as_ref()
+            .map(|input| input.audio.sample_rate)
+            .unwrap_or(DEFAULT_SAMPLE_RATE))
+    }
+
+    fn output_sample_rate(&self, device_id: Option<&str>) -> Result<u32, DeviceError> {
+        self.check_device(device_id, DeviceDirection::Output)?;
+        Ok(self.output_rate)
+    }
+
+    fn open_input(
+        &self,
+        device_id: Option<&str>,
+        channels: u16,
+        _: Option<StreamErrorCallback>,
+    ) -> Result<(impl AudioInput + Send + 'static, u32, Self::InputStream), DeviceError> {
+        let sample_rate = self.input_sample_rate(device_id)?;
+        let input = FileInput {
+            file: self.input.clone(),
+            channels: channels as usize,
+            looping: self.looping,
+            pacing: self.pacing,
+            sample_rate,
+            position: 0,
+            started: None,
+            frames_read: 0,
+        };
+        Ok((input, sample_rate, ()))
+    }
+
+    fn open_output(
+        &self,
+        device_id: Option<&str>,
+        channels: u16,
+        _: Option<StreamErrorCallback>,
+    ) -> Result<(impl AudioOutput + Send + 'static, u32, Self::OutputStream), DeviceError> {
+        let sample_rate = self.output_sample_rate(device_id)?;
+        let output = FileOutput {
+            file: self.output.clone(),
+            channels: channels as usize,
+            sample_rate,
+            buffer: Vec::new(),
+        };
+        Ok((output, sample_rate, ()))
+    }
+
+    /// The devices never change, so the watcher ends immediately.
+    fn watch_devices(&self) -> Result<UnboundedReceiver<DeviceEvent>, DeviceError> {
+        let (_, receiver) = unbounded_channel();
+        Ok(receiver)
+    }
+}
+
+#[derive(Debug)]
+struct InputFile {
+    name: String,
+    audio: DecodedAudio,
+}
+
+/// Plays the input file, or silence without one.
+struct FileInput {
+    file: Option<Arc<InputFile>>,
+    /// Interleaved channels the processor reads.
+    channels: usize,
+    looping: bool,
+    pacing: FilePacing,
+    sample_rate: u32,
+    /// The next sample frame of the file.
+    position: usize,
+    /// When the first samples were read, used for real-time pacing.
+    started: Option<Instant>,
+    frames_read: u64,
+}
+
+impl AudioInput for FileInput {
+    fn read_into(&mut self, dst: &mut [f32]) -> Result<usize, Error> {
+        let channels = self.channels.max(1);
+        let mut frames = dst.len() / channels;
+
+        if let Some(file) = &self.file {
+            let audio = &file.audio;
+            let total = audio.samples.len() / audio.channels;
+            for (index, frame) in dst.chunks_exact_mut(channels).enumerate() {
+                if self.position >= total {
+                    if !self.looping || total == 0 {
+                        frames = index;
+                        break;
+                    }
+                    self.position = 0;
+                }
+                // file channel `c % file channels` feeds processor channel `c`
+                let start = self.position * audio.channels;
+                let samples = &audio.samples[start..start + audio.channels];
+                for (c, sample) in frame.iter_mut().enumerate() {
+                    *sample = samples[c % audio.channels];
+                }
+                self.position += 1;
+            }
+        } else {
+            dst[..frames * channels].fill(0.0);
+        }
+
+        if self.pacing == FilePacing::RealTime && frames > 0 {
+            // sleeps until the samples would have been captured, without drift
+            let started = *self.started.get_or_insert_with(Instant::now);
+            self.frames_read += frames as u64;
+            let due = started
+                + Duration::from_secs_f64(self.frames_read as f64 / self.sample_rate as f64);
+            if let Some(wait) = due.checked_duration_since(Instant::now()) {
+                thread::sleep(wait);
+            }
+        }
+
+        Ok(frames * channels)
+    }
+}
+
+#[derive(Debug)]
+struct OutputFile {
+    path: PathBuf,
+    state: OutputState,
+}
+
+#[derive(Debug)]
+enum OutputState {
+    /// No output stream has been opened yet.
+    Created(File),
+    Writing {
+        writer: WavWriter<BufWriter<File>>,
+        channels: usize,
+    },
+    /// Writing failed, the rest of the output is discarded.
+    Failed,
+}
+
+impl OutputFile {
+    fn write(&mut self, samples: &[f32], channels: usize, sample_rate: u32, buffer: &mut Vec<i16>) {
+        // the first stream sets the format of the file
+        self.state = match mem::replace(&mut self.state, OutputState::Failed) {
+            OutputState::Created(file) => {
+                match WavWriter::new(BufWriter::new(file), sample_rate, channels as u16) {
+                    Ok(writer) => OutputState::Writing { writer, channels },
+                    Err(error) => {
+                        warn!(%error, path = %self.path.display(), "file_output_failed");
+                        OutputState::Failed
+                    }
+                }
+            }
+            state => state,
+        };
+
+        let OutputState::Writing {
+            writer,
+            channels: file_channels,
+        } = &mut self.state
+        else {
+            return;
+        };
+
+        // processor channel `c % channels` feeds file channel `c`
+        buffer.clear();
+        for frame in samples.chunks_exact(channels) {
+            buffer.extend(
+                (0..*file_channels)
+                    .map(|c| (frame[c % channels].clamp(-1.0, 1.0) * i16::MAX as f32) as i16),
+            );
+        }
+        if let Err(error) = writer.write_samples(buffer) {
+            warn!(%error, path = %self.path.display(), "file_output_failed");
+            self.state = OutputState::Failed;
+        }
+    }
+
+    fn update_header(&mut self) {
+        if let OutputState::Writing { writer, .. } = &mut self.state
+            && let Err(error) = writer.update_header()
+        {
+            warn!(%error, path = %self.path.display(), "file_output_failed");
+        }
+    }
+}
+
+/// Writes the output to the output file, or discards it without one.
+struct FileOutput {
+    file: Option<Arc<Mutex<OutputFile>>>,
+    channels: usize,
+    sample_rate: u32,
+    /// Scratch space for the converted samples.
+    buffer: Vec<i16>,
+}
+
+impl AudioOutput for FileOutput {
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    fn write_samples(&mut self, samples: &[f32]) -> Result<usize, Error> {
+        if let Some(file) = &self.file {
+            file.lock().unwrap().write(
+                samples,
+                self.channels.max(1),
+                self.sample_rate,
+                &mut self.buffer,
+            );
+        }
+        Ok(0)
+    }
+}
+
+impl Drop for FileOutput {
+    /// Leaves a complete WAV file behind every closed output stream.
+    fn drop(&mut self) {
+        if let Some(file) = &self.file
+            && let Ok(mut file) = file.lock()
+        {
+            file.update_header();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 16-bit PCM WAV file with the given interleaved samples.
+    fn wav_bytes(sample_rate: u32, channels: u16, samples: &[i16]) -> Vec<u8> {
+        let mut writer =
+            WavWriter::new(std::io::Cursor::new(Vec::new()), sample_rate, channels).unwrap();
+        writer.write_samples(samples).unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn input_maps_file_channels_and_ends_with_the_file() {
+        let host = FileAudioHost::new()
+            .input_bytes(wav_bytes(16_000, 1, &[i16::MAX, 0, i16::MIN + 1]))
+            .unwrap()
+            .pacing(FilePacing::AsFastAsPossible);
+        assert_eq!(host.input_sample_rate(None).unwrap(), 16_000);
+
+        let (mut input, sample_rate, _) = host.open_input(None, 2, None).unwrap();
+        assert_eq!(sample_rate, 16_000);
+
+        let mut samples = [0.5; 8];
+        assert_eq!(input.read_into(&mut samples).unwrap(), 6);
+        assert_eq!(&samples[..6], &[1.0, 1.0, 0.0, 0.0, -1.0, -1.0]);
+        assert_eq!(input.read_into(&mut samples).unwrap(), 0);
+    }
+
+    #[test]
+    fn looping_input_restarts_the_file() {
+        let host = FileAudioHost::new()
+            .input_bytes(wav_bytes(48_000, 2, &[i16::MAX, 0]))
+            .unwrap()
+            .pacing(FilePacing::AsFastAsPossible)
+            .looping(true);
+
+        let (mut input, _, _) = host.open_input(None, 1, None).unwrap();
+        let mut samples = [0.5; 3];
+        assert_eq!(input.read_into(&mut samples).unwrap(), 3);
+        assert_eq!(samples, [1.0; 3]);
+    }
+
+    #[test]
+    fn real_time_input_is_paced() {
+        let host = FileAudioHost::new();
+        let (mut input, _, _) = host.open_input(None, 1, None).unwrap();
+
+        // 2400 samples at 48 kHz take 50ms
+        let started = Instant::now();
+        let mut samples = vec![1.0; 2_400];
+        for _ in 0..2 {
+            assert_eq!(input.read_into(&mut samples).unwrap(), samples.len());
+        }
+        assert!(started.elapsed() >= Duration::from_millis(100));
+        assert!(samples.iter().all(|sample| *sample == 0.0));
+    }
+
+    #[test]
+    fn unknown_devices_are_not_found() {
+        let host = FileAudioHost::new();
+        assert!(host.open_input(Some(FILE_DEVICE_ID), 1, None).is_ok());
+        assert!(matches!(
+            host.open_output(Some("speakers"), 1, None),
+            Err(DeviceError::DeviceNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn output_streams_append_to_one_wav_file() {
+        let path =
+            std::env::temp_dir().join(format!("telepathy-file-host-{}.wav", std::process::id()));
+        let host = FileAudioHost::new()
+            .output_file(&path)
+            .unwrap()
+            .output_rate(24_000);
+
+        let (mut output, sample_rate, _) = host.open_output(None, 2, None).unwrap();
+        assert_eq!(sample_rate, 24_000);
+        output.write_samples(&[1.0, -1.0, 0.0, 0.0]).unwrap();
+        drop(output);
+
+        // a replacement stream with fewer channels fills every file channel
+        let (mut output, _, _) = host.open_output(None, 1, None).unwrap();
+        output.write_samples(&[1.0]).unwrap();
+        drop(output);
+
+        let audio = decode_audio(std::fs::read(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(audio.sample_rate, 24_000);
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.samples, vec![1.0, -1.0, 0.0, 0.0, 1.0, 1.0]);
+    }
+}
@@ -4,11 +4,15 @@
 /// stored value on each call. Used by tests that need to run without a physical
 /// audio device.
 use crate::Error;
-use crate::devices::{AudioDeviceInfo, AudioDeviceList, AudioHost, DeviceError};
+use crate::devices::{AudioDeviceInfo, AudioDeviceList, AudioHost, DeviceError, DeviceEvent};
 use crate::internal::traits::{AudioInput, AudioOutput};
 use crate::io::StreamErrorCallback;
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+#[cfg(not(target_family = "wasm"))]
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
 
 const DEFAULT_SAMPLE_RATE: u32 = 48_000;
 const MOCK_DEVICE_ID: &str = "mock";
@@ -19,6 +23,10 @@ pub struct MockAudioHost<I, O> {
     input_rate: u32,
     output: O,
     output_rate: u32,
+    /// Events every new watcher receives first.
+    device_events: Vec<DeviceEvent>,
+    /// Senders of every watcher, shared between clones.
+    watchers: Arc<Mutex<Vec<UnboundedSender<DeviceEvent>>>>,
 }
 
 impl<I, O> MockAudioHost<I, O> {
@@ -30,8 +38,23 @@ impl<I, O> MockAudioHost<I, O> {
             input_rate,
             output,
             output_rate,
+            device_events: Vec::new(),
+            watchers: Default::default(),
         }
     }
+
+    /// Scripts the events each [`watch_devices`](AudioHost::watch_devices)
+    /// receiver starts with.
+    pub fn with_device_events(mut self, events: Vec<DeviceEvent>) -> Self {
+        self.device_events = events;
+        self
+    }
+
+    /// Sends `event` to every open device watcher of this host and its clones.
+    pub fn emit_device_event(&self, event: DeviceEvent) {
+        let mut watchers = self.watchers.lock().unwrap();
+        watchers.retain(|watcher| watcher.send(event.clone()).is_ok());
+    }
 }
 
 impl<I, O> AudioHost for MockAudioHost<I, O>
@@ -75,6 +98,7 @@ where
     fn open_input(
         &self,
         _: Option<&str>,
+        _: u16,
         _: Option<StreamErrorCallback>,
     ) -> Result<(impl AudioInput + Send + 'static, u32, Self::InputStream), DeviceError> {
         Ok((self.input.clone(), self.input_rate, ()))
@@ -83,10 +107,22 @@ where
     fn open_output(
         &self,
         _: Option<&str>,
+        _: u16,
         _: Option<StreamErrorCallback>,
     ) -> Result<(impl AudioOutput + Send + 'static, u32, Self::OutputStream), DeviceError> {
         Ok((self.output.clone(), self.output_rate, ()))
     }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn watch_devices(&self) -> Result<UnboundedReceiver<DeviceEvent>, DeviceError> {
+        let (sender, receiver) = unbounded_channel();
+        for event in &self.device_events {
+            // the receiver is still in hand, so sending cannot fail
+            let _ = sender.send(event.clone());
+        }
+        self.watchers.lock().unwrap().push(sender);
+        Ok(receiver)
+    }
 }
 
 impl<I: Default, O: Default> Default for MockAudioHost<I, O> {
@@ -96,6 +132,8 @@ impl<I: Default, O: Default> Default for MockAudioHost<I, O> {
             input_rate: DEFAULT_SAMPLE_RATE,
             output: Default::default(),
             output_rate: DEFAULT_SAMPLE_RATE,
+            device_events: Vec::new(),
+            watchers: Default::default(),
         }
     }
 }
@@ -155,6 +193,7 @@ impl AudioOutput for MockAudioOutput {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::devices::DeviceDirection;
 
     #[test]
     fn mock_audio_input_emits_changing_non_silent_samples() {
@@ -171,4 +210,36 @@ mod tests {
         assert!(second.iter().any(|sample| *sample != 0.0));
         assert_ne!(first, second);
     }
+
+    #[test]
+    fn watchers_receive_scripted_and_emitted_events() {
+        let added = DeviceEvent::Added {
+            direction: DeviceDirection::Input,
+            device: AudioDeviceInfo {
+                name: "Headset".to_string(),
+                id: "headset".to_string(),
+            },
+        };
+        let default_changed = DeviceEvent::DefaultChanged {
+            direction: DeviceDirection::Output,
+            device: None,
+        };
+        let host = MockAudioHost::<MockAudioInput, MockAudioOutput>::default()
+            .with_device_events(vec![added.clone()]);
+
+        let mut first = host.watch_devices().unwrap();
+        let mut second = host.clone().watch_devices().unwrap();
+        host.emit_device_event(default_changed.clone());
+
+        for watcher in [&mut first, &mut second] {
+            assert_eq!(watcher.try_recv().unwrap(), added);
+            assert_eq!(watcher.try_recv().unwrap(), default_changed);
+            assert!(watcher.try_recv().is_err());
+        }
+
+        // closed watchers are forgotten
+        drop(first);
+        host.emit_device_event(default_changed);
+        assert_eq!(host.watchers.lock().unwrap().len(), 1);
+    }
 }
@@ -1,12 +1,15 @@
 //! Audio device enumeration and selection module.
 //!
 //! This module provides types and functions for enumerating and selecting
-//! audio input/output devices across platforms.
+//! audio input/output devices across platforms, and for watching them change.
 
 mod cpal_host;
 mod direction;
 mod error;
+#[cfg(not(target_family = "wasm"))]
+mod file_host;
 mod mock_host;
+mod watch;
 
 #[cfg(not(target_family = "wasm"))]
 use crate::internal::traits::AudioInput;
@@ -17,7 +20,14 @@ use cpal::traits::DeviceTrait;
 pub use cpal_host::CpalAudioHost;
 pub use direction::DeviceDirection;
 pub use error::DeviceError;
+#[cfg(not(target_family = "wasm"))]
+pub use file_host::{FileAudioHost, FilePacing};
 pub use mock_host::{MockAudioHost, MockAudioInput, MockAudioOutput};
+#[cfg(not(target_family = "wasm"))]
+use tokio::sync::mpsc::UnboundedReceiver;
+#[cfg(not(target_family = "wasm"))]
+pub use watch::DEVICE_POLL_INTERVAL;
+pub use watch::{DeviceEvent, DeviceSnapshot};
 
 /// Host abstraction for device enumeration, selection, and stream lifecycle.
 ///
@@ -54,11 +64,15 @@ pub trait AudioHost {
     /// native sample rate, and the platform stream handle. The platform handle
     /// must outlive all use of the processor input.
     ///
+    /// The processor input yields `channels` interleaved channels; device
+    /// channels are mapped onto them round robin.
+    ///
     /// `error_callback`, when `Some`, replaces the default error log path.
     #[cfg(not(target_family = "wasm"))]
     fn open_input(
         &self,
         device_id: Option<&str>,
+        channels: u16,
         error_callback: Option<StreamErrorCallback>,
     ) -> Result<(impl AudioInput + Send + 'static, u32, Self::InputStream), DeviceError>;
 
@@ -66,12 +80,25 @@ pub trait AudioHost {
     /// native sample rate, and the platform stream handle. The platform handle
     /// must outlive all use of the processor output.
     ///
+    /// The processor output accepts `channels` interleaved channels, which are
+    /// mapped onto the device's channels round robin.
+    ///
     /// `error_callback`, when `Some`, replaces the default error log path.
     fn open_output(
         &self,
         device_id: Option<&str>,
+        channels: u16,
         error_callback: Option<StreamErrorCallback>,
     ) -> Result<(impl AudioOutput + Send + 'static, u32, Self::OutputStream), DeviceError>;
+
+    /// Watches for devices being connected or disconnected and for changes of
+    /// the system default devices.
+    ///
+    /// Events are delivered until the receiver is dropped. Errors only come
+    /// from setting up the watch; later enumeration failures are logged and
+    /// retried.
+    #[cfg(not(target_family = "wasm"))]
+    fn watch_devices(&self) -> Result<UnboundedReceiver<DeviceEvent>, DeviceError>;
 }
 
 /// Information about an audio device.
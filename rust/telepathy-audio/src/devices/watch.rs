@@ -0,0 +1,244 @@
+//! Device hot-plug and default-device-change notifications.
+//!
+//! [`AudioHost::watch_devices`](crate::devices::AudioHost::watch_devices)
+//! returns a receiver of [`DeviceEvent`]s. Backends without native change
+//! notifications poll a [`DeviceSnapshot`] and report the differences
+//! between consecutive snapshots.
+
+#[cfg(not(target_family = "wasm"))]
+use crate::devices::DeviceError;
+use crate::devices::{AudioDeviceInfo, DeviceDirection};
+#[cfg(not(target_family = "wasm"))]
+use crate::internal::thread;
+#[cfg(not(target_family = "wasm"))]
+use std::time::Duration;
+#[cfg(not(target_family = "wasm"))]
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+#[cfg(not(target_family = "wasm"))]
+use tracing::warn;
+
+/// How often devices are polled on backends without change notifications.
+#[cfg(not(target_family = "wasm"))]
+pub const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A change to the available audio devices.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeviceEvent {
+    /// A device was connected.
+    Added {
+        /// Whether the device records or plays audio.
+        direction: DeviceDirection,
+        /// The new device.
+        device: AudioDeviceInfo,
+    },
+    /// A device was disconnected.
+    Removed {
+        /// Whether the device recorded or played audio.
+        direction: DeviceDirection,
+        /// The removed device.
+        device: AudioDeviceInfo,
+    },
+    /// The system default device changed.
+    DefaultChanged {
+        /// Whether the default input or output device changed.
+        direction: DeviceDirection,
+        /// The new default device, `None` when there is none.
+        device: Option<AudioDeviceInfo>,
+    },
+}
+
+/// The devices of a host at one point in time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeviceSnapshot {
+    /// Available input (recording) devices.
+    pub input_devices: Vec<AudioDeviceInfo>,
+    /// Available output (playback) devices.
+    pub output_devices: Vec<AudioDeviceInfo>,
+    /// The system default input device.
+    pub default_input: Option<AudioDeviceInfo>,
+    /// The system default output device.
+    pub default_output: Option<AudioDeviceInfo>,
+}
+
+impl DeviceSnapshot {
+    /// Returns the events that lead from this snapshot to `current`.
+    ///
+    /// Devices are matched by ID. For each direction, additions are reported
+    /// before removals, followed by a change of the default device.
+    pub fn changes(&self, current: &DeviceSnapshot) -> Vec<DeviceEvent> {
+        let mut events = Vec::new();
+
+        for (direction, previous, current, previous_default, current_default) in [
+            (
+                DeviceDirection::Input,
+                &self.input_devices,
+                &current.input_devices,
+                &self.default_input,
+                &current.default_input,
+            ),
+            (
+                DeviceDirection::Output,
+                &self.output_devices,
+                &current.output_devices,
+                &self.default_output,
+                &current.default_output,
+            ),
+        ] {
+            let contains = |devices: &[AudioDeviceInfo], id: &str| {
+                devices.iter().any(|device| device.id == id)
+            };
+
+            events.extend(
+                current
+                    .iter()
+                    .filter(|device| !contains(previous, &device.id))
+                    .map(|device| DeviceEvent::Added {
+                        direction,
+                        device: device.clone(),
+                    }),
+            );
+            events.extend(
+                previous
+                    .iter()
+                    .filter(|device| !contains(current, &device.id))
+                    .map(|device| DeviceEvent::Removed {
+                        direction,
+                        device: device.clone(),
+                    }),
+            );
+
+            let default_id =
+                |device: &Option<AudioDeviceInfo>| device.as_ref().map(|device| device.id.clone());
+            if default_id(previous_default) != default_id(current_default) {
+                events.push(DeviceEvent::DefaultChanged {
+                    direction,
+                    device: current_default.clone(),
+                });
+            }
+        }
+
+        events
+    }
+}
+
+/// Spawns a thread which polls `snapshot` every [`DEVICE_POLL_INTERVAL`] and
+/// sends the changes since `initial`, until the receiver is dropped.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn poll_devices<F>(
+    initial: DeviceSnapshot,
+    mut snapshot: F,
+) -> UnboundedReceiver<DeviceEvent>
+where
+    F: FnMut() -> Result<DeviceSnapshot, DeviceError> + Send + 'static,
+{
+    let (sender, receiver) = unbounded_channel();
+
+    thread::spawn(move || {
+        let mut previous = initial;
+
+        loop {
+            thread::sleep(DEVICE_POLL_INTERVAL);
+            if sender.is_closed() {
+                break;
+            }
+
+            let current = match snapshot() {
+                Ok(current) => current,
+                Err(error) => {
+                    warn!(%error, "device_poll_failed");
+                    continue;
+                }
+            };
+
+            for event in previous.changes(&current) {
+                if sender.send(event).is_err() {
+                    return;
+                }
+            }
+            previous = current;
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(id: &str) -> AudioDeviceInfo {
+        AudioDeviceInfo {
+            name: format!("Device {id}"),
+            id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn unchanged_snapshots_have_no_changes() {
+        let snapshot = DeviceSnapshot {
+            input_devices: vec![device("mic")],
+            output_devices: vec![device("speakers")],
+            default_input: Some(device("mic")),
+            default_output: Some(device("speakers")),
+        };
+
+        assert!(snapshot.changes(&snapshot.clone()).is_empty());
+    }
+
+    #[test]
+    fn changes_report_added_removed_and_default_devices() {
+        let previous = DeviceSnapshot {
+            input_devices: vec![device("mic")],
+            output_devices: vec![device("speakers"), device("headset")],
+            default_input: Some(device("mic")),
+            default_output: Some(device("headset")),
+        };
+        let current = DeviceSnapshot {
+            input_devices: vec![device("mic"), device("usb")],
+            output_devices: vec![device("speakers")],
+            default_input: Some(device("mic")),
+            default_output: Some(device("speakers")),
+        };
+
+        assert_eq!(
+            previous.changes(&current),
+            vec![
+                DeviceEvent::Added {
+                    direction: DeviceDirection::Input,
+                    device: device("usb"),
+                },
+                DeviceEvent::Removed {
+                    direction: DeviceDirection::Output,
+                    device: device("headset"),
+                },
+                DeviceEvent::DefaultChanged {
+                    direction: DeviceDirection::Output,
+                    device: Some(device("speakers")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn losing_the_default_device_reports_none() {
+        let previous = DeviceSnapshot {
+            input_devices: vec![device("mic")],
+            default_input: Some(device("mic")),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            previous.changes(&DeviceSnapshot::default()),
+            vec![
+                DeviceEvent::Removed {
+                    direction: DeviceDirection::Input,
+                    device: device("mic"),
+                },
+                DeviceEvent::DefaultChanged {
+                    direction: DeviceDirection::Input,
+                    device: None,
+                },
+            ]
+        );
+    }
+}
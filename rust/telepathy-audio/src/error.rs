@@ -23,7 +23,9 @@
 //! without stringification. The crate does not depend on `thiserror`; all
 //! human-facing messages are produced by [`Display`] implementations only.
 
+use crate::codec::CodecId;
 use crate::devices::DeviceError;
+use crate::lossless::LosslessError;
 use crate::sea::codec::common::SeaError;
 use cpal::{SampleFormat, SupportedStreamConfig};
 
@@ -163,6 +165,15 @@ pub enum ProcessingError {
     Slice(std::array::TryFromSliceError),
     /// The SEA codec returned an error during streaming encode/decode.
     Codec(SeaError),
+    /// libopus returned an error during encode/decode.
+    #[cfg(not(target_family = "wasm"))]
+    Opus(audiopus::Error),
+    /// The lossless codec rejected a frame.
+    Lossless(LosslessError),
+    /// `realfft` rejected a buffer during echo cancellation.
+    Fft(realfft::FftError),
+    /// A frame payload did not have the length its codec requires.
+    FrameLength { expected: usize, actual: usize },
     /// Resampler factory rejected zero channels.
     ResamplerZeroChannels,
     /// An internal frame source was constructed with zero channels.
@@ -179,6 +190,13 @@ impl std::fmt::Display for ProcessingError {
             ProcessingError::Buffer(err) => write!(f, "audio buffer error: {:?}", err),
             ProcessingError::Slice(err) => write!(f, "slice conversion error: {}", err),
             ProcessingError::Codec(err) => write!(f, "codec error: {}", err),
+            #[cfg(not(target_family = "wasm"))]
+            ProcessingError::Opus(err) => write!(f, "opus error: {}", err),
+            ProcessingError::Lossless(err) => write!(f, "lossless codec error: {}", err),
+            ProcessingError::Fft(err) => write!(f, "fft error: {}", err),
+            ProcessingError::FrameLength { expected, actual } => {
+                write!(f, "frame is {} bytes, expected {}", actual, expected)
+            }
             ProcessingError::ResamplerZeroChannels => {
                 write!(f, "resampler requires > 0 channels")
             }
@@ -196,7 +214,12 @@ impl std::error::Error for ProcessingError {
             ProcessingError::Resample(err) => Some(err),
             ProcessingError::Slice(err) => Some(err),
             ProcessingError::Codec(err) => Some(err),
+            #[cfg(not(target_family = "wasm"))]
+            ProcessingError::Opus(err) => Some(err),
+            ProcessingError::Lossless(err) => Some(err),
+            ProcessingError::Fft(err) => Some(err),
             ProcessingError::Buffer(_)
+            | ProcessingError::FrameLength { .. }
             | ProcessingError::ResamplerZeroChannels
             | ProcessingError::ZeroChannelFrameSource => None,
         }
@@ -218,6 +241,10 @@ pub enum ChannelError {
     DataSourceFailed(std::io::Error),
     /// A data sink returned an `io::Error` from `send`.
     DataSinkFailed(std::io::Error),
+    /// The mixer processor stopped and can no longer accept sources.
+    MixerStopped,
+    /// The processor stopped and can no longer switch devices.
+    ProcessorStopped,
 }
 
 impl std::fmt::Display for ChannelError {
@@ -230,6 +257,8 @@ impl std::fmt::Display for ChannelError {
                 write!(f, "audio data source failed: {}", err)
             }
             ChannelError::DataSinkFailed(err) => write!(f, "audio data sink failed: {}", err),
+            ChannelError::MixerStopped => write!(f, "audio mixer stopped"),
+            ChannelError::ProcessorStopped => write!(f, "audio processor stopped"),
         }
     }
 }
@@ -239,7 +268,10 @@ impl std::error::Error for ChannelError {
         match self {
             ChannelError::Chunk(err) => Some(err),
             ChannelError::DataSourceFailed(err) | ChannelError::DataSinkFailed(err) => Some(err),
-            ChannelError::BlockingWriteCanceled | ChannelError::ConsumerAbandoned => None,
+            ChannelError::BlockingWriteCanceled
+            | ChannelError::ConsumerAbandoned
+            | ChannelError::MixerStopped
+            | ChannelError::ProcessorStopped => None,
         }
     }
 }
@@ -255,6 +287,14 @@ pub enum ConfigError {
     MissingDataSource,
     /// A WASM input builder has no `WebAudioWrapper` set.
     MissingWebAudioWrapper,
+    /// A codec identifier does not match any known [`CodecId`](crate::codec::CodecId).
+    UnknownCodec(u8),
+    /// A known codec is not available in this build.
+    UnsupportedCodec(CodecId),
+    /// A channel count that cannot be carried by a network frame.
+    UnsupportedChannels(u16),
+    /// A recording tap already has a running recorder.
+    AlreadyRecording,
 }
 
 impl std::fmt::Display for ConfigError {
@@ -273,6 +313,14 @@ impl std::fmt::Display for ConfigError {
                 f,
                 "WebAudioWrapper must be set via web_audio_wrapper() before calling build() on WASM targets"
             ),
+            ConfigError::UnknownCodec(id) => write!(f, "unknown codec id {}", id),
+            ConfigError::UnsupportedCodec(id) => {
+                write!(f, "codec {:?} is not supported on this platform", id)
+            }
+            ConfigError::UnsupportedChannels(channels) => {
+                write!(f, "{} channel audio is not supported", channels)
+            }
+            ConfigError::AlreadyRecording => write!(f, "a recording is already running"),
         }
     }
 }
@@ -288,6 +336,8 @@ pub enum TaskError {
     Join(tokio::task::JoinError),
     /// A playback task dropped its initialization oneshot without sending a result.
     PlaybackInitChannelClosed,
+    /// The recording writer thread panicked.
+    RecorderPanicked,
     /// A WASM oneshot receive failed.
     #[cfg(target_family = "wasm")]
     OneshotReceive(tokio::sync::oneshot::error::RecvError),
@@ -303,6 +353,7 @@ impl std::fmt::Display for TaskError {
                     "playback task terminated unexpectedly before initialization"
                 )
             }
+            TaskError::RecorderPanicked => write!(f, "recording writer thread panicked"),
             #[cfg(target_family = "wasm")]
             TaskError::OneshotReceive(err) => {
                 write!(f, "wasm blocking task channel closed: {}", err)
@@ -317,14 +368,14 @@ impl std::error::Error for TaskError {
             TaskError::Join(err) => Some(err),
             #[cfg(target_family = "wasm")]
             TaskError::OneshotReceive(err) => Some(err),
-            TaskError::PlaybackInitChannelClosed => None,
+            TaskError::PlaybackInitChannelClosed | TaskError::RecorderPanicked => None,
         }
     }
 }
 
 // ---- AudioFile ----
 
-/// Failures raised when parsing a WAV or SEA file.
+/// Failures raised when parsing or writing a WAV or SEA file.
 #[derive(Debug)]
 pub enum AudioFileError {
     /// Decoding this file would exceed the player's bounded in-memory frame limit.
@@ -343,6 +394,33 @@ pub enum AudioFileError {
     },
     /// The WAV signature (RIFF/WAVE) is missing or invalid.
     InvalidSignature,
+    /// A required RIFF chunk (`fmt ` or `data`) was not found.
+    MissingChunk([u8; 4]),
+    /// A RIFF chunk declares more bytes than the file contains.
+    TruncatedChunk {
+        /// The chunk id.
+        id: [u8; 4],
+        /// The chunk size declared in its header.
+        declared: u32,
+        /// The bytes remaining after the chunk header.
+        available: usize,
+    },
+    /// The `fmt ` chunk is shorter than its format tag requires.
+    FormatChunkTooShort {
+        /// The declared `fmt ` chunk size.
+        size: u32,
+        /// The size required by the format tag.
+        required: usize,
+    },
+    /// The `fmt ` block align does not match the channels and sample size.
+    BlockAlignMismatch {
+        /// The block align from the `fmt ` chunk.
+        block_align: u16,
+        /// Channels times bytes per sample.
+        expected: usize,
+    },
+    /// A `WAVE_FORMAT_EXTENSIBLE` sub-format GUID is neither PCM nor IEEE float.
+    UnsupportedSubFormat([u8; 16]),
     /// The WAV `(audio_format, bits_per_sample)` pair is not supported.
     UnsupportedSampleFormat {
         /// WAV `audio_format` value (1 = PCM, 3 = IEEE float).
@@ -367,6 +445,8 @@ pub enum AudioFileError {
     UnknownSampleFormat(SampleFormat),
     /// The SEA codec returned an error during file/header parsing.
     Codec(SeaError),
+    /// Reading or writing an audio file failed.
+    Io(std::io::Error),
 }
 
 impl std::fmt::Display for AudioFileError {
@@ -385,6 +465,40 @@ impl std::fmt::Display for AudioFileError {
             AudioFileError::InvalidSignature => {
                 write!(f, "missing or invalid RIFF/WAVE signature")
             }
+            AudioFileError::MissingChunk(id) => {
+                write!(f, "missing '{}' chunk", String::from_utf8_lossy(id))
+            }
+            AudioFileError::TruncatedChunk {
+                id,
+                declared,
+                available,
+            } => write!(
+                f,
+                "'{}' chunk is truncated: declares {} bytes, {} available",
+                String::from_utf8_lossy(id),
+                declared,
+                available
+            ),
+            AudioFileError::FormatChunkTooShort { size, required } => write!(
+                f,
+                "'fmt ' chunk is too short: got {} bytes, need at least {}",
+                size, required
+            ),
+            AudioFileError::BlockAlignMismatch {
+                block_align,
+                expected,
+            } => write!(
+                f,
+                "WAV block align is {}, expected {}",
+                block_align, expected
+            ),
+            AudioFileError::UnsupportedSubFormat(guid) => {
+                write!(f, "unsupported WAVE_FORMAT_EXTENSIBLE sub-format: ")?;
+                for byte in guid {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
             AudioFileError::UnsupportedSampleFormat {
                 audio_format,
                 bits_per_sample,
@@ -408,6 +522,7 @@ impl std::fmt::Display for AudioFileError {
                 write!(f, "unsupported sample format: {:?}", sample_format)
             }
             AudioFileError::Codec(err) => write!(f, "SEA codec error: {}", err),
+            AudioFileError::Io(err) => write!(f, "audio file I/O error: {}", err),
         }
     }
 }
@@ -416,6 +531,7 @@ impl std::error::Error for AudioFileError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             AudioFileError::Codec(err) => Some(err),
+            AudioFileError::Io(err) => Some(err),
             _ => None,
         }
     }
@@ -518,6 +634,25 @@ impl From<SeaError> for Error {
     }
 }
 
+#[cfg(not(target_family = "wasm"))]
+impl From<audiopus::Error> for Error {
+    fn from(err: audiopus::Error) -> Self {
+        Error::Processing(ProcessingError::Opus(err))
+    }
+}
+
+impl From<LosslessError> for Error {
+    fn from(err: LosslessError) -> Self {
+        Error::Processing(ProcessingError::Lossless(err))
+    }
+}
+
+impl From<realfft::FftError> for Error {
+    fn from(err: realfft::FftError) -> Self {
+        Error::Processing(ProcessingError::Fft(err))
+    }
+}
+
 impl From<rtrb::chunks::ChunkError> for Error {
     fn from(err: rtrb::chunks::ChunkError) -> Self {
         Error::Channel(ChannelError::Chunk(err))
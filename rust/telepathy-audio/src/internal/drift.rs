@@ -0,0 +1,262 @@
+//! Clock drift compensation for the output path.
+//!
+//! The sound cards of two peers never run at exactly the same rate, so over a
+//! long call the output buffer slowly fills up or runs dry. The output
+//! processor measures how many samples are queued ahead of the device before
+//! every frame and nudges the resampling ratio so that this latency stays
+//! where it settled when the stream started.
+//!
+//! ## Algorithm
+//!
+//! - The queued sample count is smoothed, since devices drain the buffer in
+//!   bursts of whole callbacks.
+//! - For the first [`SETTLE_FRAMES`] frames nothing is corrected; the smoothed
+//!   level at the end becomes the target latency.
+//! - Afterwards a PI controller turns the distance from the target, measured
+//!   in frames, into a relative ratio correction. The integral term tracks the
+//!   steady drift between the clocks, the proportional term pulls the latency
+//!   back after jumps.
+//! - Corrections are capped at [`MAX_CORRECTION`], far below audible pitch
+//!   changes, and applied through rubato's ramped ratio changes.
+
+use crate::error::Error;
+use audioadapter_buffers::direct::InterleavedSlice;
+use rubato::{
+    Async, FixedAsync, Resampler, SincInterpolationParameters, SincInterpolationType,
+    WindowFunction,
+};
+
+/// Number of frames the latency settles for before it becomes the target.
+pub const SETTLE_FRAMES: usize = 50;
+
+/// Largest relative ratio correction applied (2000 ppm, about 3.5 cents).
+pub const MAX_CORRECTION: f64 = 0.002;
+
+/// Smoothing factor for the queued sample count.
+const LEVEL_SMOOTHING: f64 = 0.05;
+
+/// Correction per frame of distance from the target latency.
+const PROPORTIONAL_GAIN: f64 = 0.0005;
+
+/// Integral correction added per update and frame of distance.
+const INTEGRAL_GAIN: f64 = 0.000_002;
+
+/// Keeps the latency of an output buffer constant by steering the resampling
+/// ratio.
+#[derive(Debug)]
+pub struct DriftController {
+    /// Samples in one frame, the unit the distance is measured in.
+    frame_samples: f64,
+    /// Smoothed number of queued samples.
+    level: f64,
+    /// Latency the controller holds, once settled.
+    target: Option<f64>,
+    /// Updates seen while settling.
+    settled: usize,
+    /// Integral term of the controller.
+    integral: f64,
+    /// Current relative ratio correction.
+    correction: f64,
+}
+
+impl DriftController {
+    /// Creates a controller for frames of `frame_samples` interleaved samples.
+    pub fn new(frame_samples: usize) -> Self {
+        Self {
+            frame_samples: frame_samples.max(1) as f64,
+            level: 0.0,
+            target: None,
+            settled: 0,
+            integral: 0.0,
+            correction: 0.0,
+        }
+    }
+
+    /// Feeds the number of samples queued ahead of the device.
+    ///
+    /// Returns the ratio to apply relative to the nominal resampling ratio;
+    /// values below 1.0 produce fewer samples and drain the buffer.
+    pub fn update(&mut self, queued: usize) -> f64 {
+        let queued = queued as f64;
+        if self.settled == 0 {
+            self.level = queued;
+        } else {
+            self.level += (queued - self.level) * LEVEL_SMOOTHING;
+        }
+
+        let Some(target) = self.target else {
+            self.settled += 1;
+            if self.settled >= SETTLE_FRAMES {
+                self.target = Some(self.level);
+            }
+            return 1.0;
+        };
+
+        let error = (self.level - target) / self.frame_samples;
+        self.integral =
+            (self.integral + error * INTEGRAL_GAIN).clamp(-MAX_CORRECTION, MAX_CORRECTION);
+        self.correction =
+            -(error * PROPORTIONAL_GAIN + self.integral).clamp(-MAX_CORRECTION, MAX_CORRECTION);
+        1.0 + self.correction
+    }
+
+    /// Gets the current correction in parts per million.
+    ///
+    /// Positive values speed playback up to refill a buffer running dry.
+    pub fn correction_ppm(&self) -> f32 {
+        (self.correction * 1_000_000.0) as f32
+    }
+
+    /// Gets the smoothed number of samples queued ahead of the device.
+    pub fn queued(&self) -> usize {
+        self.level.round() as usize
+    }
+}
+
+/// An adjustable resampler steered by a [`DriftController`].
+///
+/// Always resamples, even between equal rates, so the ratio can be nudged.
+pub(crate) struct DriftCompensator {
+    resampler: Async<f32>,
+    controller: DriftController,
+    channels: usize,
+}
+
+impl DriftCompensator {
+    /// Creates a compensator resampling frames of `frames` samples per channel
+    /// from `input_rate` to `output_rate`.
+    pub(crate) fn new(
+        input_rate: usize,
+        output_rate: usize,
+        channels: usize,
+        frames: usize,
+    ) -> Result<Self, Error> {
+        let parameters = SincInterpolationParameters {
+            sinc_len: 128,
+            f_cutoff: 0.95,
+            interpolation: SincInterpolationType::Linear,
+            oversampling_factor: 128,
+            window: WindowFunction::BlackmanHarris2,
+        };
+        let resampler = Async::<f32>::new_sinc(
+            output_rate as f64 / input_rate as f64,
+            1.0 + MAX_CORRECTION,
+            &parameters,
+            frames,
+            channels,
+            FixedAsync::Input,
+        )?;
+
+        Ok(Self {
+            resampler,
+            controller: DriftController::new(frames * channels),
+            channels,
+        })
+    }
+
+    /// The maximum number of samples one frame is resampled to.
+    pub(crate) fn max_output_samples(&self) -> usize {
+        self.resampler.output_frames_max() * self.channels
+    }
+
+    /// Resamples one frame into `output`, steering the ratio by the number of
+    /// samples `queued` ahead of the device.
+    ///
+    /// Returns the number of samples written.
+    pub(crate) fn process(
+        &mut self,
+        frame: &[f32],
+        output: &mut [f32],
+        queued: usize,
+    ) -> Result<usize, Error> {
+        let relative = self.controller.update(queued);
+        self.resampler.set_resample_ratio_relative(relative, true)?;
+
+        let input_adapter =
+            InterleavedSlice::new(frame, self.channels, frame.len() / self.channels)?;
+        let mut output_adapter =
+            InterleavedSlice::new_mut(output, self.channels, output.len() / self.channels)?;
+        let processed =
+            self.resampler
+                .process_into_buffer(&input_adapter, &mut output_adapter, None)?;
+        Ok(processed.1 * self.channels)
+    }
+
+    /// Gets the controller steering the ratio.
+    pub(crate) fn controller(&self) -> &DriftController {
+        &self.controller
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: usize = 480;
+
+    /// Settles a controller at `queued` samples.
+    fn settled(queued: usize) -> DriftController {
+        let mut controller = DriftController::new(FRAME);
+        for _ in 0..SETTLE_FRAMES {
+            assert_eq!(controller.update(queued), 1.0);
+        }
+        controller
+    }
+
+    #[test]
+    fn holds_the_ratio_while_settling_and_on_target() {
+        let mut controller = settled(2_000);
+
+        for _ in 0..100 {
+            assert_eq!(controller.update(2_000), 1.0);
+        }
+        assert_eq!(controller.correction_ppm(), 0.0);
+        assert_eq!(controller.queued(), 2_000);
+    }
+
+    #[test]
+    fn drains_a_filling_buffer_and_refills_a_dry_one() {
+        let mut full = settled(2_000);
+        let mut dry = settled(2_000);
+
+        let mut full_ratio = 1.0;
+        let mut dry_ratio = 1.0;
+        for _ in 0..100 {
+            full_ratio = full.update(2_000 + FRAME);
+            dry_ratio = dry.update(2_000 - FRAME);
+        }
+
+        assert!(full_ratio < 1.0 && full.correction_ppm() < 0.0);
+        assert!(dry_ratio > 1.0 && dry.correction_ppm() > 0.0);
+    }
+
+    #[test]
+    fn caps_the_correction() {
+        let mut controller = settled(0);
+
+        for _ in 0..100_000 {
+            controller.update(100 * FRAME);
+        }
+
+        assert!((controller.update(100 * FRAME) - (1.0 - MAX_CORRECTION)).abs() < 1e-12);
+    }
+
+    /// Simulates a device draining at `drift` relative to the nominal rate
+    /// and checks the controller settles on the opposite correction.
+    #[test]
+    fn integral_tracks_a_steady_drift() {
+        let drift = 0.000_1;
+        let mut controller = DriftController::new(FRAME);
+        let mut queued = 2_000.0;
+        let mut ratio = 1.0;
+
+        for _ in 0..200_000 {
+            ratio = controller.update(queued as usize);
+            // the processor writes a resampled frame, the device drains one
+            queued += FRAME as f64 * ratio - FRAME as f64 * (1.0 + drift);
+        }
+
+        assert!((ratio - (1.0 + drift)).abs() < 0.000_02, "ratio {ratio}");
+        assert!((queued - 2_000.0).abs() < FRAME as f64, "queued {queued}");
+    }
+}
@@ -0,0 +1,449 @@
+//! Acoustic echo cancellation.
+//!
+//! The output processor taps every far-end frame it plays into an
+//! [`EchoReference`]. The input processor owns an [`EchoCanceller`] that reads
+//! those frames back and subtracts the estimated echo from the microphone
+//! signal before it reaches the denoiser.
+//!
+//! ## Algorithm
+//!
+//! The canceller is a partitioned-block frequency-domain adaptive filter
+//! (PBFDAF, the same family as Speex's MDF) working on `FRAME_SIZE` blocks
+//! with a `2 * FRAME_SIZE` real FFT. The echo tail is split into
+//! [`DEFAULT_TAIL_FRAMES`] partitions, giving 160 ms of coverage at 48 kHz.
+//!
+//! The adaptation step is scaled by the ratio of estimated echo power to
+//! residual power once the filter has warmed up. During double talk the
+//! residual is dominated by near-end speech, so the filter slows down instead
+//! of diverging.
+//!
+//! ## Reference Channel
+//!
+//! Every output stream registers its own lock-free SPSC ring buffer with the
+//! shared [`EchoReference`]. The canceller picks up new registrations through a
+//! lock-free queue, sums all active streams into a single far-end frame, and
+//! drops streams whose producer has gone away. Rooms can therefore feed every
+//! remote peer into one canceller without a mixing thread.
+
+use crate::constants::FRAME_SIZE;
+use crate::error::Error;
+use crossbeam::queue::SegQueue;
+use realfft::num_complex::Complex;
+use realfft::{ComplexToReal, RealFftPlanner, RealToComplex};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Number of `FRAME_SIZE` partitions in the adaptive filter.
+///
+/// At 48 kHz each partition covers 10 ms, so 16 partitions model echo paths
+/// (device buffering plus room reverberation) of up to 160 ms.
+pub const DEFAULT_TAIL_FRAMES: usize = 16;
+
+/// Capacity of each per-stream reference ring buffer in samples.
+const REFERENCE_CAPACITY: usize = FRAME_SIZE * 32;
+
+/// Maximum reference backlog kept per stream.
+///
+/// Samples queued beyond this are stale relative to the microphone and are
+/// skipped so the far-end signal never lags the echo it should cancel.
+const MAX_REFERENCE_BACKLOG: usize = FRAME_SIZE * 3;
+
+/// FFT length used by the overlap-save block filter.
+const FFT_SIZE: usize = FRAME_SIZE * 2;
+
+/// Number of complex bins produced by the real FFT.
+const BINS: usize = FFT_SIZE / 2 + 1;
+
+/// Base NLMS step size.
+const STEP_SIZE: f32 = 0.5;
+
+/// Smoothing factor for the per-bin far-end power estimate.
+const POWER_SMOOTHING: f32 = 0.9;
+
+/// Keeps the normalization finite when the far end is quiet.
+const REGULARIZATION: f32 = 1e-2;
+
+/// Far-end peaks below this level are treated as silence and do not adapt the filter.
+const FAR_END_SILENCE: f32 = 1e-4;
+
+/// Number of active far-end frames (per partition) adapted at the full step size.
+const WARMUP_FRAMES_PER_PARTITION: usize = 4;
+
+/// Shared far-end reference between output processors and an echo canceller.
+///
+/// Cloning is cheap; all clones register streams with the same canceller.
+#[derive(Clone, Default)]
+pub struct EchoReference {
+    streams: Arc<SegQueue<ReferenceStream>>,
+}
+
+impl EchoReference {
+    /// Creates a new, empty echo reference.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new far-end stream and returns its producer half.
+    ///
+    /// `sample_rate` is the rate of the samples that will be pushed. Streams
+    /// whose rate does not match the canceller are ignored.
+    pub fn producer(&self, sample_rate: u32) -> EchoReferenceProducer {
+        let (producer, consumer) = rtrb::RingBuffer::new(REFERENCE_CAPACITY);
+        self.streams.push(ReferenceStream {
+            consumer,
+            sample_rate,
+        });
+        EchoReferenceProducer { producer }
+    }
+}
+
+/// The producer half of one far-end stream.
+///
+/// Dropping the producer unregisters the stream once the canceller has drained it.
+pub struct EchoReferenceProducer {
+    producer: rtrb::Producer<f32>,
+}
+
+impl EchoReferenceProducer {
+    /// Pushes far-end samples, dropping any that do not fit.
+    pub fn push(&mut self, samples: &[f32]) {
+        let len = samples.len().min(self.producer.slots());
+        if let Ok(chunk) = self.producer.write_chunk_uninit(len) {
+            chunk.fill_from_iter(samples.iter().copied());
+        }
+    }
+}
+
+struct ReferenceStream {
+    consumer: rtrb::Consumer<f32>,
+    sample_rate: u32,
+}
+
+/// Frequency-domain adaptive echo canceller.
+pub struct EchoCanceller {
+    reference: EchoReference,
+    sample_rate: u32,
+    /// Active far-end streams
+    streams: Vec<rtrb::Consumer<f32>>,
+    forward: Arc<dyn RealToComplex<f32>>,
+    inverse: Arc<dyn ComplexToReal<f32>>,
+    /// The summed far-end frame for the current block
+    far_frame: [f32; FRAME_SIZE],
+    /// The last two far-end blocks (overlap-save window)
+    far_history: Vec<f32>,
+    /// Spectra of the most recent far-end windows, indexed from `head`
+    far_spectra: Vec<Vec<Complex<f32>>>,
+    /// Peak magnitude of the far-end block matching each spectrum
+    far_peaks: Vec<f32>,
+    /// Index of the newest far-end spectrum
+    head: usize,
+    /// Filter weights, one spectrum per partition
+    weights: Vec<Vec<Complex<f32>>>,
+    /// Smoothed per-bin far-end power across all partitions
+    power: Vec<f32>,
+    time_buf: Vec<f32>,
+    freq_buf: Vec<Complex<f32>>,
+    error_spectrum: Vec<Complex<f32>>,
+    /// Number of frames adapted so far, saturating
+    adapted_frames: usize,
+}
+
+impl EchoCanceller {
+    /// Creates a canceller reading far-end audio from `reference`.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The shared reference the output processors write into
+    /// * `sample_rate` - The rate of the frames passed to [`process`](Self::process)
+    /// * `tail_frames` - Echo tail length in `FRAME_SIZE` partitions (at least 1)
+    pub fn new(reference: &EchoReference, sample_rate: u32, tail_frames: usize) -> Self {
+        let partitions = tail_frames.max(1);
+        let mut planner = RealFftPlanner::<f32>::new();
+        let zero = Complex::new(0_f32, 0_f32);
+
+        Self {
+            reference: reference.clone(),
+            sample_rate,
+            streams: Vec::new(),
+            forward: planner.plan_fft_forward(FFT_SIZE),
+            inverse: planner.plan_fft_inverse(FFT_SIZE),
+            far_frame: [0_f32; FRAME_SIZE],
+            far_history: vec![0_f32; FFT_SIZE],
+            far_spectra: vec![vec![zero; BINS]; partitions],
+            far_peaks: vec![0_f32; partitions],
+            head: 0,
+            weights: vec![vec![zero; BINS]; partitions],
+            power: vec![0_f32; BINS],
+            time_buf: vec![0_f32; FFT_SIZE],
+            freq_buf: vec![zero; BINS],
+            error_spectrum: vec![zero; BINS],
+            adapted_frames: 0,
+        }
+    }
+
+    /// Removes the estimated echo from one microphone frame in place.
+    ///
+    /// `frame` must contain exactly `FRAME_SIZE` samples at the canceller's
+    /// sample rate.
+    pub fn process(&mut self, frame: &mut [f32]) -> Result<(), Error> {
+        let frame: &mut [f32; FRAME_SIZE] = frame.try_into()?;
+        let partitions = self.weights.len();
+        let scale = 1_f32 / FFT_SIZE as f32;
+
+        self.pull_reference();
+
+        // slide the overlap-save window and transform the newest far-end block
+        self.far_history.copy_within(FRAME_SIZE.., 0);
+        self.far_history[FRAME_SIZE..].copy_from_slice(&self.far_frame);
+        self.head = (self.head + partitions - 1) % partitions;
+        self.far_peaks[self.head] = self.far_frame.iter().fold(0_f32, |m, s| m.max(s.abs()));
+        self.time_buf.copy_from_slice(&self.far_history);
+        self.forward
+            .process(&mut self.time_buf, &mut self.far_spectra[self.head])?;
+
+        // estimate the echo as the sum of every partition's contribution
+        self.freq_buf.fill(Complex::new(0_f32, 0_f32));
+        for (partition, weights) in self.weights.iter().enumerate() {
+            let spectrum = &self.far_spectra[(self.head + partition) % partitions];
+            for ((acc, w), x) in self.freq_buf.iter_mut().zip(weights).zip(spectrum) {
+                *acc += w * x;
+            }
+        }
+        clear_edge_imaginary(&mut self.freq_buf);
+        self.inverse
+            .process(&mut self.freq_buf, &mut self.time_buf)?;
+
+        // subtract the echo estimate (the last block of the window is valid)
+        let mut echo_power = 0_f32;
+        let mut error_power = 0_f32;
+        for (sample, echo) in frame.iter_mut().zip(&self.time_buf[FRAME_SIZE..]) {
+            let echo = echo * scale;
+            *sample -= echo;
+            echo_power += echo * echo;
+            error_power += *sample * *sample;
+        }
+
+        // update the far-end power estimate used for normalization
+        for (bin, power) in self.power.iter_mut().enumerate() {
+            let total: f32 = self.far_spectra.iter().map(|s| s[bin].norm_sqr()).sum();
+            *power = POWER_SMOOTHING * *power + (1_f32 - POWER_SMOOTHING) * total;
+        }
+
+        // nothing to learn from while the far end is silent
+        if self.far_peaks.iter().all(|peak| *peak < FAR_END_SILENCE) {
+            return Ok(());
+        }
+
+        let step = if self.adapted_frames < partitions * WARMUP_FRAMES_PER_PARTITION {
+            STEP_SIZE
+        } else {
+            // near-end speech inflates the residual and slows adaptation down
+            STEP_SIZE * (echo_power / (error_power + f32::EPSILON)).min(1_f32)
+        };
+        self.adapted_frames = self.adapted_frames.saturating_add(1);
+
+        // transform the residual, zero-padded to the front of the window
+        self.time_buf[..FRAME_SIZE].fill(0_f32);
+        self.time_buf[FRAME_SIZE..].copy_from_slice(frame);
+        self.forward
+            .process(&mut self.time_buf, &mut self.error_spectrum)?;
+
+        for (partition, weights) in self.weights.iter_mut().enumerate() {
+            let spectrum = &self.far_spectra[(self.head + partition) % partitions];
+            for (((w, x), e), power) in weights
+                .iter_mut()
+                .zip(spectrum)
+                .zip(&self.error_spectrum)
+                .zip(&self.power)
+            {
+                *w += x.conj() * e * (step / (power + REGULARIZATION));
+            }
+
+            // constrain the partition to a linear (not circular) convolution
+            self.freq_buf.copy_from_slice(weights);
+            clear_edge_imaginary(&mut self.freq_buf);
+            self.inverse
+                .process(&mut self.freq_buf, &mut self.time_buf)?;
+            self.time_buf[FRAME_SIZE..].fill(0_f32);
+            self.time_buf[..FRAME_SIZE]
+                .iter_mut()
+                .for_each(|sample| *sample *= scale);
+            self.forward.process(&mut self.time_buf, weights)?;
+        }
+
+        Ok(())
+    }
+
+    /// Collects new streams and sums one frame of every active stream into `far_frame`.
+    fn pull_reference(&mut self) {
+        while let Some(stream) = self.reference.streams.pop() {
+            if stream.sample_rate == self.sample_rate {
+                self.streams.push(stream.consumer);
+            } else {
+                warn!(
+                    reference_rate = stream.sample_rate,
+                    canceller_rate = self.sample_rate,
+                    "echo_reference_rate_mismatch"
+                );
+            }
+        }
+
+        self.far_frame.fill(0_f32);
+        let far_frame = &mut self.far_frame;
+        self.streams.retain_mut(|consumer| {
+            let available = consumer.slots();
+            if available > MAX_REFERENCE_BACKLOG
+                && let Ok(stale) = consumer.read_chunk(available - MAX_REFERENCE_BACKLOG)
+            {
+                stale.commit_all();
+            }
+
+            let len = consumer.slots().min(FRAME_SIZE);
+            if let Ok(chunk) = consumer.read_chunk(len) {
+                let (first, second) = chunk.as_slices();
+                for (dst, src) in far_frame.iter_mut().zip(first.iter().chain(second)) {
+                    *dst += src;
+                }
+                chunk.commit_all();
+            }
+
+            !(consumer.is_abandoned() && consumer.is_empty())
+        });
+    }
+}
+
+/// The inverse real FFT requires purely real DC and Nyquist bins.
+fn clear_edge_imaginary(spectrum: &mut [Complex<f32>]) {
+    if let Some(first) = spectrum.first_mut() {
+        first.im = 0_f32;
+    }
+    if let Some(last) = spectrum.last_mut() {
+        last.im = 0_f32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic white noise in [-amplitude, amplitude].
+    struct Noise(u32);
+
+    impl Noise {
+        fn next(&mut self, amplitude: f32) -> f32 {
+            self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            ((self.0 >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0) * amplitude
+        }
+    }
+
+    /// Convolves `far` with a sparse impulse response of `(delay, gain)` taps.
+    fn echo_path(history: &[f32], taps: &[(usize, f32)], index: usize) -> f32 {
+        taps.iter()
+            .filter(|(delay, _)| *delay <= index)
+            .map(|(delay, gain)| history[index - delay] * gain)
+            .sum()
+    }
+
+    fn power(samples: &[f32]) -> f32 {
+        samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32
+    }
+
+    /// Runs far-end noise through a synthetic echo path and returns the
+    /// (microphone, residual) power over the last `measure` frames.
+    fn run_echo_path(
+        frames: usize,
+        measure: usize,
+        taps: &[(usize, f32)],
+        near_amplitude: f32,
+    ) -> (f32, f32) {
+        let reference = EchoReference::new();
+        let mut producer = reference.producer(48_000);
+        let mut canceller = EchoCanceller::new(&reference, 48_000, DEFAULT_TAIL_FRAMES);
+        let mut far_noise = Noise(1);
+        let mut near_noise = Noise(2);
+        let mut far = Vec::new();
+        let mut mic_tail = Vec::new();
+        let mut residual_tail = Vec::new();
+
+        for index in 0..frames {
+            let far_frame: Vec<f32> = (0..FRAME_SIZE).map(|_| far_noise.next(0.3)).collect();
+            producer.push(&far_frame);
+            far.extend_from_slice(&far_frame);
+
+            let start = index * FRAME_SIZE;
+            let mut mic: Vec<f32> = (0..FRAME_SIZE)
+                .map(|i| echo_path(&far, taps, start + i) + near_noise.next(near_amplitude))
+                .collect();
+
+            if index >= frames - measure {
+                mic_tail.extend_from_slice(&mic);
+            }
+            canceller.process(&mut mic).unwrap();
+            if index >= frames - measure {
+                residual_tail.extend_from_slice(&mic);
+            }
+        }
+
+        (power(&mic_tail), power(&residual_tail))
+    }
+
+    #[test]
+    fn canceller_converges_on_synthetic_echo_path() {
+        let taps = [(1_200, 0.6), (1_450, -0.25), (2_900, 0.1)];
+        let (mic, residual) = run_echo_path(600, 100, &taps, 0.0);
+
+        // at least 20 dB of echo return loss enhancement
+        assert!(residual < mic * 0.01, "mic {mic}, residual {residual}");
+    }
+
+    #[test]
+    fn canceller_preserves_near_end_during_double_talk() {
+        let taps = [(960, 0.5)];
+        let (mic, residual) = run_echo_path(600, 100, &taps, 0.1);
+        let near_power = 0.1_f32 * 0.1 / 3.0;
+
+        // the residual is close to the near-end speech, not cancelled along with the echo
+        assert!(residual > near_power * 0.5, "residual {residual}");
+        assert!(residual < mic, "mic {mic}, residual {residual}");
+    }
+
+    #[test]
+    fn canceller_passes_audio_through_without_reference() {
+        let reference = EchoReference::new();
+        let mut canceller = EchoCanceller::new(&reference, 48_000, DEFAULT_TAIL_FRAMES);
+        let mut noise = Noise(3);
+        let original: Vec<f32> = (0..FRAME_SIZE).map(|_| noise.next(0.5)).collect();
+        let mut frame = original.clone();
+
+        canceller.process(&mut frame).unwrap();
+
+        assert_eq!(frame, original);
+    }
+
+    #[test]
+    fn canceller_ignores_reference_at_other_rates() {
+        let reference = EchoReference::new();
+        let mut producer = reference.producer(44_100);
+        let mut canceller = EchoCanceller::new(&reference, 48_000, DEFAULT_TAIL_FRAMES);
+        producer.push(&[0.5; FRAME_SIZE]);
+
+        let mut frame = [0_f32; FRAME_SIZE];
+        canceller.process(&mut frame).unwrap();
+
+        assert!(canceller.streams.is_empty());
+    }
+
+    #[test]
+    fn canceller_drops_abandoned_streams() {
+        let reference = EchoReference::new();
+        let mut canceller = EchoCanceller::new(&reference, 48_000, DEFAULT_TAIL_FRAMES);
+        let mut producer = reference.producer(48_000);
+        producer.push(&[0.25; FRAME_SIZE]);
+        drop(producer);
+
+        let mut frame = [0_f32; FRAME_SIZE];
+        canceller.process(&mut frame).unwrap();
+
+        assert!(canceller.streams.is_empty());
+    }
+}
@@ -0,0 +1,780 @@
+//! Pluggable audio effects.
+//!
+//! An [`EffectChain`] runs its [`AudioEffect`]s in order on every frame. The
+//! input processor runs its chain after noise suppression and automatic gain
+//! control, the output processor runs its chain on the decoded frame before
+//! the output volume is applied. Chains are set on the input and output
+//! builders and can be replaced while the stream runs through the handles.
+//!
+//! Frames hold [`FRAME_SIZE`] interleaved samples scaled to -1.0 to 1.0, and
+//! all levels are in dBFS relative to 1.0.
+//!
+//! ## Built-in Effects
+//!
+//! - [`HighPass`] - Removes rumble and handling noise below a cutoff
+//! - [`Compressor`] - Evens out loud passages above a threshold
+//! - [`DeEsser`] - Attenuates harsh sibilance
+//! - [`Equalizer`] - Shelving and peaking filters, with [`EqPreset`]s for speech
+
+use crate::constants::FRAME_SIZE;
+use crate::internal::gain::to_db;
+use crate::internal::processing::{calculate_rms, wide_mul};
+use crate::internal::utils::db_to_multiplier;
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
+
+/// Sample rate effects are designed for until a processor prepares them.
+const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+
+/// Per-frame recovery of the de-esser cut once sibilance fades.
+const DE_ESSER_RELEASE: f32 = 0.3;
+
+/// A stage that processes audio frames in place.
+///
+/// Effects run on the processor thread, so `process` should not block.
+pub trait AudioEffect: Send {
+    /// Prepares the effect for frames of `channels` interleaved channels at
+    /// `sample_rate` Hz.
+    ///
+    /// Called whenever a chain is installed on a processor, before its first
+    /// frame. Effects should design their filters and clear their state here.
+    fn prepare(&mut self, _sample_rate: u32, _channels: usize) {}
+
+    /// Processes one frame in place.
+    fn process(&mut self, frame: &mut [f32; FRAME_SIZE]);
+}
+
+/// An ordered list of effects applied one after another.
+#[derive(Default)]
+pub struct EffectChain {
+    effects: Vec<Box<dyn AudioEffect>>,
+}
+
+impl EffectChain {
+    /// Creates an empty chain, which leaves frames untouched.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an effect to the end of the chain.
+    pub fn with(mut self, effect: impl AudioEffect + 'static) -> Self {
+        self.push(effect);
+        self
+    }
+
+    /// Appends an effect to the end of the chain.
+    pub fn push(&mut self, effect: impl AudioEffect + 'static) {
+        self.effects.push(Box::new(effect));
+    }
+
+    /// Gets the number of effects in the chain.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Checks if the chain has no effects.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+}
+
+impl AudioEffect for EffectChain {
+    fn prepare(&mut self, sample_rate: u32, channels: usize) {
+        for effect in &mut self.effects {
+            effect.prepare(sample_rate, channels);
+        }
+    }
+
+    fn process(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+        for effect in &mut self.effects {
+            effect.process(frame);
+        }
+    }
+}
+
+/// High-pass filter that removes rumble and handling noise.
+///
+/// Frequencies below the cutoff fall off at 12 dB per octave.
+pub struct HighPass {
+    cutoff: f32,
+    filter: BiquadFilter,
+}
+
+impl HighPass {
+    /// Creates a high-pass filter with a cutoff of `cutoff` Hz.
+    pub fn new(cutoff: f32) -> Self {
+        let mut high_pass = Self {
+            cutoff,
+            filter: BiquadFilter::default(),
+        };
+        high_pass.prepare(DEFAULT_SAMPLE_RATE, 1);
+        high_pass
+    }
+}
+
+impl Default for HighPass {
+    /// A high-pass filter at 80 Hz, below the fundamental of most voices.
+    fn default() -> Self {
+        Self::new(80.0)
+    }
+}
+
+impl AudioEffect for HighPass {
+    fn prepare(&mut self, sample_rate: u32, channels: usize) {
+        let coefficients = Biquad::high_pass(sample_rate as f32, self.cutoff, FRAC_1_SQRT_2);
+        self.filter.design(coefficients, channels);
+    }
+
+    fn process(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+        self.filter.process(frame);
+    }
+}
+
+/// Configuration for the [`Compressor`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompressorConfig {
+    /// Level above which the gain is reduced, in dBFS.
+    pub threshold_db: f32,
+    /// Input to output ratio above the threshold, 4.0 compresses 4:1.
+    pub ratio: f32,
+    /// Time the level detector takes to follow a rising level, in ms.
+    pub attack_ms: f32,
+    /// Time the level detector takes to follow a falling level, in ms.
+    pub release_ms: f32,
+    /// Gain applied after compression, in dB.
+    pub makeup_gain_db: f32,
+}
+
+impl Default for CompressorConfig {
+    fn default() -> Self {
+        Self {
+            threshold_db: -24.0,
+            ratio: 4.0,
+            attack_ms: 10.0,
+            release_ms: 150.0,
+            makeup_gain_db: 0.0,
+        }
+    }
+}
+
+/// Downward compressor that reduces the level of loud passages.
+///
+/// The level is detected on the RMS of each frame, and the gain is ramped
+/// linearly across the frame to avoid zipper noise.
+pub struct Compressor {
+    config: CompressorConfig,
+    /// Smoothing factor applied when the level rises.
+    attack: f32,
+    /// Smoothing factor applied when the level falls.
+    release: f32,
+    /// Detected level in dBFS.
+    level_db: f32,
+    /// Linear gain applied at the end of the previous frame.
+    applied_gain: f32,
+    channels: usize,
+}
+
+impl Compressor {
+    /// Creates a compressor with the given configuration.
+    pub fn new(config: CompressorConfig) -> Self {
+        let mut compressor = Self {
+            config,
+            attack: 1.0,
+            release: 1.0,
+            level_db: to_db(0.0),
+            applied_gain: db_to_multiplier(config.makeup_gain_db),
+            channels: 1,
+        };
+        compressor.prepare(DEFAULT_SAMPLE_RATE, 1);
+        compressor
+    }
+
+    /// Computes the gain for the detected level, including the makeup gain.
+    fn target_gain(&self) -> f32 {
+        let over_db = (self.level_db - self.config.threshold_db).max(0.0);
+        let reduction_db = over_db * (1.0 - 1.0 / self.config.ratio.max(1.0));
+        db_to_multiplier(self.config.makeup_gain_db - reduction_db)
+    }
+}
+
+impl Default for Compressor {
+    fn default() -> Self {
+        Self::new(CompressorConfig::default())
+    }
+}
+
+impl AudioEffect for Compressor {
+    fn prepare(&mut self, sample_rate: u32, channels: usize) {
+        let channels = channels.max(1);
+        let frame_ms = (FRAME_SIZE / channels) as f32 * 1_000.0 / sample_rate as f32;
+        self.attack = smoothing_factor(frame_ms, self.config.attack_ms);
+        self.release = smoothing_factor(frame_ms, self.config.release_ms);
+        self.level_db = to_db(0.0);
+        self.applied_gain = db_to_multiplier(self.config.makeup_gain_db);
+        self.channels = channels;
+    }
+
+    fn process(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+        let frame_db = to_db(calculate_rms(frame));
+        let factor = if frame_db > self.level_db {
+            self.attack
+        } else {
+            self.release
+        };
+        self.level_db += (frame_db - self.level_db) * factor;
+
+        let gain = self.target_gain();
+        if gain == self.applied_gain {
+            wide_mul(frame, gain);
+        } else {
+            // ramp the gain across the frame, every channel of a sample alike
+            let step = (gain - self.applied_gain) / (FRAME_SIZE / self.channels) as f32;
+            for (index, sample) in frame.iter_mut().enumerate() {
+                let ramp = self.applied_gain + step * (index / self.channels + 1) as f32;
+                *sample = (*sample * ramp).clamp(-1.0, 1.0);
+            }
+            self.applied_gain = gain;
+        }
+    }
+}
+
+/// Configuration for the [`DeEsser`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeEsserConfig {
+    /// Lower edge of the sibilance band, in Hz.
+    pub frequency: f32,
+    /// Level of the sibilance band above which it is attenuated, in dBFS.
+    pub threshold_db: f32,
+    /// Maximum attenuation of the sibilance band, in dB.
+    pub max_reduction_db: f32,
+}
+
+impl Default for DeEsserConfig {
+    fn default() -> Self {
+        Self {
+            frequency: 5_000.0,
+            threshold_db: -30.0,
+            max_reduction_db: 12.0,
+        }
+    }
+}
+
+/// De-esser that cuts the sibilance band while it is too loud.
+///
+/// The band above the configured frequency is measured on every frame, and a
+/// high shelf at the same frequency cuts it by as much as it exceeds the
+/// threshold. Without sibilance the shelf is flat and the frame is untouched.
+pub struct DeEsser {
+    config: DeEsserConfig,
+    sample_rate: f32,
+    /// Isolates the sibilance band for measuring.
+    detector: BiquadFilter,
+    /// Cuts the sibilance band of the frame.
+    shelf: BiquadFilter,
+    /// The sibilance band of the current frame.
+    band: [f32; FRAME_SIZE],
+    /// Current cut of the sibilance band, in dB.
+    reduction_db: f32,
+}
+
+impl DeEsser {
+    /// Creates a de-esser with the given configuration.
+    pub fn new(config: DeEsserConfig) -> Self {
+        let mut de_esser = Self {
+            config,
+            sample_rate: DEFAULT_SAMPLE_RATE as f32,
+            detector: BiquadFilter::default(),
+            shelf: BiquadFilter::default(),
+            band: [0.0; FRAME_SIZE],
+            reduction_db: 0.0,
+        };
+        de_esser.prepare(DEFAULT_SAMPLE_RATE, 1);
+        de_esser
+    }
+}
+
+impl Default for DeEsser {
+    fn default() -> Self {
+        Self::new(DeEsserConfig::default())
+    }
+}
+
+impl AudioEffect for DeEsser {
+    fn prepare(&mut self, sample_rate: u32, channels: usize) {
+        self.sample_rate = sample_rate as f32;
+        let coefficients =
+            Biquad::high_pass(self.sample_rate, self.config.frequency, FRAC_1_SQRT_2);
+        self.detector.design(coefficients, channels);
+        self.shelf.design(Biquad::default(), channels);
+        self.reduction_db = 0.0;
+    }
+
+    fn process(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+        self.band.copy_from_slice(frame);
+        self.detector.process(&mut self.band);
+
+        let band_db = to_db(calculate_rms(&self.band));
+        let target_db =
+            (band_db - self.config.threshold_db).clamp(0.0, self.config.max_reduction_db);
+        // cut at once, recover over a few frames
+        if target_db > self.reduction_db {
+            self.reduction_db = target_db;
+        } else {
+            self.reduction_db += (target_db - self.reduction_db) * DE_ESSER_RELEASE;
+        }
+
+        let coefficients =
+            Biquad::high_shelf(self.sample_rate, self.config.frequency, -self.reduction_db);
+        self.shelf.retune(coefficients);
+        self.shelf.process(frame);
+    }
+}
+
+/// A single band of the [`Equalizer`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EqBand {
+    /// Boosts or cuts everything below `frequency` Hz.
+    LowShelf { frequency: f32, gain_db: f32 },
+    /// Boosts or cuts everything above `frequency` Hz.
+    HighShelf { frequency: f32, gain_db: f32 },
+    /// Boosts or cuts around `frequency` Hz, narrower as `q` grows.
+    Peak {
+        frequency: f32,
+        q: f32,
+        gain_db: f32,
+    },
+}
+
+impl EqBand {
+    /// Designs the filter for this band at `sample_rate` Hz.
+    fn coefficients(&self, sample_rate: f32) -> Biquad {
+        match *self {
+            Self::LowShelf { frequency, gain_db } => {
+                Biquad::low_shelf(sample_rate, frequency, gain_db)
+            }
+            Self::HighShelf { frequency, gain_db } => {
+                Biquad::high_shelf(sample_rate, frequency, gain_db)
+            }
+            Self::Peak {
+                frequency,
+                q,
+                gain_db,
+            } => Biquad::peak(sample_rate, frequency, q, gain_db),
+        }
+    }
+}
+
+/// Equalizer curves tuned for speech.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EqPreset {
+    /// Leaves the frame untouched.
+    #[default]
+    Flat,
+    /// Tames boominess and lifts presence for intelligible speech.
+    Voice,
+    /// Fuller low end with softened highs.
+    Warm,
+    /// Adds clarity to dull microphones.
+    Bright,
+}
+
+impl EqPreset {
+    /// Gets the bands making up this preset.
+    pub fn bands(self) -> &'static [EqBand] {
+        match self {
+            Self::Flat => &[],
+            Self::Voice => &[
+                EqBand::LowShelf {
+                    frequency: 200.0,
+                    gain_db: -3.0,
+                },
+                EqBand::Peak {
+                    frequency: 3_000.0,
+                    q: 1.0,
+                    gain_db: 3.0,
+                },
+            ],
+            Self::Warm => &[
+                EqBand::LowShelf {
+                    frequency: 250.0,
+                    gain_db: 3.0,
+                },
+                EqBand::HighShelf {
+                    frequency: 6_000.0,
+                    gain_db: -3.0,
+                },
+            ],
+            Self::Bright => &[
+                EqBand::Peak {
+                    frequency: 300.0,
+                    q: 1.0,
+                    gain_db: -2.0,
+                },
+                EqBand::HighShelf {
+                    frequency: 5_000.0,
+                    gain_db: 4.0,
+                },
+            ],
+        }
+    }
+}
+
+/// Equalizer made of shelving and peaking filters applied in series.
+pub struct Equalizer {
+    bands: Vec<EqBand>,
+    filters: Vec<BiquadFilter>,
+}
+
+impl Equalizer {
+    /// Creates an equalizer from custom bands.
+    pub fn new(bands: impl IntoIterator<Item = EqBand>) -> Self {
+        let bands: Vec<_> = bands.into_iter().collect();
+        let mut equalizer = Self {
+            filters: bands.iter().map(|_| BiquadFilter::default()).collect(),
+            bands,
+        };
+        equalizer.prepare(DEFAULT_SAMPLE_RATE, 1);
+        equalizer
+    }
+
+    /// Creates an equalizer from a preset.
+    pub fn preset(preset: EqPreset) -> Self {
+        Self::new(preset.bands().iter().copied())
+    }
+}
+
+impl AudioEffect for Equalizer {
+    fn prepare(&mut self, sample_rate: u32, channels: usize) {
+        for (band, filter) in self.bands.iter().zip(&mut self.filters) {
+            filter.design(band.coefficients(sample_rate as f32), channels);
+        }
+    }
+
+    fn process(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+        for filter in &mut self.filters {
+            filter.process(frame);
+        }
+    }
+}
+
+/// Converts a time constant to a per-frame smoothing factor.
+fn smoothing_factor(frame_ms: f32, time_ms: f32) -> f32 {
+    if time_ms <= 0.0 {
+        1.0
+    } else {
+        1.0 - (-frame_ms / time_ms).exp()
+    }
+}
+
+/// Second order filter coefficients, normalized so that `a0` is 1.
+///
+/// The designs follow the Audio EQ Cookbook by Robert Bristow-Johnson.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Biquad {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+}
+
+impl Default for Biquad {
+    /// A filter that passes every sample through unchanged.
+    fn default() -> Self {
+        Self {
+            b0: 1.0,
+            b1: 0.0,
+            b2: 0.0,
+            a1: 0.0,
+            a2: 0.0,
+        }
+    }
+}
+
+impl Biquad {
+    fn high_pass(sample_rate: f32, frequency: f32, q: f32) -> Self {
+        let (cos, alpha) = Self::angle(sample_rate, frequency, q);
+        Self::normalized(
+            [(1.0 + cos) / 2.0, -(1.0 + cos), (1.0 + cos) / 2.0],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    fn low_shelf(sample_rate: f32, frequency: f32, gain_db: f32) -> Self {
+        let a = 10_f32.powf(gain_db / 40.0);
+        let (cos, alpha) = Self::angle(sample_rate, frequency, FRAC_1_SQRT_2);
+        let shelf = 2.0 * a.sqrt() * alpha;
+        Self::normalized(
+            [
+                a * ((a + 1.0) - (a - 1.0) * cos + shelf),
+                2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+                a * ((a + 1.0) - (a - 1.0) * cos - shelf),
+            ],
+            [
+                (a + 1.0) + (a - 1.0) * cos + shelf,
+                -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+                (a + 1.0) + (a - 1.0) * cos - shelf,
+            ],
+        )
+    }
+
+    fn high_shelf(sample_rate: f32, frequency: f32, gain_db: f32) -> Self {
+        let a = 10_f32.powf(gain_db / 40.0);
+        let (cos, alpha) = Self::angle(sample_rate, frequency, FRAC_1_SQRT_2);
+        let shelf = 2.0 * a.sqrt() * alpha;
+        Self::normalized(
+            [
+                a * ((a + 1.0) + (a - 1.0) * cos + shelf),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                a * ((a + 1.0) + (a - 1.0) * cos - shelf),
+            ],
+            [
+                (a + 1.0) - (a - 1.0) * cos + shelf,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                (a + 1.0) - (a - 1.0) * cos - shelf,
+            ],
+        )
+    }
+
+    fn peak(sample_rate: f32, frequency: f32, q: f32, gain_db: f32) -> Self {
+        let a = 10_f32.powf(gain_db / 40.0);
+        let (cos, alpha) = Self::angle(sample_rate, frequency, q);
+        Self::normalized(
+            [1.0 + alpha * a, -2.0 * cos, 1.0 - alpha * a],
+            [1.0 + alpha / a, -2.0 * cos, 1.0 - alpha / a],
+        )
+    }
+
+    /// Gets the cosine of the center frequency and the bandwidth term.
+    ///
+    /// The frequency is kept below the Nyquist frequency so low sample
+    /// rates still produce a stable filter.
+    fn angle(sample_rate: f32, frequency: f32, q: f32) -> (f32, f32) {
+        let frequency = frequency.clamp(1.0, sample_rate * 0.45);
+        let omega = 2.0 * PI * frequency / sample_rate;
+        (omega.cos(), omega.sin() / (2.0 * q.max(0.1)))
+    }
+
+    fn normalized([b0, b1, b2]: [f32; 3], [a0, a1, a2]: [f32; 3]) -> Self {
+        Self {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+}
+
+/// A biquad with its own state for every interleaved channel.
+#[derive(Debug)]
+struct BiquadFilter {
+    coefficients: Biquad,
+    /// Transposed direct form II delay line per channel.
+    state: Vec<[f32; 2]>,
+}
+
+impl Default for BiquadFilter {
+    fn default() -> Self {
+        Self {
+            coefficients: Biquad::default(),
+            state: vec![[0.0; 2]],
+        }
+    }
+}
+
+impl BiquadFilter {
+    /// Replaces the coefficients and clears the state for `channels` channels.
+    fn design(&mut self, coefficients: Biquad, channels: usize) {
+        self.coefficients = coefficients;
+        self.state = vec![[0.0; 2]; channels.max(1)];
+    }
+
+    /// Replaces the coefficients, keeping the state so the output stays smooth.
+    fn retune(&mut self, coefficients: Biquad) {
+        self.coefficients = coefficients;
+    }
+
+    fn process(&mut self, frame: &mut [f32]) {
+        let Biquad { b0, b1, b2, a1, a2 } = self.coefficients;
+        let channels = self.state.len();
+        for (index, sample) in frame.iter_mut().enumerate() {
+            let [z1, z2] = &mut self.state[index % channels];
+            let input = *sample;
+            let output = b0 * input + *z1;
+            *z1 = b1 * input - a1 * output + *z2;
+            *z2 = b2 * input - a2 * output;
+            *sample = output;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fills a frame with a sine of `frequency` Hz at `amplitude`.
+    fn sine(frequency: f32, amplitude: f32, sample_rate: f32, offset: usize) -> [f32; FRAME_SIZE] {
+        std::array::from_fn(|index| {
+            let time = (offset + index) as f32 / sample_rate;
+            amplitude * (2.0 * PI * frequency * time).sin()
+        })
+    }
+
+    /// Runs `frames` frames of a sine through `effect` and gets the RMS of
+    /// the last one.
+    fn settled_rms(effect: &mut impl AudioEffect, frequency: f32, amplitude: f32) -> f32 {
+        let mut rms = 0.0;
+        for index in 0..20 {
+            let mut frame = sine(frequency, amplitude, 48_000.0, index * FRAME_SIZE);
+            effect.process(&mut frame);
+            rms = calculate_rms(&frame);
+        }
+        rms
+    }
+
+    #[test]
+    fn empty_chain_leaves_frames_untouched() {
+        let mut chain = EffectChain::new();
+        let mut frame = sine(440.0, 0.5, 48_000.0, 0);
+        let original = frame;
+
+        chain.prepare(48_000, 1);
+        chain.process(&mut frame);
+
+        assert!(chain.is_empty());
+        assert_eq!(frame, original);
+    }
+
+    #[test]
+    fn chain_runs_effects_in_order() {
+        struct Add(f32);
+        impl AudioEffect for Add {
+            fn process(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+                frame.iter_mut().for_each(|sample| *sample += self.0);
+            }
+        }
+        struct Double;
+        impl AudioEffect for Double {
+            fn process(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+                frame.iter_mut().for_each(|sample| *sample *= 2.0);
+            }
+        }
+
+        let mut chain = EffectChain::new().with(Add(0.1)).with(Double);
+        let mut frame = [0.0; FRAME_SIZE];
+        chain.process(&mut frame);
+
+        assert_eq!(chain.len(), 2);
+        assert!(frame.iter().all(|&sample| (sample - 0.2).abs() < 1e-6));
+    }
+
+    #[test]
+    fn high_pass_removes_rumble_and_keeps_voice() {
+        let rumble = settled_rms(&mut HighPass::default(), 20.0, 0.5);
+        let voice = settled_rms(&mut HighPass::default(), 1_000.0, 0.5);
+
+        assert!(rumble < 0.5 * FRAC_1_SQRT_2 * 0.1, "rumble rms {rumble}");
+        assert!(
+            (voice - 0.5 * FRAC_1_SQRT_2).abs() < 0.01,
+            "voice rms {voice}"
+        );
+    }
+
+    #[test]
+    fn high_pass_filters_channels_independently() {
+        let mut high_pass = HighPass::default();
+        high_pass.prepare(48_000, 2);
+
+        // a constant offset on the left, silence on the right
+        let mut frame = [0.0; FRAME_SIZE];
+        for _ in 0..20 {
+            frame = std::array::from_fn(|index| if index % 2 == 0 { 0.5 } else { 0.0 });
+            high_pass.process(&mut frame);
+        }
+
+        assert!(frame.iter().all(|sample| sample.abs() < 0.01));
+    }
+
+    #[test]
+    fn compressor_reduces_loud_frames_only() {
+        let config = CompressorConfig {
+            threshold_db: -20.0,
+            ratio: 4.0,
+            ..Default::default()
+        };
+        // about -9 dBFS, 11 dB over the threshold
+        let loud = settled_rms(&mut Compressor::new(config), 440.0, 0.5);
+        // about -29 dBFS, under the threshold
+        let quiet = settled_rms(&mut Compressor::new(config), 440.0, 0.05);
+
+        let expected_db = -20.0 + (to_db(0.5 * FRAC_1_SQRT_2) + 20.0) / 4.0;
+        assert!((to_db(loud) - expected_db).abs() < 0.5, "loud rms {loud}");
+        assert!(
+            (quiet - 0.05 * FRAC_1_SQRT_2).abs() < 1e-3,
+            "quiet rms {quiet}"
+        );
+    }
+
+    #[test]
+    fn de_esser_attenuates_sibilance_only() {
+        let config = DeEsserConfig::default();
+        let sibilance = settled_rms(&mut DeEsser::new(config), 8_000.0, 0.5);
+        let voice = settled_rms(&mut DeEsser::new(config), 500.0, 0.5);
+
+        // the shelf reaches its full cut well above its frequency
+        let reduction = to_db(0.5 * FRAC_1_SQRT_2) - to_db(sibilance);
+        assert!(
+            reduction > config.max_reduction_db - 3.0 && reduction <= config.max_reduction_db,
+            "reduction {reduction}"
+        );
+        assert!(
+            (voice - 0.5 * FRAC_1_SQRT_2).abs() < 0.01,
+            "voice rms {voice}"
+        );
+    }
+
+    #[test]
+    fn equalizer_bands_shape_the_spectrum() {
+        let bands = [
+            EqBand::LowShelf {
+                frequency: 200.0,
+                gain_db: -6.0,
+            },
+            EqBand::Peak {
+                frequency: 3_000.0,
+                q: 1.0,
+                gain_db: 6.0,
+            },
+        ];
+        let reference = to_db(0.25 * FRAC_1_SQRT_2);
+        let low = to_db(settled_rms(&mut Equalizer::new(bands), 50.0, 0.25));
+        let peak = to_db(settled_rms(&mut Equalizer::new(bands), 3_000.0, 0.25));
+
+        assert!((low - reference + 6.0).abs() < 0.5, "low {low}");
+        assert!((peak - reference - 6.0).abs() < 0.5, "peak {peak}");
+    }
+
+    #[test]
+    fn flat_preset_is_transparent() {
+        let mut equalizer = Equalizer::preset(EqPreset::Flat);
+        let mut frame = sine(440.0, 0.5, 48_000.0, 0);
+        let original = frame;
+
+        equalizer.process(&mut frame);
+
+        assert_eq!(frame, original);
+        assert!(!EqPreset::Voice.bands().is_empty());
+    }
+
+    #[test]
+    fn filters_stay_stable_near_nyquist() {
+        let mut de_esser = DeEsser::default();
+        de_esser.prepare(8_000, 1);
+
+        for index in 0..20 {
+            let mut frame = sine(3_500.0, 0.5, 8_000.0, index * FRAME_SIZE);
+            de_esser.process(&mut frame);
+            assert!(frame.iter().all(|sample| sample.abs() <= 1.0));
+        }
+    }
+}
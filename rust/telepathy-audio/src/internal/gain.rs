@@ -0,0 +1,240 @@
+//! Automatic gain control.
+//!
+//! The input processor runs an [`AutoGain`] stage after noise suppression so
+//! the speech level is measured on the cleaned signal. Quiet and loud
+//! microphones are pulled toward the same target level before the frame is
+//! encoded and sent to the far end.
+//!
+//! ## Algorithm
+//!
+//! - Each frame's RMS is compared against a noise gate. Frames below the gate
+//!   (background noise, pauses) leave the level estimate and gain untouched,
+//!   which prevents the controller from pumping noise up during silence.
+//! - Speech frames update a level estimate that rises quickly and decays
+//!   slowly, so the gain follows the loud parts of speech.
+//! - The gain in dB slews toward `target - level`, falling much faster than it
+//!   rises. The input volume multiplier caps the gain at all times.
+//! - The gain is ramped linearly across each frame to avoid zipper noise, and a
+//!   peak limiter catches transients the slow gain loop cannot react to.
+//!
+//! All levels are in dBFS relative to `i16::MAX`, matching the sample scale
+//! used by the denoiser.
+
+use crate::internal::processing::calculate_rms;
+use crate::internal::utils::db_to_multiplier;
+
+/// Lowest gain the controller applies to hot microphones, in dB.
+const MIN_GAIN_DB: f32 = -20.0;
+
+/// Smoothing factor applied when the speech level rises.
+const LEVEL_ATTACK: f32 = 0.3;
+
+/// Smoothing factor applied when the speech level falls.
+const LEVEL_RELEASE: f32 = 0.02;
+
+/// Maximum gain decrease per frame, in dB (100 dB/s at 48 kHz).
+const GAIN_DECREASE_DB: f32 = 1.0;
+
+/// Maximum gain increase per frame, in dB (10 dB/s at 48 kHz).
+const GAIN_INCREASE_DB: f32 = 0.1;
+
+/// Per-sample decay of the limiter envelope (roughly 40 ms at 48 kHz).
+const LIMITER_RELEASE: f32 = 0.9995;
+
+/// Floor used when converting silent frames and muted ceilings to dB.
+const SILENCE_DB: f32 = -120.0;
+
+/// Configuration for the automatic gain control stage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutoGainConfig {
+    /// Speech level the controller aims for, in dBFS.
+    pub target_level_db: f32,
+    /// Maximum gain the controller may apply, in dB.
+    pub max_gain_db: f32,
+    /// Frames below this level (before gain, in dBFS) are treated as noise.
+    pub noise_gate_db: f32,
+    /// Peak level the limiter holds the output under, in dBFS.
+    pub limiter_threshold_db: f32,
+}
+
+impl Default for AutoGainConfig {
+    fn default() -> Self {
+        Self {
+            target_level_db: -18.0,
+            max_gain_db: 30.0,
+            noise_gate_db: -55.0,
+            limiter_threshold_db: -1.0,
+        }
+    }
+}
+
+/// Automatic gain control with a peak limiter.
+pub struct AutoGain {
+    config: AutoGainConfig,
+    /// Estimated speech level in dBFS; `None` until the first speech frame.
+    level_db: Option<f32>,
+    /// Controller gain in dB.
+    gain_db: f32,
+    /// Linear gain applied at the end of the previous frame.
+    applied_gain: f32,
+    /// Limiter peak envelope.
+    envelope: f32,
+    limiter_threshold: f32,
+}
+
+impl AutoGain {
+    /// Creates a controller starting at unity gain.
+    pub fn new(config: AutoGainConfig) -> Self {
+        Self {
+            config,
+            level_db: None,
+            gain_db: 0.0,
+            applied_gain: 1.0,
+            envelope: 0.0,
+            limiter_threshold: db_to_multiplier(config.limiter_threshold_db) * i16::MAX as f32,
+        }
+    }
+
+    /// Applies gain and limiting to `frame` in place.
+    ///
+    /// `ceiling` is the linear multiplier the gain may never exceed (the
+    /// input volume). Returns the linear gain applied at the end of the frame.
+    pub fn process(&mut self, frame: &mut [f32], ceiling: f32) -> f32 {
+        if frame.is_empty() {
+            return self.applied_gain;
+        }
+
+        let frame_db = to_db(calculate_rms(frame) / i16::MAX as f32);
+        if frame_db > self.config.noise_gate_db {
+            let level_db = match self.level_db {
+                Some(level_db) => {
+                    let factor = if frame_db > level_db {
+                        LEVEL_ATTACK
+                    } else {
+                        LEVEL_RELEASE
+                    };
+                    level_db + (frame_db - level_db) * factor
+                }
+                None => frame_db,
+            };
+            self.level_db = Some(level_db);
+
+            let desired_db = (self.config.target_level_db - level_db)
+                .clamp(MIN_GAIN_DB, self.config.max_gain_db);
+            self.gain_db += (desired_db - self.gain_db).clamp(-GAIN_DECREASE_DB, GAIN_INCREASE_DB);
+        }
+
+        // the input volume is a hard ceiling; raising it later slews up normally
+        self.gain_db = self.gain_db.min(to_db(ceiling));
+        let gain = db_to_multiplier(self.gain_db).min(ceiling.max(0.0));
+
+        // ramp from the previous gain to avoid discontinuities
+        let step = (gain - self.applied_gain) / frame.len() as f32;
+        let mut current = self.applied_gain;
+        for sample in frame.iter_mut() {
+            current += step;
+            *sample *= current;
+
+            self.envelope = (self.envelope * LIMITER_RELEASE).max(sample.abs());
+            if self.envelope > self.limiter_threshold {
+                *sample *= self.limiter_threshold / self.envelope;
+            }
+        }
+
+        self.applied_gain = gain;
+        gain
+    }
+}
+
+/// Converts a linear amplitude to dB, flooring silence at [`SILENCE_DB`].
+pub(crate) fn to_db(amplitude: f32) -> f32 {
+    if amplitude > 0.0 {
+        (20.0 * amplitude.log10()).max(SILENCE_DB)
+    } else {
+        SILENCE_DB
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::FRAME_SIZE;
+
+    fn sine_frame(index: usize, amplitude: f32) -> Vec<f32> {
+        (0..FRAME_SIZE)
+            .map(|i| {
+                let t = (index * FRAME_SIZE + i) as f32 / 48_000.0;
+                (t * 440.0 * std::f32::consts::TAU).sin() * amplitude * i16::MAX as f32
+            })
+            .collect()
+    }
+
+    fn level_db(frame: &[f32]) -> f32 {
+        to_db(calculate_rms(frame) / i16::MAX as f32)
+    }
+
+    /// Feeds `frames` sine frames and returns the level of the last output frame.
+    fn settle(agc: &mut AutoGain, frames: usize, amplitude: f32, ceiling: f32) -> f32 {
+        let mut last = Vec::new();
+        for index in 0..frames {
+            let mut frame = sine_frame(index, amplitude);
+            agc.process(&mut frame, ceiling);
+            last = frame;
+        }
+        level_db(&last)
+    }
+
+    #[test]
+    fn quiet_input_is_raised_to_target() {
+        let mut agc = AutoGain::new(AutoGainConfig::default());
+        // -40 dBFS sine, needs +22 dB
+        let level = settle(&mut agc, 500, 0.0141, 100.0);
+        assert!((level + 18.0).abs() < 1.5, "level {level}");
+    }
+
+    #[test]
+    fn loud_input_is_lowered_to_target() {
+        let mut agc = AutoGain::new(AutoGainConfig::default());
+        // -6 dBFS sine, needs -12 dB
+        let level = settle(&mut agc, 100, 0.7, 100.0);
+        assert!((level + 18.0).abs() < 1.5, "level {level}");
+    }
+
+    #[test]
+    fn gain_never_exceeds_ceiling() {
+        let mut agc = AutoGain::new(AutoGainConfig::default());
+        let mut frame = sine_frame(0, 0.01);
+        for _ in 0..500 {
+            let gain = agc.process(&mut frame.clone(), 2.0);
+            assert!(gain <= 2.0 + f32::EPSILON, "gain {gain}");
+        }
+        let gain = agc.process(&mut frame, 0.5);
+        assert!(gain <= 0.5 + f32::EPSILON, "gain {gain}");
+    }
+
+    #[test]
+    fn noise_below_gate_does_not_move_gain() {
+        let mut agc = AutoGain::new(AutoGainConfig::default());
+        // -66 dBFS sine is below the default gate
+        for index in 0..500 {
+            let mut frame = sine_frame(index, 0.0005);
+            let gain = agc.process(&mut frame, 100.0);
+            assert_eq!(gain, 1.0);
+        }
+    }
+
+    #[test]
+    fn limiter_holds_peaks_under_threshold() {
+        let mut agc = AutoGain::new(AutoGainConfig::default());
+        let threshold = db_to_multiplier(-1.0) * i16::MAX as f32;
+        // settle on quiet speech, then hit a sudden loud burst
+        settle(&mut agc, 500, 0.01, 100.0);
+        let mut burst = sine_frame(0, 0.9);
+        agc.process(&mut burst, 100.0);
+        let peak = burst.iter().fold(0_f32, |peak, s| peak.max(s.abs()));
+        assert!(
+            peak <= threshold * 1.001,
+            "peak {peak}, threshold {threshold}"
+        );
+    }
+}
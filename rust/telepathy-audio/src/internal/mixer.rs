@@ -0,0 +1,461 @@
+//! Multi-source mixing for the output path.
+//!
+//! The mixer plays any number of [`AudioDataSource`]s through one output
+//! stream. Each source is decoded, concealed and resampled to the mix rate on
+//! its own [`MixerChannel`]; the mixer processor then sums one frame from
+//! every channel, applies the shared output volume and writes the result.
+//!
+//! ## Clocking
+//!
+//! Sources deliver frames whenever the network does, so the mix cannot be
+//! driven by arrivals: two peers offset by half a frame would each trigger a
+//! write. Instead the output device sets the pace: when it reports its fill
+//! level, the processor mixes a frame whenever fewer than
+//! [`DEVICE_BUFFER_FRAMES`] are queued ahead of it, so the mix follows the
+//! device clock and cannot drift against it. Outputs that do not report their
+//! fill level, and deafened mixers that write nothing, tick once per frame
+//! duration on the system clock instead.
+//!
+//! Every tick mixes whatever full frames are buffered. A source with nothing
+//! buffered contributes silence for that tick; a source that bursts ahead is
+//! trimmed to [`MAX_BACKLOG_FRAMES`] and the dropped samples count as loss.
+//!
+//! ## Control
+//!
+//! Channels are attached and detached at runtime through [`MixerCommand`]s,
+//! which the processor drains at the start of every tick.
+
+use crate::codec::AudioDecoder;
+use crate::error::{Error, ProcessingError};
+use crate::internal::echo::EchoReferenceProducer;
+use crate::internal::plc::PacketLossConcealer;
+use crate::internal::processing::*;
+use crate::internal::state::OutputProcessorState;
+use crate::internal::traits::AudioOutput;
+use crate::internal::utils::{max_output_samples, resampler_factory};
+use crate::io::traits::{AudioDataSource, ClosedOrFailed};
+use crate::recorder::RecordingTrack;
+use atomic_float::AtomicF32;
+use audioadapter_buffers::direct::InterleavedSlice;
+use crossbeam::channel::{Receiver, TryRecvError};
+use nnnoiseless::FRAME_SIZE;
+use rubato::{Fft, FixedSync, Resampler};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::sync::atomic::Ordering::Relaxed;
+use std::time::Duration;
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+use tracing::{debug, warn};
+#[cfg(target_family = "wasm")]
+use wasmtimer::std::Instant;
+
+/// The most frames a channel may buffer before the oldest samples are dropped.
+///
+/// At 48 kHz mono this bounds the added latency of a bursty source to 50 ms.
+pub const MAX_BACKLOG_FRAMES: usize = 5;
+
+/// The frames kept queued ahead of an output that reports its fill level.
+///
+/// Enough to cover the larger callbacks of some devices (30 ms at 48 kHz).
+pub const DEVICE_BUFFER_FRAMES: usize = 3;
+
+/// Control messages for a running mixer processor.
+pub enum MixerCommand {
+    /// Attaches a channel under the given id.
+    Add(u64, Box<MixerChannel>),
+    /// Detaches the channel with the given id.
+    Remove(u64),
+    /// Stops the processor.
+    Stop,
+}
+
+/// One source attached to the mixer.
+pub struct MixerChannel {
+    source: Box<dyn AudioDataSource>,
+    decoder: Box<dyn AudioDecoder>,
+    concealer: PacketLossConcealer,
+    /// Resamples from the source rate to the mix rate when they differ.
+    resampler: Option<Fft<f32>>,
+    volume: Arc<AtomicF32>,
+    rms_sender: Arc<AtomicF32>,
+    /// Receives every decoded frame at the source rate while recording.
+    recording: Option<RecordingTrack>,
+    channels: usize,
+    /// Decoded samples at the mix rate waiting to be mixed.
+    pending: VecDeque<f32>,
+    decoded_buf: [i16; FRAME_SIZE],
+    pre_buf: [f32; FRAME_SIZE],
+    post_buf: Vec<f32>,
+}
+
+impl MixerChannel {
+    /// Creates a channel that decodes `source` and resamples it from
+    /// `input_rate` to `mix_rate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - Atomic reference to this source's volume multiplier
+    /// * `rms_sender` - Atomic reference for sending this source's RMS values
+    pub fn new(
+        source: Box<dyn AudioDataSource>,
+        decoder: Box<dyn AudioDecoder>,
+        input_rate: usize,
+        mix_rate: usize,
+        channels: usize,
+        volume: Arc<AtomicF32>,
+        rms_sender: Arc<AtomicF32>,
+    ) -> Result<Self, Error> {
+        let channels = channels.max(1);
+        let frames = FRAME_SIZE / channels;
+        let resampler =
+            resampler_factory(input_rate, mix_rate, channels, frames, FixedSync::Input)?;
+        let post_size = resampler
+            .as_ref()
+            .map(|r| r.output_frames_max() * channels)
+            .unwrap_or(FRAME_SIZE);
+
+        Ok(Self {
+            source,
+            decoder,
+            concealer: PacketLossConcealer::new(input_rate, channels),
+            resampler,
+            volume,
+            rms_sender,
+            recording: None,
+            channels,
+            pending: VecDeque::with_capacity((MAX_BACKLOG_FRAMES + 1) * FRAME_SIZE),
+            decoded_buf: [0; FRAME_SIZE],
+            pre_buf: [0_f32; FRAME_SIZE],
+            post_buf: vec![0_f32; post_size],
+        })
+    }
+
+    /// Taps every decoded frame into `recording`, before the channel volume.
+    pub fn with_recording(mut self, recording: Option<RecordingTrack>) -> Self {
+        self.recording = recording;
+        self
+    }
+
+    /// Drains every frame the source has ready.
+    ///
+    /// Frames are dropped instead of decoded when `discard` is set. Returns
+    /// `Ok(false)` once the source has closed or failed.
+    pub fn poll(&mut self, discard: bool) -> Result<bool, Error> {
+        loop {
+            let buffer = match self.source.try_recv() {
+                Ok(Some(buffer)) => buffer,
+                Ok(None) => return Ok(true),
+                Err(ClosedOrFailed::Closed) => return Ok(false),
+                Err(ClosedOrFailed::Failed(error)) => {
+                    warn!(%error, "mixer_source_failed");
+                    return Ok(false);
+                }
+            };
+
+            if discard {
+                continue;
+            } else if buffer.is_empty() {
+                // the source knows this frame was lost, synthesize a replacement
+                self.concealer
+                    .conceal(&mut self.decoded_buf, self.decoder.as_mut());
+            } else {
+                match self.decoder.decode_frame(&buffer, &mut self.decoded_buf) {
+                    Ok(()) => (),
+                    Err(Error::Processing(ProcessingError::FrameLength { actual, .. })) => {
+                        warn!(len = actual, "mixer_frame_size_mismatch");
+                        continue;
+                    }
+                    Err(error) => return Err(error),
+                }
+                self.concealer.frame_received(&mut self.decoded_buf);
+            }
+
+            if let Some(recording) = &mut self.recording {
+                recording.push_i16(&self.decoded_buf);
+            }
+            self.push_decoded()?;
+        }
+    }
+
+    /// Adds one frame of this channel into `mix` at the channel's volume.
+    ///
+    /// Returns the number of samples dropped to keep the backlog bounded.
+    pub fn mix_into(&mut self, mix: &mut [f32; FRAME_SIZE]) -> usize {
+        let limit = MAX_BACKLOG_FRAMES * FRAME_SIZE;
+        let dropped = self.pending.len().saturating_sub(limit);
+        self.pending.drain(..dropped);
+
+        // a partial frame waits for the rest of its samples
+        if self.pending.len() < FRAME_SIZE {
+            return dropped;
+        }
+
+        for (sample, pending) in self
+            .pre_buf
+            .iter_mut()
+            .zip(self.pending.drain(..FRAME_SIZE))
+        {
+            *sample = pending;
+        }
+        let volume = self.volume.load(Relaxed);
+        self.rms_sender
+            .fetch_max(calculate_rms(&self.pre_buf) * volume.abs(), Relaxed);
+        wide_mix(mix, &self.pre_buf, volume);
+        dropped
+    }
+
+    /// Converts the decoded frame to the mix rate and queues it.
+    fn push_decoded(&mut self) -> Result<(), Error> {
+        let scale = 1_f32 / i16::MAX as f32;
+        wide_i16_to_f32(&self.decoded_buf, &mut self.pre_buf, scale);
+
+        if let Some(resampler) = &mut self.resampler {
+            let frames = FRAME_SIZE / self.channels;
+            let input_adapter = InterleavedSlice::new(&self.pre_buf, self.channels, frames)?;
+            let output_frames = self.post_buf.len() / self.channels;
+            let mut output_adapter =
+                InterleavedSlice::new_mut(&mut self.post_buf, self.channels, output_frames)?;
+            let processed =
+                resampler.process_into_buffer(&input_adapter, &mut output_adapter, None)?;
+            self.pending
+                .extend(&self.post_buf[..processed.1 * self.channels]);
+        } else {
+            self.pending.extend(&self.pre_buf);
+        }
+        Ok(())
+    }
+}
+
+/// Mixes attached channels into a single output stream.
+///
+/// Runs until [`MixerCommand::Stop`] is received or every command sender is
+/// dropped. Each tick mixes one frame at `mix_rate`, applies the output
+/// volume, feeds the echo reference and writes the result resampled to
+/// `output_rate`, or the rate of the replacement device last taken from the
+/// device swaps of `state`. Ticks follow the fill level of the output when it
+/// reports one, see the module docs. While deafened, incoming frames are
+/// discarded and nothing is written.
+pub fn mixer_processor<O: AudioOutput>(
+    commands: Receiver<MixerCommand>,
+    output: O,
+    mix_rate: usize,
+    output_rate: usize,
+    state: OutputProcessorState,
+    mut echo_reference: Option<EchoReferenceProducer>,
+) -> Result<(), Error> {
+    // every frame holds FRAME_SIZE interleaved samples
+    let channels = state.channels();
+    let frames = FRAME_SIZE / channels;
+    let period = Duration::from_secs_f64(frames as f64 / mix_rate as f64);
+
+    // resampler is Some if resampling is needed
+    let mut resampler_option =
+        resampler_factory(mix_rate, output_rate, channels, frames, FixedSync::Input)?;
+    // the maximum number of samples per output
+    let mut output_buffer_size = max_output_samples(&resampler_option, channels);
+    // the samples kept queued ahead of the device when it reports its fill level
+    let mut device_demand = demand_samples(mix_rate, output_rate);
+    // the device can be swapped for another one while running
+    let mut output: Box<dyn AudioOutput + '_> = Box::new(output);
+
+    let mut sources: HashMap<u64, MixerChannel> = HashMap::new();
+    // The sum of every channel
+    let mut mix = [0_f32; FRAME_SIZE];
+    // The output for the resampler
+    let mut post_buf = vec![0_f32; output_buffer_size];
+    // The mono downmix for the echo reference
+    let mut mono_buf = [0_f32; FRAME_SIZE];
+
+    let mut next_tick = Instant::now();
+    'mixer: loop {
+        loop {
+            match commands.try_recv() {
+                Ok(MixerCommand::Add(id, channel)) => {
+                    sources.insert(id, *channel);
+                }
+                Ok(MixerCommand::Remove(id)) => {
+                    sources.remove(&id);
+                }
+                Ok(MixerCommand::Stop) | Err(TryRecvError::Disconnected) => break 'mixer,
+                Err(TryRecvError::Empty) => break,
+            }
+        }
+
+        if let Some(swap) = state.take_device_swap() {
+            debug!(sample_rate = swap.sample_rate, "mixer_device_swapped");
+            output = swap.device;
+            resampler_option = resampler_factory(
+                mix_rate,
+                swap.sample_rate,
+                channels,
+                frames,
+                FixedSync::Input,
+            )?;
+            output_buffer_size = max_output_samples(&resampler_option, channels);
+            post_buf = vec![0_f32; output_buffer_size];
+            device_demand = demand_samples(mix_rate, swap.sample_rate);
+        }
+
+        let deafened = state.is_deafened();
+        // nothing is written while deafened, so the fill level cannot pace the mix
+        let queued = output.buffered().filter(|_| !deafened);
+        if queued.is_some_and(|queued| queued >= device_demand) {
+            std::thread::sleep(period / 4);
+            continue;
+        }
+
+        sources.retain(|id, channel| match channel.poll(deafened) {
+            Ok(open) => open,
+            Err(error) => {
+                warn!(id, %error, "mixer_source_removed");
+                false
+            }
+        });
+
+        if !deafened {
+            mix.fill(0.0);
+            for channel in sources.values_mut() {
+                let dropped = channel.mix_into(&mut mix);
+                if dropped > 0 {
+                    state.send_loss(dropped);
+                }
+            }
+
+            // apply the output volume, wide_mul also clamps the sum to [-1.0, 1.0]
+            wide_mul(&mut mix, state.output_volume());
+            if let Some(echo_reference) = &mut echo_reference {
+                // the echo canceller needs exactly what is about to be played
+                if channels == 1 {
+                    echo_reference.push(&mix);
+                } else {
+                    downmix_to_mono(&mix, channels, &mut mono_buf);
+                    echo_reference.push(&mono_buf[..frames]);
+                }
+            }
+            // send the rms to the statistics collector
+            state.send_rms(calculate_rms(&mix));
+
+            if output.is_full() {
+                state.send_loss(FRAME_SIZE);
+            } else {
+                // get finalized samples
+                let float_samples = if let Some(resampler) = &mut resampler_option {
+                    let input_adapter = InterleavedSlice::new(&mix, channels, frames)?;
+                    let mut output_adapter = InterleavedSlice::new_mut(
+                        &mut post_buf,
+                        channels,
+                        output_buffer_size / channels,
+                    )?;
+                    let processed =
+                        resampler.process_into_buffer(&input_adapter, &mut output_adapter, None)?;
+                    &post_buf[..processed.1 * channels]
+                } else {
+                    &mix
+                };
+
+                let lost = output.write_samples(float_samples)?;
+                if lost > 0 {
+                    state.send_loss(lost);
+                }
+            }
+        }
+
+        if queued.is_some() {
+            // the device paced this tick, the system clock restarts from here
+            next_tick = Instant::now();
+            continue;
+        }
+
+        next_tick += period;
+        let now = Instant::now();
+        if next_tick > now {
+            std::thread::sleep(next_tick - now);
+        } else if now - next_tick > period * MAX_BACKLOG_FRAMES as u32 {
+            // fell far behind (e.g. the thread was suspended), resync the clock
+            next_tick = now;
+        }
+    }
+
+    debug!("Mixer processor ended");
+    Ok(())
+}
+
+/// The output samples of [`DEVICE_BUFFER_FRAMES`] mixed frames.
+fn demand_samples(mix_rate: usize, output_rate: usize) -> usize {
+    DEVICE_BUFFER_FRAMES * FRAME_SIZE * output_rate / mix_rate.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::RawDecoder;
+    use bytes::Bytes;
+    use std::sync::Mutex;
+
+    struct FrameQueue(Mutex<VecDeque<Bytes>>);
+
+    impl AudioDataSource for FrameQueue {
+        fn recv(&self) -> Result<Bytes, ClosedOrFailed> {
+            self.try_recv()?.ok_or(ClosedOrFailed::Closed)
+        }
+
+        fn try_recv(&self) -> Result<Option<Bytes>, ClosedOrFailed> {
+            Ok(self.0.lock().unwrap().pop_front())
+        }
+    }
+
+    fn channel(frames: usize, value: i16, volume: f32) -> MixerChannel {
+        let frame: Vec<u8> = (0..FRAME_SIZE).flat_map(|_| value.to_ne_bytes()).collect();
+        let queue = (0..frames).map(|_| Bytes::from(frame.clone())).collect();
+        MixerChannel::new(
+            Box::new(FrameQueue(Mutex::new(queue))),
+            Box::new(RawDecoder),
+            48_000,
+            48_000,
+            1,
+            Arc::new(AtomicF32::new(volume)),
+            Default::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn mix_into_adds_one_frame_at_the_channel_volume() {
+        let mut channel = channel(2, i16::MAX / 2, 0.5);
+        assert!(channel.poll(false).unwrap());
+
+        let mut mix = [0.1_f32; FRAME_SIZE];
+        assert_eq!(channel.mix_into(&mut mix), 0);
+        assert!(mix.iter().all(|s| (s - 0.35).abs() < 1e-3));
+        assert_eq!(channel.pending.len(), FRAME_SIZE);
+        assert!(channel.rms_sender.load(Relaxed) > 0.24);
+    }
+
+    #[test]
+    fn mix_into_leaves_silence_without_a_full_frame() {
+        let mut channel = channel(0, 1_000, 1.0);
+        assert!(channel.poll(false).unwrap());
+
+        let mut mix = [0_f32; FRAME_SIZE];
+        assert_eq!(channel.mix_into(&mut mix), 0);
+        assert!(mix.iter().all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn mix_into_trims_the_backlog_and_reports_the_drop() {
+        let mut channel = channel(MAX_BACKLOG_FRAMES + 3, 1_000, 1.0);
+        assert!(channel.poll(false).unwrap());
+
+        let mut mix = [0_f32; FRAME_SIZE];
+        assert_eq!(channel.mix_into(&mut mix), 3 * FRAME_SIZE);
+        assert_eq!(channel.pending.len(), (MAX_BACKLOG_FRAMES - 1) * FRAME_SIZE);
+    }
+
+    #[test]
+    fn poll_discards_frames_when_asked() {
+        let mut channel = channel(3, 1_000, 1.0);
+        assert!(channel.poll(true).unwrap());
+        assert!(channel.pending.is_empty());
+    }
+}
@@ -6,21 +6,35 @@
 //!
 //! ## Modules
 //!
+//! - `drift` - Clock drift compensation for the output path
+//! - `echo` - Acoustic echo cancellation and the far-end reference channel
+//! - `effects` - Pluggable effect chains and built-in effects
+//! - `gain` - Automatic gain control and peak limiting
+//! - `mixer` - Clocked mixing of many sources into one output stream
+//! - `plc` - Packet loss concealment for the output path
 //! - `processing` - SIMD-optimized audio processing functions
 //! - `processor` - Core audio processor functions for input/output
 //! - `state` - Processor state structures
 //! - `traits` - AudioInput/AudioOutput traits and implementations
 //! - `utils` - Internal utility functions (resampling, transitions)
+//! - `vad` - Voice activity gate driven by the RNNoise speech probability
 
 use nnnoiseless::FRAME_SIZE;
 
 pub mod buffer_pool;
+pub mod drift;
+pub mod echo;
+pub mod effects;
+pub mod gain;
+pub mod mixer;
+pub mod plc;
 pub mod processing;
 pub mod processor;
 pub mod state;
 pub(crate) mod thread;
 pub mod traits;
 pub mod utils;
+pub mod vad;
 
 /// the maximum size in bytes of an audio frame
 pub const NETWORK_FRAME: usize = FRAME_SIZE * size_of::<i16>();
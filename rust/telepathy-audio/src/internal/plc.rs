@@ -0,0 +1,355 @@
+//! Packet loss concealment for the output path.
+//!
+//! When the network layer knows a frame is missing it sends
+//! [`MISSING_FRAME`](crate::io::traits::MISSING_FRAME) to the output processor
+//! instead of skipping it. The output processor asks a
+//! [`PacketLossConcealer`] to synthesize a replacement so a lost packet is not
+//! an audible hole. Codecs with forward error correction rebuild the frame
+//! from the following packet instead when it has already arrived, see
+//! [`AudioDecoder::recover_frame`].
+//!
+//! ## Strategies
+//!
+//! - **Codec extrapolation**: decoders that implement
+//!   [`AudioDecoder::conceal_frame`] continue the signal themselves. SEA
+//!   extrapolates from the LMS predictor state of the last decoded frame.
+//! - **Waveform repetition**: used for raw PCM, or when the decoder cannot
+//!   extrapolate. The pitch period of the recent history is found with
+//!   normalized autocorrelation and repeated until the frame is full.
+//!
+//! In both cases the concealed audio fades out over consecutive losses
+//! ([`MAX_CONCEALED_FRAMES`]) so a long outage decays to silence instead of
+//! buzzing. The next real frame is cross-faded with a continuation of the
+//! concealment to avoid a discontinuity. Concealed frames are not added to
+//! the pitch history, so repetition always reads real audio.
+//!
+//! Multichannel frames are interleaved; pitch lags are whole frames so every
+//! channel repeats its own history.
+
+use crate::codec::AudioDecoder;
+use crate::constants::FRAME_SIZE;
+use crate::internal::utils::{hann_fade_in, hann_fade_out};
+
+/// Number of consecutive lost frames after which concealment is silent.
+///
+/// At 48 kHz this is 60 ms; longer outages are not worth masking.
+pub const MAX_CONCEALED_FRAMES: usize = 6;
+
+/// Samples of decoded history kept for pitch detection.
+const HISTORY_LEN: usize = FRAME_SIZE * 2;
+
+/// Length of the autocorrelation window used for pitch detection.
+const PITCH_WINDOW: usize = FRAME_SIZE / 4;
+
+/// Length of the cross-fade from concealment back into real audio.
+const CROSS_FADE_LEN: usize = FRAME_SIZE / 4;
+
+/// Normalized correlation below which the history is treated as unvoiced.
+const VOICED_CORRELATION: f32 = 0.3;
+
+/// Synthesizes replacement audio for frames lost in transit.
+pub struct PacketLossConcealer {
+    /// The most recent output samples, oldest first.
+    history: [f32; HISTORY_LEN],
+    /// Continuation of the concealment used to cross-fade into the next real frame.
+    overlap: [f32; CROSS_FADE_LEN],
+    /// Number of consecutive frames concealed so far.
+    lost_frames: usize,
+    /// Pitch period chosen at the start of the current loss.
+    pitch: usize,
+    /// Read position inside the repeated pitch period.
+    phase: usize,
+    /// Shortest and longest pitch lags searched, in samples.
+    min_lag: usize,
+    max_lag: usize,
+    /// Number of interleaved channels.
+    channels: usize,
+    /// False until a real frame has been played.
+    primed: bool,
+}
+
+impl PacketLossConcealer {
+    /// Creates a concealer for `channels` interleaved channels at `sample_rate` Hz.
+    pub fn new(sample_rate: usize, channels: usize) -> Self {
+        let channels = channels.max(1);
+        // search pitch periods between 2.5 ms (400 Hz) and 15 ms (~67 Hz)
+        let max_lag =
+            (sample_rate * 15 / 1_000).clamp(2, ((HISTORY_LEN - PITCH_WINDOW) / channels).max(2));
+        let min_lag = (sample_rate / 400).clamp(1, max_lag);
+        // the lags must fit in the history even for more channels than a stream carries
+        let max_lag = (max_lag * channels).min(HISTORY_LEN - PITCH_WINDOW);
+        let min_lag = (min_lag * channels).min(max_lag);
+
+        Self {
+            history: [0_f32; HISTORY_LEN],
+            overlap: [0_f32; CROSS_FADE_LEN],
+            lost_frames: 0,
+            pitch: max_lag,
+            phase: 0,
+            min_lag,
+            max_lag,
+            channels,
+            primed: false,
+        }
+    }
+
+    /// Returns the number of consecutive frames concealed so far.
+    pub fn lost_frames(&self) -> usize {
+        self.lost_frames
+    }
+
+    /// Records a real frame, cross-fading from the concealment if the previous
+    /// frame was lost.
+    pub fn frame_received(&mut self, frame: &mut [i16; FRAME_SIZE]) {
+        if self.lost_frames > 0 {
+            for (i, (sample, concealed)) in frame.iter_mut().zip(self.overlap).enumerate() {
+                let mixed = concealed * hann_fade_out(i, CROSS_FADE_LEN)
+                    + *sample as f32 * hann_fade_in(i, CROSS_FADE_LEN);
+                *sample = mixed.round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+            }
+        }
+
+        self.lost_frames = 0;
+        self.primed = true;
+        self.push_history(frame);
+    }
+
+    /// Fills `frame` with concealment audio for a lost frame.
+    ///
+    /// The decoder's own extrapolation is preferred; if it has none the
+    /// previous waveform is repeated.
+    pub fn conceal(&mut self, frame: &mut [i16; FRAME_SIZE], decoder: &mut dyn AudioDecoder) {
+        if !self.primed || self.lost_frames >= MAX_CONCEALED_FRAMES {
+            // nothing to extrapolate from, or the outage is too long to mask
+            self.lost_frames = self.lost_frames.saturating_add(1);
+            self.overlap.fill(0_f32);
+            frame.fill(0);
+            return;
+        }
+
+        if self.lost_frames == 0 {
+            self.pitch = self.detect_pitch();
+            self.phase = 0;
+        }
+
+        let extrapolated = decoder.conceal_frame(frame);
+        if !extrapolated {
+            for sample in frame.iter_mut() {
+                *sample = self.next_repeated() as i16;
+            }
+        }
+
+        // fade linearly toward silence over MAX_CONCEALED_FRAMES
+        let start = 1_f32 - self.lost_frames as f32 / MAX_CONCEALED_FRAMES as f32;
+        let end = 1_f32 - (self.lost_frames + 1) as f32 / MAX_CONCEALED_FRAMES as f32;
+        let step = (end - start) / FRAME_SIZE as f32;
+        for (i, sample) in frame.iter_mut().enumerate() {
+            *sample = (*sample as f32 * (start + step * i as f32)).round() as i16;
+        }
+        self.lost_frames += 1;
+
+        // keep a continuation of the concealment for the cross-fade
+        if extrapolated {
+            // periodic extension of the extrapolated frame's last period
+            let period = self.pitch.min(FRAME_SIZE);
+            for (i, sample) in self.overlap.iter_mut().enumerate() {
+                *sample = frame[FRAME_SIZE - period + i % period] as f32;
+            }
+        } else {
+            for (i, sample) in self.overlap.iter_mut().enumerate() {
+                let position = (self.phase + i) % self.pitch;
+                *sample = self.history[HISTORY_LEN - self.pitch + position] * end;
+            }
+        }
+    }
+
+    /// Returns the next sample of the repeated pitch period.
+    fn next_repeated(&mut self) -> f32 {
+        let sample = self.history[HISTORY_LEN - self.pitch + self.phase];
+        self.phase = (self.phase + 1) % self.pitch;
+        sample
+    }
+
+    /// Finds the lag with the highest normalized autocorrelation.
+    fn detect_pitch(&self) -> usize {
+        let window = &self.history[HISTORY_LEN - PITCH_WINDOW..];
+        let window_energy: f32 = window.iter().map(|s| s * s).sum();
+        if window_energy <= f32::EPSILON {
+            return self.max_lag;
+        }
+
+        let mut best_lag = self.max_lag;
+        let mut best_score = VOICED_CORRELATION;
+        for lag in (self.min_lag..=self.max_lag).step_by(self.channels) {
+            let start = HISTORY_LEN - PITCH_WINDOW - lag;
+            let lagged = &self.history[start..start + PITCH_WINDOW];
+            let mut correlation = 0_f32;
+            let mut lagged_energy = 0_f32;
+            for (a, b) in window.iter().zip(lagged) {
+                correlation += a * b;
+                lagged_energy += b * b;
+            }
+
+            let score = correlation / (window_energy * lagged_energy).sqrt().max(f32::EPSILON);
+            if score > best_score {
+                best_score = score;
+                best_lag = lag;
+            }
+        }
+
+        best_lag
+    }
+
+    fn push_history(&mut self, frame: &[i16; FRAME_SIZE]) {
+        self.history.copy_within(FRAME_SIZE.., 0);
+        for (dst, src) in self.history[HISTORY_LEN - FRAME_SIZE..]
+            .iter_mut()
+            .zip(frame)
+        {
+            *dst = *src as f32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::RawDecoder;
+    use std::f32::consts::TAU;
+
+    fn sine_frame(index: usize, frequency: f32) -> [i16; FRAME_SIZE] {
+        std::array::from_fn(|i| {
+            let t = (index * FRAME_SIZE + i) as f32 / 48_000.0;
+            ((t * frequency * TAU).sin() * 10_000.0) as i16
+        })
+    }
+
+    fn longest_zero_run(samples: &[i16]) -> usize {
+        let mut longest = 0;
+        let mut current = 0;
+        for sample in samples {
+            if *sample == 0 {
+                current += 1;
+                longest = longest.max(current);
+            } else {
+                current = 0;
+            }
+        }
+        longest
+    }
+
+    #[test]
+    fn repetition_continues_periodic_signal() {
+        let mut plc = PacketLossConcealer::new(48_000, 1);
+        for index in 0..4 {
+            plc.frame_received(&mut sine_frame(index, 200.0));
+        }
+
+        let mut concealed = [0_i16; FRAME_SIZE];
+        plc.conceal(&mut concealed, &mut RawDecoder);
+        let expected = sine_frame(4, 200.0);
+
+        // 200 Hz at 48 kHz has a 240 sample period
+        assert_eq!(plc.pitch % 240, 0);
+        // early samples are barely faded and match the true continuation
+        for i in 0..48 {
+            assert!(
+                (concealed[i] as i32 - expected[i] as i32).abs() < 500,
+                "sample {i}: {} vs {}",
+                concealed[i],
+                expected[i]
+            );
+        }
+        assert!(longest_zero_run(&concealed) < 4);
+    }
+
+    #[test]
+    fn consecutive_losses_fade_to_silence() {
+        let mut plc = PacketLossConcealer::new(48_000, 1);
+        for index in 0..4 {
+            plc.frame_received(&mut sine_frame(index, 200.0));
+        }
+
+        let mut peaks = Vec::new();
+        for _ in 0..MAX_CONCEALED_FRAMES + 2 {
+            let mut frame = [0_i16; FRAME_SIZE];
+            plc.conceal(&mut frame, &mut RawDecoder);
+            peaks.push(frame.iter().map(|s| s.unsigned_abs()).max().unwrap());
+        }
+
+        assert!(peaks.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert_eq!(peaks[MAX_CONCEALED_FRAMES], 0);
+        assert_eq!(plc.lost_frames(), MAX_CONCEALED_FRAMES + 2);
+    }
+
+    #[test]
+    fn recovery_cross_fades_into_real_audio() {
+        let mut plc = PacketLossConcealer::new(48_000, 1);
+        for index in 0..4 {
+            plc.frame_received(&mut sine_frame(index, 200.0));
+        }
+        let mut concealed = [0_i16; FRAME_SIZE];
+        plc.conceal(&mut concealed, &mut RawDecoder);
+
+        let mut real = sine_frame(5, 200.0);
+        let original = real;
+        plc.frame_received(&mut real);
+
+        // the first sample is close to where the concealment would have gone
+        let jump = (real[0] as i32 - concealed[FRAME_SIZE - 1] as i32).abs();
+        assert!(jump < 2_000, "jump {jump}");
+        // after the cross-fade the real frame is untouched
+        assert_eq!(&real[CROSS_FADE_LEN..], &original[CROSS_FADE_LEN..]);
+        assert_eq!(plc.lost_frames(), 0);
+    }
+
+    #[test]
+    fn stereo_repetition_keeps_channels_apart() {
+        // the right channel is the inverted left channel
+        let stereo_frame = |index: usize| -> [i16; FRAME_SIZE] {
+            std::array::from_fn(|i| {
+                let t = (index * FRAME_SIZE / 2 + i / 2) as f32 / 48_000.0;
+                let sample = ((t * 200.0 * TAU).sin() * 10_000.0) as i16;
+                if i % 2 == 0 { sample } else { -sample }
+            })
+        };
+
+        let mut plc = PacketLossConcealer::new(48_000, 2);
+        for index in 0..8 {
+            plc.frame_received(&mut stereo_frame(index));
+        }
+        let mut concealed = [0_i16; FRAME_SIZE];
+        plc.conceal(&mut concealed, &mut RawDecoder);
+
+        // the lag is a whole number of frames
+        assert_eq!(plc.pitch % 2, 0);
+        let expected = stereo_frame(8);
+        for i in 0..96 {
+            assert!(
+                (concealed[i] as i32 - expected[i] as i32).abs() < 500,
+                "sample {i}: {} vs {}",
+                concealed[i],
+                expected[i]
+            );
+        }
+    }
+
+    #[test]
+    fn excessive_channel_counts_do_not_panic() {
+        let mut plc = PacketLossConcealer::new(48_000, 1_000);
+        for index in 0..4 {
+            plc.frame_received(&mut sine_frame(index, 200.0));
+        }
+        let mut concealed = [0_i16; FRAME_SIZE];
+        plc.conceal(&mut concealed, &mut RawDecoder);
+        plc.frame_received(&mut sine_frame(5, 200.0));
+    }
+
+    #[test]
+    fn concealment_before_any_audio_is_silent() {
+        let mut plc = PacketLossConcealer::new(48_000, 1);
+        let mut frame = [1_i16; FRAME_SIZE];
+        plc.conceal(&mut frame, &mut RawDecoder);
+        assert!(frame.iter().all(|s| *s == 0));
+    }
+}
@@ -8,25 +8,38 @@
 //! All public functions in this module automatically select the optimal
 //! implementation based on runtime CPU feature detection:
 //!
-//! | Function | AVX-512 | AVX2/AVX | WASM SIMD (v128) | Scalar |
-//! |----------|---------|----------|------------------|--------|
-//! | [`wide_mul`] | 16 floats/iter | 8 floats/iter | 4 floats/iter | 1 float/iter |
-//! | [`wide_i16_to_f32`] | N/A | 16 samples/iter | 8 samples/iter | 1 sample/iter |
-//! | [`wide_float_scaler`] | 16 floats/iter | 8 floats/iter | 4 floats/iter | 1 float/iter |
-//! | [`wide_f32_to_i16`] | N/A | 8 samples/iter | 4 samples/iter | 1 sample/iter |
-//! | [`calculate_rms`] | N/A | 8 floats/iter | 4 floats/iter | 4 floats/iter (unrolled) |
+//! | Function | AVX-512 | AVX2/AVX | NEON | WASM SIMD (v128) | Scalar |
+//! |----------|---------|----------|------|------------------|--------|
+//! | [`wide_mul`] | 16 floats/iter | 8 floats/iter | 4 floats/iter | 4 floats/iter | 1 float/iter |
+//! | [`wide_i16_to_f32`] | N/A | 16 samples/iter | 8 samples/iter | 8 samples/iter | 1 sample/iter |
+//! | [`wide_float_scaler`] | 16 floats/iter | 8 floats/iter | 4 floats/iter | 4 floats/iter | 1 float/iter |
+//! | [`wide_f32_to_i16`] | N/A | 8 samples/iter | 8 samples/iter | 4 samples/iter | 1 sample/iter |
+//! | [`calculate_rms`] | N/A | 8 floats/iter | 4 floats/iter | 4 floats/iter | 4 floats/iter (unrolled) |
+//! | [`wide_mix`] | 16 floats/iter | 8 floats/iter | 4 floats/iter | 4 floats/iter | 1 float/iter |
+//! | [`downmix_to_mono`] | N/A | N/A | N/A | N/A | 1 frame/iter |
+//!
+//! Every function except [`downmix_to_mono`] treats its input as a flat run
+//! of samples, so interleaved multichannel frames need no special handling.
 //!
 //! ## Alignment Requirements
 //!
 //! SIMD paths are only used when frame length meets alignment requirements:
 //! - AVX-512: frame length must be a multiple of 16
 //! - AVX2/AVX: frame length must be a multiple of 8
+//! - NEON: frame length must be a multiple of 4
 //! - WASM SIMD (v128): frame length must be a multiple of 4
 //! - Scalar fallback handles any length
 //!
 //! The standard `FRAME_SIZE` (480) is a multiple of 16, so SIMD paths are
 //! typically used for normal audio processing (480 % 4 = 0 for WASM SIMD).
 //!
+//! ## NEON Support
+//!
+//! On `aarch64` targets (Android, iOS and Apple Silicon macOS) this module
+//! uses 128-bit NEON intrinsics from `std::arch::aarch64`. NEON is part of the
+//! baseline of every aarch64 target, but is still detected at runtime like
+//! the x86_64 extensions.
+//!
 //! ## WASM SIMD Support
 //!
 //! On `wasm32` targets compiled with `target-feature=+simd128`, this module
@@ -42,6 +55,9 @@
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+#[cfg(target_arch = "aarch64")]
+use std::arch::aarch64::*;
+
 #[cfg(target_arch = "wasm32")]
 use std::arch::wasm32::*;
 
@@ -88,6 +104,14 @@ pub fn wide_mul(frame: &mut [f32], factor: f32) {
         }
     }
 
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && frame.len().is_multiple_of(4) {
+            unsafe { neon_mul(frame, factor) }
+            return;
+        }
+    }
+
     #[cfg(target_arch = "wasm32")]
     {
         if cfg!(target_feature = "simd128") && frame.len().is_multiple_of(4) {
@@ -120,6 +144,14 @@ pub fn wide_i16_to_f32(ints: &[i16], out: &mut [f32], scale: f32) {
         }
     }
 
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            unsafe { i16_to_f32_neon(ints, out, scale) }
+            return;
+        }
+    }
+
     #[cfg(target_arch = "wasm32")]
     {
         if cfg!(target_feature = "simd128") {
@@ -160,6 +192,14 @@ pub fn wide_float_scaler(floats: &mut [f32], scale: f32) {
         }
     }
 
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            unsafe { neon_float_scaler(floats, scale) }
+            return;
+        }
+    }
+
     #[cfg(target_arch = "wasm32")]
     {
         if cfg!(target_feature = "simd128") {
@@ -187,6 +227,14 @@ pub fn calculate_rms(data: &[f32]) -> f32 {
         }
     }
 
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && data.len().is_multiple_of(4) {
+            let sum = unsafe { calculate_rms_neon(data) };
+            return (sum * inv_len).sqrt();
+        }
+    }
+
     // Scalar fallback with loop unrolling
     let mut sum1 = 0.0;
     let mut sum2 = 0.0;
@@ -206,6 +254,72 @@ pub fn calculate_rms(data: &[f32]) -> f32 {
     mean_of_squares.sqrt()
 }
 
+/// Adds `frame` scaled by `gain` into `mix` with automatic SIMD optimization.
+///
+/// Used to sum several sources into one output frame. The result is not
+/// clamped; apply [`wide_mul`] to the finished mix to limit it to [-1.0, 1.0].
+/// Only the first `min(mix.len(), frame.len())` samples are mixed.
+///
+/// # Performance Notes
+///
+/// - AVX-512: processes 16 floats per iteration
+/// - AVX: processes 8 floats per iteration
+/// - Scalar: processes 1 float per iteration
+///
+/// For optimal performance, ensure the length is a multiple of 16.
+/// The standard `FRAME_SIZE` (480) satisfies this requirement.
+pub fn wide_mix(mix: &mut [f32], frame: &[f32], gain: f32) {
+    let len = mix.len().min(frame.len());
+    let (mix, frame) = (&mut mix[..len], &frame[..len]);
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx512f") && len.is_multiple_of(16) {
+            unsafe { avx512_mix(mix, frame, gain) }
+            return;
+        }
+        if is_x86_feature_detected!("avx") && len.is_multiple_of(8) {
+            unsafe { avx_mix(mix, frame, gain) }
+            return;
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && len.is_multiple_of(4) {
+            unsafe { neon_mix(mix, frame, gain) }
+            return;
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        if cfg!(target_feature = "simd128") && len.is_multiple_of(4) {
+            wasm_simd_mix(mix, frame, gain);
+            return;
+        }
+    }
+
+    scalar_mix(mix, frame, gain);
+}
+
+/// Averages each interleaved frame of `channels` samples into one mono sample.
+///
+/// Writes `interleaved.len() / channels` samples to `mono`, stopping early if
+/// `mono` is shorter. A trailing partial frame is ignored.
+pub fn downmix_to_mono(interleaved: &[f32], channels: usize, mono: &mut [f32]) {
+    if channels <= 1 {
+        let len = interleaved.len().min(mono.len());
+        mono[..len].copy_from_slice(&interleaved[..len]);
+        return;
+    }
+
+    let scale = 1_f32 / channels as f32;
+    for (dst, frame) in mono.iter_mut().zip(interleaved.chunks_exact(channels)) {
+        *dst = frame.iter().sum::<f32>() * scale;
+    }
+}
+
 /// Scalar multiplication for any length input.
 pub fn scalar_mul(frame: &mut [f32], factor: f32) {
     for p in frame.iter_mut() {
@@ -257,6 +371,52 @@ pub unsafe fn avx512_mul(frame: &mut [f32], factor: f32) {
     }
 }
 
+/// Scalar implementation of mixing for any length input.
+pub fn scalar_mix(mix: &mut [f32], frame: &[f32], gain: f32) {
+    for (m, f) in mix.iter_mut().zip(frame) {
+        *m += *f * gain;
+    }
+}
+
+/// Optimized mixing for AVX with 8|len==true.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx")]
+#[allow(unsafe_op_in_unsafe_fn)]
+pub unsafe fn avx_mix(mix: &mut [f32], frame: &[f32], gain: f32) {
+    let len = mix.len().min(frame.len());
+    let mut i = 0;
+
+    let gain_vec = _mm256_set1_ps(gain);
+
+    while i + 8 <= len {
+        let source = _mm256_loadu_ps(frame.as_ptr().add(i)); // load
+        let sum = _mm256_loadu_ps(mix.as_ptr().add(i));
+        let sum = _mm256_add_ps(sum, _mm256_mul_ps(source, gain_vec)); // scale & add
+        _mm256_storeu_ps(mix.as_mut_ptr().add(i), sum); // write
+        i += 8;
+    }
+}
+
+/// Optimized mixing for AVX-512 with 16|len==true.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[allow(unsafe_op_in_unsafe_fn)]
+pub unsafe fn avx512_mix(mix: &mut [f32], frame: &[f32], gain: f32) {
+    let len = mix.len().min(frame.len());
+    let mut i = 0;
+
+    let gain_vec = _mm512_set1_ps(gain);
+
+    // process 16 floats per iteration
+    while i + 16 <= len {
+        let source = _mm512_loadu_ps(frame.as_ptr().add(i)); // load
+        let sum = _mm512_loadu_ps(mix.as_ptr().add(i));
+        let sum = _mm512_add_ps(sum, _mm512_mul_ps(source, gain_vec)); // scale & add
+        _mm512_storeu_ps(mix.as_mut_ptr().add(i), sum); // write
+        i += 16;
+    }
+}
+
 /// Scalar implementation of i16 to f32 conversion with scaling.
 pub fn i16_to_f32_scalar(ints: &[i16], out: &mut [f32], scale: f32) {
     for (out, &x) in out.iter_mut().zip(ints.iter()) {
@@ -453,6 +613,16 @@ pub fn wide_f32_to_i16(floats: &[f32], output: &mut [i16]) {
         }
     }
 
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon")
+            && floats.len().min(output.len()).is_multiple_of(8)
+        {
+            unsafe { f32_to_i16_neon(floats, output) }
+            return;
+        }
+    }
+
     #[cfg(target_arch = "wasm32")]
     {
         if cfg!(target_feature = "simd128") && floats.len() >= 4 {
@@ -467,6 +637,193 @@ pub fn wide_f32_to_i16(floats: &[f32], output: &mut [i16]) {
     }
 }
 
+/// NEON multiplication: 4 floats per iteration.
+///
+/// Multiplies each sample by `factor` and clamps the result to [-1.0, 1.0].
+/// The caller must ensure `frame.len()` is a multiple of 4.
+///
+/// # Safety
+///
+/// The CPU must support NEON.
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[allow(unsafe_op_in_unsafe_fn)]
+pub unsafe fn neon_mul(frame: &mut [f32], factor: f32) {
+    let len = frame.len();
+    let mut i = 0;
+
+    let factor_vec = vdupq_n_f32(factor);
+    let min_vec = vdupq_n_f32(-1_f32);
+    let max_vec = vdupq_n_f32(1_f32);
+
+    while i + 4 <= len {
+        let mut chunk = vld1q_f32(frame.as_ptr().add(i)); // load
+        chunk = vmulq_f32(chunk, factor_vec); // multiply
+        chunk = vmaxq_f32(min_vec, vminq_f32(max_vec, chunk)); // clamp
+        vst1q_f32(frame.as_mut_ptr().add(i), chunk); // write
+        i += 4;
+    }
+}
+
+/// NEON mixing: 4 floats per iteration.
+///
+/// Adds each sample of `frame` multiplied by `gain` into `mix` without
+/// clamping. The multiply and add stay separate instructions so the result
+/// matches [`scalar_mix`] exactly. The caller must ensure the length is a
+/// multiple of 4.
+///
+/// # Safety
+///
+/// The CPU must support NEON.
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[allow(unsafe_op_in_unsafe_fn)]
+pub unsafe fn neon_mix(mix: &mut [f32], frame: &[f32], gain: f32) {
+    let len = mix.len().min(frame.len());
+    let mut i = 0;
+
+    let gain_vec = vdupq_n_f32(gain);
+
+    while i + 4 <= len {
+        let source = vld1q_f32(frame.as_ptr().add(i)); // load
+        let sum = vld1q_f32(mix.as_ptr().add(i));
+        let sum = vaddq_f32(sum, vmulq_f32(source, gain_vec)); // scale & add
+        vst1q_f32(mix.as_mut_ptr().add(i), sum); // write
+        i += 4;
+    }
+}
+
+/// NEON implementation of i16 to f32 conversion with scaling.
+///
+/// Converts 8 samples per iteration and the remainder with the scalar path.
+///
+/// # Safety
+///
+/// The CPU must support NEON.
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[allow(unsafe_op_in_unsafe_fn)]
+pub unsafe fn i16_to_f32_neon(ints: &[i16], out: &mut [f32], scale: f32) {
+    let n = ints.len().min(out.len());
+    let mut i = 0;
+
+    let scale_vec = vdupq_n_f32(scale);
+    let min_vec = vdupq_n_f32(-1_f32);
+    let max_vec = vdupq_n_f32(1_f32);
+
+    // process 8 i16 -> 8 f32 per loop
+    while i + 8 <= n {
+        // load 8 i16
+        let v16 = vld1q_s16(ints.as_ptr().add(i));
+
+        // lower 4 i16 -> 4 i32 -> 4 f32
+        let lo32 = vmovl_s16(vget_low_s16(v16));
+        let mut lo = vmulq_f32(vcvtq_f32_s32(lo32), scale_vec);
+        lo = vmaxq_f32(vminq_f32(lo, max_vec), min_vec);
+
+        // upper 4 i16 -> 4 i32 -> 4 f32
+        let hi32 = vmovl_high_s16(v16);
+        let mut hi = vmulq_f32(vcvtq_f32_s32(hi32), scale_vec);
+        hi = vmaxq_f32(vminq_f32(hi, max_vec), min_vec);
+
+        // store 8 f32
+        vst1q_f32(out.as_mut_ptr().add(i), lo);
+        vst1q_f32(out.as_mut_ptr().add(i + 4), hi);
+
+        i += 8;
+    }
+
+    i16_to_f32_scalar(&ints[i..n], &mut out[i..n], scale);
+}
+
+/// NEON implementation of float scaling with truncation.
+///
+/// Scales 4 floats per iteration and the remainder with the scalar path.
+///
+/// # Safety
+///
+/// The CPU must support NEON.
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[allow(unsafe_op_in_unsafe_fn)]
+pub unsafe fn neon_float_scaler(floats: &mut [f32], scale: f32) {
+    let n = floats.len();
+    let mut i = 0;
+
+    let scale_vec = vdupq_n_f32(scale);
+    let min_vec = vdupq_n_f32(MIN_I16_F32);
+    let max_vec = vdupq_n_f32(MAX_I16_F32);
+
+    while i + 4 <= n {
+        let mut v = vld1q_f32(floats.as_ptr().add(i));
+        v = vmulq_f32(v, scale_vec);
+        // round toward zero
+        v = vrndq_f32(v);
+        v = vmaxq_f32(v, min_vec);
+        v = vminq_f32(v, max_vec);
+        vst1q_f32(floats.as_mut_ptr().add(i), v);
+        i += 4;
+    }
+
+    scalar_float_scaler(&mut floats[i..], scale);
+}
+
+/// NEON sum of squares for the RMS calculation: 4 floats per iteration.
+///
+/// Returns the sum of the squared samples; the caller divides by the length
+/// and takes the square root. The caller must ensure `data.len()` is a
+/// multiple of 4.
+///
+/// # Safety
+///
+/// The CPU must support NEON.
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[allow(unsafe_op_in_unsafe_fn)]
+pub unsafe fn calculate_rms_neon(data: &[f32]) -> f32 {
+    let n = data.len();
+    let mut i = 0;
+
+    // Accumulator vector for parallel sum
+    let mut sum_vec = vdupq_n_f32(0_f32);
+
+    while i + 4 <= n {
+        let v = vld1q_f32(data.as_ptr().add(i));
+        // Square and accumulate: sum += v * v
+        sum_vec = vaddq_f32(sum_vec, vmulq_f32(v, v));
+        i += 4;
+    }
+
+    // Horizontal sum of the 4 lanes
+    vaddvq_f32(sum_vec)
+}
+
+/// NEON f32 to i16 conversion: 8 floats -> 8 i16 per iteration.
+///
+/// Truncates toward zero and saturates to the i16 range, matching the scalar
+/// `as i16` conversion. The caller must ensure the length is a multiple of 8.
+///
+/// # Safety
+///
+/// The CPU must support NEON.
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[allow(unsafe_op_in_unsafe_fn)]
+pub unsafe fn f32_to_i16_neon(floats: &[f32], output: &mut [i16]) {
+    let n = floats.len().min(output.len());
+    let mut i = 0;
+
+    while i + 8 <= n {
+        // Truncate toward zero with saturation to i32
+        let lo = vcvtq_s32_f32(vld1q_f32(floats.as_ptr().add(i)));
+        let hi = vcvtq_s32_f32(vld1q_f32(floats.as_ptr().add(i + 4)));
+        // Narrow to i16 with signed saturation
+        let packed = vqmovn_high_s32(vqmovn_s32(lo), hi);
+        vst1q_s16(output.as_mut_ptr().add(i), packed);
+        i += 8;
+    }
+}
+
 /// WASM SIMD multiplication: 4 floats per iteration via v128.
 ///
 /// Multiplies each sample by `factor` and clamps the result to [-1.0, 1.0].
@@ -492,6 +849,29 @@ fn wasm_simd_mul(frame: &mut [f32], factor: f32) {
     }
 }
 
+/// WASM SIMD mixing: 4 floats per iteration via v128.
+///
+/// Adds each sample of `frame` multiplied by `gain` into `mix` without
+/// clamping. The caller must ensure the length is a multiple of 4.
+#[cfg(target_arch = "wasm32")]
+#[target_feature(enable = "simd128")]
+fn wasm_simd_mix(mix: &mut [f32], frame: &[f32], gain: f32) {
+    let len = mix.len().min(frame.len());
+    let mut i = 0;
+
+    let gain_vec = f32x4_splat(gain);
+
+    while i + 4 <= len {
+        let source = unsafe { v128_load(frame.as_ptr().add(i) as *const v128) };
+        let mut sum = unsafe { v128_load(mix.as_ptr().add(i) as *const v128) };
+        sum = f32x4_add(sum, f32x4_mul(source, gain_vec));
+        unsafe {
+            v128_store(mix.as_mut_ptr().add(i) as *mut v128, sum);
+        }
+        i += 4;
+    }
+}
+
 /// WASM SIMD i16->f32 conversion with scaling: 8 i16 -> 8 f32 per iteration.
 ///
 /// Converts 16-bit integer samples to 32-bit floats, applies `scale`, and
@@ -595,7 +975,8 @@ fn wasm_simd_f32_to_i16(floats: &[f32], output: &mut [i16]) {
 ///
 /// These tests verify that all SIMD implementations produce identical results
 /// to the scalar fallback implementations. Tests run on x86_64 architecture
-/// and conditionally test AVX2 and AVX-512 paths based on CPU support.
+/// and conditionally test AVX2 and AVX-512 paths based on CPU support, and on
+/// aarch64 where they test the NEON paths.
 #[cfg(test)]
 mod tests {
     #![allow(non_snake_case)]
@@ -613,7 +994,11 @@ mod tests {
     }
 
     /// Creates a test frame of i16 samples.
-    #[cfg(target_arch = "x86_64")]
+    #[cfg(any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "wasm32"
+    ))]
     fn dummy_int_frame() -> [i16; FRAME_SIZE] {
         let mut frame = [0_i16; FRAME_SIZE];
         for (i, sample) in frame.iter_mut().enumerate() {
@@ -733,6 +1118,50 @@ mod tests {
         );
     }
 
+    /// Verifies mixing variants produce identical output.
+    ///
+    /// Tests scalar, AVX, AVX-512, and wide_mix against each other.
+    #[test]
+    fn MixVariants_DummyFrame_EqualOutputs() {
+        let frame = dummy_frame();
+        let mut scalar_mix = frame;
+        let mut wide_mix = frame;
+
+        super::scalar_mix(&mut scalar_mix, &frame, 0.75);
+        super::wide_mix(&mut wide_mix, &frame, 0.75);
+
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("avx") {
+            let mut avx_mix = frame;
+            unsafe { super::avx_mix(&mut avx_mix, &frame, 0.75) };
+            assert_eq!(scalar_mix, avx_mix);
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("avx512f") {
+            let mut avx512_mix = frame;
+            unsafe { super::avx512_mix(&mut avx512_mix, &frame, 0.75) };
+            assert_eq!(scalar_mix, avx512_mix);
+        }
+
+        assert_eq!(scalar_mix, wide_mix);
+        assert!((scalar_mix[0] - frame[0] * 1.75).abs() < 1e-6);
+    }
+
+    /// Verifies downmixing averages the channels of every frame.
+    #[test]
+    fn Downmix_StereoFrame_AveragesChannels() {
+        let stereo = [0.5_f32, -0.5, 1.0, 0.0, 0.25, 0.75];
+        let mut mono = [9_f32; 3];
+        super::downmix_to_mono(&stereo, 2, &mut mono);
+        assert_eq!(mono, [0.0, 0.5, 0.5]);
+
+        let frame = dummy_frame();
+        let mut copied = [0_f32; FRAME_SIZE];
+        super::downmix_to_mono(&frame, 1, &mut copied);
+        assert_eq!(frame, copied);
+    }
+
     /// Verifies f32 to i16 conversion.
     #[test]
     #[cfg(target_arch = "x86_64")]
@@ -754,6 +1183,127 @@ mod tests {
         assert_eq!(scalar_output, simd_output);
     }
 
+    /// Verifies NEON multiplication produces identical output to scalar.
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn NeonMulVariants_DummyFrame_EqualOutputs() {
+        let frame = dummy_frame();
+        let mut scalar_frame = frame;
+        let mut neon_frame = frame;
+        let mut wide_frame = frame;
+
+        super::scalar_mul(&mut scalar_frame, 2_f32);
+        super::wide_mul(&mut wide_frame, 2_f32);
+
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            unsafe { super::neon_mul(&mut neon_frame, 2_f32) };
+            assert_eq!(scalar_frame, neon_frame);
+        }
+
+        assert_eq!(scalar_frame, wide_frame);
+    }
+
+    /// Verifies NEON mixing produces identical output to scalar.
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn NeonMixVariants_DummyFrame_EqualOutputs() {
+        let frame = dummy_frame();
+        let mut scalar_mix = frame;
+        let mut neon_mix = frame;
+
+        super::scalar_mix(&mut scalar_mix, &frame, 0.75);
+
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            unsafe { super::neon_mix(&mut neon_mix, &frame, 0.75) };
+            assert_eq!(scalar_mix, neon_mix);
+        }
+    }
+
+    /// Verifies NEON i16->f32 conversion produces identical output to scalar.
+    ///
+    /// The odd length also covers the scalar remainder.
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn NeonIntConversion_DummyFrame_EqualOutputs() {
+        let frame = dummy_int_frame();
+        let mut scalar_frame = [0_f32; FRAME_SIZE];
+        let mut neon_frame = [0_f32; FRAME_SIZE];
+        let mut wide_frame = [0_f32; FRAME_SIZE];
+        let scale = (1_f32 / i16::MAX as f32) * 2.0;
+
+        super::i16_to_f32_scalar(&frame, &mut scalar_frame, scale);
+        super::wide_i16_to_f32(&frame, &mut wide_frame, scale);
+
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            let len = FRAME_SIZE - 3;
+            unsafe { super::i16_to_f32_neon(&frame[..len], &mut neon_frame[..len], scale) };
+            assert_eq!(scalar_frame[..len], neon_frame[..len]);
+        }
+
+        assert_eq!(scalar_frame, wide_frame);
+    }
+
+    /// Verifies NEON float scaling produces identical output to scalar.
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn NeonFloatConversion_DummyFrame_EqualOutputs() {
+        let frame = dummy_frame();
+        let mut scalar_frame = frame;
+        let mut neon_frame = frame;
+        let mut wide_frame = frame;
+
+        let scale = i16::MAX as f32 * 2.0;
+        super::scalar_float_scaler(&mut scalar_frame, scale);
+        super::wide_float_scaler(&mut wide_frame, scale);
+
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            unsafe { super::neon_float_scaler(&mut neon_frame, scale) };
+            assert_eq!(scalar_frame, neon_frame);
+        }
+
+        assert_eq!(scalar_frame, wide_frame);
+    }
+
+    /// Verifies NEON f32->i16 conversion saturates like the scalar cast.
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn NeonF32ToI16_DummyFrame_CorrectOutput() {
+        let frame = dummy_frame();
+        // twice the i16 range, so half of the samples saturate
+        let scaled: Vec<f32> = frame.iter().map(|x| x * 4.0 * i16::MAX as f32).collect();
+
+        let scalar_output: Vec<i16> = scaled.iter().map(|&f| f as i16).collect();
+        let mut neon_output = vec![0i16; FRAME_SIZE];
+
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            unsafe { super::f32_to_i16_neon(&scaled, &mut neon_output) };
+            assert_eq!(scalar_output, neon_output);
+        }
+    }
+
+    /// Verifies NEON RMS calculation matches scalar reference.
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn NeonRmsCalculation_DummyFrame_CorrectResult() {
+        let frame = dummy_frame();
+
+        let sum: f32 = frame.iter().map(|x| x * x).sum();
+        let expected = (sum / frame.len() as f32).sqrt();
+
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            // the NEON kernel returns the sum of squares, calculate_rms finishes it
+            let neon_sum = unsafe { super::calculate_rms_neon(&frame) };
+            let result = (neon_sum / frame.len() as f32).sqrt();
+
+            assert!(
+                (result - expected).abs() < 1e-6,
+                "NEON RMS mismatch: {} vs {}",
+                result,
+                expected
+            );
+        }
+    }
+
     /// Verifies WASM SIMD multiplication produces identical output to scalar.
     #[wasm_bindgen_test::wasm_bindgen_test]
     #[cfg(target_arch = "wasm32")]
@@ -774,6 +1324,26 @@ mod tests {
         assert_eq!(scalar_frame, wide_frame);
     }
 
+    /// Verifies WASM SIMD mixing produces identical output to scalar.
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    #[cfg(target_arch = "wasm32")]
+    fn WasmMixVariants_DummyFrame_EqualOutputs() {
+        let frame = dummy_frame();
+        let mut scalar_mix = frame;
+        let mut wasm_simd_mix = frame;
+        let mut wide_mix = frame;
+
+        super::scalar_mix(&mut scalar_mix, &frame, 0.75);
+        super::wide_mix(&mut wide_mix, &frame, 0.75);
+
+        if cfg!(target_feature = "simd128") {
+            unsafe { super::wasm_simd_mix(&mut wasm_simd_mix, &frame, 0.75) };
+            assert_eq!(scalar_mix, wasm_simd_mix);
+        }
+
+        assert_eq!(scalar_mix, wide_mix);
+    }
+
     /// Verifies WASM SIMD i16->f32 conversion produces identical output to scalar.
     #[wasm_bindgen_test::wasm_bindgen_test]
     #[cfg(target_arch = "wasm32")]
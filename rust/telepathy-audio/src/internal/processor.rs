@@ -1,8 +1,9 @@
 //! Core audio processor functions.
 //!
 //! This module contains the main audio processing functions for input
-//! and output streams, including resampling, noise suppression, volume
-//! control, and silence detection.
+//! and output streams, including resampling, echo cancellation, noise
+//! suppression, volume control, pluggable effects, and silence or voice
+//! activity gating.
 //!
 //! ## Threading Model
 //!
@@ -19,18 +20,24 @@
 //! Callers typically spawn [`input_processor`] and [`output_processor`] in
 //! dedicated threads, since they perform blocking I/O on the source/sink.
 
+use crate::codec::{AudioDecoder, AudioEncoder};
 use crate::constants::MINIMUM_SILENCE_LENGTH;
-use crate::error::{ChannelError, Error};
-use crate::internal::NETWORK_FRAME;
+use crate::error::{ChannelError, Error, ProcessingError};
 use crate::internal::buffer_pool::BufferPool;
+use crate::internal::drift::DriftCompensator;
+use crate::internal::echo::{EchoCanceller, EchoReferenceProducer};
+use crate::internal::effects::{AudioEffect, EffectChain};
+use crate::internal::gain::AutoGain;
+use crate::internal::plc::PacketLossConcealer;
 use crate::internal::processing::*;
 use crate::internal::state::{InputProcessorState, OutputProcessorState};
 use crate::internal::traits::{AudioInput, AudioOutput};
-use crate::internal::utils::resampler_factory;
+use crate::internal::utils::{max_input_samples, max_output_samples, resampler_factory};
+use crate::internal::vad::VoiceGate;
 use crate::io::traits::{AudioDataSink, AudioDataSource, ClosedOrFailed};
-use crate::sea::decoder::SeaDecoder;
-use crate::sea::encoder::SeaEncoder;
+use crate::recorder::RecordingTrack;
 use audioadapter_buffers::direct::InterleavedSlice;
+use bytes::Bytes;
 use nnnoiseless::{DenoiseState, FRAME_SIZE};
 use rubato::{FixedSync, Resampler};
 use tracing::{debug, warn};
@@ -40,17 +47,23 @@ use tracing::{debug, warn};
 /// This function handles the complete input processing pipeline:
 /// - Reading from the audio input source
 /// - Resampling from `input_rate` to `output_rate` (when they differ)
+/// - Acoustic echo cancellation (if enabled)
 /// - Applying input volume adjustment
 /// - Noise suppression (if enabled)
+/// - Automatic gain control & limiting (if enabled)
+/// - Running the effect chain (if one was installed)
+/// - Tapping the processed frame into a recording (if enabled)
 /// - RMS calculation and threshold detection
+/// - Voice activity gating (if enabled, replaces the RMS threshold)
 /// - Silence transition handling
-/// - Converting to i16 samples for network transmission
+/// - Converting to i16 samples and encoding them for network transmission
 ///
 /// ## Threading
 ///
 /// This function is designed to run in a dedicated thread. It performs blocking
 /// reads from the input source and blocking sends to the output channel. The
-/// function returns when the input source signals end-of-stream (read returns 0).
+/// function returns when the input source signals end-of-stream (read returns 0),
+/// unless a replacement device is waiting in `state`'s device swaps.
 ///
 /// ## Channel Communication
 ///
@@ -66,22 +79,40 @@ use tracing::{debug, warn};
 /// * `output_rate` - Target network/output sample rate in Hz. The processor
 ///   resamples from `input_rate` to `output_rate` when they differ.
 /// * `denoiser` - Optional noise suppression state (requires 48kHz input)
-/// * `state` - Shared state for volume, mute, and statistics
-/// * `encoder_option` - Optional SEA encoder; encodes into the `PooledBuffer`
-///   before sending when present.
+/// * `echo_canceller` - Optional echo canceller; must run at `output_rate`
+///   and read the far-end reference written by the output processors.
+/// * `auto_gain` - Optional automatic gain control; when present it replaces
+///   the fixed input volume, which becomes the gain ceiling.
+/// * `voice_gate` - Optional voice activity gate; when present it decides which
+///   frames are sent instead of the RMS threshold. It reuses the denoiser's
+///   speech probability and requires a 48kHz `output_rate`.
+/// * `recording` - Optional recording track; every processed frame is pushed
+///   at `output_rate`, before silence or voice gating.
+/// * `state` - Shared state for volume, mute, statistics, replacement input
+///   devices and effect chains, the number of interleaved channels, and the
+///   residual bits the encoder follows.
+///   Noise suppression and echo cancellation only support mono, pass `None`
+///   for them with multichannel input.
+/// * `encoder` - Encodes each frame into the `PooledBuffer` before sending;
+///   use [`RawEncoder`](crate::codec::RawEncoder) to send raw i16 samples.
 ///
 /// # Returns
 ///
 /// Returns `Ok(())` when the input stream ends or channel closes, or an error
 /// if processing fails.
+#[allow(clippy::too_many_arguments)]
 pub fn input_processor<I: AudioInput>(
-    mut input: I,
+    input: I,
     sink: impl AudioDataSink,
     input_rate: usize,
     output_rate: usize,
     mut denoiser: Option<Box<DenoiseState>>,
+    mut echo_canceller: Option<EchoCanceller>,
+    mut auto_gain: Option<AutoGain>,
+    mut voice_gate: Option<VoiceGate>,
+    mut recording: Option<RecordingTrack>,
     state: InputProcessorState,
-    mut encoder_option: Option<SeaEncoder>,
+    mut encoder: Box<dyn AudioEncoder>,
 ) -> Result<(), Error> {
     // the maximum value for i16 as f32
     let max_i16_f32 = i16::MAX as f32;
@@ -91,17 +122,18 @@ pub fn input_processor<I: AudioInput>(
     // output for 16 bit samples
     let mut int_buffer = [0; FRAME_SIZE];
 
+    // every frame holds FRAME_SIZE interleaved samples
+    let channels = state.channels();
+    let frames = FRAME_SIZE / channels;
+
+    // the device can be swapped for another one while running
+    let mut input: Box<dyn AudioInput + '_> = Box::new(input);
     // resampler is Some if resampling is needed
     let mut resampler =
-        resampler_factory(input_rate, output_rate, 1, FRAME_SIZE, FixedSync::Output)?;
-    // the maximum number of samples per input
-    let input_buffer_size = resampler
-        .as_ref()
-        .map(|r| r.input_frames_max())
-        .unwrap_or(FRAME_SIZE);
+        resampler_factory(input_rate, output_rate, channels, frames, FixedSync::Output)?;
 
     // the input for the resampler
-    let mut pre_buf = vec![0_f32; input_buffer_size];
+    let mut pre_buf = vec![0_f32; max_input_samples(&resampler, channels)];
     // the output for the resampler
     let mut post_buf = vec![0_f32; FRAME_SIZE];
 
@@ -109,14 +141,41 @@ pub fn input_processor<I: AudioInput>(
     let mut position = 0;
     // a counter for short silence detection
     let mut silence_length = 0_u16;
+    // the effect chain can be replaced while running
+    let mut effects = EffectChain::new();
+    // the residual bits last handed to the encoder
+    let mut residual_bits = None;
 
     loop {
+        if let Some(mut chain) = state.take_effect_chain() {
+            chain.prepare(output_rate as u32, channels);
+            effects = chain;
+        }
+
+        if let Some(swap) = state.take_device_swap() {
+            debug!(sample_rate = swap.sample_rate, "input_device_swapped");
+            input = swap.device;
+            resampler = resampler_factory(
+                swap.sample_rate,
+                output_rate,
+                channels,
+                frames,
+                FixedSync::Output,
+            )?;
+            pre_buf = vec![0_f32; max_input_samples(&resampler, channels)];
+            position = 0;
+        }
+
         let in_len = resampler
             .as_ref()
-            .map(|r| r.input_frames_next())
+            .map(|r| r.input_frames_next() * channels)
             .unwrap_or(FRAME_SIZE);
         let read = input.read_into(&mut pre_buf[position..in_len])?;
         if read == 0 {
+            if state.device_swap_pending() {
+                // the old device was closed after its replacement was sent
+                continue;
+            }
             debug!("Resampler loop ended (EOF)");
             break;
         }
@@ -134,11 +193,11 @@ pub fn input_processor<I: AudioInput>(
 
         let (target_buffer, len) = if let Some(resampler) = &mut resampler {
             // resample the data
-            let input_adapter = InterleavedSlice::new(&pre_buf, 1, in_len)?;
-            let mut output_adapter = InterleavedSlice::new_mut(&mut post_buf, 1, FRAME_SIZE)?;
+            let input_adapter = InterleavedSlice::new(&pre_buf, channels, in_len / channels)?;
+            let mut output_adapter = InterleavedSlice::new_mut(&mut post_buf, channels, frames)?;
             let processed =
                 resampler.process_into_buffer(&input_adapter, &mut output_adapter, None)?;
-            (&mut post_buf, processed.1)
+            (&mut post_buf, processed.1 * channels)
         } else {
             (&mut pre_buf, FRAME_SIZE)
         };
@@ -150,49 +209,91 @@ pub fn input_processor<I: AudioInput>(
             continue;
         }
 
+        if let Some(ref mut echo_canceller) = echo_canceller {
+            // remove the far-end echo before volume & denoising
+            echo_canceller.process(&mut target_buffer[..len])?;
+        }
+
+        // automatic gain control applies the volume itself, after denoising
+        let volume = if auto_gain.is_some() {
+            1_f32
+        } else {
+            state.input_volume()
+        };
         // apply the input volume & scale the samples to -32768.0 to 32767.0
-        wide_float_scaler(
-            &mut target_buffer[..len],
-            max_i16_f32 * state.input_volume(),
-        );
+        wide_float_scaler(&mut target_buffer[..len], max_i16_f32 * volume);
 
-        if let Some(ref mut denoiser) = denoiser {
+        let voice_probability = if let Some(ref mut denoiser) = denoiser {
             // denoise the frame
-            denoiser.process_frame(&mut out_buf, &target_buffer[..len]);
+            Some(denoiser.process_frame(&mut out_buf, &target_buffer[..len]))
         } else {
             out_buf = target_buffer[..len].try_into()?;
+            None
         };
 
+        // detect voice before the gain changes the level
+        let voice = voice_gate
+            .as_mut()
+            .map(|voice_gate| voice_gate.process(&out_buf, voice_probability));
+
+        if let Some(ref mut auto_gain) = auto_gain {
+            // level the speech, never exceeding the input volume
+            let gain = auto_gain.process(&mut out_buf, state.input_volume());
+            state.send_gain(gain);
+        }
+
+        if !effects.is_empty() {
+            // effects work on samples scaled to -1.0 to 1.0
+            wide_mul(&mut out_buf, 1_f32 / max_i16_f32);
+            effects.process(&mut out_buf);
+            wide_float_scaler(&mut out_buf, max_i16_f32);
+        }
+
+        if let Some(ref mut recording) = recording
+            && recording.is_recording()
+        {
+            // record every processed frame, including those the gate drops
+            wide_f32_to_i16(&out_buf, &mut int_buffer);
+            recording.push_i16(&int_buffer);
+        }
+
         // calculate the rms
         let rms = calculate_rms(&out_buf);
         // send the rms to the statistics collector
         state.send_rms(rms);
 
-        // check if the frame is below the rms threshold
-        if rms < state.rms_threshold() {
-            if silence_length < MINIMUM_SILENCE_LENGTH {
-                silence_length += 1; // short silences are ignored
-            } else {
-                continue; // long ones are dropped
+        match voice {
+            // the voice gate applies its own hangover
+            Some(false) => continue,
+            Some(true) => (),
+            // check if the frame is below the rms threshold
+            None if rms < state.rms_threshold() => {
+                if silence_length < MINIMUM_SILENCE_LENGTH {
+                    silence_length += 1; // short silences are ignored
+                } else {
+                    continue; // long ones are dropped
+                }
             }
-        } else {
-            silence_length = 0;
+            None => silence_length = 0,
         }
 
         // use SIMD-accelerated f32 to i16 conversion
         wide_f32_to_i16(&out_buf, &mut int_buffer);
 
+        if let Some(bits) = state.residual_bits()
+            && residual_bits != Some(bits)
+        {
+            // an invalid setting keeps the previous one
+            if let Err(error) = encoder.set_residual_bits(bits) {
+                warn!(bits, ?error, "input_residual_bits_rejected");
+            }
+            residual_bits = Some(bits);
+        }
+
         // acquire a buffer from the pool
         let mut pooled = BufferPool::acquire(state.buffer_pool());
-        if let Some(encoder) = &mut encoder_option {
-            // encode frame into the pooled buffer
-            encoder.encode_frame(int_buffer, pooled.inner_mut())?;
-        } else {
-            // copy frame data into the pooled buffer
-            pooled.inner_mut().copy_from_slice(unsafe {
-                std::slice::from_raw_parts(int_buffer.as_ptr() as *const u8, NETWORK_FRAME)
-            });
-        }
+        // encode frame into the pooled buffer
+        encoder.encode_frame(int_buffer, pooled.inner_mut())?;
 
         // send buffer to network
         match sink.send(pooled) {
@@ -212,11 +313,16 @@ pub fn input_processor<I: AudioInput>(
 /// Processes audio output from the network and sends it to the output device.
 ///
 /// This function handles the complete output processing pipeline:
-/// - Receiving audio frames from the network
+/// - Receiving audio frames from the network and decoding them
+/// - Concealing frames the source reports as missing
+/// - Tapping the decoded frame into a recording (if enabled)
 /// - Converting from i16 to f32 samples
+/// - Running the effect chain (if one was installed)
 /// - Applying output volume adjustment
+/// - Feeding the echo canceller's far-end reference (if enabled)
 /// - RMS calculation for statistics
 /// - Resampling to the output device sample rate
+/// - Compensating clock drift against the output's fill level (if enabled)
 /// - Handling deafen state and buffer overflow
 ///
 /// ## Threading
@@ -234,37 +340,63 @@ pub fn input_processor<I: AudioInput>(
 /// # Arguments
 ///
 /// * `source` - Source of audio frames implementing `AudioDataSource`. Each
-///   frame is `Bytes` containing i16 samples (raw or encoded).
+///   frame is `Bytes` containing i16 samples (raw or encoded), or
+///   [`MISSING_FRAME`](crate::io::traits::MISSING_FRAME) for a frame lost in transit.
+///   A lost frame is rebuilt from the following frame when the source already
+///   has it and the decoder supports [`AudioDecoder::recover_frame`].
 /// * `output` - The audio output destination implementing `AudioOutput`.
 /// * `input_rate` - Network/source sample rate in Hz.
 /// * `output_rate` - Sample rate of the audio output device in Hz. The
 ///   processor resamples from `input_rate` to `output_rate` when they differ.
-/// * `state` - Shared state for volume, deafen, and statistics
-/// * `decoder_option` - Optional `SeaDecoder` for decoding received frames
-///   before playback. When `None`, frames are treated as raw i16 samples.
+/// * `state` - Shared state for volume, deafen, drift compensation,
+///   statistics, and replacement output devices and effect chains
+/// * `decoder` - Decodes received frames before playback; use
+///   [`RawDecoder`](crate::codec::RawDecoder) for raw i16 samples. Frames with
+///   the wrong length for the codec are skipped.
+/// * `echo_reference` - Optional far-end reference producer; every played
+///   frame is pushed at `input_rate` for the input side's echo canceller.
+///   Multichannel frames are downmixed to mono first.
+/// * `recording` - Optional recording track; every decoded or concealed frame
+///   is pushed at `input_rate`, before the output volume is applied.
+///
+/// The number of interleaved channels is taken from `state`.
 ///
 /// # Returns
 ///
 /// Returns `Ok(())` when the input channel closes, or an error if processing fails.
+#[allow(clippy::too_many_arguments)]
 pub fn output_processor<O: AudioOutput>(
     source: impl AudioDataSource,
-    mut output: O,
+    output: O,
     input_rate: usize,
     output_rate: usize,
     state: OutputProcessorState,
-    mut decoder_option: Option<SeaDecoder>,
+    mut decoder: Box<dyn AudioDecoder>,
+    mut echo_reference: Option<EchoReferenceProducer>,
+    mut recording: Option<RecordingTrack>,
 ) -> Result<(), Error> {
     // base scale to convert i16 to f32
     let scale = 1_f32 / i16::MAX as f32;
 
+    // every frame holds FRAME_SIZE interleaved samples
+    let channels = state.channels();
+    let frames = FRAME_SIZE / channels;
+
     // resampler is Some if resampling is needed
     let mut resampler_option =
-        resampler_factory(input_rate, output_rate, 1, FRAME_SIZE, FixedSync::Input)?;
+        resampler_factory(input_rate, output_rate, channels, frames, FixedSync::Input)?;
     // the maximum number of samples per output
-    let output_buffer_size = resampler_option
-        .as_ref()
-        .map(|r| r.output_frames_max())
-        .unwrap_or(FRAME_SIZE);
+    let mut output_buffer_size = max_output_samples(&resampler_option, channels);
+    // the device can be swapped for another one while running
+    let mut output: Box<dyn AudioOutput + '_> = Box::new(output);
+    // replaces the resampler when the output's latency is held constant
+    let mut drift = drift_compensator(&*output, &state, input_rate, output_rate, frames)?;
+    if let Some(drift) = &drift {
+        output_buffer_size = output_buffer_size.max(drift.max_output_samples());
+    }
+
+    // synthesizes replacements for missing frames
+    let mut concealer = PacketLossConcealer::new(input_rate, channels);
 
     // The output of the decoder
     let mut decoded_buf = [0_i16; FRAME_SIZE];
@@ -272,9 +404,15 @@ pub fn output_processor<O: AudioOutput>(
     let mut pre_buf = [0_f32; FRAME_SIZE];
     // The output for the resampler
     let mut post_buf = vec![0_f32; output_buffer_size];
+    // The mono downmix for the echo reference
+    let mut mono_buf = [0_f32; FRAME_SIZE];
+    // the effect chain can be replaced while running
+    let mut effects = EffectChain::new();
+    // a frame received early to rebuild the lost frame before it
+    let mut pending: Option<Bytes> = None;
 
     loop {
-        let buffer = match source.recv() {
+        let buffer = match pending.take().map_or_else(|| source.recv(), Ok) {
             Ok(b) => b,
             Err(ClosedOrFailed::Closed) => break,
             Err(ClosedOrFailed::Failed(error)) => {
@@ -282,39 +420,106 @@ pub fn output_processor<O: AudioOutput>(
             }
         };
 
+        if let Some(swap) = state.take_device_swap() {
+            debug!(sample_rate = swap.sample_rate, "output_device_swapped");
+            output = swap.device;
+            resampler_option = resampler_factory(
+                input_rate,
+                swap.sample_rate,
+                channels,
+                frames,
+                FixedSync::Input,
+            )?;
+            output_buffer_size = max_output_samples(&resampler_option, channels);
+            drift = drift_compensator(&*output, &state, input_rate, swap.sample_rate, frames)?;
+            if let Some(drift) = &drift {
+                output_buffer_size = output_buffer_size.max(drift.max_output_samples());
+            }
+            post_buf = vec![0_f32; output_buffer_size];
+        }
+
+        if let Some(mut chain) = state.take_effect_chain() {
+            chain.prepare(input_rate as u32, channels);
+            effects = chain;
+        }
+
         let int_samples = if state.is_deafened() {
             continue;
         } else if output.is_full() {
             state.send_loss(FRAME_SIZE);
             continue; // ignore frames while output is full
-        } else if let Some(decoder) = &mut decoder_option {
-            decoder.decode_frame(&buffer, &mut decoded_buf)?;
+        } else if buffer.is_empty() {
+            // the source knows this frame was lost, rebuild it from the next
+            // frame if that already arrived or synthesize a replacement
+            pending = match source.try_recv() {
+                Ok(next) => next,
+                Err(ClosedOrFailed::Closed) => None,
+                Err(ClosedOrFailed::Failed(error)) => {
+                    return Err(Error::Channel(ChannelError::DataSourceFailed(error)));
+                }
+            };
+            match &pending {
+                Some(next) if !next.is_empty() && decoder.recover_frame(next, &mut decoded_buf) => {
+                    concealer.frame_received(&mut decoded_buf)
+                }
+                _ => concealer.conceal(&mut decoded_buf, decoder.as_mut()),
+            }
             &decoded_buf
-        } else if buffer.len() != NETWORK_FRAME {
-            warn!(len = buffer.len(), "output_frame_size_mismatch");
-            continue;
         } else {
-            // copy samples from the payload into decoded_buf
-            for (dst, src) in decoded_buf.iter_mut().zip(buffer.chunks_exact(2)) {
-                *dst = i16::from_ne_bytes([src[0], src[1]]);
+            match decoder.decode_frame(&buffer, &mut decoded_buf) {
+                Ok(()) => (),
+                Err(Error::Processing(ProcessingError::FrameLength { actual, .. })) => {
+                    warn!(len = actual, "output_frame_size_mismatch");
+                    continue;
+                }
+                Err(error) => return Err(error),
             }
+            concealer.frame_received(&mut decoded_buf);
             &decoded_buf
         };
 
-        // convert the i16 samples to f32 & apply the output volume
-        wide_i16_to_f32(int_samples, &mut pre_buf, scale * state.output_volume());
+        if let Some(recording) = &mut recording {
+            // record the peer before the local output volume
+            recording.push_i16(int_samples);
+        }
+
+        if effects.is_empty() {
+            // convert the i16 samples to f32 & apply the output volume
+            wide_i16_to_f32(int_samples, &mut pre_buf, scale * state.output_volume());
+        } else {
+            // effects run before the output volume so their levels hold
+            wide_i16_to_f32(int_samples, &mut pre_buf, scale);
+            effects.process(&mut pre_buf);
+            wide_mul(&mut pre_buf, state.output_volume());
+        }
+        if let Some(echo_reference) = &mut echo_reference {
+            // the echo canceller needs exactly what is about to be played
+            if channels == 1 {
+                echo_reference.push(&pre_buf);
+            } else {
+                downmix_to_mono(&pre_buf, channels, &mut mono_buf);
+                echo_reference.push(&mono_buf[..frames]);
+            }
+        }
         // send the rms to the statistics collector
         state.send_rms(calculate_rms(&pre_buf));
+
         // get finalized samples
-        let float_samples = if let Some(resampler) = &mut resampler_option {
+        let float_samples = if let Some(drift) = &mut drift {
+            // steer the ratio by the samples still queued ahead of the device
+            let queued = output.buffered().unwrap_or_default();
+            let len = drift.process(&pre_buf, &mut post_buf, queued)?;
+            state.send_drift(drift.controller());
+            &post_buf[..len]
+        } else if let Some(resampler) = &mut resampler_option {
             // resample the data
-            let input_adapter = InterleavedSlice::new(&pre_buf, 1, FRAME_SIZE)?;
+            let input_adapter = InterleavedSlice::new(&pre_buf, channels, frames)?;
             let mut output_adapter =
-                InterleavedSlice::new_mut(&mut post_buf, 1, output_buffer_size)?;
+                InterleavedSlice::new_mut(&mut post_buf, channels, output_buffer_size / channels)?;
             let processed =
                 resampler.process_into_buffer(&input_adapter, &mut output_adapter, None)?;
             // send the resampled data to the output stream
-            &post_buf[..processed.1]
+            &post_buf[..processed.1 * channels]
         } else {
             // if no resampling is needed, send the data to the output stream
             &pre_buf
@@ -329,3 +534,19 @@ pub fn output_processor<O: AudioOutput>(
     debug!("Output processor ended");
     Ok(())
 }
+
+/// Creates a drift compensator when it is enabled and `output` reports its
+/// fill level.
+fn drift_compensator(
+    output: &dyn AudioOutput,
+    state: &OutputProcessorState,
+    input_rate: usize,
+    output_rate: usize,
+    frames: usize,
+) -> Result<Option<DriftCompensator>, Error> {
+    if !state.drift_compensation() || output.buffered().is_none() {
+        return Ok(None);
+    }
+
+    DriftCompensator::new(input_rate, output_rate, state.channels(), frames).map(Some)
+}
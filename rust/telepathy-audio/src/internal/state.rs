@@ -15,15 +15,47 @@
 //! monitoring. The processor updates these atomics with the maximum RMS
 //! value encountered, which can be read by the application for visualization
 //! or voice activity detection.
+//!
+//! ## Device Swaps
+//!
+//! A processor can move to another device while it runs. The new device is
+//! sent as a [`DeviceSwap`] through the receiver given to
+//! `with_device_swaps`, and the processor continues on it at the next frame.
+//! Effect chains are installed the same way, through the receiver given to
+//! `with_effect_chains`.
+//!
+//! ## Drift Statistics
+//!
+//! With drift compensation enabled the output state also reports the
+//! resampling ratio correction and the latency it holds, see
+//! [`OutputProcessorState::drift_ppm`] and [`OutputProcessorState::queued`].
 
 use crate::internal::NETWORK_FRAME;
 use crate::internal::buffer_pool::BufferPool;
+use crate::internal::drift::DriftController;
+use crate::internal::effects::EffectChain;
+use crate::internal::traits::{AudioInput, AudioOutput};
 use atomic_float::AtomicF32;
+use crossbeam::channel::Receiver;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::Relaxed;
 
+/// A device replacing the one a running processor reads from or writes to.
+pub struct DeviceSwap<D> {
+    /// The newly opened device.
+    pub device: D,
+    /// The sample rate of the new device in Hz.
+    pub sample_rate: usize,
+}
+
+/// A replacement for the device of a running input processor.
+pub type InputSwap = DeviceSwap<Box<dyn AudioInput + Send>>;
+
+/// A replacement for the device of a running output or mixer processor.
+pub type OutputSwap = DeviceSwap<Box<dyn AudioOutput + Send>>;
+
 /// State for the input audio processor.
 ///
 /// This structure holds atomic references to shared state that can be
@@ -33,7 +65,12 @@ pub struct InputProcessorState {
     pub(crate) rms_threshold: Arc<AtomicF32>,
     pub(crate) muted: Arc<AtomicBool>,
     pub(crate) rms_sender: Arc<AtomicF32>,
+    pub(crate) gain_sender: Arc<AtomicF32>,
     pub(crate) buffer_pool: Arc<BufferPool>,
+    pub(crate) channels: usize,
+    pub(crate) device_swaps: Option<Receiver<InputSwap>>,
+    pub(crate) effect_chains: Option<Receiver<EffectChain>>,
+    pub(crate) residual_bits: Option<Arc<AtomicF32>>,
 }
 
 impl InputProcessorState {
@@ -57,10 +94,79 @@ impl InputProcessorState {
             rms_threshold: rms_threshold.clone(),
             muted: muted.clone(),
             rms_sender,
+            gain_sender: Arc::new(AtomicF32::new(1.0)),
             buffer_pool: Arc::new(BufferPool::new(pool_size, NETWORK_FRAME)),
+            channels: 1,
+            device_swaps: None,
+            effect_chains: None,
+            residual_bits: None,
         }
     }
 
+    /// Replaces the atomic the automatic gain control reports its gain to.
+    pub fn with_gain_sender(mut self, gain_sender: Arc<AtomicF32>) -> Self {
+        self.gain_sender = gain_sender;
+        self
+    }
+
+    /// Sets the number of interleaved channels in every frame.
+    pub fn with_channels(mut self, channels: u16) -> Self {
+        self.channels = channels.max(1) as usize;
+        self
+    }
+
+    /// Gets the number of interleaved channels in every frame.
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Sets the receiver replacement input devices arrive on.
+    pub fn with_device_swaps(mut self, device_swaps: Receiver<InputSwap>) -> Self {
+        self.device_swaps = Some(device_swaps);
+        self
+    }
+
+    /// Takes the most recent replacement input device, if one arrived.
+    pub(crate) fn take_device_swap(&self) -> Option<InputSwap> {
+        self.device_swaps.as_ref()?.try_iter().last()
+    }
+
+    /// Checks if a replacement input device is waiting to be taken.
+    pub(crate) fn device_swap_pending(&self) -> bool {
+        self.device_swaps
+            .as_ref()
+            .is_some_and(|swaps| !swaps.is_empty())
+    }
+
+    /// Sets the receiver replacement effect chains arrive on.
+    pub fn with_effect_chains(mut self, effect_chains: Receiver<EffectChain>) -> Self {
+        self.effect_chains = Some(effect_chains);
+        self
+    }
+
+    /// Takes the most recent replacement effect chain, if one arrived.
+    pub(crate) fn take_effect_chain(&self) -> Option<EffectChain> {
+        self.effect_chains.as_ref()?.try_iter().last()
+    }
+
+    /// Sets the atomic the encoder's residual bits are followed from.
+    pub fn with_residual_bits(mut self, residual_bits: Arc<AtomicF32>) -> Self {
+        self.residual_bits = Some(residual_bits);
+        self
+    }
+
+    /// Gets the residual bits the encoder should use, if they are controlled.
+    pub(crate) fn residual_bits(&self) -> Option<f32> {
+        self.residual_bits.as_ref().map(|bits| bits.load(Relaxed))
+    }
+
+    /// Gets the linear gain most recently applied by automatic gain control.
+    ///
+    /// Stays at 1.0 when automatic gain control is disabled.
+    pub fn gain(&self) -> f32 {
+        self.gain_sender.load(Relaxed)
+    }
+
     /// Gets the current input volume multiplier.
     pub(crate) fn input_volume(&self) -> f32 {
         self.input_volume.load(Relaxed)
@@ -81,6 +187,11 @@ impl InputProcessorState {
         self.rms_sender.fetch_max(rms, Relaxed);
     }
 
+    /// Publishes the gain applied by automatic gain control.
+    pub(crate) fn send_gain(&self, gain: f32) {
+        self.gain_sender.store(gain, Relaxed);
+    }
+
     /// Returns a reference to the buffer pool.
     pub(crate) fn buffer_pool(&self) -> &Arc<BufferPool> {
         &self.buffer_pool
@@ -94,7 +205,12 @@ impl Default for InputProcessorState {
             rms_threshold: Arc::new(AtomicF32::new(1.0)),
             muted: Default::default(),
             rms_sender: Default::default(),
+            gain_sender: Arc::new(AtomicF32::new(1.0)),
             buffer_pool: Default::default(),
+            channels: 1,
+            device_swaps: None,
+            effect_chains: None,
+            residual_bits: None,
         }
     }
 }
@@ -108,6 +224,12 @@ pub struct OutputProcessorState {
     pub(crate) rms_sender: Arc<AtomicF32>,
     pub(crate) deafened: Arc<AtomicBool>,
     pub(crate) loss_sender: Arc<AtomicUsize>,
+    pub(crate) channels: usize,
+    pub(crate) device_swaps: Option<Receiver<OutputSwap>>,
+    pub(crate) effect_chains: Option<Receiver<EffectChain>>,
+    pub(crate) drift_compensation: bool,
+    pub(crate) drift_sender: Arc<AtomicF32>,
+    pub(crate) queued_sender: Arc<AtomicUsize>,
 }
 
 impl OutputProcessorState {
@@ -130,9 +252,90 @@ impl OutputProcessorState {
             rms_sender,
             deafened: deafened.clone(),
             loss_sender,
+            channels: 1,
+            device_swaps: None,
+            effect_chains: None,
+            drift_compensation: false,
+            drift_sender: Arc::new(Default::default()),
+            queued_sender: Arc::new(Default::default()),
         }
     }
 
+    /// Sets the number of interleaved channels in every frame.
+    pub fn with_channels(mut self, channels: u16) -> Self {
+        self.channels = channels.max(1) as usize;
+        self
+    }
+
+    /// Gets the number of interleaved channels in every frame.
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Sets the receiver replacement output devices arrive on.
+    pub fn with_device_swaps(mut self, device_swaps: Receiver<OutputSwap>) -> Self {
+        self.device_swaps = Some(device_swaps);
+        self
+    }
+
+    /// Takes the most recent replacement output device, if one arrived.
+    pub(crate) fn take_device_swap(&self) -> Option<OutputSwap> {
+        self.device_swaps.as_ref()?.try_iter().last()
+    }
+
+    /// Sets the receiver replacement effect chains arrive on.
+    pub fn with_effect_chains(mut self, effect_chains: Receiver<EffectChain>) -> Self {
+        self.effect_chains = Some(effect_chains);
+        self
+    }
+
+    /// Takes the most recent replacement effect chain, if one arrived.
+    pub(crate) fn take_effect_chain(&self) -> Option<EffectChain> {
+        self.effect_chains.as_ref()?.try_iter().last()
+    }
+
+    /// Enables clock drift compensation.
+    ///
+    /// Only outputs that report their fill level through
+    /// [`AudioOutput::buffered`](crate::internal::traits::AudioOutput::buffered)
+    /// are compensated.
+    pub fn with_drift_compensation(mut self, enabled: bool) -> Self {
+        self.drift_compensation = enabled;
+        self
+    }
+
+    /// Checks if clock drift compensation is enabled.
+    pub(crate) fn drift_compensation(&self) -> bool {
+        self.drift_compensation
+    }
+
+    /// Replaces the atomic the drift compensation reports its correction to.
+    pub fn with_drift_sender(mut self, drift_sender: Arc<AtomicF32>) -> Self {
+        self.drift_sender = drift_sender;
+        self
+    }
+
+    /// Replaces the atomic the drift compensation reports the output latency to.
+    pub fn with_queued_sender(mut self, queued_sender: Arc<AtomicUsize>) -> Self {
+        self.queued_sender = queued_sender;
+        self
+    }
+
+    /// Gets the resampling ratio correction in parts per million.
+    ///
+    /// Positive values speed playback up to refill the output buffer, negative
+    /// values slow it down. Stays at 0.0 while drift compensation is inactive.
+    pub fn drift_ppm(&self) -> f32 {
+        self.drift_sender.load(Relaxed)
+    }
+
+    /// Gets the smoothed number of samples queued ahead of the output device.
+    ///
+    /// Stays at 0 while drift compensation is inactive.
+    pub fn queued(&self) -> usize {
+        self.queued_sender.load(Relaxed)
+    }
+
     /// Gets the current output volume multiplier.
     pub(crate) fn output_volume(&self) -> f32 {
         self.output_volume.load(Relaxed)
@@ -152,6 +355,13 @@ impl OutputProcessorState {
     pub(crate) fn send_loss(&self, loss: usize) {
         self.loss_sender.fetch_add(loss, Relaxed);
     }
+
+    /// Publishes the state of the drift compensation.
+    pub(crate) fn send_drift(&self, controller: &DriftController) {
+        self.drift_sender
+            .store(controller.correction_ppm(), Relaxed);
+        self.queued_sender.store(controller.queued(), Relaxed);
+    }
 }
 
 impl Default for OutputProcessorState {
@@ -161,6 +371,12 @@ impl Default for OutputProcessorState {
             rms_sender: Arc::new(Default::default()),
             deafened: Arc::new(Default::default()),
             loss_sender: Arc::new(Default::default()),
+            channels: 1,
+            device_swaps: None,
+            effect_chains: None,
+            drift_compensation: false,
+            drift_sender: Arc::new(Default::default()),
+            queued_sender: Arc::new(Default::default()),
         }
     }
 }
@@ -266,6 +482,33 @@ mod tests {
         approx_eq(rms_sender.load(Relaxed), 0.3);
     }
 
+    #[test]
+    fn input_state_send_gain_overwrites() {
+        let gain_sender = Arc::new(AtomicF32::new(1.0));
+        let state = InputProcessorState::default().with_gain_sender(gain_sender.clone());
+
+        approx_eq(state.gain(), 1.0);
+        state.send_gain(3.5);
+        state.send_gain(0.5);
+
+        approx_eq(gain_sender.load(Relaxed), 0.5);
+        approx_eq(state.gain(), 0.5);
+    }
+
+    #[test]
+    fn states_default_to_mono() {
+        assert_eq!(InputProcessorState::default().channels(), 1);
+        assert_eq!(OutputProcessorState::default().channels(), 1);
+        assert_eq!(
+            InputProcessorState::default().with_channels(2).channels(),
+            2
+        );
+        assert_eq!(
+            OutputProcessorState::default().with_channels(0).channels(),
+            1
+        );
+    }
+
     #[test]
     fn input_state_send_rms_updates_upward() {
         let input_volume = Arc::new(AtomicF32::new(1.0));
@@ -286,6 +529,25 @@ mod tests {
         approx_eq(rms_sender.load(Relaxed), 0.9);
     }
 
+    #[test]
+    fn states_take_the_latest_effect_chain() {
+        let (sender, receiver) = crossbeam::channel::unbounded();
+        let state = InputProcessorState::default().with_effect_chains(receiver);
+        assert!(state.take_effect_chain().is_none());
+
+        sender.send(EffectChain::new()).unwrap();
+        sender
+            .send(EffectChain::new().with(crate::internal::effects::HighPass::default()))
+            .unwrap();
+        assert_eq!(state.take_effect_chain().map(|chain| chain.len()), Some(1));
+        assert!(state.take_effect_chain().is_none());
+        assert!(
+            OutputProcessorState::default()
+                .take_effect_chain()
+                .is_none()
+        );
+    }
+
     #[test]
     fn output_state_default_volume_is_one() {
         let state = OutputProcessorState::default();
@@ -351,4 +613,28 @@ mod tests {
 
         assert_eq!(loss_sender.load(Relaxed), 6);
     }
+
+    #[test]
+    fn output_state_send_drift_publishes_the_controller() {
+        let drift_sender = Arc::new(AtomicF32::new(0.0));
+        let queued_sender = Arc::new(AtomicUsize::new(0));
+        let state = OutputProcessorState::default()
+            .with_drift_sender(drift_sender.clone())
+            .with_queued_sender(queued_sender.clone());
+        assert!(!state.drift_compensation());
+
+        let mut controller = DriftController::new(480);
+        for _ in 0..crate::internal::drift::SETTLE_FRAMES {
+            controller.update(960);
+        }
+        for _ in 0..20 {
+            controller.update(1_920);
+        }
+        state.send_drift(&controller);
+
+        assert!(state.drift_ppm() < 0.0);
+        approx_eq(drift_sender.load(Relaxed), controller.correction_ppm());
+        assert_eq!(state.queued(), controller.queued());
+        assert_eq!(queued_sender.load(Relaxed), controller.queued());
+    }
 }
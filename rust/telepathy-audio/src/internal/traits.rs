@@ -53,6 +53,14 @@ pub trait AudioOutput {
     /// Writes as many samples as it can.
     /// Returns how many samples were dropped (loss).
     fn write_samples(&mut self, samples: &[f32]) -> Result<usize, Error>;
+
+    /// Returns how many written samples are still queued ahead of the device.
+    ///
+    /// Outputs that can report their fill level get clock drift compensation;
+    /// the default `None` plays at the nominal resampling ratio.
+    fn buffered(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Lock-free ring buffer audio input for native platforms.
@@ -150,6 +158,8 @@ impl AudioInput for RingBufferInput {
 pub struct RingBufferOutput {
     /// The producer end of the ring buffer for audio samples.
     producer: rtrb::Producer<f32>,
+    /// Number of interleaved channels; partial writes keep whole frames.
+    channels: usize,
 }
 
 impl RingBufferOutput {
@@ -159,22 +169,35 @@ impl RingBufferOutput {
     ///
     /// * `producer` - The producer end of an `rtrb::RingBuffer<f32>`
     pub fn new(producer: rtrb::Producer<f32>) -> Self {
-        Self { producer }
+        Self {
+            producer,
+            channels: 1,
+        }
+    }
+
+    /// Sets the number of interleaved channels in the written samples.
+    ///
+    /// Partial writes are rounded down to whole frames so the consumer never
+    /// sees the channels shift.
+    pub fn with_channels(mut self, channels: u16) -> Self {
+        self.channels = channels.max(1) as usize;
+        self
     }
 }
 
 impl AudioOutput for RingBufferOutput {
     fn is_full(&self) -> bool {
-        self.producer.slots() == 0
+        self.producer.slots() < self.channels
     }
 
     fn write_samples(&mut self, samples: &[f32]) -> Result<usize, Error> {
         let available = self.producer.slots();
-        if available == 0 {
+        let to_write = samples.len().min(available);
+        let to_write = to_write - to_write % self.channels;
+        if to_write == 0 {
             return Ok(samples.len());
         }
 
-        let to_write = samples.len().min(available);
         match self.producer.write_chunk_uninit(to_write) {
             Ok(chunk) => {
                 chunk.fill_from_iter(samples[..to_write].iter().copied());
@@ -183,6 +206,10 @@ impl AudioOutput for RingBufferOutput {
             Err(_) => Ok(samples.len()),
         }
     }
+
+    fn buffered(&self) -> Option<usize> {
+        Some(self.producer.buffer().capacity() - self.producer.slots())
+    }
 }
 
 #[cfg(all(test, not(target_family = "wasm")))]
@@ -241,6 +268,31 @@ mod tests {
         assert_eq!(loss, 3);
     }
 
+    #[test]
+    fn ring_buffer_output_partial_write_keeps_whole_frames() {
+        let (producer, _consumer) = RingBuffer::<f32>::new(9);
+        let mut output = RingBufferOutput::new(producer).with_channels(2);
+        output.write_samples(&[0.1; 4]).unwrap();
+
+        let loss = output.write_samples(&[0.2; 8]).unwrap();
+        assert_eq!(loss, 4);
+        // one slot is left but it cannot hold a whole frame
+        assert!(output.is_full());
+    }
+
+    #[test]
+    fn ring_buffer_output_reports_queued_samples() {
+        let (producer, mut consumer) = RingBuffer::<f32>::new(960);
+        let mut output = RingBufferOutput::new(producer);
+        assert_eq!(output.buffered(), Some(0));
+
+        output.write_samples(&[0.0; 480]).unwrap();
+        assert_eq!(output.buffered(), Some(480));
+
+        consumer.read_chunk(80).unwrap().commit_all();
+        assert_eq!(output.buffered(), Some(400));
+    }
+
     #[test]
     fn ring_buffer_output_written_samples_readable_by_consumer() {
         let (producer, mut consumer) = RingBuffer::<f32>::new(8);
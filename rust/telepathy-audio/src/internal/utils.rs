@@ -26,7 +26,8 @@
 //! This is necessary because cpal streams are not inherently `Send`.
 
 use crate::error::{Error, ProcessingError};
-use rubato::{Fft, FixedSync};
+use nnnoiseless::FRAME_SIZE;
+use rubato::{Fft, FixedSync, Resampler};
 
 /// Converts a decibel value to a linear multiplier.
 ///
@@ -106,6 +107,22 @@ pub fn resampler_factory(
     }
 }
 
+/// The maximum number of samples the input resampler consumes at once
+pub(crate) fn max_input_samples(resampler: &Option<Fft<f32>>, channels: usize) -> usize {
+    resampler
+        .as_ref()
+        .map(|r| r.input_frames_max() * channels)
+        .unwrap_or(FRAME_SIZE)
+}
+
+/// The maximum number of samples the output resampler produces at once
+pub(crate) fn max_output_samples(resampler: &Option<Fft<f32>>, channels: usize) -> usize {
+    resampler
+        .as_ref()
+        .map(|r| r.output_frames_max() * channels)
+        .unwrap_or(FRAME_SIZE)
+}
+
 #[inline]
 pub(crate) fn hann_fade_in(i: usize, len: usize) -> f32 {
     // t in (0, 1]
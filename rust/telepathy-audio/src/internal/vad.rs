@@ -0,0 +1,195 @@
+//! Voice activity gate.
+//!
+//! The input processor can gate frames on voice activity instead of the raw
+//! RMS threshold. A [`VoiceGate`] uses the speech probability RNNoise
+//! computes, so keyboard clacks and other loud transients stay out while soft
+//! speech gets through.
+//!
+//! ## Probability Source
+//!
+//! - When the input is denoised, the probability returned by the denoiser is
+//!   reused and no extra work is done.
+//! - Otherwise the gate runs a dedicated RNNoise instance only for its
+//!   detector, discarding the denoised output. Multichannel frames are
+//!   downmixed first and the detector runs once a full window of
+//!   [`FRAME_SIZE`] mono samples has accumulated, so the probability updates
+//!   every `channels` frames.
+//!
+//! Both sources expect 48 kHz audio scaled to the `i16` range.
+//!
+//! ## Hangover
+//!
+//! After the probability drops below the threshold, the gate stays open for
+//! [`VoiceGateConfig::hangover_frames`] more frames so word endings and short
+//! pauses are not clipped. This replaces the fixed
+//! [`MINIMUM_SILENCE_LENGTH`](crate::constants::MINIMUM_SILENCE_LENGTH) used
+//! by the RMS gate.
+
+use crate::constants::FRAME_SIZE;
+use crate::internal::processing::downmix_to_mono;
+use nnnoiseless::DenoiseState;
+
+/// Default number of frames the gate stays open after speech ends.
+///
+/// At 48 kHz each frame is 10 ms, so 20 frames hold the gate for 200 ms.
+pub const DEFAULT_HANGOVER_FRAMES: u16 = 20;
+
+/// Configuration for the voice activity gate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VoiceGateConfig {
+    /// Speech probability (0.0 to 1.0) at or above which a frame is voice.
+    pub threshold: f32,
+    /// Frames still sent after the probability drops below the threshold.
+    pub hangover_frames: u16,
+}
+
+impl Default for VoiceGateConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.6,
+            hangover_frames: DEFAULT_HANGOVER_FRAMES,
+        }
+    }
+}
+
+/// Gates input frames on RNNoise voice activity with a configurable hangover.
+pub struct VoiceGate {
+    config: VoiceGateConfig,
+    channels: usize,
+    /// Dedicated detector; created on first use without a denoiser.
+    detector: Option<Box<DenoiseState<'static>>>,
+    /// Mono samples waiting for the dedicated detector.
+    window: [f32; FRAME_SIZE],
+    filled: usize,
+    /// Discarded denoiser output of the dedicated detector.
+    scratch: [f32; FRAME_SIZE],
+    /// Most recent speech probability.
+    probability: f32,
+    /// Consecutive frames below the threshold.
+    silence_length: u16,
+}
+
+impl VoiceGate {
+    /// Creates a gate for frames of `channels` interleaved channels.
+    pub fn new(config: VoiceGateConfig, channels: usize) -> Self {
+        Self {
+            config,
+            channels: channels.max(1),
+            detector: None,
+            window: [0_f32; FRAME_SIZE],
+            filled: 0,
+            scratch: [0_f32; FRAME_SIZE],
+            probability: 0.0,
+            silence_length: 0,
+        }
+    }
+
+    /// Updates the gate with one frame and returns whether to send it.
+    ///
+    /// `probability` is the speech probability returned by the denoiser for
+    /// this frame; pass `None` to run the dedicated detector on `frame`.
+    pub fn process(&mut self, frame: &[f32], probability: Option<f32>) -> bool {
+        match probability {
+            Some(probability) => self.probability = probability,
+            None => self.detect(frame),
+        }
+
+        if self.probability >= self.config.threshold {
+            self.silence_length = 0;
+            true
+        } else if self.silence_length < self.config.hangover_frames {
+            self.silence_length += 1; // the hangover keeps word endings
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The most recent speech probability.
+    pub fn probability(&self) -> f32 {
+        self.probability
+    }
+
+    /// Runs the dedicated detector once a full mono window is available.
+    fn detect(&mut self, frame: &[f32]) {
+        let samples = (frame.len() / self.channels).min(FRAME_SIZE - self.filled);
+        downmix_to_mono(
+            frame,
+            self.channels,
+            &mut self.window[self.filled..self.filled + samples],
+        );
+        self.filled += samples;
+
+        if self.filled == FRAME_SIZE {
+            self.filled = 0;
+            let detector = self.detector.get_or_insert_with(DenoiseState::new);
+            self.probability = detector.process_frame(&mut self.scratch, &self.window);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gate(hangover_frames: u16) -> VoiceGate {
+        let config = VoiceGateConfig {
+            threshold: 0.5,
+            hangover_frames,
+        };
+        VoiceGate::new(config, 1)
+    }
+
+    #[test]
+    fn hangover_holds_gate_open_after_speech() {
+        let mut gate = gate(3);
+        let frame = [0_f32; FRAME_SIZE];
+
+        assert!(gate.process(&frame, Some(0.9)));
+        let sent: Vec<bool> = (0..5).map(|_| gate.process(&frame, Some(0.1))).collect();
+        assert_eq!(sent, [true, true, true, false, false]);
+        assert!(gate.process(&frame, Some(0.9)));
+    }
+
+    #[test]
+    fn short_dips_below_threshold_reset_the_hangover() {
+        let mut gate = gate(2);
+        let frame = [0_f32; FRAME_SIZE];
+
+        for probability in [0.1, 0.1, 0.9, 0.1, 0.1] {
+            assert!(gate.process(&frame, Some(probability)));
+        }
+        assert!(!gate.process(&frame, Some(0.1)));
+    }
+
+    #[test]
+    fn zero_hangover_closes_immediately() {
+        let mut gate = gate(0);
+        let frame = [0_f32; FRAME_SIZE];
+
+        assert!(gate.process(&frame, Some(0.5)));
+        assert!(!gate.process(&frame, Some(0.49)));
+    }
+
+    #[test]
+    fn dedicated_detector_closes_on_digital_silence() {
+        let mut gate = gate(2);
+        let frame = [0_f32; FRAME_SIZE];
+
+        let sent: Vec<bool> = (0..4).map(|_| gate.process(&frame, None)).collect();
+        assert_eq!(sent, [true, true, false, false]);
+        assert_eq!(gate.probability(), 0.0);
+    }
+
+    #[test]
+    fn dedicated_detector_waits_for_a_full_window_with_stereo() {
+        let mut gate = VoiceGate::new(VoiceGateConfig::default(), 2);
+        let frame = [0_f32; FRAME_SIZE];
+
+        gate.process(&frame, None);
+        assert!(gate.detector.is_none());
+        gate.process(&frame, None);
+        assert!(gate.detector.is_some());
+        assert_eq!(gate.filled, 0);
+    }
+}
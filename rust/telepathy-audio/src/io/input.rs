@@ -62,50 +62,73 @@
 //!     .unwrap();
 //! ```
 
+use crate::codec::{self, AudioEncoder, EncoderFactory, RawEncoder};
+use crate::constants::FRAME_SIZE;
 use crate::devices::AudioHost;
-use crate::error::{ConfigError, Error};
+use crate::error::{ChannelError, ConfigError, Error};
 use crate::internal::buffer_pool::{DEFAULT_POOL_CAPACITY, PooledBuffer};
+use crate::internal::echo::{DEFAULT_TAIL_FRAMES, EchoCanceller, EchoReference};
+use crate::internal::effects::{AudioEffect, EffectChain};
+use crate::internal::gain::{AutoGain, AutoGainConfig};
 use crate::internal::processor::input_processor;
 use crate::internal::state::InputProcessorState;
+#[cfg(not(target_family = "wasm"))]
+use crate::internal::state::{DeviceSwap, InputSwap};
 use crate::internal::thread::{self, JoinHandle};
 use crate::internal::traits::AudioInput;
+use crate::internal::vad::{VoiceGate, VoiceGateConfig};
 use crate::io::StreamErrorCallback;
 use crate::io::traits::AudioDataSink;
 #[cfg(target_family = "wasm")]
 use crate::platform::web_audio::WebAudioWrapper;
+use crate::recorder::RecordingTap;
 use crate::sea::encoder::{EncoderSettings, SeaEncoder};
 use atomic_float::AtomicF32;
+use crossbeam::channel::{Sender, unbounded};
 use nnnoiseless::{DenoiseState, RnnModel};
 use std::sync::Arc;
 #[cfg(not(target_family = "wasm"))]
 use std::sync::Condvar;
 use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
 
 /// Configuration for an audio input stream. Prefer [`AudioInputBuilder`].
 ///
 /// When `denoise_enabled` is `true` the processor upsamples to 48 kHz for
 /// RNNoise and outputs 48 kHz; otherwise it passes through at the device's
 /// native rate. The encoder sample rate always matches the processor's output.
+///
+/// Noise suppression and echo cancellation only support mono; they are
+/// skipped when `channels` is greater than one. The voice gate also runs at
+/// 48 kHz, downmixing multichannel input for its detector.
 pub struct AudioInputConfig {
     /// Input device ID; `None` selects the system default.
     pub device_id: Option<String>,
+    /// Number of interleaved channels captured and encoded. Defaults to 1.
+    pub channels: u16,
     /// Optional custom RNNoise model. `None` uses the default model.
     pub denoise_model: Option<RnnModel>,
+    /// Far-end reference for echo cancellation. `None` disables it.
+    pub echo_reference: Option<EchoReference>,
+    /// Automatic gain control settings. `None` applies `volume` directly.
+    pub auto_gain: Option<AutoGainConfig>,
+    /// Voice activity gate settings. `None` gates on `rms_threshold`.
+    pub voice_gate: Option<VoiceGateConfig>,
+    /// Recording tap and track name that receive every processed frame.
+    pub recording: Option<(RecordingTap, String)>,
+    /// Effects run on every processed frame, in order.
+    pub effects: EffectChain,
     /// Input gain. 1.0 = unity; values < 1.0 attenuate, > 1.0 amplify.
     pub volume: f32,
     /// RMS threshold for silence detection. 0.0 disables it.
     pub rms_threshold: f32,
-    /// When true, the SEA codec encodes the processor output.
-    pub codec_enabled: bool,
-    /// Codec bitrate mode.
-    pub codec_mode: CodecBitrateMode,
-    /// Codec residual bits (typically 2.0–8.0). Higher = better, larger.
-    pub codec_residual_bits: f32,
+    /// Creates the encoder for the processor output. `None` sends raw PCM.
+    pub encoder: Option<EncoderFactory>,
     /// Stream-error callback; `None` falls back to a default log path.
     pub error_callback: Option<StreamErrorCallback>,
-    /// Output rate override (only when denoise is disabled). When denoise is
-    /// enabled, output is always 48 kHz and this field is ignored.
+    /// Output rate override (only when denoise is disabled). When denoise or
+    /// the voice gate is enabled, output is always 48 kHz and this field is
+    /// ignored.
     pub output_sample_rate: Option<u32>,
 }
 
@@ -113,12 +136,16 @@ impl Default for AudioInputConfig {
     fn default() -> Self {
         Self {
             device_id: None,
+            channels: 1,
             denoise_model: None,
+            echo_reference: None,
+            auto_gain: None,
+            voice_gate: None,
+            recording: None,
+            effects: EffectChain::new(),
             volume: 1.0,
             rms_threshold: 0.0,
-            codec_enabled: false,
-            codec_mode: CodecBitrateMode::Cbr,
-            codec_residual_bits: 5.0,
+            encoder: None,
             error_callback: None,
             output_sample_rate: None,
         }
@@ -143,6 +170,10 @@ where
     shared_muted: Option<Arc<AtomicBool>>,
     /// Optional shared atomic for rms state (enables real-time synchronization)
     shared_rms: Option<Arc<AtomicF32>>,
+    /// Optional shared atomic for the automatic gain (enables real-time monitoring)
+    shared_gain: Option<Arc<AtomicF32>>,
+    /// Optional shared atomic for the encoder's residual bits (enables bitrate control)
+    shared_residual_bits: Option<Arc<AtomicF32>>,
     /// Pre-initialized WebAudioWrapper for WASM audio input
     #[cfg(target_family = "wasm")]
     web_audio_wrapper: Option<WebAudioWrapper>,
@@ -153,7 +184,11 @@ struct InputBuildContext {
     input_volume: Arc<AtomicF32>,
     rms_threshold: Arc<AtomicF32>,
     muted: Arc<AtomicBool>,
+    gain: Arc<AtomicF32>,
     processor_handle: JoinHandle<()>,
+    #[cfg(not(target_family = "wasm"))]
+    device_swaps: Sender<InputSwap>,
+    effect_chains: Sender<EffectChain>,
 }
 
 impl AudioInputBuilder<Box<dyn Fn(PooledBuffer) + Send + 'static>> {
@@ -166,6 +201,8 @@ impl AudioInputBuilder<Box<dyn Fn(PooledBuffer) + Send + 'static>> {
             shared_rms_threshold: None,
             shared_muted: None,
             shared_rms: None,
+            shared_gain: None,
+            shared_residual_bits: None,
             #[cfg(target_family = "wasm")]
             web_audio_wrapper: None,
         }
@@ -190,6 +227,17 @@ where
         self
     }
 
+    /// Sets the number of interleaved channels to capture.
+    ///
+    /// Defaults to mono. Device channels are mapped round robin, so a mono
+    /// microphone fills every channel. The count must split a frame into
+    /// whole blocks (see [`supports_channels`](codec::supports_channels)); noise suppression and echo
+    /// cancellation are skipped for multichannel input.
+    pub fn channels(mut self, channels: u16) -> Self {
+        self.config.channels = channels;
+        self
+    }
+
     /// Enables denoising.
     ///
     /// # Arguments
@@ -201,6 +249,68 @@ where
         self
     }
 
+    /// Enables acoustic echo cancellation.
+    ///
+    /// The canceller subtracts whatever the output streams attached to the same
+    /// reference (see [`AudioOutputBuilder::echo_reference`]) are playing.
+    /// Cancellation runs before denoising and volume adjustment.
+    ///
+    /// [`AudioOutputBuilder::echo_reference`]: crate::io::AudioOutputBuilder::echo_reference
+    pub fn echo_cancellation(mut self, reference: &EchoReference) -> Self {
+        self.config.echo_reference = Some(reference.clone());
+        self
+    }
+
+    /// Enables automatic gain control.
+    ///
+    /// The controller tracks the speech level after denoising and applies a
+    /// smoothed gain toward the configured target, followed by a peak limiter.
+    /// The input volume (see [`volume`](Self::volume) and
+    /// [`input_volume_shared`](Self::input_volume_shared)) becomes the gain
+    /// ceiling instead of a fixed multiplier.
+    pub fn auto_gain(mut self, config: AutoGainConfig) -> Self {
+        self.config.auto_gain = Some(config);
+        self
+    }
+
+    /// Gates frames on voice activity instead of the RMS threshold.
+    ///
+    /// Frames are sent while the RNNoise speech probability is at or above the
+    /// configured threshold, plus a hangover of quieter frames after speech
+    /// ends. The denoiser's probability is reused when
+    /// [`denoise`](Self::denoise) is enabled; otherwise a dedicated detector
+    /// runs. Either way the output rate becomes 48 kHz.
+    pub fn voice_gate(mut self, config: VoiceGateConfig) -> Self {
+        self.config.voice_gate = Some(config);
+        self
+    }
+
+    /// Taps every processed frame into a recording as the track `name`.
+    ///
+    /// Frames are recorded after denoising, gain control and effects but
+    /// before silence or voice gating, and only while a
+    /// [`Recorder`](crate::recorder::Recorder) runs on `tap`.
+    pub fn recording(mut self, tap: &RecordingTap, name: impl Into<String>) -> Self {
+        self.config.recording = Some((tap.clone(), name.into()));
+        self
+    }
+
+    /// Appends an effect to the end of the effect chain.
+    ///
+    /// Effects run in the order they were added, after denoising and gain
+    /// control, at the processor's output rate. See
+    /// [`effects`](mod@crate::internal::effects) for the built-in effects.
+    pub fn effect(mut self, effect: impl AudioEffect + 'static) -> Self {
+        self.config.effects.push(effect);
+        self
+    }
+
+    /// Replaces the effect chain.
+    pub fn effects(mut self, effects: EffectChain) -> Self {
+        self.config.effects = effects;
+        self
+    }
+
     /// Sets the input volume multiplier.
     ///
     /// * 1.0 = unity gain (default)
@@ -214,7 +324,8 @@ where
     /// Sets the RMS threshold for silence detection.
     ///
     /// Audio below this threshold will be treated as silence.
-    /// A value of 0.0 disables silence detection.
+    /// A value of 0.0 disables silence detection. Ignored when the
+    /// [`voice_gate`](Self::voice_gate) is enabled.
     pub fn rms_threshold(mut self, threshold: f32) -> Self {
         self.config.rms_threshold = threshold;
         self
@@ -275,18 +386,64 @@ where
         self
     }
 
-    /// Configures codec encoding.
+    /// Sets a shared atomic for the automatic gain, enabling real-time monitoring.
+    ///
+    /// When provided, the processor writes the linear gain applied by automatic
+    /// gain control into this atomic after every frame. It stays at 1.0 when
+    /// [`auto_gain`](Self::auto_gain) is not enabled.
+    pub fn gain_shared(mut self, shared: &Arc<AtomicF32>) -> Self {
+        self.shared_gain = Some(shared.clone());
+        self
+    }
+
+    /// Sets a shared atomic for the encoder's residual bits, enabling bitrate
+    /// control while running.
+    ///
+    /// The processor hands every new value to
+    /// [`AudioEncoder::set_residual_bits`] before the next frame. Frames keep
+    /// decoding with the same decoder, so the sender can follow the network
+    /// without renegotiating. Codecs other than SEA ignore the value.
+    pub fn residual_bits_shared(mut self, shared: &Arc<AtomicF32>) -> Self {
+        self.shared_residual_bits = Some(shared.clone());
+        self
+    }
+
+    /// Configures SEA codec encoding.
     ///
     /// # Arguments
     ///
     /// * `mode` - Whether to use constant bit rate (CBR) or variable bit rate (VBR)
     /// * `residual_bits` - Quality setting for residual encoding
     ///
-    /// Calling this method always enables codec encoding.
-    pub fn codec(mut self, mode: CodecBitrateMode, residual_bits: f32) -> Self {
-        self.config.codec_enabled = true;
-        self.config.codec_mode = mode;
-        self.config.codec_residual_bits = residual_bits;
+    /// Shorthand for [`encoder`](Self::encoder) with a [`SeaEncoder`].
+    pub fn codec(self, mode: CodecBitrateMode, residual_bits: f32) -> Self {
+        let settings = EncoderSettings {
+            residual_bits,
+            vbr: matches!(mode, CodecBitrateMode::Vbr),
+            ..Default::default()
+        };
+        self.encoder(move |sample_rate, channels| {
+            let settings = EncoderSettings {
+                frames_per_chunk: FRAME_SIZE as u16 / channels,
+                ..settings
+            };
+            Ok(SeaEncoder::new(channels as u8, sample_rate, settings)?)
+        })
+    }
+
+    /// Sets the encoder applied to every processed frame.
+    ///
+    /// `factory` is called once at build time with the processor's output
+    /// sample rate and channel count. Without an encoder, frames are sent as
+    /// raw PCM.
+    pub fn encoder<E, F>(mut self, factory: F) -> Self
+    where
+        E: AudioEncoder + 'static,
+        F: FnOnce(u32, u16) -> Result<E, Error> + Send + 'static,
+    {
+        self.config.encoder = Some(Box::new(move |sample_rate, channels| {
+            factory(sample_rate, channels).map(|encoder| Box::new(encoder) as Box<dyn AudioEncoder>)
+        }));
         self
     }
 
@@ -372,6 +529,8 @@ where
             shared_rms_threshold: self.shared_rms_threshold,
             shared_muted: self.shared_muted,
             shared_rms: self.shared_rms,
+            shared_gain: self.shared_gain,
+            shared_residual_bits: self.shared_residual_bits,
             #[cfg(target_family = "wasm")]
             web_audio_wrapper: self.web_audio_wrapper,
         }
@@ -386,6 +545,8 @@ where
             shared_rms_threshold: self.shared_rms_threshold,
             shared_muted: self.shared_muted,
             shared_rms: self.shared_rms,
+            shared_gain: self.shared_gain,
+            shared_residual_bits: self.shared_residual_bits,
             #[cfg(target_family = "wasm")]
             web_audio_wrapper: self.web_audio_wrapper,
         }
@@ -394,15 +555,21 @@ where
     /// Common initialization logic shared between native and WASM builds.
     ///
     /// This method handles all shared setup steps:
-    /// - Resolves shared atomics (input_volume, rms_threshold, muted, rms_sender),
+    /// - Resolves shared atomics (input_volume, rms_threshold, muted, rms_sender, gain),
+    ///   and passes on the shared residual bits if any,
     ///   using caller-supplied `Arc`s when provided or creating new ones otherwise
     /// - Calculates the output sample rate with the following precedence:
-    ///   1. **Denoise enabled**: Always 48 kHz (RNNoise requirement)
+    ///   1. **Denoise or voice gate enabled**: Always 48 kHz (RNNoise requirement)
     ///   2. **Custom `output_sample_rate`**: Uses the specified rate
     ///   3. **Neither**: Passes through at the device's native rate (`input_rate`)
-    /// - Creates a [`DenoiseState`] if a denoise model was configured
+    /// - Creates a [`DenoiseState`] if a denoise model was configured and the
+    ///   input is mono
+    /// - Creates an [`EchoCanceller`] at the output rate if echo cancellation is
+    ///   enabled and the input is mono
+    /// - Creates an [`AutoGain`] stage if automatic gain control is enabled
+    /// - Creates a [`VoiceGate`] if voice activity gating is enabled
     /// - Creates an [`InputProcessorState`] for atomic state management
-    /// - Creates a [`SeaEncoder`] if codec encoding is enabled (sample rate matches output rate)
+    /// - Creates the configured encoder at the output rate, or a [`RawEncoder`]
     /// - Spawns the processor thread via [`input_processor`]
     ///
     /// # Type Parameters
@@ -434,36 +601,72 @@ where
             .shared_muted
             .unwrap_or_else(|| Arc::new(AtomicBool::new(false)));
         let rms_sender = self.shared_rms.unwrap_or_default();
+        #[cfg_attr(target_family = "wasm", allow(unused_variables))]
+        let (device_swaps, swap_receiver) = unbounded();
+        let (effect_chains, chain_receiver) = unbounded();
+        // the processor installs the initial chain like any replacement
+        let _ = effect_chains.send(self.config.effects);
+        let gain = self
+            .shared_gain
+            .unwrap_or_else(|| Arc::new(AtomicF32::new(1.0)));
         let sink = self
             .sink
             .ok_or(Error::Config(ConfigError::MissingDataSink))?;
 
+        let channels = self.config.channels;
+        let mono = channels == 1;
+        if !mono && (self.config.denoise_model.is_some() || self.config.echo_reference.is_some()) {
+            warn!(
+                channels,
+                "denoise and echo cancellation are skipped for multichannel input"
+            );
+        }
+
+        // the output rate stays at 48 kHz whenever rnnoise is used
+        let output_rate = if self.config.denoise_model.is_some() || self.config.voice_gate.is_some()
+        {
+            48_000
+        } else {
+            self.config.output_sample_rate.unwrap_or(input_rate)
+        };
         // create denoiser if needed
-        let denoiser = self.config.denoise_model.map(DenoiseState::from_model);
-        let state = InputProcessorState::new(
+        let denoiser = self
+            .config
+            .denoise_model
+            .filter(|_| mono)
+            .map(DenoiseState::from_model);
+        let mut state = InputProcessorState::new(
             &input_volume,
             &rms_threshold,
             &muted,
             rms_sender,
             DEFAULT_POOL_CAPACITY,
-        );
-        let output_rate = if denoiser.is_some() {
-            48_000
-        } else {
-            self.config.output_sample_rate.unwrap_or(input_rate)
-        };
-        let encoder = if self.config.codec_enabled {
-            Some(SeaEncoder::new(
-                1,
-                output_rate,
-                EncoderSettings {
-                    residual_bits: self.config.codec_residual_bits,
-                    vbr: matches!(self.config.codec_mode, CodecBitrateMode::Vbr),
-                    ..Default::default()
-                },
-            )?)
-        } else {
-            None
+        )
+        .with_gain_sender(gain.clone())
+        .with_channels(channels)
+        .with_device_swaps(swap_receiver)
+        .with_effect_chains(chain_receiver);
+        if let Some(residual_bits) = self.shared_residual_bits {
+            state = state.with_residual_bits(residual_bits);
+        }
+        let echo_canceller = self
+            .config
+            .echo_reference
+            .as_ref()
+            .filter(|_| mono)
+            .map(|reference| EchoCanceller::new(reference, output_rate, DEFAULT_TAIL_FRAMES));
+        let auto_gain = self.config.auto_gain.map(AutoGain::new);
+        let voice_gate = self
+            .config
+            .voice_gate
+            .map(|config| VoiceGate::new(config, channels as usize));
+        let recording = self
+            .config
+            .recording
+            .map(|(tap, name)| tap.track(name, output_rate, channels as usize));
+        let encoder: Box<dyn AudioEncoder> = match self.config.encoder {
+            Some(factory) => factory(output_rate, channels)?,
+            None => Box::new(RawEncoder),
         };
 
         // spawn processor thread
@@ -474,6 +677,10 @@ where
                 input_rate as usize,
                 output_rate as usize,
                 denoiser,
+                echo_canceller,
+                auto_gain,
+                voice_gate,
+                recording,
                 state,
                 encoder,
             ) {
@@ -486,7 +693,11 @@ where
             input_volume,
             rms_threshold,
             muted,
+            gain,
             processor_handle,
+            #[cfg(not(target_family = "wasm"))]
+            device_swaps,
+            effect_chains,
         })
     }
 
@@ -503,6 +714,7 @@ where
     ///
     /// Returns an error if:
     /// - No sink was set via [`callback`](Self::callback) or [`sink`](Self::sink)
+    /// - The channel count is not supported (see [`channels`](Self::channels))
     /// - The host fails to open the input device (see [`AudioHost::open_input`])
     /// - Codec initialization fails (invalid encoder settings)
     /// - The processor thread cannot be spawned
@@ -516,12 +728,17 @@ where
     {
         if self.sink.is_none() {
             return Err(Error::Config(ConfigError::MissingDataSink));
+        } else if !codec::supports_channels(self.config.channels) {
+            return Err(Error::Config(ConfigError::UnsupportedChannels(
+                self.config.channels,
+            )));
         }
 
         // Open the input
         let error_callback = self.config.error_callback.take();
+        let channels = self.config.channels;
         let (processor_input, input_rate, stream) =
-            host.open_input(self.config.device_id.as_deref(), error_callback)?;
+            host.open_input(self.config.device_id.as_deref(), channels, error_callback)?;
         // Build common components (channels, threads, state)
         let context = self.build_common(processor_input, input_rate)?;
 
@@ -531,6 +748,10 @@ where
             input_volume: context.input_volume,
             rms_threshold: context.rms_threshold,
             muted: context.muted,
+            gain: context.gain,
+            channels,
+            device_swaps: context.device_swaps,
+            effect_chains: context.effect_chains,
         })
     }
 
@@ -549,6 +770,7 @@ where
     /// Returns an error if:
     /// - No sink was set via [`callback`](Self::callback) or [`sink`](Self::sink)
     /// - No [`WebAudioWrapper`] was provided via [`web_audio_wrapper`](Self::web_audio_wrapper)
+    /// - More than one channel was requested; browser capture is mono
     /// - Codec initialization fails
     /// - The processor thread cannot be spawned
     ///
@@ -574,6 +796,10 @@ where
     ) -> Result<AudioInputHandle<I>, Error> {
         if self.sink.is_none() {
             return Err(Error::Config(ConfigError::MissingDataSink));
+        } else if self.config.channels != 1 {
+            return Err(Error::Config(ConfigError::UnsupportedChannels(
+                self.config.channels,
+            )));
         }
 
         let Some(mut web_audio) = self.web_audio_wrapper.take() else {
@@ -596,6 +822,8 @@ where
             input_volume: context.input_volume,
             rms_threshold: context.rms_threshold,
             muted: context.muted,
+            gain: context.gain,
+            effect_chains: context.effect_chains,
         })
     }
 }
@@ -613,6 +841,12 @@ pub struct AudioInputHandle<S> {
     input_volume: Arc<AtomicF32>,
     rms_threshold: Arc<AtomicF32>,
     muted: Arc<AtomicBool>,
+    gain: Arc<AtomicF32>,
+    #[cfg(not(target_family = "wasm"))]
+    channels: u16,
+    #[cfg(not(target_family = "wasm"))]
+    device_swaps: Sender<InputSwap>,
+    effect_chains: Sender<EffectChain>,
 }
 
 impl<S> AudioInputHandle<S> {
@@ -652,6 +886,57 @@ impl<S> AudioInputHandle<S> {
     pub fn rms_threshold(&self) -> f32 {
         self.rms_threshold.load(Relaxed)
     }
+
+    /// Gets the linear gain most recently applied by automatic gain control.
+    pub fn gain(&self) -> f32 {
+        self.gain.load(Relaxed)
+    }
+
+    /// Replaces the effect chain of the running input.
+    ///
+    /// The processor prepares the new chain and switches to it at the next
+    /// frame; pass an empty chain to remove every effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the processor has stopped.
+    pub fn set_effects(&self, effects: EffectChain) -> Result<(), Error> {
+        self.effect_chains
+            .send(effects)
+            .map_err(|_| Error::Channel(ChannelError::ProcessorStopped))
+    }
+
+    /// Moves the running input to another device.
+    ///
+    /// The device is opened with the same channel count and the processor
+    /// continues on it, resampling from its sample rate. The stream of the
+    /// previous device is closed once the new device is handed over, and
+    /// `error_callback` takes over stream error reporting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The host fails to open the device, the previous device keeps running
+    /// - The processor has stopped
+    #[cfg(not(target_family = "wasm"))]
+    pub fn switch_device(
+        &mut self,
+        host: &impl AudioHost<InputStream = S>,
+        device_id: Option<&str>,
+        error_callback: Option<StreamErrorCallback>,
+    ) -> Result<(), Error> {
+        let (input, sample_rate, stream) =
+            host.open_input(device_id, self.channels, error_callback)?;
+        self.device_swaps
+            .send(DeviceSwap {
+                device: Box::new(input),
+                sample_rate: sample_rate as usize,
+            })
+            .map_err(|_| Error::Channel(ChannelError::ProcessorStopped))?;
+        // closing the previous stream wakes a processor blocked on it
+        self._stream = Some(stream);
+        Ok(())
+    }
 }
 
 /// Codec bit rate mode for input encoding.
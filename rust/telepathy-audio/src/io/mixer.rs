@@ -0,0 +1,553 @@
+//! Multi-source audio output API.
+//!
+//! An [`AudioMixerHandle`] owns one output stream and plays any number of
+//! sources through it. Sources can be attached and detached while the stream
+//! runs, each with its own decoder, sample rate and volume, which suits rooms
+//! where peers come and go.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use telepathy_audio::devices::CpalAudioHost;
+//! use telepathy_audio::io::{AudioMixerBuilder, MixerSourceBuilder};
+//! use telepathy_audio::adapters::MpscSource;
+//! use bytes::Bytes;
+//! use std::sync::mpsc;
+//!
+//! let host = CpalAudioHost::new();
+//! let mixer = AudioMixerBuilder::new().build(&host).unwrap();
+//!
+//! // Attach a peer; dropping the returned handle detaches it again
+//! let (tx, rx) = mpsc::channel::<Bytes>();
+//! let peer = mixer
+//!     .add_source(
+//!         MixerSourceBuilder::new()
+//!             .sample_rate(44_100)
+//!             .volume(0.8)
+//!             .codec(true)
+//!             .source(MpscSource::new(rx)),
+//!     )
+//!     .unwrap();
+//!
+//! // tx.send(encoded_frame).unwrap();
+//! let _ = (tx, peer);
+//! ```
+
+use crate::codec::{self, AudioDecoder, DecoderFactory, RawDecoder};
+use crate::devices::AudioHost;
+use crate::error::{ChannelError, ConfigError, Error};
+use crate::internal::NETWORK_FRAME;
+use crate::internal::echo::EchoReference;
+use crate::internal::mixer::{MixerChannel, MixerCommand, mixer_processor};
+use crate::internal::state::{DeviceSwap, OutputProcessorState, OutputSwap};
+use crate::internal::thread::{self, JoinHandle};
+use crate::io::StreamErrorCallback;
+use crate::io::traits::AudioDataSource;
+use crate::recorder::RecordingTap;
+use crate::sea::codec::file::SeaFileHeader;
+use crate::sea::decoder::SeaDecoder;
+use atomic_float::AtomicF32;
+use crossbeam::channel::{Sender, unbounded};
+use nnnoiseless::FRAME_SIZE;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering::Relaxed};
+use tracing::{debug, error};
+
+/// Configuration for an audio mixer. Prefer [`AudioMixerBuilder`].
+pub struct AudioMixerConfig {
+    /// Output device ID; `None` selects the system default.
+    pub device_id: Option<String>,
+    /// Rate sources are resampled to before mixing. The mix is resampled to
+    /// the device's native rate as needed.
+    pub sample_rate: u32,
+    /// Number of interleaved channels in the mix and in every source.
+    pub channels: u16,
+    /// Output gain applied to the mix. 1.0 = unity.
+    pub volume: f32,
+    /// Echo canceller reference that receives every played frame.
+    pub echo_reference: Option<EchoReference>,
+    /// Stream-error callback; `None` falls back to a default log path.
+    pub error_callback: Option<StreamErrorCallback>,
+}
+
+impl Default for AudioMixerConfig {
+    fn default() -> Self {
+        Self {
+            device_id: None,
+            sample_rate: 48_000,
+            channels: 1,
+            volume: 1.0,
+            echo_reference: None,
+            error_callback: None,
+        }
+    }
+}
+
+/// Builder for configuring and creating an audio mixer.
+pub struct AudioMixerBuilder {
+    config: AudioMixerConfig,
+    shared_output_volume: Option<Arc<AtomicF32>>,
+    shared_deafened: Option<Arc<AtomicBool>>,
+    shared_rms: Option<Arc<AtomicF32>>,
+    shared_loss: Option<Arc<AtomicUsize>>,
+}
+
+impl AudioMixerBuilder {
+    /// Creates a new audio mixer builder with default configuration.
+    pub fn new() -> Self {
+        Self {
+            config: AudioMixerConfig::default(),
+            shared_output_volume: None,
+            shared_deafened: None,
+            shared_rms: None,
+            shared_loss: None,
+        }
+    }
+
+    /// Sets the output device by ID.
+    ///
+    /// If not set or set to None, the default output device will be used.
+    pub fn device(mut self, device_id: Option<String>) -> Self {
+        self.config.device_id = device_id;
+        self
+    }
+
+    /// Sets the mix sample rate in Hz. Defaults to 48 kHz.
+    ///
+    /// Every source is resampled to this rate before mixing.
+    pub fn sample_rate(mut self, rate: u32) -> Self {
+        self.config.sample_rate = rate;
+        self
+    }
+
+    /// Sets the number of interleaved channels in the mix.
+    ///
+    /// Every source must carry the same number of channels. The count must
+    /// split a frame into whole blocks (see [`supports_channels`](codec::supports_channels)).
+    pub fn channels(mut self, channels: u16) -> Self {
+        self.config.channels = channels;
+        self
+    }
+
+    /// Sets the volume multiplier applied to the whole mix.
+    pub fn volume(mut self, volume: f32) -> Self {
+        self.config.volume = volume;
+        self
+    }
+
+    /// Sets a shared atomic for the mix volume, enabling real-time synchronization.
+    ///
+    /// See [`AudioOutputBuilder::output_volume_shared`](crate::io::AudioOutputBuilder::output_volume_shared).
+    pub fn output_volume_shared(mut self, volume: &Arc<AtomicF32>) -> Self {
+        self.shared_output_volume = Some(volume.clone());
+        self
+    }
+
+    /// Sets a shared atomic for deafened state, enabling real-time synchronization.
+    ///
+    /// While deafened every source is drained and nothing is played.
+    pub fn deafened_shared(mut self, deafened: &Arc<AtomicBool>) -> Self {
+        self.shared_deafened = Some(deafened.clone());
+        self
+    }
+
+    /// Sets a shared atomic the processor writes the RMS of the mix into.
+    pub fn rms_shared(mut self, rms: &Arc<AtomicF32>) -> Self {
+        self.shared_rms = Some(rms.clone());
+        self
+    }
+
+    /// Taps every decoded frame into a recording as the track `name`.
+    ///
+    /// Frames are recorded before any volume is applied, and only while a
+    /// [`Recorder`](crate::recorder::Recorder) runs on `tap`.
+    pub fn recording(mut self, tap: &RecordingTap, name: impl Into<String>) -> Self {
+        self.recording = Some((tap.clone(), name.into()));
+        self
+    }
+
+    /// Sets a shared atomic counting samples lost to a full output or to
+    /// sources that ran too far ahead.
+    pub fn loss_shared(mut self, loss: &Arc<AtomicUsize>) -> Self {
+        self.shared_loss = Some(loss.clone());
+        self
+    }
+
+    /// Feeds the mix into an echo canceller reference.
+    ///
+    /// See [`AudioOutputBuilder::echo_reference`](crate::io::AudioOutputBuilder::echo_reference).
+    pub fn echo_reference(mut self, reference: &EchoReference) -> Self {
+        self.config.echo_reference = Some(reference.clone());
+        self
+    }
+
+    /// Sets a callback to be triggered on stream errors.
+    ///
+    /// When set, the callback receives the underlying CPAL stream error.
+    pub fn on_error<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(cpal::Error) + Send + 'static,
+    {
+        self.config.error_callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Builds and starts the mixer with no sources attached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The channel count is not supported (see [`channels`](Self::channels))
+    /// - The host fails to open the output device (see [`AudioHost::open_output`])
+    /// - The processor thread cannot be spawned
+    pub fn build<I>(
+        mut self,
+        host: &impl AudioHost<OutputStream = I>,
+    ) -> Result<AudioMixerHandle<I>, Error>
+    where
+        I: Send + 'static,
+    {
+        if !codec::supports_channels(self.config.channels) {
+            return Err(Error::Config(ConfigError::UnsupportedChannels(
+                self.config.channels,
+            )));
+        }
+
+        // Open the output
+        let error_callback = self.config.error_callback.take();
+        let channels = self.config.channels;
+        let (processor_output, output_rate, stream) =
+            host.open_output(self.config.device_id.as_deref(), channels, error_callback)?;
+
+        // Create shared atomic state (use provided shared atomics or create new ones)
+        let output_volume = self
+            .shared_output_volume
+            .clone()
+            .unwrap_or_else(|| Arc::new(AtomicF32::new(self.config.volume)));
+        let deafened = self.shared_deafened.clone().unwrap_or_default();
+        let rms_sender = self.shared_rms.clone().unwrap_or_default();
+        let loss_sender = self.shared_loss.clone().unwrap_or_default();
+
+        let (device_swaps, swap_receiver) = unbounded();
+        let state =
+            OutputProcessorState::new(&output_volume, rms_sender, &deafened, loss_sender.clone())
+                .with_channels(channels)
+                .with_device_swaps(swap_receiver);
+        let echo_reference = self
+            .config
+            .echo_reference
+            .as_ref()
+            .map(|reference| reference.producer(self.config.sample_rate));
+
+        let (commands, receiver) = unbounded();
+        let mix_rate = self.config.sample_rate;
+        let processor_handle = thread::safe_spawn(move || {
+            if let Err(e) = mixer_processor(
+                receiver,
+                processor_output,
+                mix_rate as usize,
+                output_rate as usize,
+                state,
+                echo_reference,
+            ) {
+                error!("Mixer processor error: {}", e);
+            }
+            debug!("Mixer processor thread ended");
+        })?;
+
+        Ok(AudioMixerHandle {
+            _stream: Some(stream),
+            _processor_handle: Some(processor_handle),
+            commands,
+            next_id: AtomicU64::new(0),
+            sample_rate: mix_rate,
+            channels,
+            output_volume,
+            deafened,
+            loss_sender,
+            device_swaps,
+        })
+    }
+}
+
+impl Default for AudioMixerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle to a running audio mixer.
+///
+/// Dropping the handle stops the processor and tears down the stream. Sources
+/// still attached at that point are dropped with it.
+pub struct AudioMixerHandle<S> {
+    _stream: Option<S>,
+    _processor_handle: Option<JoinHandle<()>>,
+    commands: Sender<MixerCommand>,
+    next_id: AtomicU64,
+    sample_rate: u32,
+    channels: u16,
+    output_volume: Arc<AtomicF32>,
+    deafened: Arc<AtomicBool>,
+    loss_sender: Arc<AtomicUsize>,
+    device_swaps: Sender<OutputSwap>,
+}
+
+impl<S> AudioMixerHandle<S> {
+    /// Attaches a source to the running mix.
+    ///
+    /// The source plays until the returned handle is dropped or the source
+    /// closes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No source was set via [`MixerSourceBuilder::source`]
+    /// - Decoder or resampler initialization fails
+    /// - The mixer processor has stopped
+    pub fn add_source(&self, mut builder: MixerSourceBuilder) -> Result<MixerSourceHandle, Error> {
+        let source = builder
+            .source
+            .take()
+            .ok_or(Error::Config(ConfigError::MissingDataSource))?;
+        let decoder: Box<dyn AudioDecoder> = match builder.decoder.take() {
+            Some(factory) => factory(builder.sample_rate, self.channels)?,
+            None => Box::new(RawDecoder),
+        };
+        let volume = builder
+            .shared_volume
+            .unwrap_or_else(|| Arc::new(AtomicF32::new(builder.volume)));
+        let rms = builder.shared_rms.unwrap_or_default();
+
+        let channel = MixerChannel::new(
+            source,
+            decoder,
+            builder.sample_rate as usize,
+            self.sample_rate as usize,
+            self.channels as usize,
+            volume.clone(),
+            rms.clone(),
+        )?
+        .with_recording(
+            builder
+                .recording
+                .take()
+                .map(|(tap, name)| tap.track(name, builder.sample_rate, self.channels as usize)),
+        );
+
+        let id = self.next_id.fetch_add(1, Relaxed);
+        self.commands
+            .send(MixerCommand::Add(id, Box::new(channel)))
+            .map_err(|_| Error::Channel(ChannelError::MixerStopped))?;
+
+        Ok(MixerSourceHandle {
+            id,
+            commands: self.commands.clone(),
+            volume,
+            rms,
+        })
+    }
+
+    /// Deafens the mixer.
+    ///
+    /// When deafened, incoming audio data from every source is discarded and
+    /// no sound will be played.
+    pub fn deafen(&self) {
+        self.deafened.store(true, Relaxed);
+    }
+
+    /// Undeafens the mixer.
+    pub fn undeafen(&self) {
+        self.deafened.store(false, Relaxed);
+    }
+
+    /// Returns whether the mixer is currently deafened.
+    pub fn is_deafened(&self) -> bool {
+        self.deafened.load(Relaxed)
+    }
+
+    /// Sets the volume multiplier applied to the whole mix.
+    pub fn set_volume(&self, volume: f32) {
+        self.output_volume.store(volume, Relaxed);
+    }
+
+    /// Gets the current volume multiplier of the whole mix.
+    pub fn volume(&self) -> f32 {
+        self.output_volume.load(Relaxed)
+    }
+
+    /// Gets the loss receiver
+    pub fn loss_receiver(&self) -> Arc<AtomicUsize> {
+        self.loss_sender.clone()
+    }
+
+    /// Moves the running mix to another device.
+    ///
+    /// The device is opened with the same channel count and every attached
+    /// source keeps playing on it, resampled to its sample rate. The stream
+    /// of the previous device is closed once the new device is handed over,
+    /// and `error_callback` takes over stream error reporting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The host fails to open the device, the previous device keeps running
+    /// - The mixer processor has stopped
+    pub fn switch_device(
+        &mut self,
+        host: &impl AudioHost<OutputStream = S>,
+        device_id: Option<&str>,
+        error_callback: Option<StreamErrorCallback>,
+    ) -> Result<(), Error> {
+        let (output, sample_rate, stream) =
+            host.open_output(device_id, self.channels, error_callback)?;
+        self.device_swaps
+            .send(DeviceSwap {
+                device: Box::new(output),
+                sample_rate: sample_rate as usize,
+            })
+            .map_err(|_| Error::Channel(ChannelError::MixerStopped))?;
+        self._stream = Some(stream);
+        Ok(())
+    }
+}
+
+impl<S> Drop for AudioMixerHandle<S> {
+    fn drop(&mut self) {
+        _ = self.commands.send(MixerCommand::Stop);
+    }
+}
+
+/// Builder for a source attached with [`AudioMixerHandle::add_source`].
+pub struct MixerSourceBuilder {
+    source: Option<Box<dyn AudioDataSource>>,
+    sample_rate: u32,
+    volume: f32,
+    decoder: Option<DecoderFactory>,
+    shared_volume: Option<Arc<AtomicF32>>,
+    shared_rms: Option<Arc<AtomicF32>>,
+    recording: Option<(RecordingTap, String)>,
+}
+
+impl MixerSourceBuilder {
+    /// Creates a new source builder for raw PCM at 48 kHz and unity volume.
+    pub fn new() -> Self {
+        Self {
+            source: None,
+            sample_rate: 48_000,
+            volume: 1.0,
+            decoder: None,
+            shared_volume: None,
+            shared_rms: None,
+            recording: None,
+        }
+    }
+
+    /// Sets the source the frames are received from.
+    pub fn source<T: AudioDataSource>(mut self, source: T) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    /// Sets the sample rate of the incoming audio in Hz.
+    ///
+    /// The audio is resampled to the mix rate if needed.
+    pub fn sample_rate(mut self, rate: u32) -> Self {
+        self.sample_rate = rate;
+        self
+    }
+
+    /// Sets this source's volume multiplier.
+    pub fn volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    /// Sets a shared atomic for this source's volume, enabling real-time synchronization.
+    pub fn output_volume_shared(mut self, volume: &Arc<AtomicF32>) -> Self {
+        self.shared_volume = Some(volume.clone());
+        self
+    }
+
+    /// Sets a shared atomic the processor writes this source's RMS level into.
+    pub fn rms_shared(mut self, rms: &Arc<AtomicF32>) -> Self {
+        self.shared_rms = Some(rms.clone());
+        self
+    }
+
+    /// Enables SEA codec decoding.
+    ///
+    /// Shorthand for [`decoder`](Self::decoder) with a [`SeaDecoder`];
+    /// `false` restores raw PCM.
+    pub fn codec(mut self, enabled: bool) -> Self {
+        if !enabled {
+            self.decoder = None;
+            return self;
+        }
+
+        self.decoder(|sample_rate, channels| {
+            Ok(SeaDecoder::new(SeaFileHeader {
+                version: 1,
+                channels: channels as u8,
+                chunk_size: NETWORK_FRAME as u16,
+                frames_per_chunk: FRAME_SIZE as u16 / channels,
+                sample_rate,
+            })?)
+        })
+    }
+
+    /// Sets the decoder applied to every received frame.
+    ///
+    /// `factory` is called once by [`AudioMixerHandle::add_source`] with the
+    /// configured [`sample_rate`](Self::sample_rate) and the mixer's channel
+    /// count. Without a decoder, frames are expected to be raw PCM.
+    pub fn decoder<D, F>(mut self, factory: F) -> Self
+    where
+        D: AudioDecoder + 'static,
+        F: FnOnce(u32, u16) -> Result<D, Error> + Send + 'static,
+    {
+        self.decoder = Some(Box::new(move |sample_rate, channels| {
+            factory(sample_rate, channels).map(|decoder| Box::new(decoder) as Box<dyn AudioDecoder>)
+        }));
+        self
+    }
+}
+
+impl Default for MixerSourceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle to a source attached to an [`AudioMixerHandle`].
+///
+/// Dropping the handle detaches the source from the mix.
+pub struct MixerSourceHandle {
+    id: u64,
+    commands: Sender<MixerCommand>,
+    volume: Arc<AtomicF32>,
+    rms: Arc<AtomicF32>,
+}
+
+impl MixerSourceHandle {
+    /// Sets this source's volume multiplier.
+    pub fn set_volume(&self, volume: f32) {
+        self.volume.store(volume, Relaxed);
+    }
+
+    /// Gets this source's current volume multiplier.
+    pub fn volume(&self) -> f32 {
+        self.volume.load(Relaxed)
+    }
+
+    /// Gets the atomic this source's RMS level is written into.
+    pub fn rms(&self) -> Arc<AtomicF32> {
+        self.rms.clone()
+    }
+}
+
+impl Drop for MixerSourceHandle {
+    fn drop(&mut self) {
+        _ = self.commands.send(MixerCommand::Remove(self.id));
+    }
+}
@@ -6,8 +6,9 @@
 //!
 //! ## Modules
 //!
-//! - [`input`](mod@crate::io::input) - Audio input capture with noise suppression and codec encoding
+//! - [`input`](mod@crate::io::input) - Audio input capture with echo cancellation, noise suppression and codec encoding
 //! - [`output`](mod@crate::io::output) - Audio output playback with codec decoding and volume control
+//! - [`mixer`](mod@crate::io::mixer) - Playback of many sources through one output stream
 //!
 //! ## Example
 //!
@@ -40,6 +41,7 @@
 //! ```
 
 pub mod input;
+pub mod mixer;
 pub mod output;
 pub mod traits;
 
@@ -47,9 +49,20 @@ pub type StreamErrorCallback = Box<dyn FnMut(cpal::Error) + Send + 'static>;
 
 // Re-export main types for convenience
 pub use input::{AudioInputBuilder, AudioInputConfig, AudioInputHandle, CodecBitrateMode};
+pub use mixer::{
+    AudioMixerBuilder, AudioMixerConfig, AudioMixerHandle, MixerSourceBuilder, MixerSourceHandle,
+};
 pub use output::{AudioOutputBuilder, AudioOutputConfig, AudioOutputHandle};
 pub use traits::{AudioDataSink, AudioDataSource};
 
+pub use crate::internal::echo::EchoReference;
+pub use crate::internal::effects::{
+    AudioEffect, Compressor, CompressorConfig, DeEsser, DeEsserConfig, EffectChain, EqBand,
+    EqPreset, Equalizer, HighPass,
+};
+pub use crate::internal::gain::AutoGainConfig;
+pub use crate::internal::vad::VoiceGateConfig;
+
 /// cpal::Stream is not yet send and sync on WASM
 ///
 /// SendStream allows the Stream to be used in spawned tasks
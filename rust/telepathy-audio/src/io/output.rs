@@ -27,17 +27,22 @@
 //! let _ = tx;
 //! ```
 
+use crate::codec::{self, AudioDecoder, DecoderFactory, RawDecoder};
 use crate::devices::AudioHost;
-use crate::error::{ConfigError, Error};
+use crate::error::{ChannelError, ConfigError, Error};
 use crate::internal::NETWORK_FRAME;
+use crate::internal::echo::EchoReference;
+use crate::internal::effects::{AudioEffect, EffectChain};
 use crate::internal::processor::output_processor;
-use crate::internal::state::OutputProcessorState;
+use crate::internal::state::{DeviceSwap, OutputProcessorState, OutputSwap};
 use crate::internal::thread::{self, JoinHandle};
 use crate::io::StreamErrorCallback;
 use crate::io::traits::AudioDataSource;
+use crate::recorder::RecordingTap;
 use crate::sea::codec::file::SeaFileHeader;
 use crate::sea::decoder::SeaDecoder;
 use atomic_float::AtomicF32;
+use crossbeam::channel::{Sender, unbounded};
 use nnnoiseless::FRAME_SIZE;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering::Relaxed};
@@ -50,10 +55,21 @@ pub struct AudioOutputConfig {
     /// Remote (source) sample rate. The processor resamples to the device's
     /// native rate as needed.
     pub sample_rate: u32,
+    /// Number of interleaved channels in every frame. Defaults to 1.
+    pub channels: u16,
     /// Output gain. 1.0 = unity; values < 1.0 attenuate, > 1.0 amplify.
     pub volume: f32,
-    /// When true, the SEA codec decodes incoming frames.
-    pub codec_enabled: bool,
+    /// Creates the decoder for incoming frames. `None` expects raw PCM.
+    pub decoder: Option<DecoderFactory>,
+    /// Echo canceller reference that receives every played frame.
+    pub echo_reference: Option<EchoReference>,
+    /// Recording tap and track name that receive every decoded frame.
+    pub recording: Option<(RecordingTap, String)>,
+    /// Effects run on every decoded frame, in order.
+    pub effects: EffectChain,
+    /// Nudges the resampling ratio to hold the device buffer's latency
+    /// constant against clock drift. Defaults to `true`.
+    pub drift_compensation: bool,
     /// Stream-error callback; `None` falls back to a default log path.
     pub error_callback: Option<StreamErrorCallback>,
 }
@@ -63,8 +79,13 @@ impl Default for AudioOutputConfig {
         Self {
             device_id: None,
             sample_rate: 48_000,
+            channels: 1,
             volume: 1.0,
-            codec_enabled: false,
+            decoder: None,
+            echo_reference: None,
+            recording: None,
+            effects: EffectChain::new(),
+            drift_compensation: true,
             error_callback: None,
         }
     }
@@ -135,6 +156,16 @@ where
         self
     }
 
+    /// Sets the number of interleaved channels in the incoming audio.
+    ///
+    /// Defaults to mono. Channels are mapped round robin onto the device's
+    /// channels, so mono audio plays on every speaker. The count must split a
+    /// frame into whole blocks (see [`supports_channels`](codec::supports_channels)).
+    pub fn channels(mut self, channels: u16) -> Self {
+        self.config.channels = channels;
+        self
+    }
+
     /// Sets the output volume multiplier.
     ///
     /// * 1.0 = unity gain (default)
@@ -193,9 +224,88 @@ where
         self
     }
 
-    /// Enables codec decoding
+    /// Enables SEA codec decoding.
+    ///
+    /// Shorthand for [`decoder`](Self::decoder) with a [`SeaDecoder`];
+    /// `false` restores raw PCM.
     pub fn codec(mut self, enabled: bool) -> Self {
-        self.config.codec_enabled = enabled;
+        if !enabled {
+            self.config.decoder = None;
+            return self;
+        }
+
+        self.decoder(|sample_rate, channels| {
+            Ok(SeaDecoder::new(SeaFileHeader {
+                version: 1,
+                channels: channels as u8,
+                chunk_size: NETWORK_FRAME as u16,
+                frames_per_chunk: FRAME_SIZE as u16 / channels,
+                sample_rate,
+            })?)
+        })
+    }
+
+    /// Sets the decoder applied to every received frame.
+    ///
+    /// `factory` is called once at build time with the configured
+    /// [`sample_rate`](Self::sample_rate) and [`channels`](Self::channels).
+    /// Without a decoder, frames are expected to be raw PCM.
+    pub fn decoder<D, F>(mut self, factory: F) -> Self
+    where
+        D: AudioDecoder + 'static,
+        F: FnOnce(u32, u16) -> Result<D, Error> + Send + 'static,
+    {
+        self.config.decoder = Some(Box::new(move |sample_rate, channels| {
+            factory(sample_rate, channels).map(|decoder| Box::new(decoder) as Box<dyn AudioDecoder>)
+        }));
+        self
+    }
+
+    /// Feeds played audio into an echo canceller reference.
+    ///
+    /// Inputs built with [`AudioInputBuilder::echo_cancellation`] on the same
+    /// reference will remove this stream's audio from the microphone signal.
+    ///
+    /// [`AudioInputBuilder::echo_cancellation`]: crate::io::AudioInputBuilder::echo_cancellation
+    pub fn echo_reference(mut self, reference: &EchoReference) -> Self {
+        self.config.echo_reference = Some(reference.clone());
+        self
+    }
+
+    /// Taps every decoded frame into a recording as the track `name`.
+    ///
+    /// Frames are recorded before the output volume is applied, and only
+    /// while a [`Recorder`](crate::recorder::Recorder) runs on `tap`.
+    pub fn recording(mut self, tap: &RecordingTap, name: impl Into<String>) -> Self {
+        self.config.recording = Some((tap.clone(), name.into()));
+        self
+    }
+
+    /// Appends an effect to the end of the effect chain.
+    ///
+    /// Effects run in the order they were added, on the decoded frame at the
+    /// source's sample rate, before the output volume is applied. See
+    /// [`effects`](mod@crate::internal::effects) for the built-in effects.
+    pub fn effect(mut self, effect: impl AudioEffect + 'static) -> Self {
+        self.config.effects.push(effect);
+        self
+    }
+
+    /// Replaces the effect chain.
+    pub fn effects(mut self, effects: EffectChain) -> Self {
+        self.config.effects = effects;
+        self
+    }
+
+    /// Enables or disables clock drift compensation (enabled by default).
+    ///
+    /// The sender's and the device's clocks never run at exactly the same
+    /// rate, so the device buffer slowly fills up or runs dry. With
+    /// compensation the processor watches the buffer and adjusts the
+    /// resampling ratio by at most 0.2% to keep its latency constant. Devices
+    /// that do not report their fill level play at the nominal ratio.
+    pub fn drift_compensation(mut self, enabled: bool) -> Self {
+        self.config.drift_compensation = enabled;
         self
     }
 
@@ -223,6 +333,7 @@ where
     ///
     /// Returns an error if:
     /// - No source was set via [`source`](Self::source)
+    /// - The channel count is not supported (see [`channels`](Self::channels))
     /// - The host fails to open the output device (see [`AudioHost::open_output`])
     /// - Codec initialization fails (invalid decoder settings)
     /// - The processor thread cannot be spawned
@@ -235,12 +346,17 @@ where
     {
         if self.source.is_none() {
             return Err(Error::Config(ConfigError::MissingDataSource));
+        } else if !codec::supports_channels(self.config.channels) {
+            return Err(Error::Config(ConfigError::UnsupportedChannels(
+                self.config.channels,
+            )));
         }
 
         // Open the output
         let error_callback = self.config.error_callback.take();
+        let channels = self.config.channels;
         let (processor_output, output_rate, stream) =
-            host.open_output(self.config.device_id.as_deref(), error_callback)?;
+            host.open_output(self.config.device_id.as_deref(), channels, error_callback)?;
 
         // Create shared atomic state (use provided shared atomics or create new ones)
         let output_volume = self
@@ -254,20 +370,34 @@ where
             .source
             .ok_or(Error::Config(ConfigError::MissingDataSource))?;
 
+        let (device_swaps, swap_receiver) = unbounded();
+        let (effect_chains, chain_receiver) = unbounded();
+        // the processor installs the initial chain like any replacement
+        let _ = effect_chains.send(std::mem::take(&mut self.config.effects));
+        let drift = Arc::new(AtomicF32::new(0.0));
+        let queued = Arc::new(AtomicUsize::new(0));
         let state =
-            OutputProcessorState::new(&output_volume, rms_sender, &deafened, loss_sender.clone());
+            OutputProcessorState::new(&output_volume, rms_sender, &deafened, loss_sender.clone())
+                .with_channels(channels)
+                .with_device_swaps(swap_receiver)
+                .with_effect_chains(chain_receiver)
+                .with_drift_compensation(self.config.drift_compensation)
+                .with_drift_sender(drift.clone())
+                .with_queued_sender(queued.clone());
 
-        let decoder = if self.config.codec_enabled {
-            Some(SeaDecoder::new(SeaFileHeader {
-                version: 1,
-                channels: 1,
-                chunk_size: NETWORK_FRAME as u16,
-                frames_per_chunk: FRAME_SIZE as u16,
-                sample_rate: self.config.sample_rate,
-            })?)
-        } else {
-            None
+        let decoder: Box<dyn AudioDecoder> = match self.config.decoder.take() {
+            Some(factory) => factory(self.config.sample_rate, channels)?,
+            None => Box::new(RawDecoder),
         };
+        let echo_reference = self
+            .config
+            .echo_reference
+            .as_ref()
+            .map(|reference| reference.producer(self.config.sample_rate));
+        let recording =
+            self.config.recording.as_ref().map(|(tap, name)| {
+                tap.track(name.clone(), self.config.sample_rate, channels as usize)
+            });
 
         // Spawn processor thread (safe_spawn catches panics on WASM when threading is unavailable)
         let processor_handle = thread::safe_spawn(move || {
@@ -278,6 +408,8 @@ where
                 output_rate as usize,
                 state,
                 decoder,
+                echo_reference,
+                recording,
             ) {
                 error!("Output processor error: {}", e);
             }
@@ -290,6 +422,11 @@ where
             output_volume,
             deafened,
             loss_sender,
+            drift,
+            queued,
+            channels,
+            device_swaps,
+            effect_chains,
         })
     }
 }
@@ -311,6 +448,11 @@ pub struct AudioOutputHandle<S> {
     output_volume: Arc<AtomicF32>,
     deafened: Arc<AtomicBool>,
     loss_sender: Arc<AtomicUsize>,
+    drift: Arc<AtomicF32>,
+    queued: Arc<AtomicUsize>,
+    channels: u16,
+    device_swaps: Sender<OutputSwap>,
+    effect_chains: Sender<EffectChain>,
 }
 
 impl<S> AudioOutputHandle<S> {
@@ -346,4 +488,63 @@ impl<S> AudioOutputHandle<S> {
     pub fn loss_receiver(&self) -> Arc<AtomicUsize> {
         self.loss_sender.clone()
     }
+
+    /// Gets the clock drift correction in parts per million.
+    ///
+    /// Positive values speed playback up, negative values slow it down; 0.0
+    /// while drift compensation is disabled or still settling.
+    pub fn drift_ppm(&self) -> f32 {
+        self.drift.load(Relaxed)
+    }
+
+    /// Gets the smoothed number of samples queued ahead of the device.
+    ///
+    /// Only tracked while drift compensation is active.
+    pub fn queued(&self) -> usize {
+        self.queued.load(Relaxed)
+    }
+
+    /// Replaces the effect chain of the running output.
+    ///
+    /// The processor prepares the new chain and switches to it at the next
+    /// frame; pass an empty chain to remove every effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the processor has stopped.
+    pub fn set_effects(&self, effects: EffectChain) -> Result<(), Error> {
+        self.effect_chains
+            .send(effects)
+            .map_err(|_| Error::Channel(ChannelError::ProcessorStopped))
+    }
+
+    /// Moves the running output to another device.
+    ///
+    /// The device is opened with the same channel count and the processor
+    /// continues on it, resampling to its sample rate. The stream of the
+    /// previous device is closed once the new device is handed over, and
+    /// `error_callback` takes over stream error reporting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The host fails to open the device, the previous device keeps running
+    /// - The processor has stopped
+    pub fn switch_device(
+        &mut self,
+        host: &impl AudioHost<OutputStream = S>,
+        device_id: Option<&str>,
+        error_callback: Option<StreamErrorCallback>,
+    ) -> Result<(), Error> {
+        let (output, sample_rate, stream) =
+            host.open_output(device_id, self.channels, error_callback)?;
+        self.device_swaps
+            .send(DeviceSwap {
+                device: Box::new(output),
+                sample_rate: sample_rate as usize,
+            })
+            .map_err(|_| Error::Channel(ChannelError::ProcessorStopped))?;
+        self._stream = Some(stream);
+        Ok(())
+    }
 }
@@ -23,10 +23,17 @@ impl AudioDataSink for Box<dyn Fn(PooledBuffer) + Send + 'static> {
     }
 }
 
+/// Marker frame a source yields in place of a frame lost in transit.
+///
+/// The output processor conceals it (see [`crate::internal::plc`]) instead of
+/// leaving a gap. Real frames are never empty.
+pub const MISSING_FRAME: Bytes = Bytes::new();
+
 /// A source of audio output data.
 ///
 /// Implementations are used by the output processor thread to receive audio frames
-/// to be decoded (optional) and played.
+/// to be decoded (optional) and played. A source that knows a frame was lost
+/// should yield [`MISSING_FRAME`] in its place.
 pub trait AudioDataSource: Send + 'static {
     /// Receive the next audio frame (blocking).
     fn recv(&self) -> Result<Bytes, ClosedOrFailed>;
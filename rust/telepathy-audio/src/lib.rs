@@ -7,25 +7,33 @@
 //!
 //! - **Device Management**: Enumerate and select audio input/output devices
 //! - **Audio Capture**: High-quality audio input with optional noise suppression
-//! - **Audio Playback**: Low-latency audio output with automatic resampling
-//! - **Codec Support**: Encoding/decoding for efficient transmission using a modified verison of <https://github.com/Daninet/sea-codec>
+//! - **Echo Cancellation**: Removes locally played audio from the captured signal
+//! - **Automatic Gain Control**: Levels quiet and loud microphones with a built-in limiter
+//! - **Voice Activity Gate**: Sends frames only while someone is speaking
+//! - **Audio Playback**: Low-latency audio output with automatic resampling and clock drift compensation
+//! - **Effects**: Pluggable effect chains on inputs and outputs, with a high-pass filter, compressor, de-esser and EQ presets
+//! - **Mixing**: Many sources with their own decoder and volume played through one output stream
+//! - **Recording**: Captured and received audio written to WAV or SEA files, mixed or as separate tracks
+//! - **Packet Loss Concealment**: Lost network frames are synthesized and cross-faded back into real audio
+//! - **Codec Support**: Pluggable encoders/decoders for efficient transmission, including Opus, a lossless LPC + Rice codec, and a modified verison of <https://github.com/Daninet/sea-codec>
 //! - **SIMD Optimization**: Hardware-accelerated audio processing with automatic detection
 //!   - AVX-512 for 16-element aligned frames (on supported CPUs)
 //!   - AVX2 for 8-element aligned frames (on supported CPUs)
+//!   - NEON for 4-element aligned frames (on aarch64: Android, iOS and Apple Silicon)
 //!   - Scalar fallback when alignment requirements aren't met
 //! - **Cross-Platform**: Native support for Windows, macOS, Linux, Android, iOS, and WebAssembly
 //!
 //!
 //! ## Platform Support
 //!
-//! | Platform | Backend | Threading | Denoising | SEA Codec |
-//! |----------|---------|-----------|------|-----|
-//! | Windows  | WASAPI  | OS threads | ✅ | ✅ |
-//! | macOS    | CoreAudio | OS threads |✅|✅|
-//! | Linux    | ALSA    | OS threads |✅|✅|
-//! | Android  | AAudio  |  OS threads |✅|✅|
-//! | iOS      | CoreAudio  |  OS threads |✅|✅|
-//! | Web      | AudioWorklet/WebAudio | Web Workers |✅|✅|
+//! | Platform | Backend | Threading | Denoising | SEA Codec | Opus Codec |
+//! |----------|---------|-----------|------|-----|-----|
+//! | Windows  | WASAPI  | OS threads | ✅ | ✅ | ✅ |
+//! | macOS    | CoreAudio | OS threads |✅|✅|✅|
+//! | Linux    | ALSA    | OS threads |✅|✅|✅|
+//! | Android  | AAudio  |  OS threads |✅|✅|✅|
+//! | iOS      | CoreAudio  |  OS threads |✅|✅|✅|
+//! | Web      | AudioWorklet/WebAudio | Web Workers |✅|✅|❌|
 //!
 //! ## Module Organization
 //!
@@ -34,8 +42,10 @@
 //! - **Public Modules**:
 //!   - [`devices`] - Device enumeration and selection
 //!   - [`adapters`] - Ready-to-use channel adapters (std::sync::mpsc)
+//!   - [`codec`] - Encoder/decoder traits with raw PCM, SEA, Opus and lossless implementations
 //!   - [`io`] - Audio input/output builders and handles
 //!   - [`player`] - Audio file playback (WAV and SEA codec)
+//!   - [`recorder`] - Call recording to WAV and SEA codec files
 //!   - [`error`] - Error types
 //!
 //! ### WASM Threading
@@ -183,6 +193,10 @@
 //!     .unwrap();
 //! ```
 //!
+//! `codec()` is a shorthand for SEA. Any [`codec::AudioEncoder`] /
+//! [`codec::AudioDecoder`] can be used through the builders' `encoder()` and
+//! `decoder()` methods; see the [`codec`] module.
+//!
 //! ## With Shared Atomic State
 //!
 //! For real-time state synchronization between core components and audio processing,
@@ -232,16 +246,20 @@
 //! ```
 
 pub mod adapters;
+pub mod codec;
 pub mod devices;
 pub mod error;
 pub mod io;
 pub mod player;
+pub mod recorder;
 
 #[doc(hidden)]
 pub mod constants;
 #[doc(hidden)]
 pub mod internal;
 #[doc(hidden)]
+pub mod lossless;
+#[doc(hidden)]
 pub mod sea;
 
 mod platform;
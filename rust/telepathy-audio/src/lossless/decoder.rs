@@ -0,0 +1,175 @@
+use crate::constants::FRAME_SIZE;
+use crate::lossless::lpc::{self, MAX_ORDER, MAX_SHIFT};
+use crate::lossless::rice::{self, BitReader};
+use crate::lossless::{
+    KIND_CONSTANT, KIND_LPC, KIND_VERBATIM, LosslessError, PARTITIONS, RICE_PARAMETER_BITS,
+};
+
+const PARTITION_SIZE: usize = FRAME_SIZE / PARTITIONS;
+
+#[derive(Default)]
+pub struct LosslessDecoder;
+
+impl LosslessDecoder {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn decode_frame(
+        &mut self,
+        frame: &[u8],
+        output: &mut [i16; FRAME_SIZE],
+    ) -> Result<(), LosslessError> {
+        let (&kind, payload) = frame.split_first().ok_or(LosslessError::Truncated)?;
+
+        match kind {
+            KIND_CONSTANT => {
+                let sample = read_i16(payload, 0)?;
+                output.fill(sample);
+            }
+            KIND_VERBATIM => {
+                for (i, sample) in output.iter_mut().enumerate() {
+                    *sample = read_i16(payload, i)?;
+                }
+            }
+            KIND_LPC => Self::decode_lpc(payload, output)?,
+            other => return Err(LosslessError::UnknownKind(other)),
+        }
+
+        Ok(())
+    }
+
+    fn decode_lpc(payload: &[u8], output: &mut [i16; FRAME_SIZE]) -> Result<(), LosslessError> {
+        let [order, shift, ..] = *payload else {
+            return Err(LosslessError::Truncated);
+        };
+        let (order, shift) = (order as usize, shift as u32);
+        if !(1..=MAX_ORDER).contains(&order) || shift > MAX_SHIFT {
+            return Err(LosslessError::InvalidPredictor);
+        }
+
+        let payload = &payload[2..];
+        let mut coefficients = [0_i16; MAX_ORDER];
+        for (i, coefficient) in coefficients[..order].iter_mut().enumerate() {
+            *coefficient = read_i16(payload, i)?;
+        }
+        for (i, sample) in output[..order].iter_mut().enumerate() {
+            *sample = read_i16(payload, order + i)?;
+        }
+
+        let mut reader = BitReader::new(&payload[order * 4..]);
+        for partition in 0..PARTITIONS {
+            let parameter = reader.read_bits(RICE_PARAMETER_BITS)? as u32;
+            let start = (partition * PARTITION_SIZE).max(order);
+            for i in start..(partition + 1) * PARTITION_SIZE {
+                let residual = rice::unzigzag(reader.read_rice(parameter)?);
+                let prediction = lpc::predict(&coefficients[..order], shift, &output[i - order..i]);
+                output[i] = i16::try_from(prediction as i64 + residual)
+                    .map_err(|_| LosslessError::SampleOutOfRange)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the `index`th little endian sample from `payload`.
+#[inline(always)]
+fn read_i16(payload: &[u8], index: usize) -> Result<i16, LosslessError> {
+    payload
+        .get(index * 2..index * 2 + 2)
+        .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or(LosslessError::Truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lossless::encoder::LosslessEncoder;
+    use bytes::BytesMut;
+
+    fn round_trip(frame: [i16; FRAME_SIZE]) -> usize {
+        let mut encoder = LosslessEncoder::new();
+        let mut decoder = LosslessDecoder::new();
+        let mut buffer = BytesMut::new();
+        encoder.encode_frame(frame, &mut buffer).unwrap();
+
+        let mut output = [0_i16; FRAME_SIZE];
+        decoder.decode_frame(&buffer, &mut output).unwrap();
+        assert_eq!(output, frame);
+        buffer.len()
+    }
+
+    fn sine_frame(offset: usize, amplitude: f64) -> [i16; FRAME_SIZE] {
+        std::array::from_fn(|i| {
+            let t = (offset + i) as f64 / 48_000.0;
+            ((t * 440.0 * std::f64::consts::TAU).sin() * amplitude
+                + (t * 1_250.0 * std::f64::consts::TAU).sin() * amplitude / 4.0) as i16
+        })
+    }
+
+    #[test]
+    fn speech_like_frames_round_trip_compressed() {
+        for offset in [0, 480, 4_800] {
+            let size = round_trip(sine_frame(offset, 8_000.0));
+            assert!(size < FRAME_SIZE, "frame was {} bytes", size);
+        }
+    }
+
+    #[test]
+    fn extreme_frames_round_trip() {
+        round_trip([0; FRAME_SIZE]);
+        round_trip([i16::MIN; FRAME_SIZE]);
+        round_trip(std::array::from_fn(|i| {
+            if i % 2 == 0 { i16::MAX } else { i16::MIN }
+        }));
+        round_trip(std::array::from_fn(|i| {
+            if (i / 7) % 2 == 0 { i16::MAX } else { i16::MIN }
+        }));
+        round_trip(sine_frame(0, 40_000.0).map(|s| s.clamp(-32_000, 32_000)));
+
+        let mut state = 0x9E37_79B9_u32;
+        round_trip(std::array::from_fn(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as i16
+        }));
+    }
+
+    #[test]
+    fn rejects_malformed_frames() {
+        let mut decoder = LosslessDecoder::new();
+        let mut output = [0_i16; FRAME_SIZE];
+
+        assert_eq!(
+            decoder.decode_frame(&[], &mut output),
+            Err(LosslessError::Truncated)
+        );
+        assert_eq!(
+            decoder.decode_frame(&[9, 0, 0], &mut output),
+            Err(LosslessError::UnknownKind(9))
+        );
+        assert_eq!(
+            decoder.decode_frame(&[KIND_VERBATIM, 0, 0], &mut output),
+            Err(LosslessError::Truncated)
+        );
+        assert_eq!(
+            decoder.decode_frame(&[KIND_LPC, 0, 0], &mut output),
+            Err(LosslessError::InvalidPredictor)
+        );
+        assert_eq!(
+            decoder.decode_frame(&[KIND_LPC, 1, MAX_SHIFT as u8 + 1], &mut output),
+            Err(LosslessError::InvalidPredictor)
+        );
+
+        let mut buffer = BytesMut::new();
+        LosslessEncoder::new()
+            .encode_frame(sine_frame(0, 8_000.0), &mut buffer)
+            .unwrap();
+        assert_eq!(
+            decoder.decode_frame(&buffer[..buffer.len() / 2], &mut output),
+            Err(LosslessError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use crate::constants::FRAME_SIZE;
+use crate::lossless::lpc::{self, MAX_ORDER};
+use crate::lossless::rice::{self, BitWriter};
+use crate::lossless::{
+    KIND_CONSTANT, KIND_LPC, KIND_VERBATIM, LosslessError, PARTITIONS, RICE_PARAMETER_BITS,
+};
+use bytes::{BufMut, BytesMut};
+
+/// Predictor orders tried for every frame.
+const CANDIDATE_ORDERS: [usize; 5] = [1, 2, 4, 8, MAX_ORDER];
+
+const PARTITION_SIZE: usize = FRAME_SIZE / PARTITIONS;
+
+/// Size of a verbatim frame, the upper bound for every other kind.
+const VERBATIM_SIZE: usize = 1 + FRAME_SIZE * 2;
+
+/// A quantized predictor and the frame size it produces.
+#[derive(Clone, Copy)]
+struct Predictor {
+    order: usize,
+    coefficients: [i16; MAX_ORDER],
+    shift: u32,
+    parameters: [u32; PARTITIONS],
+    size: usize,
+}
+
+pub struct LosslessEncoder {
+    /// zigzagged residuals of the predictor being evaluated
+    residuals: [u32; FRAME_SIZE],
+}
+
+impl Default for LosslessEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LosslessEncoder {
+    pub fn new() -> Self {
+        Self {
+            residuals: [0; FRAME_SIZE],
+        }
+    }
+
+    pub fn encode_frame(
+        &mut self,
+        frame: [i16; FRAME_SIZE],
+        buffer: &mut BytesMut,
+    ) -> Result<(), LosslessError> {
+        buffer.clear();
+
+        if frame.iter().all(|sample| *sample == frame[0]) {
+            buffer.put_u8(KIND_CONSTANT);
+            buffer.put_i16_le(frame[0]);
+            return Ok(());
+        }
+
+        let candidates = lpc::levinson(&lpc::autocorrelation(&frame));
+        let best = CANDIDATE_ORDERS
+            .iter()
+            .map(|order| self.evaluate(&frame, &candidates[order - 1][..*order]))
+            .min_by_key(|predictor| predictor.size);
+
+        match best {
+            Some(predictor) if predictor.size < VERBATIM_SIZE => {
+                self.write_lpc(&frame, &predictor, buffer)
+            }
+            _ => {
+                buffer.put_u8(KIND_VERBATIM);
+                for sample in frame {
+                    buffer.put_i16_le(sample);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Quantizes `coefficients` and measures the frame they would produce.
+    fn evaluate(&mut self, frame: &[i16; FRAME_SIZE], coefficients: &[f64]) -> Predictor {
+        let order = coefficients.len();
+        let mut predictor = Predictor {
+            order,
+            coefficients: [0; MAX_ORDER],
+            shift: 0,
+            parameters: [0; PARTITIONS],
+            size: 0,
+        };
+        predictor.shift = lpc::quantize(coefficients, &mut predictor.coefficients[..order]);
+        self.compute_residuals(frame, &predictor);
+
+        let mut bits = 0;
+        for (partition, parameter) in predictor.parameters.iter_mut().enumerate() {
+            let start = (partition * PARTITION_SIZE).max(order);
+            let end = (partition + 1) * PARTITION_SIZE;
+            let (best, cost) = rice::best_parameter(&self.residuals[start..end]);
+            *parameter = best;
+            bits += RICE_PARAMETER_BITS as usize + cost;
+        }
+
+        // kind, order, shift, coefficients, warm-up samples, residuals
+        predictor.size = 3 + order * 4 + bits.div_ceil(8);
+        predictor
+    }
+
+    fn compute_residuals(&mut self, frame: &[i16; FRAME_SIZE], predictor: &Predictor) {
+        let order = predictor.order;
+        let coefficients = &predictor.coefficients[..order];
+
+        for i in order..FRAME_SIZE {
+            let prediction = lpc::predict(coefficients, predictor.shift, &frame[i - order..i]);
+            self.residuals[i] = rice::zigzag(frame[i] as i32 - prediction);
+        }
+    }
+
+    fn write_lpc(
+        &mut self,
+        frame: &[i16; FRAME_SIZE],
+        predictor: &Predictor,
+        buffer: &mut BytesMut,
+    ) {
+        let order = predictor.order;
+        self.compute_residuals(frame, predictor);
+
+        buffer.reserve(predictor.size);
+        buffer.put_u8(KIND_LPC);
+        buffer.put_u8(order as u8);
+        buffer.put_u8(predictor.shift as u8);
+        for coefficient in &predictor.coefficients[..order] {
+            buffer.put_i16_le(*coefficient);
+        }
+        for sample in &frame[..order] {
+            buffer.put_i16_le(*sample);
+        }
+
+        let mut writer = BitWriter::new(buffer);
+        for (partition, parameter) in predictor.parameters.iter().enumerate() {
+            let start = (partition * PARTITION_SIZE).max(order);
+            let end = (partition + 1) * PARTITION_SIZE;
+            writer.write_bits(*parameter, RICE_PARAMETER_BITS);
+            for residual in &self.residuals[start..end] {
+                writer.write_rice(*residual, *parameter);
+            }
+        }
+        writer.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_frames_are_three_bytes() {
+        let mut encoder = LosslessEncoder::new();
+        let mut buffer = BytesMut::new();
+        encoder.encode_frame([-7; FRAME_SIZE], &mut buffer).unwrap();
+        assert_eq!(&buffer[..], &[KIND_CONSTANT, 0xF9, 0xFF]);
+    }
+
+    #[test]
+    fn frames_never_exceed_verbatim_size() {
+        // a deterministic full scale noise burst is not predictable
+        let mut state = 0x1234_5678_u32;
+        let frame = std::array::from_fn(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as i16
+        });
+
+        let mut encoder = LosslessEncoder::new();
+        let mut buffer = BytesMut::new();
+        encoder.encode_frame(frame, &mut buffer).unwrap();
+        assert!(buffer.len() <= VERBATIM_SIZE);
+    }
+}
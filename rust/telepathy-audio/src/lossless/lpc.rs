@@ -0,0 +1,138 @@
+use crate::constants::FRAME_SIZE;
+
+/// Highest predictor order the encoder searches.
+pub(crate) const MAX_ORDER: usize = 12;
+
+/// Quantized coefficients are stored as signed values of this many bits.
+pub(crate) const COEFFICIENT_PRECISION: u32 = 14;
+
+/// Largest right shift applied to the predictor sum.
+pub(crate) const MAX_SHIFT: u32 = 15;
+
+/// Autocorrelation of the Welch windowed frame for lags `0..=MAX_ORDER`.
+pub(crate) fn autocorrelation(frame: &[i16; FRAME_SIZE]) -> [f64; MAX_ORDER + 1] {
+    let half = FRAME_SIZE as f64 / 2.0;
+    let mut windowed = [0_f64; FRAME_SIZE];
+    for (i, (dst, sample)) in windowed.iter_mut().zip(frame).enumerate() {
+        let x = (i as f64 - half) / half;
+        *dst = *sample as f64 * (1.0 - x * x);
+    }
+
+    std::array::from_fn(|lag| {
+        windowed[lag..]
+            .iter()
+            .zip(&windowed)
+            .map(|(a, b)| a * b)
+            .sum()
+    })
+}
+
+/// Levinson-Durbin recursion.
+///
+/// Row `order - 1` of the result holds the predictor coefficients for
+/// `order`, where coefficient `j` multiplies the sample `j + 1` steps back.
+pub(crate) fn levinson(autocorrelation: &[f64; MAX_ORDER + 1]) -> [[f64; MAX_ORDER]; MAX_ORDER] {
+    let mut coefficients = [[0_f64; MAX_ORDER]; MAX_ORDER];
+    let mut current = [0_f64; MAX_ORDER];
+    let mut error = autocorrelation[0];
+
+    for order in 0..MAX_ORDER {
+        if error <= 0.0 {
+            // the signal is fully predicted, higher orders cannot improve on it
+            coefficients[order] = current;
+            continue;
+        }
+
+        let mut reflection = autocorrelation[order + 1];
+        for j in 0..order {
+            reflection -= current[j] * autocorrelation[order - j];
+        }
+        reflection /= error;
+
+        let previous = current;
+        current[order] = reflection;
+        for j in 0..order {
+            current[j] = previous[j] - reflection * previous[order - 1 - j];
+        }
+
+        error *= 1.0 - reflection * reflection;
+        coefficients[order] = current;
+    }
+
+    coefficients
+}
+
+/// Quantizes `coefficients` to [`COEFFICIENT_PRECISION`] bits.
+///
+/// Returns the shift that scales the integer predictor back down. The
+/// rounding error is carried into the next coefficient, like FLAC does.
+pub(crate) fn quantize(coefficients: &[f64], quantized: &mut [i16]) -> u32 {
+    let limit = (1_i32 << (COEFFICIENT_PRECISION - 1)) - 1;
+    let max = coefficients.iter().fold(0_f64, |max, c| max.max(c.abs()));
+
+    let shift = if max > 0.0 {
+        let log2 = max.log2().floor() as i32 + 1;
+        (COEFFICIENT_PRECISION as i32 - 1 - log2).clamp(0, MAX_SHIFT as i32) as u32
+    } else {
+        0
+    };
+
+    let scale = (1_u32 << shift) as f64;
+    let mut error = 0_f64;
+    for (dst, coefficient) in quantized.iter_mut().zip(coefficients) {
+        error += coefficient * scale;
+        let value = (error.round() as i32).clamp(-limit - 1, limit);
+        error -= value as f64;
+        *dst = value as i16;
+    }
+
+    shift
+}
+
+/// Predicts the sample following `history` (oldest first).
+///
+/// The prediction is clamped to the 16 bit range so residuals always fit in
+/// 17 bits. The encoder and decoder share this function, which keeps the
+/// round trip exact.
+#[inline(always)]
+pub(crate) fn predict(coefficients: &[i16], shift: u32, history: &[i16]) -> i32 {
+    let sum: i64 = coefficients
+        .iter()
+        .zip(history.iter().rev())
+        .map(|(c, s)| *c as i64 * *s as i64)
+        .sum();
+    (sum >> shift).clamp(i16::MIN as i64, i16::MAX as i64) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levinson_finds_a_second_order_resonator() {
+        // a pure sine satisfies x[n] = 2cos(w) x[n-1] - x[n-2]
+        let w = 2.0 * std::f64::consts::PI * 440.0 / 48_000.0;
+        let frame: [i16; FRAME_SIZE] =
+            std::array::from_fn(|i| ((i as f64 * w).sin() * 10_000.0) as i16);
+        let coefficients = levinson(&autocorrelation(&frame));
+
+        assert!((coefficients[1][0] - 2.0 * w.cos()).abs() < 0.05);
+        assert!((coefficients[1][1] + 1.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn quantize_uses_the_full_precision() {
+        let mut quantized = [0_i16; 2];
+        let shift = quantize(&[1.99, -0.99], &mut quantized);
+        assert_eq!(shift, 12);
+        assert!((quantized[0] as f64 / 4096.0 - 1.99).abs() < 0.001);
+        assert!((quantized[1] as f64 / 4096.0 + 0.99).abs() < 0.001);
+    }
+
+    #[test]
+    fn prediction_is_clamped() {
+        let history = [i16::MAX; 2];
+        assert_eq!(predict(&[8_191, 8_191], 0, &history), i16::MAX as i32);
+        assert_eq!(predict(&[-8_192, -8_192], 0, &history), i16::MIN as i32);
+    }
+}
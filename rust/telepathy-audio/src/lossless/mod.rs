@@ -0,0 +1,77 @@
+//! # Lossless Codec - Streaming LPC + Rice Encoder/Decoder
+//!
+//! A FLAC-style lossless codec for real-time streaming audio. Every frame of
+//! [`FRAME_SIZE`](crate::constants::FRAME_SIZE) samples is predicted with
+//! a linear predictor and the prediction residuals are Rice coded. Decoded
+//! samples are bit-identical to the input.
+//!
+//! Multichannel frames are coded as one interleaved sequence; the higher
+//! predictor orders reach back to the previous sample of the same channel.
+//!
+//! ## Frame Format
+//!
+//! Frames are self-contained so a lost datagram never affects its neighbours.
+//! The first byte selects the frame kind:
+//!
+//! | Kind | Layout |
+//! |------|--------|
+//! | Constant | `0`, sample (`i16` LE) |
+//! | Verbatim | `1`, [`FRAME_SIZE`](crate::constants::FRAME_SIZE) samples (`i16` LE) |
+//! | LPC | `2`, order, shift, coefficients (`i16` LE), warm-up samples (`i16` LE), Rice coded residuals |
+//!
+//! The Rice coded residuals are split into [`PARTITIONS`] equal partitions,
+//! each starting with a 5 bit Rice parameter. The first partition skips the
+//! warm-up samples. The encoder falls back to a verbatim frame whenever the
+//! prediction would not save space, so a frame is never larger than one byte
+//! over raw PCM.
+//!
+//! ## Module Structure
+//!
+//! - [`encoder`] — Public streaming encoder API
+//! - [`decoder`] — Public streaming decoder API
+//! - `lpc` — Autocorrelation, Levinson-Durbin recursion and prediction
+//! - `rice` — Bit level Rice coding
+
+pub mod decoder;
+pub mod encoder;
+mod lpc;
+mod rice;
+
+use std::fmt;
+
+pub(crate) const KIND_CONSTANT: u8 = 0;
+pub(crate) const KIND_VERBATIM: u8 = 1;
+pub(crate) const KIND_LPC: u8 = 2;
+
+/// Number of Rice partitions per frame.
+pub const PARTITIONS: usize = 8;
+
+/// Bits used to store each partition's Rice parameter.
+pub(crate) const RICE_PARAMETER_BITS: u32 = 5;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LosslessError {
+    /// The frame ended before all samples were read.
+    Truncated,
+    /// The frame kind byte is not known.
+    UnknownKind(u8),
+    /// The predictor order or shift is out of range.
+    InvalidPredictor,
+    /// A decoded sample does not fit in 16 bits.
+    SampleOutOfRange,
+}
+
+impl fmt::Display for LosslessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LosslessError::Truncated => f.write_str("lossless frame is truncated"),
+            LosslessError::UnknownKind(kind) => write!(f, "unknown lossless frame kind {}", kind),
+            LosslessError::InvalidPredictor => f.write_str("invalid lossless predictor"),
+            LosslessError::SampleOutOfRange => {
+                f.write_str("lossless sample is out of the 16 bit range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LosslessError {}
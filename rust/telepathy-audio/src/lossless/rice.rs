@@ -0,0 +1,180 @@
+use crate::lossless::LosslessError;
+use bytes::{BufMut, BytesMut};
+
+/// Largest Rice parameter the encoder considers.
+///
+/// Residuals are bounded by the clamped prediction to 17 bits, so larger
+/// parameters never pay off.
+pub(crate) const MAX_RICE_PARAMETER: u32 = 17;
+
+/// Maps signed residuals onto unsigned values: 0, -1, 1, -2, 2, ...
+#[inline(always)]
+pub(crate) fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+#[inline(always)]
+pub(crate) fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Returns the cheapest Rice parameter for `values` and its cost in bits.
+pub(crate) fn best_parameter(values: &[u32]) -> (u32, usize) {
+    let mut best = (0, usize::MAX);
+
+    for parameter in 0..=MAX_RICE_PARAMETER {
+        let bits = values
+            .iter()
+            .map(|value| (value >> parameter) as usize + 1 + parameter as usize)
+            .sum();
+
+        // the cost is convex in the parameter, stop once it starts rising
+        if bits > best.1 {
+            break;
+        }
+        best = (parameter, bits);
+    }
+
+    best
+}
+
+/// Writes bits MSB first into a [`BytesMut`].
+pub(crate) struct BitWriter<'a> {
+    output: &'a mut BytesMut,
+    carry: u64,
+    bits_stored: u32,
+}
+
+impl<'a> BitWriter<'a> {
+    pub(crate) fn new(output: &'a mut BytesMut) -> Self {
+        Self {
+            output,
+            carry: 0,
+            bits_stored: 0,
+        }
+    }
+
+    /// Writes the low `count` bits of `value`, `count` must be at most 32.
+    #[inline(always)]
+    pub(crate) fn write_bits(&mut self, value: u32, count: u32) {
+        debug_assert!(count <= 32);
+        let mask = (1_u64 << count) - 1;
+        self.carry = (self.carry << count) | (value as u64 & mask);
+        self.bits_stored += count;
+
+        while self.bits_stored >= 8 {
+            self.bits_stored -= 8;
+            self.output.put_u8((self.carry >> self.bits_stored) as u8);
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn write_rice(&mut self, value: u32, parameter: u32) {
+        let mut quotient = value >> parameter;
+        while quotient >= 32 {
+            self.write_bits(0, 32);
+            quotient -= 32;
+        }
+        self.write_bits(1, quotient + 1);
+        self.write_bits(value, parameter);
+    }
+
+    /// Pads the last partial byte with zeros.
+    pub(crate) fn finish(mut self) {
+        if self.bits_stored > 0 {
+            let padding = 8 - self.bits_stored;
+            self.write_bits(0, padding);
+        }
+    }
+}
+
+/// Reads bits MSB first from a byte slice.
+pub(crate) struct BitReader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub(crate) fn new(input: &'a [u8]) -> Self {
+        Self { input, position: 0 }
+    }
+
+    #[inline(always)]
+    fn read_bit(&mut self) -> Result<bool, LosslessError> {
+        let byte = self
+            .input
+            .get(self.position / 8)
+            .ok_or(LosslessError::Truncated)?;
+        let bit = (byte >> (7 - self.position % 8)) & 1;
+        self.position += 1;
+        Ok(bit == 1)
+    }
+
+    #[inline(always)]
+    pub(crate) fn read_bits(&mut self, count: u32) -> Result<u64, LosslessError> {
+        let mut value = 0;
+        for _ in 0..count {
+            value = (value << 1) | self.read_bit()? as u64;
+        }
+        Ok(value)
+    }
+
+    #[inline(always)]
+    pub(crate) fn read_rice(&mut self, parameter: u32) -> Result<u64, LosslessError> {
+        let mut quotient = 0_u64;
+        while !self.read_bit()? {
+            quotient += 1;
+        }
+        Ok((quotient << parameter) | self.read_bits(parameter)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zigzag_round_trips_the_residual_range() {
+        for value in [0, -1, 1, -2, 2, 65_535, -65_535] {
+            assert_eq!(unzigzag(zigzag(value) as u64), value as i64);
+        }
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+    }
+
+    #[test]
+    fn rice_codes_round_trip() {
+        let values = [0_u32, 1, 7, 8, 100, 131_070, 3];
+        let mut buffer = BytesMut::new();
+        let mut writer = BitWriter::new(&mut buffer);
+        for parameter in [0, 3, 12] {
+            writer.write_bits(parameter, 5);
+            for value in values {
+                writer.write_rice(value, parameter);
+            }
+        }
+        writer.finish();
+
+        let mut reader = BitReader::new(&buffer);
+        for parameter in [0, 3, 12] {
+            assert_eq!(reader.read_bits(5).unwrap(), parameter as u64);
+            for value in values {
+                assert_eq!(reader.read_rice(parameter).unwrap(), value as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn reader_reports_truncation() {
+        let mut reader = BitReader::new(&[0, 0]);
+        assert_eq!(reader.read_rice(2), Err(LosslessError::Truncated));
+    }
+
+    #[test]
+    fn best_parameter_matches_value_magnitude() {
+        assert_eq!(best_parameter(&[0; 16]).0, 0);
+        let (parameter, bits) = best_parameter(&[1_000; 16]);
+        assert!((8..=10).contains(&parameter));
+        assert!(bits < 16 * 16);
+    }
+}
@@ -1,12 +1,15 @@
 //! Audio player module for playing WAV and SEA codec files.
 //!
 //! This module provides a framework-agnostic audio player with support for:
-//! - WAV file playback with multiple sample formats (U8, I16, I32, F32, F64)
+//! - WAV file playback with multiple sample formats (U8, I16, I24, I32, F32, F64),
+//!   including `WAVE_FORMAT_EXTENSIBLE` files and files with extra RIFF chunks
 //! - SEA codec file playback with automatic decoding
 //! - Automatic resampling to match output device sample rate
 //! - Volume control with decibel-based API
 //! - Smooth fade in/out to prevent audio clicks
 //! - Cancellation support with graceful fade-out
+//! - Pause, resume, seeking and looping through the [`SoundHandle`]
+//! - Overlapping sounds mixed into one lazily opened output stream
 //! - Platform support for both native and WebAssembly targets
 //!
 //! ## Usage
@@ -22,11 +25,16 @@
 //!     let bytes = std::fs::read("sound.wav").unwrap();
 //!     let handle = player.play(bytes).await.unwrap();
 //!
+//!     // Loop until canceled, starting one second in
+//!     handle.set_looping(true);
+//!     handle.seek(std::time::Duration::from_secs(1));
+//!
 //!     // Optionally cancel playback
 //!     handle.cancel();
 //! }
 //! ```
 
+use crate::codec;
 use crate::devices::CpalAudioHost;
 use crate::devices::DeviceDirection;
 use crate::devices::DeviceError;
@@ -38,6 +46,7 @@ use crate::internal::processing::wide_mul;
 use crate::internal::thread::safe_spawn;
 use crate::internal::traits::CHANNEL_SIZE;
 use crate::internal::utils::{db_to_multiplier, resampler_factory};
+use crate::io::SendStream;
 use crate::sea::codec::file::SeaFileHeader;
 use crate::sea::decoder::SeaDecoder;
 use crate::sea::encoder::{EncoderSettings, SeaEncoder};
@@ -46,22 +55,29 @@ use audioadapter_buffers::direct::InterleavedSlice;
 use bytes::BytesMut;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use cpal::{DeviceId, Host, SampleFormat};
+use crossbeam::channel::{Receiver, Sender, bounded, unbounded};
 use nnnoiseless::FRAME_SIZE;
 use rtrb::RingBuffer;
 use rubato::{FixedSync, Resampler};
 use std::mem;
+use std::ops::Range;
 use std::sync::Arc;
 #[cfg(not(target_family = "wasm"))]
 use std::sync::Condvar;
 #[cfg(not(target_family = "wasm"))]
 use std::sync::Mutex as SyncMutex;
-use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use std::sync::atomic::{
+    AtomicBool, AtomicU64, AtomicUsize,
+    Ordering::{Acquire, Relaxed, Release},
+};
 use std::time::Duration;
 #[cfg(not(target_family = "wasm"))]
 use std::time::Instant;
 use tokio::select;
 use tokio::sync::oneshot;
 use tokio::sync::{Mutex, Notify};
+#[cfg(not(target_family = "wasm"))]
+use tokio::time::sleep;
 use tracing::{debug, error, info};
 #[cfg(target_family = "wasm")]
 use wasm_bindgen_futures::spawn_local;
@@ -69,12 +85,25 @@ use wasm_bindgen_futures::spawn_local;
 use wasm_sync::{Condvar, Mutex as SyncMutex};
 #[cfg(target_family = "wasm")]
 use wasmtimer::std::Instant;
+#[cfg(target_family = "wasm")]
+use wasmtimer::tokio::sleep;
 
 /// Bound accumulated decoded SEA data before offloading playback work.
 const MAX_DECODED_SEA_FRAMES: usize = 60 * 48_000 / FRAME_SIZE;
 /// Number of frames to fade out when canceling playback.
 /// This prevents audio pops/clicks when stopping playback abruptly.
 const FADE_FRAMES: usize = 60;
+/// Sentinel for [`PlaybackControl::seek`] when no seek is pending.
+const NO_SEEK: usize = usize::MAX;
+/// Pauses, resumes and seeks ramp the volume over 1/200th of a second.
+const RAMP_RATE: f32 = 200_f32;
+/// The shared output stream closes after this long without playback.
+pub const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Voices the shared output stream mixes at once, more wait for a free slot.
+const VOICE_CAPACITY: usize = 16;
+/// How often a sound frees the voices its stream handed back while it waits
+/// for its own voice to leave the mix.
+const RETIRE_INTERVAL: Duration = Duration::from_millis(10);
 
 /// Iterator-like source of interleaved `f32` audio frames.
 ///
@@ -95,6 +124,9 @@ trait AudioFrameSource {
 
     /// Number of audio channels.
     fn channels(&self) -> usize;
+
+    /// Moves the read position to `frame` (per channel), clamped to the end.
+    fn seek(&mut self, frame: usize);
 }
 
 impl<T: AudioFrameSource + ?Sized> AudioFrameSource for Box<T> {
@@ -109,12 +141,17 @@ impl<T: AudioFrameSource + ?Sized> AudioFrameSource for Box<T> {
     fn channels(&self) -> usize {
         (**self).channels()
     }
+
+    fn seek(&mut self, frame: usize) {
+        (**self).seek(frame)
+    }
 }
 
 /// Trait for converting audio samples from various formats to a target type.
 ///
 /// This trait provides methods for converting from each WAV sample format
-/// (U8, I16, I32, F32, F64) to the target type. It handles the appropriate
+/// (U8, I16, I24, I32, F32, F64) to the target type. 24-bit samples are
+/// shifted into the top of an `i32` and converted as I32. It handles the appropriate
 /// scaling and normalization for each format.
 ///
 /// # Implementations
@@ -191,15 +228,22 @@ impl SampleConversion for f32 {
 
 struct WavFrameSource {
     bytes: Vec<u8>,
+    data: Range<usize>, // The samples of the data chunk
     sample_format: SampleFormat,
     channels: usize,
     position: usize, // Current byte offset in the data section
 }
 
 impl WavFrameSource {
-    fn new(bytes: Vec<u8>, sample_format: SampleFormat, channels: usize) -> Self {
+    fn new(
+        bytes: Vec<u8>,
+        data: Range<usize>,
+        sample_format: SampleFormat,
+        channels: usize,
+    ) -> Self {
         Self {
             bytes,
+            data,
             sample_format,
             channels,
             position: 0,
@@ -213,13 +257,13 @@ impl AudioFrameSource for WavFrameSource {
             return Err(Error::Processing(ProcessingError::ZeroChannelFrameSource));
         }
 
-        let data_start = 44usize.saturating_add(self.position);
-        if data_start >= self.bytes.len() {
+        let data_start = self.data.start.saturating_add(self.position);
+        if data_start >= self.data.end {
             return Ok(None);
         }
 
-        let chunk_size = FRAME_SIZE * self.sample_format.sample_size();
-        let data_end = (data_start + chunk_size).min(self.bytes.len());
+        let chunk_size = FRAME_SIZE * wav_sample_size(self.sample_format);
+        let data_end = (data_start + chunk_size).min(self.data.end);
         let chunk = &self.bytes[data_start..data_end];
 
         self.position = self.position.saturating_add(chunk_size);
@@ -238,19 +282,23 @@ impl AudioFrameSource for WavFrameSource {
             return 0;
         }
 
-        let data_len = self.bytes.len().saturating_sub(44);
-        data_len / self.sample_format.sample_size() / self.channels
+        self.data.len() / wav_sample_size(self.sample_format) / self.channels
     }
 
     fn channels(&self) -> usize {
         self.channels
     }
+
+    fn seek(&mut self, frame: usize) {
+        let frame = frame.min(self.total_frame_count());
+        self.position = frame * self.channels * wav_sample_size(self.sample_format);
+    }
 }
 
 struct DecodedFrameSource {
     samples: Vec<[i16; FRAME_SIZE]>,
     channels: usize,
-    position: usize, // Current frame index
+    position: usize, // Current sample frame (per channel)
 }
 
 impl DecodedFrameSource {
@@ -269,19 +317,22 @@ impl AudioFrameSource for DecodedFrameSource {
             return Err(Error::Processing(ProcessingError::ZeroChannelFrameSource));
         }
 
-        if self.position >= self.samples.len() {
+        // a seek may start mid frame, so samples are read across frame boundaries
+        let start = self.position * self.channels;
+        let end = (start + FRAME_SIZE).min(self.samples.len() * FRAME_SIZE);
+        if start >= end {
             return Ok(None);
         }
 
-        let samples_frame = &self.samples[self.position];
-        self.position += 1;
-
         let scale = 1_f32 / i16::MAX as f32;
-        for (sample, output) in samples_frame.iter().zip(output.iter_mut()) {
-            *output = (*sample as f32) * scale;
+        output.fill(0_f32);
+        for (index, output) in (start..end).zip(output.iter_mut()) {
+            *output = (self.samples[index / FRAME_SIZE][index % FRAME_SIZE] as f32) * scale;
         }
 
-        Ok(Some(FRAME_SIZE / self.channels))
+        let frame_count = (end - start) / self.channels;
+        self.position += frame_count;
+        Ok(Some(frame_count))
     }
 
     fn total_frame_count(&self) -> usize {
@@ -295,31 +346,51 @@ impl AudioFrameSource for DecodedFrameSource {
     fn channels(&self) -> usize {
         self.channels
     }
+
+    fn seek(&mut self, frame: usize) {
+        self.position = frame.min(self.total_frame_count());
+    }
 }
 
+/// `fmt ` format tag for integer PCM.
+const WAVE_FORMAT_PCM: u16 = 1;
+/// `fmt ` format tag for IEEE float samples.
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+/// `fmt ` format tag whose real format is the sub-format GUID.
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+/// The trailing 14 bytes shared by every `KSDATAFORMAT_SUBTYPE_*` GUID; the
+/// first two bytes hold the plain format tag.
+const SUBFORMAT_GUID_SUFFIX: [u8; 14] = [
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+];
+
 /// Audio file header information parsed from WAV format.
 struct AudioHeader {
     /// Number of audio channels (1 for mono, 2 for stereo).
     channels: u32,
     /// Sample rate in Hz (e.g., 44100, 48000).
     sample_rate: u32,
-    /// Sample format (U8, I16, I32, F32, F64).
+    /// Sample format (U8, I16, I24, I32, F32, F64).
     sample_format: SampleFormat,
+    /// Byte range of the samples in the `data` chunk.
+    data: Range<usize>,
 }
 
 impl TryFrom<&[u8]> for AudioHeader {
     type Error = Error;
 
-    /// Parses a WAV file header from bytes.
+    /// Parses a whole WAV file.
     ///
-    /// Expects at least 44 bytes (standard WAV header size).
-    /// Supports PCM (format 1) and IEEE float (format 3) formats.
-    /// Validates WAV signature (RIFF/WAVE) and ensures channels/sample_rate are non-zero.
+    /// Walks the RIFF chunks to find `fmt ` and `data` wherever they are,
+    /// skipping `LIST`, `bext`, `fact` and any other chunk. Supports PCM
+    /// (format 1), IEEE float (format 3) and `WAVE_FORMAT_EXTENSIBLE` with a
+    /// PCM or float sub-format. A `data` chunk that runs past the end of the
+    /// file is clamped, as written by recorders that never patch the size.
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        if value.len() < 44 {
+        if value.len() < 12 {
             return Err(Error::AudioFile(AudioFileError::TooShort {
                 actual: value.len(),
-                required: 44,
+                required: 12,
             }));
         }
 
@@ -328,15 +399,82 @@ impl TryFrom<&[u8]> for AudioHeader {
             return Err(Error::AudioFile(AudioFileError::InvalidSignature));
         }
 
-        let bits_per_sample = u16::from_le_bytes([value[34], value[35]]);
-        let audio_format = u16::from_le_bytes([value[20], value[21]]);
+        let mut format = None;
+        let mut data = None;
+        let mut position = 12;
+        while position + 8 <= value.len() && (format.is_none() || data.is_none()) {
+            let id: [u8; 4] = value[position..position + 4].try_into().unwrap_or_default();
+            let declared = u32::from_le_bytes(
+                value[position + 4..position + 8]
+                    .try_into()
+                    .unwrap_or_default(),
+            );
+            let start = position + 8;
+            let available = value.len() - start;
+
+            let size = if (declared as usize) <= available {
+                declared as usize
+            } else if &id == b"data" {
+                available
+            } else {
+                return Err(Error::AudioFile(AudioFileError::TruncatedChunk {
+                    id,
+                    declared,
+                    available,
+                }));
+            };
+
+            match &id {
+                b"fmt " => format = Some(&value[start..start + size]),
+                b"data" => data = Some(start..start + size),
+                _ => (),
+            }
+
+            // chunks are padded to an even size
+            position = start.saturating_add(size).saturating_add(size & 1);
+        }
+
+        let format = format.ok_or(Error::AudioFile(AudioFileError::MissingChunk(*b"fmt ")))?;
+        let data = data.ok_or(Error::AudioFile(AudioFileError::MissingChunk(*b"data")))?;
+
+        if format.len() < 16 {
+            return Err(Error::AudioFile(AudioFileError::FormatChunkTooShort {
+                size: format.len() as u32,
+                required: 16,
+            }));
+        }
+
+        let mut audio_format = u16::from_le_bytes([format[0], format[1]]);
+        let channels = u16::from_le_bytes([format[2], format[3]]) as u32;
+        let sample_rate = u32::from_le_bytes([format[4], format[5], format[6], format[7]]);
+        let block_align = u16::from_le_bytes([format[12], format[13]]);
+        let bits_per_sample = u16::from_le_bytes([format[14], format[15]]);
+
+        if audio_format == WAVE_FORMAT_EXTENSIBLE {
+            // cbSize, valid bits, channel mask and the 16-byte sub-format GUID
+            if format.len() < 40 {
+                return Err(Error::AudioFile(AudioFileError::FormatChunkTooShort {
+                    size: format.len() as u32,
+                    required: 40,
+                }));
+            }
+
+            let guid: [u8; 16] = format[24..40].try_into().unwrap_or_default();
+            if guid[2..] != SUBFORMAT_GUID_SUFFIX {
+                return Err(Error::AudioFile(AudioFileError::UnsupportedSubFormat(guid)));
+            }
+            // samples are stored in containers of `bits_per_sample`, any unused
+            // low bits are zero so the container format plays them as is
+            audio_format = u16::from_le_bytes([guid[0], guid[1]]);
+        }
 
         let sample_format = match (audio_format, bits_per_sample) {
-            (1, 8) => SampleFormat::U8,
-            (1, 16) => SampleFormat::I16,
-            (1, 32) => SampleFormat::I32,
-            (3, 32) => SampleFormat::F32,
-            (3, 64) => SampleFormat::F64,
+            (WAVE_FORMAT_PCM, 8) => SampleFormat::U8,
+            (WAVE_FORMAT_PCM, 16) => SampleFormat::I16,
+            (WAVE_FORMAT_PCM, 24) => SampleFormat::I24,
+            (WAVE_FORMAT_PCM, 32) => SampleFormat::I32,
+            (WAVE_FORMAT_IEEE_FLOAT, 32) => SampleFormat::F32,
+            (WAVE_FORMAT_IEEE_FLOAT, 64) => SampleFormat::F64,
             _ => {
                 return Err(Error::AudioFile(AudioFileError::UnsupportedSampleFormat {
                     audio_format,
@@ -345,9 +483,6 @@ impl TryFrom<&[u8]> for AudioHeader {
             }
         };
 
-        let channels = u16::from_le_bytes([value[22], value[23]]) as u32;
-        let sample_rate = u32::from_le_bytes([value[24], value[25], value[26], value[27]]);
-
         // Validate channels and sample_rate are non-zero to prevent divide-by-zero
         if channels == 0 {
             return Err(Error::AudioFile(AudioFileError::ZeroChannels));
@@ -356,18 +491,42 @@ impl TryFrom<&[u8]> for AudioHeader {
             return Err(Error::AudioFile(AudioFileError::ZeroSampleRate));
         }
 
+        let expected = channels as usize * wav_sample_size(sample_format);
+        if block_align as usize != expected {
+            return Err(Error::AudioFile(AudioFileError::BlockAlignMismatch {
+                block_align,
+                expected,
+            }));
+        }
+
         Ok(Self {
             channels,
             sample_rate,
             sample_format,
+            data,
         })
     }
 }
 
+/// The bytes one sample of `sample_format` occupies in a WAV `data` chunk.
+///
+/// Differs from [`SampleFormat::sample_size`] for 24-bit audio, which WAV
+/// packs into three bytes.
+fn wav_sample_size(sample_format: SampleFormat) -> usize {
+    match sample_format {
+        SampleFormat::I24 => 3,
+        sample_format => sample_format.sample_size(),
+    }
+}
+
 /// Framework-agnostic audio player for WAV and SEA codec files.
 ///
 /// The player handles device selection, volume control, resampling,
 /// and provides cancellation support through `SoundHandle`.
+///
+/// Sounds share one output stream, which the first sound opens and which
+/// closes after [`IDLE_TIMEOUT`] without playback. Overlapping sounds are
+/// mixed into it, each at its own volume.
 pub struct AudioPlayer {
     /// Output volume as a linear multiplier (stored atomically for thread safety).
     output_volume: Arc<AtomicF32>,
@@ -375,6 +534,8 @@ pub struct AudioPlayer {
     output_device: Arc<Mutex<Option<DeviceId>>>,
     /// The cpal audio host for device access.
     host: CpalAudioHost,
+    /// The shared output stream, `None` while the player is idle.
+    stream: Arc<Mutex<Option<Arc<MixingStream>>>>,
 }
 
 impl AudioPlayer {
@@ -398,13 +559,15 @@ impl AudioPlayer {
             output_volume: Arc::new(AtomicF32::new(db_to_multiplier(output_volume_db))),
             output_device: Default::default(),
             host: CpalAudioHost::new(),
+            stream: Default::default(),
         }
     }
 
     /// Plays audio from the provided bytes.
     ///
-    /// Supports both WAV files (with standard 44-byte header) and SEA codec files.
-    /// The format is auto-detected based on header validation.
+    /// Supports both WAV files and SEA codec files. Files starting with
+    /// `RIFF` are parsed as WAV, anything else as SEA. The sound is mixed
+    /// into the player's shared output stream, which is opened if needed.
     ///
     /// # Arguments
     ///
@@ -412,12 +575,13 @@ impl AudioPlayer {
     ///
     /// # Returns
     ///
-    /// A `SoundHandle` that can be used to cancel playback.
+    /// A `SoundHandle` that can be used to pause, seek, loop or cancel playback.
     ///
     /// # Errors
     ///
     /// Returns `AudioError` if:
-    /// - The file is too short (< 44 bytes for WAV, < 14 bytes for SEA)
+    /// - The file is too short (< 14 bytes)
+    /// - The WAV chunks or SEA header are malformed
     /// - No output device is available
     /// - Stream configuration cannot be obtained
     /// - Stream creation fails
@@ -431,32 +595,28 @@ impl AudioPlayer {
             }));
         }
 
-        // Get output device and config before spawning to catch device errors early
-        let output_device = get_output_device(&self.output_device, self.host.inner()).await?;
-        let output_config = output_device.default_output_config().map_err(|source| {
-            Error::Device(DeviceError::DefaultConfig {
-                direction: DeviceDirection::Output,
-                source,
-            })
-        })?;
+        // Open the shared stream before spawning to catch device errors early
+        let stream = self.acquire_stream().await?;
+        let stream_slot = self.stream.clone();
 
         let cancel = Arc::new(Notify::new());
         let cancel_clone = cancel.clone();
         let output_volume = self.output_volume.clone();
+        let control = Arc::new(PlaybackControl::default());
+        let control_clone = control.clone();
 
         // Use a oneshot channel to receive the typed initialization result from
         // the spawned task. The sending side gives up the error after it has
         // crossed the channel; no stringification happens on the wire.
-        let (init_tx, init_rx) = oneshot::channel::<Result<(), Error>>();
+        let (init_tx, init_rx) = oneshot::channel::<Result<SourceInfo, Error>>();
 
-        #[cfg(not(target_family = "wasm"))]
-        let handle = tokio::spawn(async move {
-            if let Err(e) = play_sound_with_device(
+        let playback = async move {
+            if let Err(e) = play_sound(
                 bytes,
                 cancel_clone,
-                output_device,
-                output_config,
+                stream.clone(),
                 output_volume,
+                control_clone,
                 init_tx,
             )
             .await
@@ -465,41 +625,68 @@ impl AudioPlayer {
                 // (initialization errors are already sent via init_tx)
                 debug!("Playback error (after init): {:?}", e);
             }
-        });
+
+            release_stream(&stream_slot, stream).await;
+        };
+
+        #[cfg(not(target_family = "wasm"))]
+        let handle = tokio::spawn(playback);
 
         #[cfg(target_family = "wasm")]
-        spawn_local(async move {
-            if let Err(e) = play_sound_with_device(
-                bytes,
-                cancel_clone,
-                output_device,
-                output_config,
-                output_volume,
-                init_tx,
-            )
-            .await
-            {
-                // Log any errors that occur after initialization
-                // (initialization errors are already sent via init_tx)
-                debug!("Playback error (after init): {:?}", e);
-            }
-        });
+        spawn_local(playback);
 
         // Wait for initialization result from the spawned task
-        // This returns as soon as the stream is built and playing, or on error
+        // This returns as soon as the sound is mixed into the stream, or on error
         match init_rx.await {
             #[cfg(not(target_family = "wasm"))]
-            Ok(Ok(())) => Ok(SoundHandle {
+            Ok(Ok(info)) => Ok(SoundHandle {
                 cancel,
+                control,
+                info,
                 _handle: handle,
             }),
             #[cfg(target_family = "wasm")]
-            Ok(Ok(())) => Ok(SoundHandle { cancel }),
+            Ok(Ok(info)) => Ok(SoundHandle {
+                cancel,
+                control,
+                info,
+            }),
             Ok(Err(e)) => Err(e),
             Err(_) => Err(Error::Task(TaskError::PlaybackInitChannelClosed)),
         }
     }
 
+    /// Returns the shared output stream, opening it on the selected device
+    /// if the player is idle.
+    ///
+    /// The stream counts the caller as active until it is handed back to
+    /// [`release_stream`].
+    async fn acquire_stream(&self) -> Result<Arc<MixingStream>, Error> {
+        let mut slot = self.stream.lock().await;
+
+        let stream = match &*slot {
+            Some(stream) => stream.clone(),
+            None => {
+                let output_device =
+                    get_output_device(&self.output_device, self.host.inner()).await?;
+                let output_config = output_device.default_output_config().map_err(|source| {
+                    Error::Device(DeviceError::DefaultConfig {
+                        direction: DeviceDirection::Output,
+                        source,
+                    })
+                })?;
+
+                let stream = Arc::new(MixingStream::open(&output_device, output_config)?);
+                *slot = Some(stream.clone());
+                stream
+            }
+        };
+
+        stream.active.fetch_add(1, Relaxed);
+        stream.activity.fetch_add(1, Relaxed);
+        Ok(stream)
+    }
+
     /// Updates the output volume.
     ///
     /// # Arguments
@@ -512,11 +699,15 @@ impl AudioPlayer {
 
     /// Sets the output device.
     ///
+    /// Sounds that are already playing finish on the previous device.
+    ///
     /// # Arguments
     ///
     /// * `device_id` - The device ID to use, or `None` for the default device.
     pub async fn set_output_device(&self, device_id: Option<DeviceId>) {
         *self.output_device.lock().await = device_id;
+        // sounds already playing finish on the old stream, new ones reopen
+        *self.stream.lock().await = None;
     }
 
     /// Returns a reference to the audio host.
@@ -527,13 +718,70 @@ impl AudioPlayer {
     }
 }
 
+/// Playback state shared by a [`SoundHandle`], its processor and its stream.
+struct PlaybackControl {
+    /// The stream plays silence without draining the ring buffer while set.
+    paused: AtomicBool,
+    /// The processor restarts the source instead of finishing while set.
+    looping: AtomicBool,
+    /// A pending seek target in source frames, `NO_SEEK` when there is none.
+    seek: AtomicUsize,
+    /// Source frames decoded so far.
+    position: AtomicUsize,
+    /// Samples written before the latest seek; the stream drops them unplayed.
+    discard_until: AtomicU64,
+    /// The volume of this sound as a linear multiplier.
+    volume: AtomicF32,
+    /// Signals the processor when ring buffer space frees up or a seek is pending.
+    space_available: Arc<Condvar>,
+}
+
+impl Default for PlaybackControl {
+    fn default() -> Self {
+        Self {
+            paused: AtomicBool::new(false),
+            looping: AtomicBool::new(false),
+            seek: AtomicUsize::new(NO_SEEK),
+            position: AtomicUsize::new(0),
+            discard_until: AtomicU64::new(0),
+            volume: AtomicF32::new(1_f32),
+            space_available: Arc::new(Condvar::new()),
+        }
+    }
+}
+
+impl PlaybackControl {
+    fn seek_pending(&self) -> bool {
+        self.seek.load(Acquire) != NO_SEEK
+    }
+
+    fn take_seek(&self) -> Option<usize> {
+        match self.seek.swap(NO_SEEK, Acquire) {
+            NO_SEEK => None,
+            frame => Some(frame),
+        }
+    }
+}
+
+/// The length of a source, reported once playback has started.
+#[derive(Clone, Copy)]
+struct SourceInfo {
+    sample_rate: u32,
+    total_frames: usize,
+}
+
 /// Handle for controlling active sound playback.
 ///
-/// The handle allows canceling playback, which triggers a graceful
-/// fade-out to prevent audio pops.
+/// The handle can pause, resume, seek, loop and set the volume of the
+/// sound. Canceling playback triggers a graceful fade-out to prevent
+/// audio pops.
 pub struct SoundHandle {
     /// Notification channel for cancellation.
     cancel: Arc<Notify>,
+    /// Shared pause, seek and loop state.
+    control: Arc<PlaybackControl>,
+    /// The length of the sound.
+    info: SourceInfo,
     /// Task handle (kept alive to ensure playback continues).
     #[cfg(not(target_family = "wasm"))]
     _handle: tokio::task::JoinHandle<()>,
@@ -547,6 +795,70 @@ impl SoundHandle {
     pub fn cancel(&self) {
         self.cancel.notify_one();
     }
+
+    /// Pauses playback, fading out briefly. The output stream stays open.
+    pub fn pause(&self) {
+        self.control.paused.store(true, Relaxed);
+    }
+
+    /// Resumes paused playback where it left off.
+    pub fn resume(&self) {
+        self.control.paused.store(false, Relaxed);
+    }
+
+    /// Returns true while playback is paused.
+    pub fn is_paused(&self) -> bool {
+        self.control.paused.load(Relaxed)
+    }
+
+    /// Jumps to `position`, clamped to the duration of the sound.
+    ///
+    /// Audio buffered before the seek is dropped, so the jump is heard
+    /// immediately, also while paused.
+    pub fn seek(&self, position: Duration) {
+        let frame = (position.as_secs_f64() * self.info.sample_rate as f64) as usize;
+        self.control
+            .seek
+            .store(frame.min(self.info.total_frames), Release);
+        // wake the processor if it is waiting for ring buffer space
+        self.control.space_available.notify_one();
+    }
+
+    /// When enabled the sound restarts from the beginning instead of ending.
+    pub fn set_looping(&self, looping: bool) {
+        self.control.looping.store(looping, Relaxed);
+    }
+
+    /// Returns true while the sound loops.
+    pub fn is_looping(&self) -> bool {
+        self.control.looping.load(Relaxed)
+    }
+
+    /// Sets the volume of this sound in decibels, applied on top of the
+    /// player volume.
+    pub fn set_volume(&self, volume_db: f32) {
+        self.control
+            .volume
+            .store(db_to_multiplier(volume_db), Relaxed);
+    }
+
+    /// The playback position.
+    ///
+    /// This is the position of the most recently decoded audio, which runs
+    /// ahead of the audible position by the output buffer.
+    pub fn position(&self) -> Duration {
+        let frame = self.control.position.load(Relaxed);
+        self.frames_to_duration(frame)
+    }
+
+    /// The total duration of the sound.
+    pub fn duration(&self) -> Duration {
+        self.frames_to_duration(self.info.total_frames)
+    }
+
+    fn frames_to_duration(&self, frames: usize) -> Duration {
+        Duration::from_secs_f64(frames as f64 / self.info.sample_rate as f64)
+    }
 }
 
 /// Blocking ring buffer audio output for native and WASM platforms.
@@ -562,6 +874,12 @@ impl SoundHandle {
 /// The shared `canceled` flag is checked before and after parking on the condvar
 /// so that the producer cannot remain blocked when playback is canceled and the
 /// stream callback stops draining the consumer during fade-out.
+///
+/// ## Seeking
+///
+/// A pending seek is checked the same way. The write is dropped instead of
+/// waiting, since audio from before a seek would be discarded anyway; this
+/// keeps seeks responsive while the stream is paused and not draining.
 struct BlockingRingBufferOutput {
     /// The producer end of the ring buffer for audio samples.
     producer: rtrb::Producer<f32>,
@@ -579,15 +897,27 @@ struct BlockingRingBufferOutput {
     /// Shared cancellation flag – when set, `write_samples_blocking` returns
     /// early instead of waiting for space that may never arrive.
     canceled: Arc<AtomicBool>,
+
+    /// Shared playback state, checked for pending seeks.
+    control: Arc<PlaybackControl>,
+
+    /// Total samples written, used to mark audio made stale by a seek.
+    written: u64,
 }
 
 impl BlockingRingBufferOutput {
-    fn new(producer: rtrb::Producer<f32>, notify: Arc<Condvar>, canceled: Arc<AtomicBool>) -> Self {
+    fn new(
+        producer: rtrb::Producer<f32>,
+        control: Arc<PlaybackControl>,
+        canceled: Arc<AtomicBool>,
+    ) -> Self {
         Self {
             producer,
-            notify,
+            notify: control.space_available.clone(),
             mutex: SyncMutex::new(()),
             canceled,
+            control,
+            written: 0,
         }
     }
 
@@ -595,7 +925,8 @@ impl BlockingRingBufferOutput {
     ///
     /// Returns early with an error when the cancellation flag is set or the
     /// consumer side has been dropped, ensuring the producer thread can never
-    /// remain parked on the condvar after cancellation is signaled.
+    /// remain parked on the condvar after cancellation is signaled. Returns
+    /// early without writing when a seek is pending.
     fn write_samples_blocking(&mut self, samples: &[f32]) -> Result<(), Error> {
         let target = samples.len();
         loop {
@@ -607,6 +938,10 @@ impl BlockingRingBufferOutput {
                 break;
             }
 
+            if self.control.seek_pending() {
+                return Ok(());
+            }
+
             if self.producer.is_abandoned() {
                 return Err(Error::Channel(ChannelError::ConsumerAbandoned));
             }
@@ -624,11 +959,17 @@ impl BlockingRingBufferOutput {
                 break;
             }
 
+            if self.control.seek_pending() {
+                drop(guard);
+                return Ok(());
+            }
+
             drop(self.notify.wait(guard).unwrap());
         }
 
         let chunk = self.producer.write_chunk_uninit(target)?;
         chunk.fill_from_iter(samples.iter().copied());
+        self.written += target as u64;
         Ok(())
     }
 }
@@ -637,60 +978,54 @@ impl BlockingRingBufferOutput {
 ///
 /// This function encodes WAV audio data into the SEA codec format,
 /// which provides efficient compression for audio transmission.
-/// Multichannel audio is automatically downmixed to mono by averaging
-/// all channels.
+/// Channel layouts supported by the codec (see
+/// [`supports_channels`](crate::codec::supports_channels)) are kept; any
+/// other layout is downmixed to mono by averaging all channels.
 ///
 /// # Arguments
 ///
-/// * `bytes` - The WAV file bytes (must include 44-byte header).
+/// * `bytes` - The WAV file bytes.
 /// * `residual_bits` - Quality parameter for encoding (higher = better quality).
 ///
 /// # Returns
 ///
-/// The encoded SEA file bytes (mono, regardless of input channel count).
+/// The encoded SEA file bytes.
 ///
 /// # Errors
 ///
 /// Returns `AudioError` if the WAV data is invalid or encoding fails.
 pub async fn wav_to_sea(bytes: Vec<u8>, residual_bits: f32) -> Result<Vec<u8>, Error> {
-    if bytes.len() < 44 {
-        return Err(Error::AudioFile(AudioFileError::TooShort {
-            actual: bytes.len(),
-            required: 44,
-        }));
-    }
-
-    let spec = AudioHeader::try_from(&bytes[0..44])?;
+    let spec = AudioHeader::try_from(bytes.as_slice())?;
     let channels = spec.channels;
     let sample_rate = spec.sample_rate;
+    let sample_format = spec.sample_format;
+    let sample_size = wav_sample_size(sample_format);
+    let data = spec.data;
 
-    let sample_size = match spec.sample_format {
-        SampleFormat::U8 => 1,
-        SampleFormat::I16 => 2,
-        SampleFormat::I32 | SampleFormat::F32 => 4,
-        SampleFormat::F64 => 8,
+    // keep the channels when every frame splits into whole codec blocks
+    let sea_channels = match u16::try_from(channels) {
+        Ok(channels) if codec::supports_channels(channels) => channels,
         _ => 1,
     };
-
-    let sample_format = spec.sample_format;
+    let frames_per_chunk = FRAME_SIZE / sea_channels as usize;
 
     spawn_cpu_task(move || {
         let settings = EncoderSettings {
-            frames_per_chunk: FRAME_SIZE as u16,
+            frames_per_chunk: frames_per_chunk as u16,
             vbr: true,
             residual_bits,
             ..Default::default()
         };
 
-        let mut encoder = SeaEncoder::new(1, sample_rate, settings)?;
+        let mut encoder = SeaEncoder::new(sea_channels as u8, sample_rate, settings)?;
 
         let mut samples = [0_i16; FRAME_SIZE];
         let mut buffer = BytesMut::new();
         let mut data: Vec<u8> = SeaFileHeader {
             version: 1,
-            channels: 1,
+            channels: sea_channels as u8,
             chunk_size: 0,
-            frames_per_chunk: FRAME_SIZE as u16,
+            frames_per_chunk: frames_per_chunk as u16,
             sample_rate,
         }
         .serialize()
@@ -699,7 +1034,15 @@ pub async fn wav_to_sea(bytes: Vec<u8>, residual_bits: f32) -> Result<Vec<u8>, E
         // Create an interleaved scratch buffer for channel-aware unpacking.
         let mut buf: Vec<i16> = vec![0; FRAME_SIZE * channels as usize];
 
-        for chunk in bytes[44..].chunks(FRAME_SIZE * sample_size * channels as usize) {
+        for chunk in bytes[data].chunks(frames_per_chunk * sample_size * channels as usize) {
+            if sea_channels > 1 {
+                // the interleaved samples are encoded as they are
+                unpack_wav_frame(chunk, sample_format, channels as usize, &mut samples)?;
+                encoder.encode_frame(samples, &mut buffer)?;
+                data.extend_from_slice(buffer.as_ref());
+                continue;
+            }
+
             // Unpack interleaved bytes into an interleaved sample buffer.
             let frames = unpack_wav_frame(chunk, sample_format, channels as usize, &mut buf)?;
 
@@ -746,71 +1089,318 @@ async fn get_output_device(
         .ok_or(Error::Device(DeviceError::NoOutputDevice))
 }
 
-/// Internal play sound function with pre-obtained device and config.
+/// The output stream shared by the sounds of an [`AudioPlayer`].
+///
+/// Each sound is handed to the stream callback as a [`Voice`] and mixed
+/// there, so overlapping sounds never open more than one device stream.
+struct MixingStream {
+    /// The device stream, kept alive while sounds may play.
+    _stream: SendStream,
+    /// Hands new voices to the stream callback.
+    voices: Sender<Voice>,
+    /// Voices the stream callback has finished with, freed off the audio thread.
+    retired: Receiver<Voice>,
+    /// The stream's channel count.
+    channels: usize,
+    /// The stream's sample rate.
+    sample_rate: u32,
+    /// Sounds currently using the stream.
+    active: AtomicUsize,
+    /// Bumped whenever a sound starts or ends, so an idle check can tell
+    /// whether the stream was used while it waited.
+    activity: AtomicU64,
+}
+
+impl MixingStream {
+    /// Opens and starts an output stream that mixes every voice sent to it.
+    fn open(
+        output_device: &cpal::Device,
+        output_config: cpal::SupportedStreamConfig,
+    ) -> Result<Self, Error> {
+        let channels = output_config.channels() as usize;
+        let sample_rate = output_config.sample_rate();
+        let (voices, receiver) = unbounded::<Voice>();
+        let (retire, retired) = bounded::<Voice>(VOICE_CAPACITY);
+        let mut playing: Vec<Voice> = Vec::with_capacity(VOICE_CAPACITY);
+
+        let stream = output_device
+            .build_output_stream(
+                output_config.into(),
+                move |output: &mut [f32], _| {
+                    mix_voices(&mut playing, &receiver, &retire, output);
+                },
+                move |err| {
+                    error!("Error in player stream: {}", err);
+                },
+                None,
+            )
+            .map_err(|source| {
+                Error::Stream(StreamError::BuildOutputStream {
+                    config: Some(output_config),
+                    source,
+                })
+            })?;
+
+        stream.play().map_err(|source| {
+            Error::Stream(StreamError::Play {
+                direction: StreamDirection::Output,
+                source,
+            })
+        })?; // Play the stream
+
+        debug!("opened player stream");
+        Ok(Self {
+            _stream: SendStream(stream),
+            voices,
+            retired,
+            channels,
+            sample_rate,
+            active: AtomicUsize::new(0),
+            activity: AtomicU64::new(0),
+        })
+    }
+}
+
+impl MixingStream {
+    /// Frees the voices the stream callback has handed back, notifying the
+    /// sounds they belonged to.
+    fn free_retired(&self) {
+        self.retired.try_iter().for_each(drop);
+    }
+}
+
+/// Mixes `playing` into `output` on the stream's real-time thread.
+///
+/// Nothing is allocated or freed here: at most [`VOICE_CAPACITY`] voices are
+/// taken from `incoming`, and voices that have faded out are handed to
+/// `retire` instead of being dropped. A voice stays in the mix, silent, while
+/// `retire` is full.
+fn mix_voices(
+    playing: &mut Vec<Voice>,
+    incoming: &Receiver<Voice>,
+    retire: &Sender<Voice>,
+    output: &mut [f32],
+) {
+    let free = VOICE_CAPACITY.saturating_sub(playing.len());
+    playing.extend(incoming.try_iter().take(free));
+
+    output.fill(0_f32);
+    let mut index = 0;
+    while index < playing.len() {
+        if playing[index].mix_into(output) || retire.is_full() {
+            index += 1;
+        } else {
+            // only fails once the stream is closing
+            let _ = retire.try_send(playing.swap_remove(index));
+        }
+    }
+    for sample in output.iter_mut() {
+        *sample = sample.clamp(-1_f32, 1_f32);
+    }
+}
+
+/// A sound playing through a [`MixingStream`].
+///
+/// The voice reads the samples its processor produced at the stream's
+/// rate and layout, and leaves the mix once it has faded out.
+struct Voice {
+    /// Samples from the processor.
+    consumer: rtrb::Consumer<f32>,
+    /// Pause, seek and volume state of the sound.
+    control: Arc<PlaybackControl>,
+    /// Set when the sound is canceled or has ended; the voice then fades out.
+    canceled: Arc<AtomicBool>,
+    /// Set once the processor has written its last samples.
+    finished: Arc<AtomicBool>,
+    /// Notified when the voice is freed after leaving the mix.
+    removed: Arc<Notify>,
+    /// The last frame contributed, held through underruns and faded on cancel.
+    last_samples: Vec<f32>,
+    /// The number of fade-out frames played so far.
+    fade: usize,
+    /// Fades the voice out on pause and back in on resume.
+    pause_gain: f32,
+    /// The per-frame step of `pause_gain`.
+    pause_step: f32,
+    /// The stream's sample rate, used to fade out canceled voices.
+    sample_rate: f32,
+    /// Samples consumed from the ring buffer, compared against seeks.
+    read: u64,
+}
+
+impl Voice {
+    fn new(
+        consumer: rtrb::Consumer<f32>,
+        control: Arc<PlaybackControl>,
+        canceled: Arc<AtomicBool>,
+        finished: Arc<AtomicBool>,
+        channels: usize,
+        sample_rate: u32,
+    ) -> Self {
+        Self {
+            consumer,
+            control,
+            canceled,
+            finished,
+            removed: Arc::new(Notify::new()),
+            last_samples: vec![0_f32; channels],
+            fade: 0,
+            pause_gain: 1_f32,
+            pause_step: RAMP_RATE / sample_rate as f32,
+            sample_rate: sample_rate as f32,
+            read: 0,
+        }
+    }
+
+    /// Adds the voice's next frames to `output`.
+    ///
+    /// Returns false once the voice has fully faded out.
+    fn mix_into(&mut self, output: &mut [f32]) -> bool {
+        let channels = self.last_samples.len();
+        let mut canceled = self.canceled.load(Relaxed);
+
+        // Drop audio buffered before the latest seek
+        let discard_until = self.control.discard_until.load(Acquire);
+        if self.read < discard_until {
+            let stale = self
+                .consumer
+                .slots()
+                .min((discard_until - self.read) as usize);
+            if let Ok(chunk) = self.consumer.read_chunk(stale) {
+                chunk.commit_all();
+            }
+            self.read += stale as u64;
+            self.control.space_available.notify_one();
+        }
+
+        let paused = self.control.paused.load(Relaxed);
+        let volume = self.control.volume.load(Relaxed);
+        for frame in output.chunks_mut(channels) {
+            if canceled {
+                // After the full fade the voice leaves the mix
+                if self.fade == FADE_FRAMES {
+                    break;
+                }
+
+                // Fade each sample
+                for sample in &mut self.last_samples {
+                    *sample *= (1_f32 - self.fade as f32 / self.sample_rate).max(0_f32);
+                }
+
+                // Play the samples
+                for (out, sample) in frame.iter_mut().zip(&self.last_samples) {
+                    *out += sample;
+                }
+                // Advance the counter
+                self.fade += 1;
+            } else if paused && self.pause_gain == 0_f32 {
+                // Hold the buffered audio until playback resumes
+                continue;
+            } else {
+                self.pause_gain = if paused {
+                    (self.pause_gain - self.pause_step).max(0_f32)
+                } else {
+                    (self.pause_gain + self.pause_step).min(1_f32)
+                };
+
+                match self.consumer.read_chunk(channels) {
+                    Ok(chunk) => {
+                        let gain = self.pause_gain * volume;
+                        for (sample, last) in chunk.into_iter().zip(&mut self.last_samples) {
+                            *last = sample * gain;
+                        }
+                        self.read += channels as u64;
+                        self.control.space_available.notify_one();
+                    }
+                    Err(_) => {
+                        // If the processor has finished and the buffer is empty, begin fading out.
+                        // If the processor is still running, hold the last samples (prevents pops).
+                        if self.finished.load(Relaxed) {
+                            self.canceled.store(true, Relaxed);
+                            canceled = true;
+                        }
+                    }
+                }
+
+                for (out, sample) in frame.iter_mut().zip(&self.last_samples) {
+                    *out += sample;
+                }
+            }
+        }
+
+        self.fade < FADE_FRAMES
+    }
+}
+
+impl Drop for Voice {
+    fn drop(&mut self) {
+        self.removed.notify_one();
+    }
+}
+
+/// Hands a stream acquired by [`AudioPlayer::acquire_stream`] back once a
+/// sound has ended, closing it after [`IDLE_TIMEOUT`] if nothing else
+/// used it in the meantime.
+async fn release_stream(slot: &Mutex<Option<Arc<MixingStream>>>, stream: Arc<MixingStream>) {
+    stream.active.fetch_sub(1, Relaxed);
+    let activity = stream.activity.fetch_add(1, Relaxed) + 1;
+
+    sleep(IDLE_TIMEOUT).await;
+
+    // acquire_stream holds the lock while counting a new sound, so the
+    // stream cannot be handed out while it is being closed
+    let mut slot = slot.lock().await;
+    let idle = slot
+        .as_ref()
+        .is_some_and(|current| Arc::ptr_eq(current, &stream))
+        && stream.active.load(Relaxed) == 0
+        && stream.activity.load(Relaxed) == activity;
+    if idle {
+        debug!("closing idle player stream");
+        *slot = None;
+    }
+}
+
+/// Internal play sound function with a pre-acquired output stream.
 ///
-/// This function is called after preflight validation and device acquisition
+/// This function is called after preflight validation and stream acquisition
 /// have been completed by the caller. It signals initialization success or failure
 /// via the `init_tx` channel before continuing with playback.
-async fn play_sound_with_device(
+async fn play_sound(
     mut bytes: Vec<u8>,
     cancel: Arc<Notify>,
-    output_device: cpal::Device,
-    output_config: cpal::SupportedStreamConfig,
+    stream: Arc<MixingStream>,
     output_volume: Arc<AtomicF32>,
-    init_tx: oneshot::Sender<Result<(), Error>>,
+    control: Arc<PlaybackControl>,
+    init_tx: oneshot::Sender<Result<SourceInfo, Error>>,
 ) -> Result<(), Error> {
     // Perform initialization and signal result to caller
     // This inner async block allows us to use ? while still signaling errors
     let init_result: Result<_, Error> = async {
-        // Parse the input spec (only attempt WAV parsing if we have enough bytes)
-        let spec_result = if bytes.len() >= 44 {
-            AudioHeader::try_from(&bytes[0..44])
+        // Parse the input spec, anything that is not a RIFF file is SEA
+        let is_wav = bytes.starts_with(b"RIFF");
+        let mut spec = if is_wav {
+            AudioHeader::try_from(bytes.as_slice())?
         } else {
-            Err(Error::AudioFile(AudioFileError::TooShort {
-                actual: bytes.len(),
-                required: 44,
-            }))
+            AudioHeader {
+                channels: 0,
+                sample_rate: 0,
+                sample_format: SampleFormat::I16,
+                data: 0..0,
+            }
         };
-        let is_valid_wav = spec_result.is_ok();
-        let mut spec = spec_result.unwrap_or(AudioHeader {
-            channels: 0,
-            sample_rate: 0,
-            sample_format: SampleFormat::I16,
-        });
         let mut samples = None;
 
-        // If not valid WAV, try to handle as SEA codec file
-        if !is_valid_wav {
+        // If not WAV, handle as SEA codec file
+        if !is_wav {
             let now = Instant::now();
             let local_bytes = mem::take(&mut bytes);
-            let header = SeaFileHeader::from_frame(&local_bytes[..14])
-                .map_err(|e| Error::AudioFile(AudioFileError::Codec(e)))?;
+            let (header, decoded) =
+                spawn_cpu_task(move || decode_sea(&local_bytes, validate_sea_frame_count)).await?;
             info!("loaded header {:?}", header);
-            let chunk_size = header.chunk_size as usize;
             spec.channels = header.channels as u32;
             spec.sample_rate = header.sample_rate;
-            let mut decoder =
-                SeaDecoder::new(header).map_err(|e| Error::AudioFile(AudioFileError::Codec(e)))?;
-
-            let frame_count = local_bytes[14..].chunks(chunk_size).len();
-            validate_sea_frame_count(frame_count)?;
-
-            samples = Some(
-                spawn_cpu_task(move || {
-                    let mut decoded = Vec::with_capacity(frame_count);
-                    let mut buffer = [0_i16; FRAME_SIZE];
-
-                    for chunk in local_bytes[14..].chunks(chunk_size) {
-                        decoder
-                            .decode_frame(chunk, &mut buffer)
-                            .map_err(|e| Error::AudioFile(AudioFileError::Codec(e)))?;
-                        decoded.push(buffer);
-                    }
-
-                    Ok::<Vec<[i16; FRAME_SIZE]>, Error>(decoded)
-                })
-                .await?,
-            );
+            samples = Some(decoded);
             info!("decoding sound took {:?}", now.elapsed());
         }
 
@@ -823,114 +1413,59 @@ async fn play_sound_with_device(
             return Err(Error::AudioFile(AudioFileError::ZeroSampleRate));
         }
 
+        let source: Box<dyn AudioFrameSource + Send> = if let Some(samples) = samples {
+            Box::new(DecodedFrameSource::new(samples, spec.channels as usize))
+        } else {
+            Box::new(WavFrameSource::new(
+                bytes,
+                spec.data,
+                spec.sample_format,
+                spec.channels as usize,
+            ))
+        };
+        let source_info = SourceInfo {
+            sample_rate: spec.sample_rate,
+            total_frames: source.total_frame_count(),
+        };
+
         // sample rates used for resampling
         let input_rate = spec.sample_rate as usize;
-        let output_rate = output_config.sample_rate() as usize;
+        let output_rate = stream.sample_rate as usize;
+        // Used to chunk the output buffer correctly
+        let output_channels = stream.channels;
 
-        // Shared cancellation/finish state between control flow, processor, and stream callback
+        // Shared cancellation/finish state between control flow, processor, and voice
         let processor_canceled = Arc::new(AtomicBool::new(false));
         let processor_finished = Arc::new(AtomicBool::new(false));
 
-        // Create unified ring buffer for processor -> stream communication
-        let (processor_producer, stream_consumer) = RingBuffer::<f32>::new(CHANNEL_SIZE * 4);
-        let space_available = Arc::new(Condvar::new());
+        // Create unified ring buffer for processor -> voice communication
+        let (processor_producer, voice_consumer) = RingBuffer::<f32>::new(CHANNEL_SIZE * 4);
+        let space_available = control.space_available.clone();
         let processor_output = BlockingRingBufferOutput::new(
             processor_producer,
-            space_available.clone(),
+            control.clone(),
             processor_canceled.clone(),
         );
 
-        // Notifies this thread when playback has finished
-        let output_finished = Arc::new(Notify::new());
-        // Used inside the output stream to notify
-        let output_finished_clone = output_finished.clone();
-        // Used to chunk the output buffer correctly
-        let output_channels = output_config.channels() as usize;
-        // Keep track of the last samples played
-        let mut last_samples = vec![0_f32; output_channels];
-        // A counter used for fading out the last samples when the sound is canceled
-        let mut i = 0;
-        // Used to provide a fade to 0 when the sound is canceled
-        let f32_sample_rate = output_config.sample_rate() as f32;
-
-        let processor_canceled_for_stream = processor_canceled.clone();
-        let processor_finished_for_stream = processor_finished.clone();
-        let space_available_for_stream = space_available.clone();
-
-        let mut stream_consumer = stream_consumer;
-        let output_stream = output_device
-            .build_output_stream(
-                output_config.into(),
-                move |output: &mut [f32], _| {
-                    let mut canceled = processor_canceled_for_stream.load(Relaxed);
-                    for frame in output.chunks_mut(output_channels) {
-                        if canceled {
-                            // After full fade fill frames with 0
-                            if i == FADE_FRAMES {
-                                frame.fill(0_f32);
-                                continue;
-                            }
-
-                            // Fade each sample
-                            for sample in &mut last_samples {
-                                *sample *= (1_f32 - i as f32 / f32_sample_rate).max(0_f32);
-                            }
-
-                            // Play the samples
-                            frame.copy_from_slice(&last_samples);
-                            // Advance the counter
-                            i += 1;
-                            // Notify main thread once after the full fade has occurred
-                            if i == FADE_FRAMES {
-                                output_finished_clone.notify_one();
-                            }
-                        } else {
-                            match stream_consumer.read_chunk(output_channels) {
-                                Ok(chunk) => {
-                                    for (sample, out) in chunk.into_iter().zip(frame.iter_mut()) {
-                                        *out = sample;
-                                    }
-                                    last_samples.copy_from_slice(frame);
-                                    space_available_for_stream.notify_one();
-                                }
-                                Err(_) => {
-                                    // If the processor has finished and the buffer is empty, begin fading out.
-                                    // If the processor is still running, hold the last samples (prevents pops).
-                                    if processor_finished_for_stream.load(Relaxed) {
-                                        processor_canceled_for_stream.store(true, Relaxed);
-                                        canceled = true;
-                                    }
-                                    frame.copy_from_slice(&last_samples);
-                                }
-                            }
-                        }
-                    }
-                },
-                move |err| {
-                    error!("Error in player stream: {}", err);
-                },
-                None,
-            )
-            .map_err(|source| {
-                Error::Stream(StreamError::BuildOutputStream {
-                    config: Some(output_config),
-                    source,
-                })
-            })?;
+        let voice = Voice::new(
+            voice_consumer,
+            control.clone(),
+            processor_canceled.clone(),
+            processor_finished.clone(),
+            output_channels,
+            stream.sample_rate,
+        );
+        // Notifies this thread when the voice has faded out
+        let voice_removed = voice.removed.clone();
+        stream
+            .voices
+            .send(voice)
+            .map_err(|_| Error::Channel(ChannelError::MixerStopped))?;
 
         let processor_canceled_for_processor = processor_canceled.clone();
         let processor_finished_for_processor = processor_finished.clone();
 
         let processor_future = spawn_cpu_task(move || {
-            let source: Box<dyn AudioFrameSource> = if let Some(samples) = samples {
-                Box::new(DecodedFrameSource::new(samples, spec.channels as usize))
-            } else {
-                Box::new(WavFrameSource::new(
-                    bytes,
-                    spec.sample_format,
-                    spec.channels as usize,
-                ))
-            };
             let result = processor(
                 source,
                 output_volume,
@@ -939,40 +1474,33 @@ async fn play_sound_with_device(
                 input_rate,
                 output_rate,
                 processor_canceled_for_processor,
+                control,
             );
             processor_finished_for_processor.store(true, Relaxed);
             result
         });
 
-        output_stream.play().map_err(|source| {
-            Error::Stream(StreamError::Play {
-                direction: StreamDirection::Output,
-                source,
-            })
-        })?; // Play the stream
-
         Ok((
-            output_stream,
-            output_finished,
+            voice_removed,
             processor_canceled,
             processor_future,
             space_available,
+            source_info,
         ))
     }
     .await;
 
     // Signal initialization result to the caller
-    let (output_stream, output_finished, processor_canceled, processor_future, space_available) =
-        match init_result {
-            Ok(state) => {
-                let _ = init_tx.send(Ok(()));
-                state
-            }
-            Err(e) => {
-                let _ = init_tx.send(Err(e));
-                return Err(Error::Task(TaskError::PlaybackInitChannelClosed));
-            }
-        };
+    let (voice_removed, processor_canceled, processor_future, space_available) = match init_result {
+        Ok((removed, canceled, future, space_available, source_info)) => {
+            let _ = init_tx.send(Ok(source_info));
+            (removed, canceled, future, space_available)
+        }
+        Err(e) => {
+            let _ = init_tx.send(Err(e));
+            return Err(Error::Task(TaskError::PlaybackInitChannelClosed));
+        }
+    };
 
     tokio::pin!(processor_future);
 
@@ -981,7 +1509,7 @@ async fn play_sound_with_device(
     select! {
         _ = cancel.notified() => {
             debug!("reached cancel sound branch");
-            // This causes the stream to begin fading out and asks the processor to stop.
+            // This causes the voice to begin fading out and asks the processor to stop.
             // The condvar notification ensures the producer wakes up from any blocking
             // wait inside write_samples_blocking so it can observe the flag and exit.
             processor_canceled.store(true, Relaxed);
@@ -994,19 +1522,15 @@ async fn play_sound_with_device(
         }
     }
 
-    // Wait for playback to complete before tearing down
-    output_finished.notified().await;
-    // we wait one second before dropping the stream.
-    // this ensures the effect playback cleanly fades to zero every time
-    cfg_if::cfg_if! {
-        if #[cfg(target_family = "wasm")] {
-            wasmtimer::tokio::sleep(Duration::from_secs(1)).await;
-        } else {
-             tokio::time::sleep(Duration::from_secs(1)).await;
+    // Wait for the voice to fade out and leave the mix, freeing the voices
+    // the stream hands back so the audio thread never drops them
+    loop {
+        stream.free_retired();
+        select! {
+            _ = voice_removed.notified() => break,
+            _ = sleep(RETIRE_INTERVAL) => (),
         }
     }
-    // dropping the output stream ends playback
-    drop(output_stream);
     debug!("starting to tear down player stack");
     // Join the processor task
     match processor_join {
@@ -1042,6 +1566,10 @@ fn downmix_interleaved_to_mono(
 /// * `input` - Audio input, either raw WAV bytes or pre-decoded samples
 /// * `sample_format` - Sample format for WAV bytes (ignored for decoded samples)
 /// * `spec` - Audio specification (channels, sample rate)
+///
+/// Seeks requested through `control` are applied between frames, and the
+/// source restarts instead of ending while looping is enabled.
+#[allow(clippy::too_many_arguments)]
 fn processor<S: AudioFrameSource>(
     mut source: S,
     output_volume: Arc<AtomicF32>,
@@ -1050,6 +1578,7 @@ fn processor<S: AudioFrameSource>(
     input_rate: usize,
     output_rate: usize,
     processor_canceled: Arc<AtomicBool>,
+    control: Arc<PlaybackControl>,
 ) -> Result<(), Error> {
     let channels_usize = source.channels();
     if channels_usize == 0 {
@@ -1090,15 +1619,44 @@ fn processor<S: AudioFrameSource>(
 
     let output_volume = output_volume.load(Relaxed);
 
+    // The source position in sample frames (per channel)
+    let mut source_position = 0_usize;
+    // Fades in the first samples after a seek
+    let mut ramp = 1_f32;
+    let ramp_step = RAMP_RATE / output_rate as f32;
+
     loop {
         if processor_canceled.load(Relaxed) {
             break;
         }
 
+        if let Some(target) = control.take_seek() {
+            source_position = target.min(sample_count);
+            source.seek(source_position);
+            if let Some(resampler) = &mut resampler {
+                resampler.reset();
+            }
+            position = (source_position as f64 * ratio) as f32;
+            // everything written so far is stale and is dropped by the stream
+            control.discard_until.store(output.written, Release);
+            control.position.store(source_position, Relaxed);
+            ramp = 0_f32;
+        }
+
         let Some(actual_frame_count) = source.next_frame(&mut pre_buf)? else {
+            if control.looping.load(Relaxed) && sample_count > 0 {
+                source.seek(0);
+                source_position = 0;
+                position = 0_f32;
+                continue;
+            }
+
             break;
         };
 
+        source_position = (source_position + actual_frame_count).min(sample_count);
+        control.position.store(source_position, Relaxed);
+
         wide_mul(&mut pre_buf, output_volume);
 
         let (target_buffer, len) = if let Some(resampler) = &mut resampler {
@@ -1117,7 +1675,7 @@ fn processor<S: AudioFrameSource>(
         };
 
         for i in 0..len {
-            if processor_canceled.load(Relaxed) {
+            if processor_canceled.load(Relaxed) || control.seek_pending() {
                 break;
             }
 
@@ -1137,6 +1695,8 @@ fn processor<S: AudioFrameSource>(
                 0_f32 // The calculated audio_len is too short
             };
 
+            let multiplier = multiplier * ramp;
+            ramp = (ramp + ramp_step).min(1_f32);
             position += 1_f32; // Advance the position
 
             for j in 0..output_channels {
@@ -1165,7 +1725,7 @@ fn processor<S: AudioFrameSource>(
 /// # Arguments
 ///
 /// * `chunk` - The raw WAV byte data (excluding header)
-/// * `sample_format` - The sample format of the input data (U8, I16, I32, F32, F64)
+/// * `sample_format` - The sample format of the input data (U8, I16, I24, I32, F32, F64)
 /// * `channels` - Number of audio channels
 /// * `output` - Pre-allocated interleaved output slice. Unused entries are zeroed.
 ///
@@ -1186,7 +1746,7 @@ fn unpack_wav_frame<T: SampleConversion + Clone>(
     channels: usize,
     output: &mut [T],
 ) -> Result<usize, Error> {
-    let bytes_per_sample = sample_format.sample_size();
+    let bytes_per_sample = wav_sample_size(sample_format);
     let bytes_per_frame = bytes_per_sample * channels;
 
     // Clear output buffers
@@ -1220,6 +1780,17 @@ fn unpack_wav_frame<T: SampleConversion + Clone>(
                     })?);
                     T::from_i16_sample(value)
                 }
+                SampleFormat::I24 => {
+                    let [low, mid, high]: [u8; 3] = sample_bytes.try_into().map_err(|_| {
+                        Error::AudioFile(AudioFileError::InvalidSampleBytes {
+                            sample_format,
+                            bytes_per_sample,
+                            actual: sample_bytes.len(),
+                        })
+                    })?;
+                    // place the 24 bits at the top of an i32
+                    T::from_i32_sample(i32::from_le_bytes([0, low, mid, high]))
+                }
                 SampleFormat::I32 => {
                     let value = i32::from_le_bytes(sample_bytes.try_into().map_err(|_| {
                         Error::AudioFile(AudioFileError::InvalidSampleBytes {
@@ -1294,6 +1865,84 @@ where
     rx.await?
 }
 
+/// Decodes every chunk of a SEA file after `validate` accepts the chunk count.
+fn decode_sea(
+    bytes: &[u8],
+    validate: impl FnOnce(usize) -> Result<(), Error>,
+) -> Result<(SeaFileHeader, Vec<[i16; FRAME_SIZE]>), Error> {
+    let header = bytes
+        .get(..14)
+        .ok_or(Error::AudioFile(AudioFileError::TooShort {
+            actual: bytes.len(),
+            required: 14,
+        }))?;
+    let header = SeaFileHeader::from_frame(header)
+        .map_err(|e| Error::AudioFile(AudioFileError::Codec(e)))?;
+    let chunk_size = header.chunk_size as usize;
+    let mut decoder =
+        SeaDecoder::new(header.clone()).map_err(|e| Error::AudioFile(AudioFileError::Codec(e)))?;
+
+    let frame_count = bytes[14..].chunks(chunk_size).len();
+    validate(frame_count)?;
+
+    let mut decoded = Vec::with_capacity(frame_count);
+    let mut buffer = [0_i16; FRAME_SIZE];
+    for chunk in bytes[14..].chunks(chunk_size) {
+        decoder
+            .decode_frame(chunk, &mut buffer)
+            .map_err(|e| Error::AudioFile(AudioFileError::Codec(e)))?;
+        decoded.push(buffer);
+    }
+
+    Ok((header, decoded))
+}
+
+/// A whole audio file decoded to interleaved samples.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub(crate) struct DecodedAudio {
+    pub(crate) samples: Vec<f32>,
+    pub(crate) channels: usize,
+    pub(crate) sample_rate: u32,
+}
+
+/// Decodes a whole WAV or SEA file, anything that is not a RIFF file is SEA.
+///
+/// Unlike [`AudioPlayer::play`], SEA files are not limited in length.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn decode_audio(bytes: Vec<u8>) -> Result<DecodedAudio, Error> {
+    let (mut source, sample_rate): (Box<dyn AudioFrameSource>, u32) = if bytes.starts_with(b"RIFF")
+    {
+        let spec = AudioHeader::try_from(bytes.as_slice())?;
+        let source =
+            WavFrameSource::new(bytes, spec.data, spec.sample_format, spec.channels as usize);
+        (Box::new(source), spec.sample_rate)
+    } else {
+        let (header, frames) = decode_sea(&bytes, |_| Ok(()))?;
+        if header.channels == 0 {
+            return Err(Error::AudioFile(AudioFileError::ZeroChannels));
+        }
+        if header.sample_rate == 0 {
+            return Err(Error::AudioFile(AudioFileError::ZeroSampleRate));
+        }
+        let source = DecodedFrameSource::new(frames, header.channels as usize);
+        (Box::new(source), header.sample_rate)
+    };
+
+    let channels = source.channels();
+    let mut samples = Vec::with_capacity(source.total_frame_count() * channels);
+    let mut frame = [0_f32; FRAME_SIZE];
+    while let Some(frame_count) = source.next_frame(&mut frame)? {
+        samples.extend_from_slice(&frame[..frame_count * channels]);
+    }
+
+    Ok(DecodedAudio {
+        samples,
+        channels,
+        sample_rate,
+    })
+}
+
 fn validate_sea_frame_count(frame_count: usize) -> Result<(), Error> {
     if frame_count > MAX_DECODED_SEA_FRAMES {
         return Err(Error::AudioFile(AudioFileError::TooLarge {
@@ -1400,6 +2049,18 @@ mod tests {
         fn channels(&self) -> usize {
             self.channels
         }
+
+        fn seek(&mut self, frame: usize) {
+            self.yielded = frame >= self.total_frames;
+        }
+    }
+
+    /// A mono source of `frames` decoded frames where sample `i` holds `i`.
+    fn counting_source(frames: usize) -> DecodedFrameSource {
+        let samples = (0..frames)
+            .map(|frame| std::array::from_fn(|i| (frame * FRAME_SIZE + i) as i16))
+            .collect();
+        DecodedFrameSource::new(samples, 1)
     }
 
     #[test]
@@ -1438,7 +2099,7 @@ mod tests {
         let zero_channels = make_wav_header(0, 48_000, 1, 16);
         let zero_sample_rate = make_wav_header(1, 0, 1, 16);
         let unsupported_format = make_wav_header(1, 48_000, 2, 16);
-        let unsupported_depth = make_wav_header(1, 48_000, 1, 24);
+        let unsupported_depth = make_wav_header(1, 48_000, 1, 20);
 
         assert!(matches!(
             AudioHeader::try_from(&bad_riff[..]),
@@ -1463,7 +2124,158 @@ mod tests {
             AudioHeader::try_from(&unsupported_depth[..]),
             Err(Error::AudioFile(AudioFileError::UnsupportedSampleFormat {
                 audio_format: 1,
-                bits_per_sample: 24
+                bits_per_sample: 20
+            }))
+        ));
+    }
+
+    /// Builds a RIFF/WAVE file from raw chunks, padding odd chunks.
+    fn make_riff(chunks: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut bytes = b"RIFF\0\0\0\0WAVE".to_vec();
+        for (id, body) in chunks {
+            bytes.extend_from_slice(*id);
+            bytes.extend_from_slice(&(body.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(body);
+            if body.len() % 2 == 1 {
+                bytes.push(0);
+            }
+        }
+        let riff_len = (bytes.len() - 8) as u32;
+        bytes[4..8].copy_from_slice(&riff_len.to_le_bytes());
+        bytes
+    }
+
+    /// The body of a `WAVE_FORMAT_EXTENSIBLE` `fmt ` chunk.
+    fn make_extensible_fmt(
+        channels: u16,
+        container_bits: u16,
+        valid_bits: u16,
+        sub_format: u16,
+    ) -> Vec<u8> {
+        let mut fmt = make_wav_header(channels, 48_000, WAVE_FORMAT_EXTENSIBLE, container_bits)
+            [20..36]
+            .to_vec();
+        fmt.extend_from_slice(&22_u16.to_le_bytes());
+        fmt.extend_from_slice(&valid_bits.to_le_bytes());
+        fmt.extend_from_slice(&0_u32.to_le_bytes());
+        fmt.extend_from_slice(&sub_format.to_le_bytes());
+        fmt.extend_from_slice(&SUBFORMAT_GUID_SUFFIX);
+        fmt
+    }
+
+    #[test]
+    fn test_audio_header_skips_unknown_chunks() {
+        let fmt = &make_wav_header(2, 44_100, 1, 16)[20..36];
+        let samples = [1_u8, 0, 2, 0, 3, 0, 4, 0];
+        let bytes = make_riff(&[
+            (b"bext", &[0; 7]),
+            (b"fmt ", fmt),
+            (b"LIST", b"INFOISFT\x03\0\0\0ed\0"),
+            (b"fact", &2_u32.to_le_bytes()),
+            (b"data", &samples),
+            (b"id3 ", &[0; 4]),
+        ]);
+
+        let parsed = AudioHeader::try_from(&bytes[..]).unwrap();
+        assert_eq!(parsed.channels, 2);
+        assert_eq!(parsed.sample_rate, 44_100);
+        assert_eq!(&bytes[parsed.data], &samples);
+    }
+
+    #[test]
+    fn test_audio_header_finds_data_before_fmt() {
+        let fmt = &make_wav_header(1, 8_000, 1, 8)[20..36];
+        let bytes = make_riff(&[(b"data", &[128, 129, 130]), (b"fmt ", fmt)]);
+
+        let parsed = AudioHeader::try_from(&bytes[..]).unwrap();
+        assert!(matches!(parsed.sample_format, SampleFormat::U8));
+        assert_eq!(&bytes[parsed.data], &[128, 129, 130]);
+    }
+
+    #[test]
+    fn test_audio_header_clamps_unterminated_data_chunk() {
+        let mut bytes = make_i16_wav(1, 48_000, &[1, 2, 3]);
+        // streaming writers leave the data size unpatched
+        bytes[40..44].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        let parsed = AudioHeader::try_from(&bytes[..]).unwrap();
+        assert_eq!(parsed.data, 44..50);
+    }
+
+    #[test]
+    fn test_audio_header_parses_extensible_formats() {
+        let cases = [
+            (24, 24, WAVE_FORMAT_PCM, SampleFormat::I24),
+            (32, 24, WAVE_FORMAT_PCM, SampleFormat::I32),
+            (16, 16, WAVE_FORMAT_PCM, SampleFormat::I16),
+            (32, 32, WAVE_FORMAT_IEEE_FLOAT, SampleFormat::F32),
+        ];
+        for (container_bits, valid_bits, sub_format, expected) in cases {
+            let fmt = make_extensible_fmt(6, container_bits, valid_bits, sub_format);
+            let bytes = make_riff(&[(b"fmt ", &fmt), (b"data", &[])]);
+
+            let parsed = AudioHeader::try_from(&bytes[..]).unwrap();
+            assert_eq!(parsed.channels, 6);
+            assert_eq!(parsed.sample_format, expected);
+        }
+    }
+
+    #[test]
+    fn test_audio_header_reports_malformed_chunks() {
+        let fmt = make_wav_header(1, 48_000, 1, 16)[20..36].to_vec();
+        let parse = |chunks: &[(&[u8; 4], &[u8])]| {
+            AudioHeader::try_from(&make_riff(chunks)[..]).map(|_| ())
+        };
+
+        assert!(matches!(
+            parse(&[(b"data", &[0; 4])]),
+            Err(Error::AudioFile(AudioFileError::MissingChunk(id))) if &id == b"fmt "
+        ));
+        assert!(matches!(
+            parse(&[(b"fmt ", &fmt)]),
+            Err(Error::AudioFile(AudioFileError::MissingChunk(id))) if &id == b"data"
+        ));
+        assert!(matches!(
+            parse(&[(b"fmt ", &fmt[..14]), (b"data", &[])]),
+            Err(Error::AudioFile(AudioFileError::FormatChunkTooShort {
+                size: 14,
+                required: 16
+            }))
+        ));
+
+        let mut bad_align = fmt.clone();
+        bad_align[12..14].copy_from_slice(&4_u16.to_le_bytes());
+        assert!(matches!(
+            parse(&[(b"fmt ", &bad_align), (b"data", &[])]),
+            Err(Error::AudioFile(AudioFileError::BlockAlignMismatch {
+                block_align: 4,
+                expected: 2
+            }))
+        ));
+
+        let extensible = make_extensible_fmt(2, 24, 24, WAVE_FORMAT_PCM);
+        assert!(matches!(
+            parse(&[(b"fmt ", &extensible[..24]), (b"data", &[])]),
+            Err(Error::AudioFile(AudioFileError::FormatChunkTooShort {
+                size: 24,
+                required: 40
+            }))
+        ));
+        let mut unknown_guid = extensible.clone();
+        unknown_guid[39] = 0;
+        assert!(matches!(
+            parse(&[(b"fmt ", &unknown_guid), (b"data", &[])]),
+            Err(Error::AudioFile(AudioFileError::UnsupportedSubFormat(_)))
+        ));
+
+        // a truncated chunk other than data cannot be skipped
+        let mut truncated = make_riff(&[(b"LIST", &[0; 8]), (b"fmt ", &fmt), (b"data", &[])]);
+        truncated[16..20].copy_from_slice(&100_u32.to_le_bytes());
+        assert!(matches!(
+            AudioHeader::try_from(&truncated[..]),
+            Err(Error::AudioFile(AudioFileError::TruncatedChunk {
+                declared: 100,
+                ..
             }))
         ));
     }
@@ -1617,6 +2429,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unpack_wav_frame_i24_stereo() {
+        let mut chunk = Vec::new();
+        chunk.extend_from_slice(&(0x40_0000_i32).to_le_bytes()[..3]); // L1, half scale
+        chunk.extend_from_slice(&(-0x40_0000_i32).to_le_bytes()[..3]); // R1
+        chunk.extend_from_slice(&(0x7F_FFFF_i32).to_le_bytes()[..3]); // L2, full scale
+        chunk.extend_from_slice(&[0x56, 0x34]); // incomplete R2
+
+        let mut output = vec![0.0_f32; 4];
+        let frames = unpack_wav_frame(&chunk, SampleFormat::I24, 2, &mut output).unwrap();
+
+        assert_eq!(frames, 1);
+        assert_samples_close(&output, &[0.5, -0.5, 0.0, 0.0]);
+
+        let mut output = vec![0_i16; 2];
+        unpack_wav_frame(&chunk[6..9], SampleFormat::I24, 1, &mut output).unwrap();
+        assert_eq!(output[0], i16::MAX);
+    }
+
     #[test]
     fn test_unpack_wav_frame_i32_mono() {
         let mut chunk = Vec::new();
@@ -1753,7 +2584,7 @@ mod tests {
         bytes.extend_from_slice(&2000_i16.to_le_bytes());
         bytes.extend_from_slice(&(-2000_i16).to_le_bytes());
 
-        let mut src = WavFrameSource::new(bytes, SampleFormat::I16, 2);
+        let mut src = WavFrameSource::new(bytes, 44..52, SampleFormat::I16, 2);
         let mut out = vec![0.0_f32; FRAME_SIZE];
 
         assert_eq!(src.channels(), 2);
@@ -1801,6 +2632,267 @@ mod tests {
         assert!(next.is_none());
     }
 
+    #[test]
+    fn test_wav_frame_source_seek() {
+        // 3 frames, stereo I16
+        let mut bytes = vec![0_u8; 44];
+        for sample in [100_i16, -100, 200, -200, 300, -300] {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+
+        let mut src = WavFrameSource::new(bytes, 44..56, SampleFormat::I16, 2);
+        let mut out = vec![0.0_f32; FRAME_SIZE];
+
+        src.seek(2);
+        assert_eq!(src.next_frame(&mut out).unwrap(), Some(1));
+        assert_samples_close(
+            &out[..2],
+            &[300.0 / i16::MAX as f32, -300.0 / i16::MAX as f32],
+        );
+
+        src.seek(10);
+        assert!(src.next_frame(&mut out).unwrap().is_none());
+
+        src.seek(0);
+        assert_eq!(src.next_frame(&mut out).unwrap(), Some(3));
+        assert_samples_close(&out[..1], &[100.0 / i16::MAX as f32]);
+    }
+
+    #[test]
+    fn test_decoded_frame_source_seek_reads_across_frames() {
+        let mut src = counting_source(2);
+        let mut out = vec![0.0_f32; FRAME_SIZE];
+        let scale = 1_f32 / i16::MAX as f32;
+
+        src.seek(FRAME_SIZE / 2);
+        assert_eq!(src.next_frame(&mut out).unwrap(), Some(FRAME_SIZE));
+        assert_samples_close(&out[..1], &[(FRAME_SIZE / 2) as f32 * scale]);
+        assert_samples_close(
+            &out[FRAME_SIZE - 1..],
+            &[(FRAME_SIZE * 3 / 2 - 1) as f32 * scale],
+        );
+
+        // the remaining half frame is zero padded
+        assert_eq!(src.next_frame(&mut out).unwrap(), Some(FRAME_SIZE / 2));
+        assert_samples_close(
+            &out[FRAME_SIZE / 2 - 1..FRAME_SIZE / 2 + 1],
+            &[(FRAME_SIZE * 2 - 1) as f32 * scale, 0.0],
+        );
+        assert!(src.next_frame(&mut out).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_blocking_output_skips_writes_while_seek_pending() {
+        let (producer, _consumer) = RingBuffer::<f32>::new(2);
+        let control = Arc::new(PlaybackControl::default());
+        let mut output = BlockingRingBufferOutput::new(
+            producer,
+            control.clone(),
+            Arc::new(AtomicBool::new(false)),
+        );
+
+        output.write_samples_blocking(&[0.1, 0.2]).unwrap();
+        assert_eq!(output.written, 2);
+
+        // the ring is full, so this would block without the pending seek
+        control.seek.store(0, Release);
+        output.write_samples_blocking(&[0.3, 0.4]).unwrap();
+        assert_eq!(output.written, 2);
+    }
+
+    #[test]
+    fn test_processor_applies_pending_seek() {
+        let (producer, mut consumer) = RingBuffer::<f32>::new(FRAME_SIZE);
+        let canceled = Arc::new(AtomicBool::new(false));
+        let control = Arc::new(PlaybackControl::default());
+        let output = BlockingRingBufferOutput::new(producer, control.clone(), canceled.clone());
+        control.seek.store(400, Release);
+
+        processor(
+            counting_source(1),
+            Arc::new(AtomicF32::new(1.0)),
+            output,
+            1,
+            48_000,
+            48_000,
+            canceled,
+            control.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(consumer.slots(), 80);
+        let samples: Vec<f32> = consumer.read_chunk(80).unwrap().into_iter().collect();
+        let scale = 1_f32 / i16::MAX as f32;
+        // playback resumes at the target, ramping up from silence
+        assert_samples_close(&samples[..1], &[0.0]);
+        assert_samples_close(&samples[70..71], &[470.0 * scale * 70.0 / 240.0]);
+        assert_eq!(control.position.load(Relaxed), FRAME_SIZE);
+        assert!(!control.seek_pending());
+    }
+
+    #[test]
+    fn test_processor_restarts_looping_sources() {
+        let (producer, mut consumer) = RingBuffer::<f32>::new(FRAME_SIZE * 3);
+        let canceled = Arc::new(AtomicBool::new(false));
+        let control = Arc::new(PlaybackControl::default());
+        let output = BlockingRingBufferOutput::new(producer, control.clone(), canceled.clone());
+        control.looping.store(true, Relaxed);
+
+        let processor_canceled = canceled.clone();
+        let processor_control = control.clone();
+        let handle = std::thread::spawn(move || {
+            processor(
+                counting_source(1),
+                Arc::new(AtomicF32::new(1.0)),
+                output,
+                1,
+                48_000,
+                48_000,
+                processor_canceled,
+                processor_control,
+            )
+        });
+
+        // a non-looping source would stop after FRAME_SIZE samples
+        while consumer.slots() < FRAME_SIZE * 2 {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        let samples: Vec<f32> = consumer
+            .read_chunk(FRAME_SIZE * 2)
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_samples_close(
+            &samples[FRAME_SIZE + 240..FRAME_SIZE + 241],
+            &[samples[240]],
+        );
+
+        canceled.store(true, Relaxed);
+        control.space_available.notify_one();
+        assert!(matches!(
+            handle.join().unwrap(),
+            Err(Error::Channel(ChannelError::BlockingWriteCanceled))
+        ));
+    }
+
+    /// A mono voice at 48 kHz with `samples` already buffered.
+    fn voice_with(samples: &[f32], finished: bool) -> (Voice, Arc<PlaybackControl>) {
+        let (mut producer, consumer) = RingBuffer::<f32>::new(1024);
+        producer
+            .write_chunk_uninit(samples.len())
+            .unwrap()
+            .fill_from_iter(samples.iter().copied());
+        let control = Arc::new(PlaybackControl::default());
+        let voice = Voice::new(
+            consumer,
+            control.clone(),
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(finished)),
+            1,
+            48_000,
+        );
+        (voice, control)
+    }
+
+    #[test]
+    fn test_voices_mix_at_their_own_volume() {
+        let (mut first, _) = voice_with(&[0.25; 4], false);
+        let (mut second, control) = voice_with(&[0.25; 4], false);
+        control.volume.store(0.5, Relaxed);
+
+        let mut output = [0_f32; 4];
+        assert!(first.mix_into(&mut output));
+        assert!(second.mix_into(&mut output));
+        assert_samples_close(&output, &[0.375; 4]);
+    }
+
+    #[test]
+    fn test_voice_fades_out_once_finished() {
+        let (mut voice, _) = voice_with(&[0.5; 2], true);
+
+        let mut output = [0_f32; 2];
+        assert!(voice.mix_into(&mut output));
+        assert_samples_close(&output, &[0.5; 2]);
+
+        // the drained buffer starts the fade, after which the voice is done
+        let mut output = [0_f32; FADE_FRAMES + 10];
+        assert!(!voice.mix_into(&mut output));
+        assert!(voice.canceled.load(Relaxed));
+        assert!(output[0] > 0.0);
+        assert_samples_close(&output[FADE_FRAMES + 1..], &[0.0; 9]);
+    }
+
+    #[test]
+    fn test_paused_voice_holds_buffered_audio() {
+        let (mut voice, control) = voice_with(&[1.0; 1000], false);
+        control.paused.store(true, Relaxed);
+
+        // ramps down over 1/RAMP_RATE seconds, consuming audio as it goes
+        let mut output = [0_f32; 480];
+        assert!(voice.mix_into(&mut output));
+        let held = voice.consumer.slots();
+        assert!((1000 - 245..=1000 - 235).contains(&held));
+        assert!(output[0] > 0.9);
+        assert_samples_close(&output[300..], &[0.0; 180]);
+
+        let mut output = [0_f32; 480];
+        assert!(voice.mix_into(&mut output));
+        assert_eq!(voice.consumer.slots(), held);
+        assert_samples_close(&output, &[0.0; 480]);
+
+        control.paused.store(false, Relaxed);
+        assert!(voice.mix_into(&mut output));
+        assert_eq!(voice.consumer.slots(), held - 480);
+    }
+
+    #[test]
+    fn test_voice_discards_audio_from_before_a_seek() {
+        let (mut voice, control) = voice_with(&[0.1, 0.2, 0.3, 0.4, 0.5], false);
+        control.discard_until.store(3, Release);
+
+        let mut output = [0_f32; 1];
+        assert!(voice.mix_into(&mut output));
+        assert_samples_close(&output, &[0.4]);
+        assert_eq!(voice.read, 4);
+    }
+
+    #[test]
+    fn test_mixer_takes_only_as_many_voices_as_it_reserved() {
+        let (voices, incoming) = unbounded();
+        let (retire, _retired) = bounded(VOICE_CAPACITY);
+        for _ in 0..VOICE_CAPACITY + 2 {
+            voices.send(voice_with(&[0.01; 8], false).0).unwrap();
+        }
+
+        let mut playing = Vec::with_capacity(VOICE_CAPACITY);
+        let mut output = [0_f32; 4];
+        mix_voices(&mut playing, &incoming, &retire, &mut output);
+        assert_eq!(playing.len(), VOICE_CAPACITY);
+        assert_eq!(playing.capacity(), VOICE_CAPACITY);
+        assert_eq!(incoming.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_mixer_hands_finished_voices_back() {
+        let (voices, incoming) = unbounded();
+        let (retire, retired) = bounded(VOICE_CAPACITY);
+        let (voice, _) = voice_with(&[], true);
+        let removed = voice.removed.clone();
+        voices.send(voice).unwrap();
+        voices.send(voice_with(&[0.5; 128], false).0).unwrap();
+
+        let mut playing = Vec::with_capacity(VOICE_CAPACITY);
+        let mut output = [0_f32; FADE_FRAMES + 10];
+        mix_voices(&mut playing, &incoming, &retire, &mut output);
+        assert_eq!(playing.len(), 1);
+        assert_eq!(retired.len(), 1);
+
+        // the voice is only freed, and its sound notified, off the audio thread
+        drop(retired.try_recv().unwrap());
+        let notified = tokio::time::timeout(Duration::from_secs(1), removed.notified());
+        assert!(notified.await.is_ok());
+    }
+
     #[test]
     fn test_processor_preserves_interleaved_stereo_frames() {
         let source = TestFrameSource {
@@ -1811,8 +2903,8 @@ mod tests {
         };
         let (producer, mut consumer) = RingBuffer::<f32>::new(32);
         let canceled = Arc::new(AtomicBool::new(false));
-        let output =
-            BlockingRingBufferOutput::new(producer, Arc::new(Condvar::new()), canceled.clone());
+        let control = Arc::new(PlaybackControl::default());
+        let output = BlockingRingBufferOutput::new(producer, control.clone(), canceled.clone());
 
         processor(
             source,
@@ -1822,6 +2914,7 @@ mod tests {
             48_000,
             48_000,
             canceled,
+            control,
         )
         .unwrap();
 
@@ -1859,7 +2952,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_wav_to_sea_produces_decodable_downmixed_padded_frame() {
-        let wav = make_i16_wav(2, 48_000, &[10_000, -2_000, 3_000, 1_000, -4_000, -8_000]);
+        // five channels cannot split a frame into whole blocks
+        let wav = make_i16_wav(
+            5,
+            48_000,
+            &[
+                10_000, -2_000, 3_000, 1_000, 8_000, -4_000, -8_000, 0, 2_000, 0,
+            ],
+        );
 
         let sea = wav_to_sea(wav, 8.0).await.unwrap();
         let header = SeaFileHeader::from_frame(&sea[..14]).unwrap();
@@ -1876,8 +2976,7 @@ mod tests {
             .unwrap();
 
         assert_i16_close(decoded[0], 4_000, 750);
-        assert_i16_close(decoded[1], 2_000, 750);
-        assert_i16_close(decoded[2], -6_000, 750);
+        assert_i16_close(decoded[1], -2_000, 750);
 
         let padded_tail = &decoded[10..];
         let tail_rms = (padded_tail
@@ -1891,4 +2990,52 @@ mod tests {
             "padded tail should be near silence, got RMS {tail_rms}"
         );
     }
+
+    #[tokio::test]
+    async fn test_wav_to_sea_reads_extensible_24_bit_files() {
+        let fmt = make_extensible_fmt(1, 24, 24, WAVE_FORMAT_PCM);
+        let mut samples = Vec::new();
+        for value in [0x10_0000_i32, -0x10_0000, 0x20_0000] {
+            samples.extend_from_slice(&value.to_le_bytes()[..3]);
+        }
+        let wav = make_riff(&[(b"fmt ", &fmt), (b"LIST", &[0; 5]), (b"data", &samples)]);
+
+        let sea = wav_to_sea(wav, 8.0).await.unwrap();
+        let header = SeaFileHeader::from_frame(&sea[..14]).unwrap();
+        assert_eq!(header.channels, 1);
+        let chunk_size = header.chunk_size as usize;
+        // three samples fit in a single padded frame
+        assert_eq!(sea.len(), 14 + chunk_size);
+
+        let mut decoder = SeaDecoder::new(header).unwrap();
+        let mut decoded = [0_i16; FRAME_SIZE];
+        decoder.decode_frame(&sea[14..], &mut decoded).unwrap();
+        for (actual, expected) in decoded.iter().zip([4_096, -4_096, 8_192]) {
+            assert_i16_close(*actual, expected, 750);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wav_to_sea_keeps_stereo_channels() {
+        let wav = make_i16_wav(2, 48_000, &[4_000, -4_000, 2_000, -2_000, -6_000, 6_000]);
+
+        let sea = wav_to_sea(wav, 8.0).await.unwrap();
+        let header = SeaFileHeader::from_frame(&sea[..14]).unwrap();
+        assert_eq!(header.channels, 2);
+        assert_eq!(header.frames_per_chunk, FRAME_SIZE as u16 / 2);
+        let chunk_size = header.chunk_size as usize;
+
+        let mut decoder = SeaDecoder::new(header).unwrap();
+        let mut decoded = [0_i16; FRAME_SIZE];
+        decoder
+            .decode_frame(&sea[14..14 + chunk_size], &mut decoded)
+            .unwrap();
+
+        for (actual, expected) in decoded
+            .iter()
+            .zip([4_000, -4_000, 2_000, -2_000, -6_000, 6_000])
+        {
+            assert_i16_close(*actual, expected, 750);
+        }
+    }
 }
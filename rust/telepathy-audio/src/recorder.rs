@@ -0,0 +1,1010 @@
+//! Call recording to WAV and SEA codec files.
+//!
+//! Processors tap the audio they handle into a shared [`RecordingTap`]: the
+//! input processor pushes every post-processing frame and each output or
+//! mixer source pushes every decoded frame. Each tap point registers its own
+//! [`RecordingTrack`] with a lock-free SPSC ring buffer, so nothing is queued
+//! or copied unless a recording is running.
+//!
+//! A [`Recorder`] drains the tracks on a writer thread. Every track is
+//! downmixed to mono and resampled to the recording rate, then the writer
+//! ticks once per `FRAME_SIZE` samples of wall-clock time. Tracks with
+//! nothing buffered contribute silence for that tick, so gated or lost
+//! frames keep their place in the timeline and separate tracks stay aligned.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use telepathy_audio::recorder::{Recorder, RecordingConfig, RecordingTap};
+//!
+//! let tap = RecordingTap::new();
+//! // pass `&tap` to the input, output and mixer source builders
+//!
+//! let recorder = Recorder::start(&tap, "call.wav", RecordingConfig::default()).unwrap();
+//! // ...
+//! let files = recorder.stop().unwrap();
+//! ```
+
+use crate::constants::FRAME_SIZE;
+#[cfg(not(target_family = "wasm"))]
+use crate::error::{AudioFileError, ConfigError, Error, TaskError};
+#[cfg(not(target_family = "wasm"))]
+use crate::internal::utils::resampler_factory;
+#[cfg(not(target_family = "wasm"))]
+use crate::sea::codec::file::SeaFileHeader;
+#[cfg(not(target_family = "wasm"))]
+use crate::sea::encoder::{EncoderSettings, SeaEncoder};
+#[cfg(not(target_family = "wasm"))]
+use audioadapter_buffers::direct::InterleavedSlice;
+#[cfg(not(target_family = "wasm"))]
+use bytes::BytesMut;
+#[cfg(not(target_family = "wasm"))]
+use rubato::{Fft, FixedSync, Resampler};
+#[cfg(not(target_family = "wasm"))]
+use std::collections::VecDeque;
+#[cfg(not(target_family = "wasm"))]
+use std::fs::File;
+#[cfg(not(target_family = "wasm"))]
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+#[cfg(not(target_family = "wasm"))]
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::{Arc, Mutex};
+#[cfg(not(target_family = "wasm"))]
+use std::thread::JoinHandle;
+#[cfg(not(target_family = "wasm"))]
+use std::time::{Duration, Instant};
+#[cfg(not(target_family = "wasm"))]
+use tracing::{debug, warn};
+
+/// Capacity of each track's ring buffer in samples.
+///
+/// The writer drains every tick, so this only needs to absorb scheduling
+/// hiccups; at 48 kHz mono it holds 320 ms.
+const TRACK_CAPACITY: usize = FRAME_SIZE * 32;
+
+/// The most frames a track may buffer before the oldest samples are dropped.
+#[cfg(not(target_family = "wasm"))]
+const MAX_BACKLOG_FRAMES: usize = 5;
+
+/// The default recording sample rate in Hz.
+pub const DEFAULT_RECORDING_RATE: u32 = 48_000;
+
+/// The file format a [`Recorder`] writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordingFormat {
+    /// 16-bit PCM WAV.
+    #[default]
+    Wav,
+    /// SEA codec file, playable by the [`player`](crate::player).
+    Sea,
+}
+
+impl RecordingFormat {
+    /// The file extension used for this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RecordingFormat::Wav => "wav",
+            RecordingFormat::Sea => "sea",
+        }
+    }
+}
+
+/// How the tracks of a recording are laid out on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordingLayout {
+    /// Every track is mixed into one file.
+    #[default]
+    Mixed,
+    /// Every track is written to its own file, named after the track.
+    SeparateTracks,
+}
+
+/// Configuration for a [`Recorder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordingConfig {
+    /// The file format to write.
+    pub format: RecordingFormat,
+    /// Whether tracks are mixed or kept apart.
+    pub layout: RecordingLayout,
+    /// The sample rate of the written files in Hz.
+    pub sample_rate: u32,
+}
+
+impl Default for RecordingConfig {
+    fn default() -> Self {
+        Self {
+            format: RecordingFormat::default(),
+            layout: RecordingLayout::default(),
+            sample_rate: DEFAULT_RECORDING_RATE,
+        }
+    }
+}
+
+/// Shared registry of recording tracks.
+///
+/// Cloning is cheap; all clones feed the same recorder.
+#[derive(Clone, Default)]
+pub struct RecordingTap {
+    shared: Arc<TapShared>,
+}
+
+#[derive(Default)]
+struct TapShared {
+    /// Whether a recorder is currently draining the tracks.
+    recording: AtomicBool,
+    tracks: Mutex<Vec<TrackSlot>>,
+}
+
+struct TrackSlot {
+    consumer: rtrb::Consumer<f32>,
+    name: String,
+    sample_rate: u32,
+    channels: usize,
+    /// Whether the running recorder has seen this track yet.
+    attached: bool,
+}
+
+impl RecordingTap {
+    /// Creates a new tap with no tracks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new track and returns its producer half.
+    ///
+    /// `sample_rate` and `channels` describe the interleaved samples that
+    /// will be pushed. With [`RecordingLayout::SeparateTracks`] the `name`
+    /// becomes part of the track's file name.
+    pub fn track(
+        &self,
+        name: impl Into<String>,
+        sample_rate: u32,
+        channels: usize,
+    ) -> RecordingTrack {
+        let (producer, consumer) = rtrb::RingBuffer::new(TRACK_CAPACITY);
+        if let Ok(mut tracks) = self.shared.tracks.lock() {
+            // tracks closed while nothing was recording never reach a writer
+            tracks.retain(|slot| slot.attached || !slot.consumer.is_abandoned());
+            tracks.push(TrackSlot {
+                consumer,
+                name: name.into(),
+                sample_rate,
+                channels: channels.max(1),
+                attached: false,
+            });
+        }
+        RecordingTrack {
+            producer,
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Returns true while a recorder is running on this tap.
+    pub fn is_recording(&self) -> bool {
+        self.shared.recording.load(Relaxed)
+    }
+}
+
+/// The producer half of one recording track.
+///
+/// Dropping the track unregisters it once the recorder has drained it.
+pub struct RecordingTrack {
+    producer: rtrb::Producer<f32>,
+    shared: Arc<TapShared>,
+}
+
+impl RecordingTrack {
+    /// Returns true while a recorder is running on this track's tap.
+    pub fn is_recording(&self) -> bool {
+        self.shared.recording.load(Relaxed)
+    }
+
+    /// Pushes samples in `-1.0..=1.0`, dropping any that do not fit.
+    ///
+    /// Nothing is queued while no recorder is running.
+    pub fn push(&mut self, samples: &[f32]) {
+        if !self.is_recording() {
+            return;
+        }
+        let len = samples.len().min(self.producer.slots());
+        if let Ok(chunk) = self.producer.write_chunk_uninit(len) {
+            chunk.fill_from_iter(samples.iter().copied());
+        }
+    }
+
+    /// Pushes i16 samples, dropping any that do not fit.
+    ///
+    /// Nothing is queued while no recorder is running.
+    pub fn push_i16(&mut self, samples: &[i16]) {
+        if !self.is_recording() {
+            return;
+        }
+        let scale = 1_f32 / i16::MAX as f32;
+        let len = samples.len().min(self.producer.slots());
+        if let Ok(chunk) = self.producer.write_chunk_uninit(len) {
+            chunk.fill_from_iter(samples.iter().map(|s| *s as f32 * scale));
+        }
+    }
+}
+
+/// A running recording.
+///
+/// Stopping (or dropping) the recorder finalizes every file it wrote.
+#[cfg(not(target_family = "wasm"))]
+pub struct Recorder {
+    shared: Arc<TapShared>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<Result<Vec<PathBuf>, Error>>>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Recorder {
+    /// Starts recording every track of `tap` to `path`.
+    ///
+    /// With [`RecordingLayout::SeparateTracks`], `path` is used as a stem and
+    /// each track is written to `<stem>-<index>-<track name>.<ext>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tap is already being recorded, the sample
+    /// rate is zero, or the mixed output file cannot be created.
+    pub fn start(
+        tap: &RecordingTap,
+        path: impl AsRef<Path>,
+        config: RecordingConfig,
+    ) -> Result<Self, Error> {
+        if config.sample_rate == 0 {
+            return Err(Error::AudioFile(AudioFileError::ZeroSampleRate));
+        }
+
+        let shared = tap.shared.clone();
+        if shared
+            .recording
+            .compare_exchange(false, true, Relaxed, Relaxed)
+            .is_err()
+        {
+            return Err(Error::Config(ConfigError::AlreadyRecording));
+        }
+
+        let writer = match RecordingWriter::new(path.as_ref(), config) {
+            Ok(writer) => writer,
+            Err(error) => {
+                shared.recording.store(false, Relaxed);
+                return Err(error);
+            }
+        };
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_shared = shared.clone();
+        let thread_stop = stop.clone();
+        let handle = std::thread::spawn(move || {
+            let result = writer.run(&thread_shared, &thread_stop);
+            thread_shared.recording.store(false, Relaxed);
+            result
+        });
+
+        Ok(Self {
+            shared,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Returns false once the writer has stopped, e.g. after a write error.
+    pub fn is_running(&self) -> bool {
+        self.shared.recording.load(Relaxed)
+    }
+
+    /// Stops the recording and returns the paths of the written files.
+    pub fn stop(mut self) -> Result<Vec<PathBuf>, Error> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<Vec<PathBuf>, Error> {
+        self.stop.store(true, Relaxed);
+        match self.handle.take() {
+            Some(handle) => handle
+                .join()
+                .map_err(|_| Error::Task(TaskError::RecorderPanicked))?,
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Err(error) = self.finish() {
+            warn!(%error, "recording_failed");
+        }
+    }
+}
+
+/// Writer-side state of one track.
+#[cfg(not(target_family = "wasm"))]
+struct TrackState {
+    /// Resamples the mono downmix to the recording rate when rates differ.
+    resampler: Option<Fft<f32>>,
+    /// Mono samples at the track rate waiting for a full resampler chunk.
+    input: Vec<f32>,
+    /// Mono samples at the recording rate waiting to be written.
+    pending: VecDeque<f32>,
+    post_buf: Vec<f32>,
+    /// The track's own file with [`RecordingLayout::SeparateTracks`].
+    file: Option<TrackFile<BufWriter<File>>>,
+    /// Whether the producer has gone away.
+    closed: bool,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TrackState {
+    fn new(slot: &TrackSlot, recording_rate: u32) -> Result<Self, Error> {
+        let chunk = FRAME_SIZE / slot.channels;
+        let resampler = resampler_factory(
+            slot.sample_rate as usize,
+            recording_rate as usize,
+            1,
+            chunk,
+            FixedSync::Input,
+        )?;
+        let post_size = resampler
+            .as_ref()
+            .map(|r| r.output_frames_max())
+            .unwrap_or(0);
+
+        Ok(Self {
+            resampler,
+            input: Vec::with_capacity(chunk * 2),
+            pending: VecDeque::with_capacity((MAX_BACKLOG_FRAMES + 1) * FRAME_SIZE),
+            post_buf: vec![0_f32; post_size],
+            file: None,
+            closed: false,
+        })
+    }
+
+    /// Moves everything the producer has queued into `pending`.
+    fn drain(&mut self, slot: &mut TrackSlot) -> Result<(), Error> {
+        let channels = slot.channels;
+        // only whole interleaved frames are read
+        let available = slot.consumer.slots() / channels * channels;
+        if let Ok(chunk) = slot.consumer.read_chunk(available) {
+            let (first, second) = chunk.as_slices();
+            let mut samples = first.iter().chain(second).copied();
+            for _ in 0..available / channels {
+                let sum: f32 = samples.by_ref().take(channels).sum();
+                self.input.push(sum / channels as f32);
+            }
+            chunk.commit_all();
+        }
+        self.closed = slot.consumer.is_abandoned() && slot.consumer.is_empty();
+
+        match &mut self.resampler {
+            None => self.pending.extend(self.input.drain(..)),
+            Some(resampler) => {
+                let mut offset = 0;
+                while self.input.len() - offset >= resampler.input_frames_next() {
+                    let frames = resampler.input_frames_next();
+                    let input = &self.input[offset..offset + frames];
+                    let input_adapter = InterleavedSlice::new(input, 1, frames)?;
+                    let output_frames = self.post_buf.len();
+                    let mut output_adapter =
+                        InterleavedSlice::new_mut(&mut self.post_buf, 1, output_frames)?;
+                    let processed =
+                        resampler.process_into_buffer(&input_adapter, &mut output_adapter, None)?;
+                    self.pending.extend(&self.post_buf[..processed.1]);
+                    offset += frames;
+                }
+                self.input.drain(..offset);
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes one frame into `frame`, or silence when a full frame is not buffered.
+    fn take_frame(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+        let limit = MAX_BACKLOG_FRAMES * FRAME_SIZE;
+        let dropped = self.pending.len().saturating_sub(limit);
+        self.pending.drain(..dropped);
+
+        if self.pending.len() < FRAME_SIZE {
+            frame.fill(0.0);
+        } else {
+            for (sample, pending) in frame.iter_mut().zip(self.pending.drain(..FRAME_SIZE)) {
+                *sample = pending;
+            }
+        }
+    }
+}
+
+/// Drains the tap on a wall-clock tick and writes the files.
+#[cfg(not(target_family = "wasm"))]
+struct RecordingWriter {
+    path: PathBuf,
+    config: RecordingConfig,
+    /// The single output file with [`RecordingLayout::Mixed`].
+    mixed: Option<TrackFile<BufWriter<File>>>,
+    files: Vec<PathBuf>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RecordingWriter {
+    fn new(path: &Path, config: RecordingConfig) -> Result<Self, Error> {
+        let mut files = Vec::new();
+        let mixed = match config.layout {
+            RecordingLayout::Mixed => {
+                files.push(path.to_path_buf());
+                Some(TrackFile::create(path, config)?)
+            }
+            RecordingLayout::SeparateTracks => None,
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            config,
+            mixed,
+            files,
+        })
+    }
+
+    /// The file path for the `index`th track with [`RecordingLayout::SeparateTracks`].
+    fn track_path(&self, index: usize, name: &str) -> PathBuf {
+        let stem = self
+            .path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| String::from("recording"));
+        let name: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let file_name = format!(
+            "{}-{}-{}.{}",
+            stem,
+            index,
+            name,
+            self.config.format.extension()
+        );
+        self.path.with_file_name(file_name)
+    }
+
+    fn run(mut self, shared: &TapShared, stop: &AtomicBool) -> Result<Vec<PathBuf>, Error> {
+        let mut tracks: Vec<TrackState> = Vec::new();
+        let result = self.record(&mut tracks, shared, stop);
+
+        // the files are finished after a write error too, so the audio
+        // written before it stays readable
+        let files = self
+            .mixed
+            .take()
+            .into_iter()
+            .chain(tracks.into_iter().filter_map(|track| track.file));
+        let finished = finish_files(files);
+
+        let written = result?;
+        finished?;
+        debug!(frames = written, "Recording ended");
+        Ok(self.files)
+    }
+
+    /// Writes one frame per tick until `stop` is set, returning the number of
+    /// frames written.
+    fn record(
+        &mut self,
+        tracks: &mut Vec<TrackState>,
+        shared: &TapShared,
+        stop: &AtomicBool,
+    ) -> Result<usize, Error> {
+        let mut frame = [0_f32; FRAME_SIZE];
+        let mut mix = [0_f32; FRAME_SIZE];
+        // frames written so far, used to align tracks that join late
+        let mut written = 0_usize;
+
+        let period = Duration::from_secs_f64(FRAME_SIZE as f64 / self.config.sample_rate as f64);
+        let mut next_tick = Instant::now();
+
+        // samples queued before this recording started are stale
+        if let Ok(mut slots) = shared.tracks.lock() {
+            for slot in slots.iter_mut() {
+                let stale = slot.consumer.slots();
+                if let Ok(chunk) = slot.consumer.read_chunk(stale) {
+                    chunk.commit_all();
+                }
+                slot.attached = false;
+            }
+        }
+
+        while !stop.load(Relaxed) {
+            if let Ok(mut slots) = shared.tracks.lock() {
+                // tracks are attached in registration order, so indices line up
+                let mut index = 0;
+                slots.retain_mut(|slot| {
+                    if !slot.attached {
+                        slot.attached = true;
+                        match TrackState::new(slot, self.config.sample_rate) {
+                            Ok(state) => tracks.insert(index, state),
+                            Err(error) => {
+                                warn!(%error, name = slot.name, "recording_track_skipped");
+                                return false;
+                            }
+                        }
+                        if self.config.layout == RecordingLayout::SeparateTracks {
+                            let path = self.track_path(self.files.len(), &slot.name);
+                            match TrackFile::create(&path, self.config) {
+                                Ok(mut file) => {
+                                    // pad with silence so every track starts at the same time
+                                    if let Err(error) = file.write_silence(written) {
+                                        warn!(%error, "recording_track_write_failed");
+                                    }
+                                    tracks[index].file = Some(file);
+                                    self.files.push(path);
+                                }
+                                Err(error) => {
+                                    warn!(%error, name = slot.name, "recording_track_skipped")
+                                }
+                            }
+                        }
+                    }
+                    if let Err(error) = tracks[index].drain(slot) {
+                        warn!(%error, "recording_track_failed");
+                        tracks[index].closed = true;
+                    }
+                    let keep = !tracks[index].closed;
+                    if !keep {
+                        // a departed track's file ends where its audio did
+                        let state = tracks.remove(index);
+                        if let Some(Err(error)) = state.file.map(TrackFile::finish) {
+                            warn!(%error, "recording_track_write_failed");
+                        }
+                    } else {
+                        index += 1;
+                    }
+                    keep
+                });
+            }
+
+            if let Some(mixed) = &mut self.mixed {
+                mix.fill(0.0);
+                for track in tracks.iter_mut() {
+                    track.take_frame(&mut frame);
+                    for (mix, sample) in mix.iter_mut().zip(frame.iter()) {
+                        *mix += sample;
+                    }
+                }
+                mixed.write_frame(&mix)?;
+            } else {
+                for track in tracks.iter_mut() {
+                    track.take_frame(&mut frame);
+                    if let Some(file) = &mut track.file {
+                        file.write_frame(&frame)?;
+                    }
+                }
+            }
+            written += 1;
+
+            next_tick += period;
+            let now = Instant::now();
+            if next_tick > now {
+                std::thread::sleep(next_tick - now);
+            } else if now - next_tick > period * MAX_BACKLOG_FRAMES as u32 {
+                // fell far behind, resync the clock
+                next_tick = now;
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+/// Finishes every file, returning the first error after trying them all.
+#[cfg(not(target_family = "wasm"))]
+fn finish_files<W: Write + Seek>(
+    files: impl IntoIterator<Item = TrackFile<W>>,
+) -> Result<(), Error> {
+    let mut result = Ok(());
+    for file in files {
+        if let Err(error) = file.finish() {
+            if result.is_ok() {
+                result = Err(error);
+            } else {
+                warn!(%error, "recording_track_write_failed");
+            }
+        }
+    }
+    result
+}
+
+/// One mono output file.
+#[cfg(not(target_family = "wasm"))]
+enum TrackFile<W: Write + Seek> {
+    Wav(WavWriter<W>),
+    Sea(Box<SeaWriter<W>>),
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TrackFile<BufWriter<File>> {
+    fn create(path: &Path, config: RecordingConfig) -> Result<Self, Error> {
+        let file =
+            File::create(path).map_err(|error| Error::AudioFile(AudioFileError::Io(error)))?;
+        Self::new(BufWriter::new(file), config)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<W: Write + Seek> TrackFile<W> {
+    fn new(writer: W, config: RecordingConfig) -> Result<Self, Error> {
+        Ok(match config.format {
+            RecordingFormat::Wav => TrackFile::Wav(WavWriter::new(writer, config.sample_rate, 1)?),
+            RecordingFormat::Sea => {
+                TrackFile::Sea(Box::new(SeaWriter::new(writer, config.sample_rate)?))
+            }
+        })
+    }
+
+    fn write_frame(&mut self, frame: &[f32; FRAME_SIZE]) -> Result<(), Error> {
+        let mut samples = [0_i16; FRAME_SIZE];
+        for (sample, value) in samples.iter_mut().zip(frame) {
+            *sample = (value.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        }
+        match self {
+            TrackFile::Wav(writer) => writer.write_frame(&samples),
+            TrackFile::Sea(writer) => writer.write_frame(samples),
+        }
+    }
+
+    fn write_silence(&mut self, frames: usize) -> Result<(), Error> {
+        let silence = [0_f32; FRAME_SIZE];
+        for _ in 0..frames {
+            self.write_frame(&silence)?;
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<W, Error> {
+        match self {
+            TrackFile::Wav(writer) => writer.finish(),
+            TrackFile::Sea(writer) => writer.finish(),
+        }
+    }
+}
+
+/// Size of the canonical WAV header written by [`WavWriter`].
+#[cfg(not(target_family = "wasm"))]
+const WAV_HEADER_SIZE: u32 = 44;
+
+/// Streams interleaved 16-bit PCM into a WAV file, filling in the sizes on finish.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub(crate) struct WavWriter<W: Write + Seek> {
+    writer: W,
+    data_len: u32,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<W: Write + Seek> WavWriter<W> {
+    pub(crate) fn new(mut writer: W, sample_rate: u32, channels: u16) -> Result<Self, Error> {
+        let block_align = channels * 2;
+        let mut header = Vec::with_capacity(WAV_HEADER_SIZE as usize);
+        header.extend_from_slice(b"RIFF");
+        header.extend_from_slice(&0_u32.to_le_bytes()); // riff size, set on finish
+        header.extend_from_slice(b"WAVEfmt ");
+        header.extend_from_slice(&16_u32.to_le_bytes()); // fmt chunk size
+        header.extend_from_slice(&1_u16.to_le_bytes()); // PCM
+        header.extend_from_slice(&channels.to_le_bytes());
+        header.extend_from_slice(&sample_rate.to_le_bytes());
+        header.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes()); // byte rate
+        header.extend_from_slice(&block_align.to_le_bytes());
+        header.extend_from_slice(&16_u16.to_le_bytes()); // bits per sample
+        header.extend_from_slice(b"data");
+        header.extend_from_slice(&0_u32.to_le_bytes()); // data size, set on finish
+        writer.write_all(&header).map_err(io_error)?;
+
+        Ok(Self {
+            writer,
+            data_len: 0,
+        })
+    }
+
+    fn write_frame(&mut self, samples: &[i16; FRAME_SIZE]) -> Result<(), Error> {
+        self.write_samples(samples)
+    }
+
+    /// Appends interleaved samples, which must be whole sample frames.
+    pub(crate) fn write_samples(&mut self, samples: &[i16]) -> Result<(), Error> {
+        let mut bytes = [0_u8; FRAME_SIZE * 2];
+        for samples in samples.chunks(FRAME_SIZE) {
+            let bytes = &mut bytes[..samples.len() * 2];
+            for (bytes, sample) in bytes.chunks_exact_mut(2).zip(samples) {
+                bytes.copy_from_slice(&sample.to_le_bytes());
+            }
+            self.writer.write_all(bytes).map_err(io_error)?;
+            self.data_len = self.data_len.saturating_add(bytes.len() as u32);
+        }
+        Ok(())
+    }
+
+    /// Writes the sizes of the samples so far into the header and flushes,
+    /// leaving the file readable while more samples may follow.
+    pub(crate) fn update_header(&mut self) -> Result<(), Error> {
+        let riff_len = self.data_len.saturating_add(WAV_HEADER_SIZE - 8);
+        self.writer.seek(SeekFrom::Start(4)).map_err(io_error)?;
+        self.writer
+            .write_all(&riff_len.to_le_bytes())
+            .map_err(io_error)?;
+        self.writer.seek(SeekFrom::Start(40)).map_err(io_error)?;
+        self.writer
+            .write_all(&self.data_len.to_le_bytes())
+            .map_err(io_error)?;
+        self.writer.seek(SeekFrom::End(0)).map_err(io_error)?;
+        self.writer.flush().map_err(io_error)
+    }
+
+    pub(crate) fn finish(mut self) -> Result<W, Error> {
+        self.update_header()?;
+        Ok(self.writer)
+    }
+}
+
+/// Streams mono SEA chunks into a file, filling in the chunk size on finish.
+#[cfg(not(target_family = "wasm"))]
+struct SeaWriter<W: Write + Seek> {
+    writer: W,
+    encoder: SeaEncoder,
+    buffer: BytesMut,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<W: Write + Seek> SeaWriter<W> {
+    fn new(mut writer: W, sample_rate: u32) -> Result<Self, Error> {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAME_SIZE as u16,
+            vbr: true,
+            ..Default::default()
+        };
+        let encoder = SeaEncoder::new(1, sample_rate, settings).map_err(sea_error)?;
+
+        let header = SeaFileHeader {
+            version: 1,
+            channels: 1,
+            chunk_size: 0, // set on finish
+            frames_per_chunk: FRAME_SIZE as u16,
+            sample_rate,
+        };
+        writer.write_all(&header.serialize()).map_err(io_error)?;
+
+        Ok(Self {
+            writer,
+            encoder,
+            buffer: BytesMut::new(),
+        })
+    }
+
+    fn write_frame(&mut self, samples: [i16; FRAME_SIZE]) -> Result<(), Error> {
+        self.encoder
+            .encode_frame(samples, &mut self.buffer)
+            .map_err(sea_error)?;
+        self.writer.write_all(&self.buffer).map_err(io_error)
+    }
+
+    fn finish(mut self) -> Result<W, Error> {
+        self.writer.seek(SeekFrom::Start(6)).map_err(io_error)?;
+        self.writer
+            .write_all(&self.encoder.chunk_size().to_le_bytes())
+            .map_err(io_error)?;
+        self.writer.flush().map_err(io_error)?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn io_error(error: std::io::Error) -> Error {
+    Error::AudioFile(AudioFileError::Io(error))
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn sea_error(error: crate::sea::codec::common::SeaError) -> Error {
+    Error::AudioFile(AudioFileError::Codec(error))
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::sea::decoder::SeaDecoder;
+    use std::io::Cursor;
+
+    fn config(format: RecordingFormat) -> RecordingConfig {
+        RecordingConfig {
+            format,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn wav_writer_fills_in_the_sizes() {
+        let mut file =
+            TrackFile::new(Cursor::new(Vec::new()), config(RecordingFormat::Wav)).unwrap();
+        file.write_frame(&[0.5; FRAME_SIZE]).unwrap();
+        file.write_silence(1).unwrap();
+        let bytes = file.finish().unwrap().into_inner();
+
+        let data_len = (FRAME_SIZE * 2 * 2) as u32;
+        assert_eq!(bytes.len(), WAV_HEADER_SIZE as usize + data_len as usize);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(
+            u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+            data_len + 36
+        );
+        assert_eq!(u16::from_le_bytes([bytes[22], bytes[23]]), 1);
+        assert_eq!(
+            u32::from_le_bytes(bytes[24..28].try_into().unwrap()),
+            DEFAULT_RECORDING_RATE
+        );
+        assert_eq!(
+            u32::from_le_bytes(bytes[40..44].try_into().unwrap()),
+            data_len
+        );
+        assert_eq!(i16::from_le_bytes([bytes[44], bytes[45]]), i16::MAX / 2);
+    }
+
+    /// Refuses writes past `limit` bytes, like a full disk.
+    struct FullDisk<'a> {
+        inner: Cursor<&'a mut Vec<u8>>,
+        limit: u64,
+    }
+
+    impl Write for FullDisk<'_> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.inner.position() + buf.len() as u64 > self.limit {
+                return Err(std::io::ErrorKind::StorageFull.into());
+            }
+            self.inner.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Seek for FullDisk<'_> {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn files_keep_their_audio_after_a_write_error() {
+        let data_len = (FRAME_SIZE * 2) as u32;
+        let mut bytes = Vec::new();
+        let disk = FullDisk {
+            inner: Cursor::new(&mut bytes),
+            limit: (WAV_HEADER_SIZE + data_len) as u64,
+        };
+        let mut file = TrackFile::new(disk, config(RecordingFormat::Wav)).unwrap();
+        file.write_frame(&[0.5; FRAME_SIZE]).unwrap();
+        assert!(file.write_frame(&[0.5; FRAME_SIZE]).is_err());
+
+        finish_files([file]).unwrap();
+        assert_eq!(
+            u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+            data_len + 36
+        );
+        assert_eq!(
+            u32::from_le_bytes(bytes[40..44].try_into().unwrap()),
+            data_len
+        );
+    }
+
+    #[test]
+    fn sea_writer_produces_a_decodable_file() {
+        let mut file =
+            TrackFile::new(Cursor::new(Vec::new()), config(RecordingFormat::Sea)).unwrap();
+        for _ in 0..3 {
+            file.write_frame(&[0.25; FRAME_SIZE]).unwrap();
+        }
+        let bytes = file.finish().unwrap().into_inner();
+
+        let header = SeaFileHeader::from_frame(&bytes[..14]).unwrap();
+        assert_eq!(header.channels, 1);
+        assert_eq!(header.sample_rate, DEFAULT_RECORDING_RATE);
+        let chunk_size = header.chunk_size as usize;
+        assert_eq!((bytes.len() - 14) % chunk_size, 0);
+        assert_eq!((bytes.len() - 14) / chunk_size, 3);
+
+        let mut decoder = SeaDecoder::new(header).unwrap();
+        let mut samples = [0_i16; FRAME_SIZE];
+        decoder
+            .decode_frame(&bytes[14 + 2 * chunk_size..], &mut samples)
+            .unwrap();
+        let expected = i16::MAX / 4;
+        assert!(samples.iter().all(|s| (s - expected).abs() < 1_000));
+    }
+
+    #[test]
+    fn tracks_drop_samples_while_not_recording() {
+        let tap = RecordingTap::new();
+        let mut track = tap.track("local", 48_000, 1);
+        track.push(&[0.5; FRAME_SIZE]);
+
+        let slots = tap.shared.tracks.lock().unwrap();
+        assert!(slots[0].consumer.is_empty());
+    }
+
+    #[test]
+    fn closed_tracks_are_pruned_while_not_recording() {
+        let tap = RecordingTap::new();
+        drop(tap.track("first call", 48_000, 1));
+        drop(tap.track("second call", 48_000, 1));
+        let _track = tap.track("third call", 48_000, 1);
+
+        let slots = tap.shared.tracks.lock().unwrap();
+        assert_eq!(slots.len(), 1);
+        assert_eq!(slots[0].name, "third call");
+    }
+
+    #[test]
+    fn track_state_downmixes_and_resamples() {
+        let tap = RecordingTap::new();
+        tap.shared.recording.store(true, Relaxed);
+        let mut track = tap.track("peer", 24_000, 2);
+        let mut slots = tap.shared.tracks.lock().unwrap();
+        let mut state = TrackState::new(&slots[0], DEFAULT_RECORDING_RATE).unwrap();
+
+        let frame: Vec<f32> = (0..FRAME_SIZE)
+            .map(|i| if i % 2 == 0 { 0.5 } else { 0.0 })
+            .collect();
+        for _ in 0..8 {
+            track.push(&frame);
+        }
+        state.drain(&mut slots[0]).unwrap();
+
+        // 8 stereo frames at half the rate become 8 mono frames at 48 kHz, minus the resampler delay
+        assert!(state.pending.len() > 4 * FRAME_SIZE);
+        assert!(state.pending.len() <= 8 * FRAME_SIZE);
+        let settled = state.pending.iter().rev().take(FRAME_SIZE);
+        assert!(settled.copied().all(|s| (s - 0.25).abs() < 0.02));
+    }
+
+    #[test]
+    fn take_frame_pads_silence_and_trims_the_backlog() {
+        let tap = RecordingTap::new();
+        let _track = tap.track("local", 48_000, 1);
+        let slots = tap.shared.tracks.lock().unwrap();
+        let mut state = TrackState::new(&slots[0], DEFAULT_RECORDING_RATE).unwrap();
+
+        let mut frame = [1_f32; FRAME_SIZE];
+        state.pending.extend([0.5; FRAME_SIZE / 2]);
+        state.take_frame(&mut frame);
+        assert!(frame.iter().all(|s| *s == 0.0));
+
+        state
+            .pending
+            .extend(vec![0.5; (MAX_BACKLOG_FRAMES + 2) * FRAME_SIZE]);
+        state.take_frame(&mut frame);
+        assert!(frame.iter().all(|s| *s == 0.5));
+        assert_eq!(state.pending.len(), (MAX_BACKLOG_FRAMES - 1) * FRAME_SIZE);
+    }
+
+    #[test]
+    fn track_paths_are_sanitized() {
+        let writer = RecordingWriter {
+            path: PathBuf::from("/tmp/call.wav"),
+            config: RecordingConfig {
+                layout: RecordingLayout::SeparateTracks,
+                ..Default::default()
+            },
+            mixed: None,
+            files: Vec::new(),
+        };
+        assert_eq!(
+            writer.track_path(2, "peer/one two"),
+            PathBuf::from("/tmp/call-2-peer_one_two.wav")
+        );
+    }
+}
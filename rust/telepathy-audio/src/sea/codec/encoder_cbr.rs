@@ -35,6 +35,12 @@ impl CbrEncoder {
     pub fn get_lms(&self) -> &Vec<SeaLMS> {
         &self.base_encoder.lms
     }
+
+    /// Takes the residual size of the following chunks from `encoder_settings`.
+    pub fn update_settings(&mut self, encoder_settings: &EncoderSettings) {
+        self.residual_size =
+            SeaResidualSize::from(libm::floorf(encoder_settings.residual_bits) as u8);
+    }
 }
 
 impl SeaEncoderTrait for CbrEncoder {
@@ -52,6 +58,7 @@ impl SeaEncoderTrait for CbrEncoder {
         self.scratch_ranks.resize(self.channels, 0);
         self.scratch_residual_sizes
             .resize(self.channels, self.residual_size);
+        self.scratch_residual_sizes.fill(self.residual_size);
 
         let slice_size = self.scale_factor_frames * self.channels;
 
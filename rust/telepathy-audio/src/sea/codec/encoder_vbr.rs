@@ -60,6 +60,11 @@ impl VbrEncoder {
         &self.base_encoder.lms
     }
 
+    /// Takes the target bitrate of the following chunks from `encoder_settings`.
+    pub fn update_settings(&mut self, encoder_settings: &EncoderSettings) {
+        self.vbr_target_bitrate = Self::get_normalized_vbr_bitrate(encoder_settings);
+    }
+
     fn get_normalized_vbr_bitrate(encoder_settings: &EncoderSettings) -> f32 {
         let mut vbr_bitrate = encoder_settings.residual_bits;
 
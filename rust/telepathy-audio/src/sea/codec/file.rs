@@ -1,7 +1,7 @@
 use super::{
     bits::{BitPacker, BitUnpacker},
     chunk::{SeaChunk, SeaChunkType},
-    common::{SEAC_MAGIC, SeaEncoderTrait, SeaError},
+    common::{SEAC_MAGIC, SeaEncoderTrait, SeaError, clamp_i16},
     decoder::Decoder,
     encoder_cbr::CbrEncoder,
     encoder_vbr::VbrEncoder,
@@ -95,7 +95,7 @@ impl ChunkSerializer {
     }
 }
 
-const DEFAULT_SCALE_FACTOR_FRAMES: usize = 20;
+pub(crate) const DEFAULT_SCALE_FACTOR_FRAMES: usize = 20;
 
 pub(crate) fn is_valid_geometry(channels: u8, frames_per_chunk: u16) -> bool {
     (channels as usize).checked_mul(frames_per_chunk as usize) == Some(FRAME_SIZE)
@@ -259,6 +259,32 @@ impl SeaFile {
         Ok(())
     }
 
+    /// Changes the residual bits of the following chunks.
+    ///
+    /// Every chunk carries its residual size and LMS state, so a decoder keeps
+    /// decoding across the change. The chunk size is measured again from the
+    /// next chunk.
+    pub fn set_residual_bits(&mut self, residual_bits: f32) -> Result<(), SeaError> {
+        let (Some(settings), Some(encoder)) = (&mut self.encoder_settings, &mut self.encoder)
+        else {
+            return Err(SeaError::InvalidParameters);
+        };
+
+        let updated = EncoderSettings {
+            residual_bits,
+            ..settings.clone()
+        };
+        updated.validate()?;
+
+        match encoder {
+            ActiveEncoder::Cbr(encoder) => encoder.update_settings(&updated),
+            ActiveEncoder::Vbr(encoder) => encoder.update_settings(&updated),
+        }
+        *settings = updated;
+        self.header.chunk_size = 0;
+        Ok(())
+    }
+
     pub fn samples_from_frame(
         &mut self,
         frame: &[u8],
@@ -309,4 +335,26 @@ impl SeaFile {
 
         Ok(())
     }
+
+    /// Extrapolates interleaved samples from the LMS state of the last decoded frame.
+    ///
+    /// The predictors run with a zero residual, continuing the short-term
+    /// envelope of the previous frame. The state advances so consecutive calls
+    /// keep extrapolating; the next real frame carries its own LMS state and
+    /// resynchronizes the decoder. Returns `false` if nothing was decoded yet.
+    pub fn extrapolate_samples(&mut self, output: &mut [i16]) -> bool {
+        let channels = self.header.channels as usize;
+        if channels == 0 || self.scratch_lms.len() != channels {
+            return false;
+        }
+
+        for (index, sample) in output.iter_mut().enumerate() {
+            let lms = &mut self.scratch_lms[index % channels];
+            let predicted = clamp_i16(lms.predict());
+            lms.update(predicted, 0);
+            *sample = predicted;
+        }
+
+        true
+    }
 }
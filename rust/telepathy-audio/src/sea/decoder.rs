@@ -27,6 +27,14 @@ impl SeaDecoder {
         Ok(())
     }
 
+    /// Fills `output` with an LMS extrapolation of the previously decoded frame.
+    ///
+    /// Used to conceal a lost frame. Returns `false` (leaving `output`
+    /// untouched) when no frame has been decoded yet.
+    pub fn conceal_frame(&mut self, output: &mut [i16; FRAME_SIZE]) -> bool {
+        self.file.extrapolate_samples(output)
+    }
+
     pub fn get_header(&self) -> SeaFileHeader {
         self.file.header.clone()
     }
@@ -157,4 +165,32 @@ mod tests {
 
         assert!(max_abs_diff(&input, &output) <= 2_000);
     }
+
+    #[test]
+    fn conceal_frame_extrapolates_previous_frame() {
+        let mut encoder = SeaEncoder::new(1, 16_000, EncoderSettings::default()).unwrap();
+        let input = sine_frame(16_000.0, 440.0, 16_000.0);
+        let mut encoded = BytesMut::new();
+        encoder.encode_frame(input, &mut encoded).unwrap();
+
+        let header = SeaFileHeader {
+            version: 1,
+            channels: 1,
+            chunk_size: encoder.chunk_size(),
+            frames_per_chunk: 480,
+            sample_rate: 16_000,
+        };
+        let mut decoder = SeaDecoder::new(header).unwrap();
+        let mut output = [0i16; FRAME_SIZE];
+        assert!(!decoder.conceal_frame(&mut output));
+
+        decoder.decode_frame(&encoded, &mut output).unwrap();
+        let last = output[FRAME_SIZE - 1];
+
+        let mut concealed = [0i16; FRAME_SIZE];
+        assert!(decoder.conceal_frame(&mut concealed));
+        // the extrapolation continues the waveform instead of restarting at zero
+        assert!((concealed[0] as i32 - last as i32).abs() < 4_000);
+        assert!(concealed[..FRAME_SIZE / 4].iter().any(|s| s.abs() > 4_000));
+    }
 }
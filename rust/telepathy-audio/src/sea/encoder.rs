@@ -122,6 +122,16 @@ impl SeaEncoder {
     pub fn chunk_size(&self) -> u16 {
         self.file.header.chunk_size
     }
+
+    /// Changes the residual bits of the following frames.
+    ///
+    /// Frames encoded before and after the change decode with the same
+    /// [`SeaDecoder`](crate::sea::decoder::SeaDecoder), so the bitrate can
+    /// follow the network mid-stream. [`chunk_size`](Self::chunk_size) is
+    /// updated by the next frame.
+    pub fn set_residual_bits(&mut self, residual_bits: f32) -> Result<(), SeaError> {
+        self.file.set_residual_bits(residual_bits)
+    }
 }
 
 #[cfg(test)]
@@ -140,4 +150,18 @@ mod tests {
         let result = SeaEncoder::new(1, 0, EncoderSettings::default());
         assert!(matches!(result, Err(SeaError::InvalidParameters)));
     }
+
+    #[test]
+    fn set_residual_bits_rejects_invalid_values_and_keeps_settings() {
+        let mut encoder = SeaEncoder::new(1, 48_000, EncoderSettings::default()).unwrap();
+
+        for residual_bits in [0.5, 9.0, f32::NAN] {
+            let result = encoder.set_residual_bits(residual_bits);
+            assert!(matches!(result, Err(SeaError::InvalidParameters)));
+        }
+        assert_eq!(
+            encoder.file.encoder_settings,
+            Some(EncoderSettings::default())
+        );
+    }
 }
@@ -12,6 +12,7 @@ use telepathy_audio::ChannelError;
 use telepathy_audio::Error;
 use telepathy_audio::FRAME_SIZE;
 use telepathy_audio::internal::buffer_pool::DEFAULT_POOL_CAPACITY;
+use telepathy_audio::internal::echo::EchoReferenceProducer;
 use telepathy_audio::internal::state::{InputProcessorState, OutputProcessorState};
 use telepathy_audio::internal::traits::{AudioInput, AudioOutput};
 use telepathy_audio::io::traits::{AudioDataSource, ClosedOrFailed};
@@ -153,6 +154,71 @@ impl AudioInput for TestAudioInput {
     }
 }
 
+/// Simulates a microphone picking up a delayed copy of far-end noise.
+///
+/// Every read plays fresh far-end noise into the optional echo reference
+/// (as an output processor would) and returns the echo of what was played
+/// `delay` samples earlier, scaled by `gain`.
+pub struct EchoAudioInput {
+    reference: Option<EchoReferenceProducer>,
+    played: Vec<f32>,
+    samples_remaining: usize,
+    delay: usize,
+    gain: f32,
+    seed: u32,
+}
+
+impl EchoAudioInput {
+    pub fn new(
+        reference: Option<EchoReferenceProducer>,
+        total_samples: usize,
+        delay: usize,
+        gain: f32,
+    ) -> Self {
+        Self {
+            reference,
+            played: Vec::with_capacity(total_samples),
+            samples_remaining: total_samples,
+            delay,
+            gain,
+            seed: 1,
+        }
+    }
+}
+
+impl AudioInput for EchoAudioInput {
+    fn read_into(&mut self, dst: &mut [f32]) -> Result<usize, Error> {
+        if self.samples_remaining == 0 {
+            return Ok(0);
+        }
+
+        let to_read = dst.len().min(self.samples_remaining);
+        let start = self.played.len();
+        for _ in 0..to_read {
+            self.seed = self
+                .seed
+                .wrapping_mul(1_664_525)
+                .wrapping_add(1_013_904_223);
+            let noise = (self.seed >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0;
+            self.played.push(noise * 0.3);
+        }
+        if let Some(reference) = &mut self.reference {
+            reference.push(&self.played[start..]);
+        }
+
+        for (offset, sample) in dst.iter_mut().take(to_read).enumerate() {
+            let index = start + offset;
+            *sample = if index >= self.delay {
+                self.played[index - self.delay] * self.gain
+            } else {
+                0.0
+            };
+        }
+        self.samples_remaining -= to_read;
+        Ok(to_read)
+    }
+}
+
 /// Counting output that records the number of samples and frames written.
 pub struct TestAudioOutput {
     samples_received: Arc<AtomicUsize>,
@@ -363,6 +429,82 @@ impl AudioOutput for PartialWriteOutput {
     }
 }
 
+/// Output simulating a device buffer that drains `drain` samples per write,
+/// as a device clock running at a slightly different rate would.
+pub struct DrainingOutput {
+    queued: f64,
+    drain: f64,
+    written: Arc<AtomicUsize>,
+}
+
+impl DrainingOutput {
+    pub fn new(drain: f64) -> (Self, Arc<AtomicUsize>) {
+        let written = Arc::new(AtomicUsize::new(0));
+        (
+            Self {
+                queued: 0.0,
+                drain,
+                written: written.clone(),
+            },
+            written,
+        )
+    }
+}
+
+impl AudioOutput for DrainingOutput {
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    fn write_samples(&mut self, samples: &[f32]) -> Result<usize, Error> {
+        self.written.fetch_add(samples.len(), Ordering::Relaxed);
+        self.queued = (self.queued + samples.len() as f64 - self.drain).max(0.0);
+        Ok(0)
+    }
+
+    fn buffered(&self) -> Option<usize> {
+        Some(self.queued as usize)
+    }
+}
+
+/// Output simulating a device buffer that only drains when the test empties
+/// the shared queued sample count.
+pub struct DeviceBufferOutput {
+    queued: Arc<AtomicUsize>,
+    writes: Arc<AtomicUsize>,
+}
+
+impl DeviceBufferOutput {
+    pub fn new() -> (Self, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let queued = Arc::new(AtomicUsize::new(0));
+        let writes = Arc::new(AtomicUsize::new(0));
+        (
+            Self {
+                queued: queued.clone(),
+                writes: writes.clone(),
+            },
+            queued,
+            writes,
+        )
+    }
+}
+
+impl AudioOutput for DeviceBufferOutput {
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    fn write_samples(&mut self, samples: &[f32]) -> Result<usize, Error> {
+        self.writes.fetch_add(1, Ordering::Relaxed);
+        self.queued.fetch_add(samples.len(), Ordering::Relaxed);
+        Ok(0)
+    }
+
+    fn buffered(&self) -> Option<usize> {
+        Some(self.queued.load(Ordering::Relaxed))
+    }
+}
+
 /// `AudioDataSource` backed by a pre-populated queue of encoded frames.
 pub struct QueueSource {
     inner: Mutex<VecDeque<Bytes>>,
@@ -0,0 +1,354 @@
+#![cfg(not(target_family = "wasm"))]
+
+mod common;
+
+use atomic_float::AtomicF32;
+use bytes::Bytes;
+use common::{
+    DeviceBufferOutput, FullAudioOutput, QueueSource, RecordingAudioOutput, TEST_SAMPLE_RATE,
+    make_output_state, raw_frame_from_i16,
+};
+use crossbeam::channel::unbounded;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use telepathy_audio::codec::RawDecoder;
+use telepathy_audio::devices::{MockAudioHost, MockAudioInput};
+use telepathy_audio::internal::mixer::{
+    DEVICE_BUFFER_FRAMES, MixerChannel, MixerCommand, mixer_processor,
+};
+use telepathy_audio::internal::traits::AudioOutput;
+use telepathy_audio::io::traits::AudioDataSource;
+use telepathy_audio::io::{AudioMixerBuilder, MixerSourceBuilder};
+use telepathy_audio::{ConfigError, Error, FRAME_SIZE};
+
+const HALF: i16 = i16::MAX / 2;
+
+fn constant_frames(value: i16, frames: usize) -> Vec<Bytes> {
+    (0..frames)
+        .map(|_| raw_frame_from_i16(&[value; FRAME_SIZE]))
+        .collect()
+}
+
+fn mixer_channel(source: impl AudioDataSource, volume: f32) -> Box<MixerChannel> {
+    Box::new(
+        MixerChannel::new(
+            Box::new(source),
+            Box::new(RawDecoder),
+            TEST_SAMPLE_RATE,
+            TEST_SAMPLE_RATE,
+            1,
+            Arc::new(AtomicF32::new(volume)),
+            Default::default(),
+        )
+        .unwrap(),
+    )
+}
+
+/// Runs the mixer with the queued commands until `frames` frames are written.
+fn run_mixer(
+    commands: Vec<MixerCommand>,
+    output: impl AudioOutput + Send + 'static,
+    deafened: bool,
+    frames: usize,
+    written: impl Fn() -> usize,
+) -> Arc<AtomicUsize> {
+    let (sender, receiver) = unbounded();
+    for command in commands {
+        sender.send(command).unwrap();
+    }
+
+    let volume = Arc::new(AtomicF32::new(1.0));
+    let deafened = Arc::new(AtomicBool::new(deafened));
+    let loss = Arc::new(AtomicUsize::new(0));
+    let state = make_output_state(&volume, Default::default(), &deafened, loss.clone());
+
+    let handle = thread::spawn(move || {
+        mixer_processor(
+            receiver,
+            output,
+            TEST_SAMPLE_RATE,
+            TEST_SAMPLE_RATE,
+            state,
+            None,
+        )
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while written() < frames && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(5));
+    }
+    sender.send(MixerCommand::Stop).unwrap();
+    handle.join().unwrap().unwrap();
+    loss
+}
+
+fn recorded_frames(recorded: &Arc<Mutex<Vec<Vec<f32>>>>) -> Vec<Vec<f32>> {
+    recorded.lock().unwrap().clone()
+}
+
+#[test]
+fn mixer_sums_sources_at_their_volumes() {
+    let (output, recorded) = RecordingAudioOutput::new();
+    let commands = vec![
+        MixerCommand::Add(
+            0,
+            mixer_channel(QueueSource::new(constant_frames(HALF, 3)), 1.0),
+        ),
+        MixerCommand::Add(
+            1,
+            mixer_channel(QueueSource::new(constant_frames(HALF, 3)), 0.5),
+        ),
+    ];
+    let counter = recorded.clone();
+    run_mixer(commands, output, false, 4, move || {
+        counter.lock().unwrap().len()
+    });
+
+    let frames = recorded_frames(&recorded);
+    assert!(frames.len() >= 4);
+    for frame in &frames[..3] {
+        assert_eq!(frame.len(), FRAME_SIZE);
+        assert!(frame.iter().all(|s| (s - 0.75).abs() < 1e-3));
+    }
+    // both sources ran dry, the mixer keeps writing silence
+    assert!(frames[3].iter().all(|s| *s == 0.0));
+}
+
+#[test]
+fn mixer_clamps_the_sum() {
+    let (output, recorded) = RecordingAudioOutput::new();
+    let commands = vec![
+        MixerCommand::Add(
+            0,
+            mixer_channel(QueueSource::new(constant_frames(i16::MAX, 1)), 1.0),
+        ),
+        MixerCommand::Add(
+            1,
+            mixer_channel(QueueSource::new(constant_frames(HALF, 1)), 1.0),
+        ),
+    ];
+    let counter = recorded.clone();
+    run_mixer(commands, output, false, 1, move || {
+        counter.lock().unwrap().len()
+    });
+
+    let frames = recorded_frames(&recorded);
+    assert!(frames[0].iter().all(|s| *s == 1.0));
+}
+
+#[test]
+fn mixer_removes_sources_on_command() {
+    let (output, recorded) = RecordingAudioOutput::new();
+    let commands = vec![
+        MixerCommand::Add(
+            0,
+            mixer_channel(QueueSource::new(constant_frames(HALF, 2)), 1.0),
+        ),
+        MixerCommand::Add(
+            1,
+            mixer_channel(QueueSource::new(constant_frames(HALF, 2)), 1.0),
+        ),
+        MixerCommand::Remove(1),
+    ];
+    let counter = recorded.clone();
+    run_mixer(commands, output, false, 2, move || {
+        counter.lock().unwrap().len()
+    });
+
+    let frames = recorded_frames(&recorded);
+    for frame in &frames[..2] {
+        assert!(frame.iter().all(|s| (s - 0.5).abs() < 1e-3));
+    }
+}
+
+#[test]
+fn mixer_writes_nothing_while_deafened() {
+    let (output, recorded) = RecordingAudioOutput::new();
+    let commands = vec![MixerCommand::Add(
+        0,
+        mixer_channel(QueueSource::new(constant_frames(HALF, 5)), 1.0),
+    )];
+    // give the mixer a few ticks to drain the source
+    let start = Instant::now();
+    run_mixer(commands, output, true, 1, move || {
+        (start.elapsed() > Duration::from_millis(50)) as usize
+    });
+
+    assert!(recorded.lock().unwrap().is_empty());
+}
+
+#[test]
+fn mixer_counts_loss_when_output_is_full() {
+    let start = Instant::now();
+    let loss = run_mixer(Vec::new(), FullAudioOutput, false, 1, move || {
+        (start.elapsed() > Duration::from_millis(50)) as usize
+    });
+
+    assert!(loss.load(Ordering::Relaxed) >= FRAME_SIZE);
+    assert!(loss.load(Ordering::Relaxed).is_multiple_of(FRAME_SIZE));
+}
+
+#[test]
+fn mixer_follows_the_fill_level_of_the_device() {
+    let (output, queued, writes) = DeviceBufferOutput::new();
+    let (sender, receiver) = unbounded();
+    let handle = thread::spawn(move || {
+        mixer_processor(
+            receiver,
+            output,
+            TEST_SAMPLE_RATE,
+            TEST_SAMPLE_RATE,
+            Default::default(),
+            None,
+        )
+    });
+
+    // the mixer fills the device buffer, then waits for it to drain
+    let wait_for = |count: usize| {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while writes.load(Ordering::Relaxed) < count && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(5));
+        }
+        thread::sleep(Duration::from_millis(50));
+        writes.load(Ordering::Relaxed)
+    };
+    assert_eq!(wait_for(DEVICE_BUFFER_FRAMES), DEVICE_BUFFER_FRAMES);
+
+    // the device consumed one frame, exactly one more is mixed
+    queued.fetch_sub(FRAME_SIZE, Ordering::Relaxed);
+    assert_eq!(wait_for(DEVICE_BUFFER_FRAMES + 1), DEVICE_BUFFER_FRAMES + 1);
+
+    sender.send(MixerCommand::Stop).unwrap();
+    assert!(handle.join().unwrap().is_ok());
+}
+
+#[test]
+fn mixer_ends_when_commands_disconnect() {
+    let (sender, receiver) = unbounded::<MixerCommand>();
+    let (output, _) = RecordingAudioOutput::new();
+    let handle = thread::spawn(move || {
+        mixer_processor(
+            receiver,
+            output,
+            TEST_SAMPLE_RATE,
+            TEST_SAMPLE_RATE,
+            Default::default(),
+            None,
+        )
+    });
+
+    drop(sender);
+    assert!(handle.join().unwrap().is_ok());
+}
+
+#[test]
+fn mixer_handle_attaches_and_detaches_sources() {
+    let (output, recorded) = RecordingAudioOutput::new();
+    let host = MockAudioHost::new(
+        MockAudioInput::default(),
+        TEST_SAMPLE_RATE as u32,
+        output,
+        TEST_SAMPLE_RATE as u32,
+    );
+    let mixer = AudioMixerBuilder::new().build(&host).unwrap();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let source = mixer
+        .add_source(
+            MixerSourceBuilder::new()
+                .volume(0.5)
+                .source(telepathy_audio::adapters::MpscSource::new(rx)),
+        )
+        .unwrap();
+    assert_eq!(source.volume(), 0.5);
+
+    for frame in constant_frames(HALF, 3) {
+        tx.send(frame).unwrap();
+    }
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while source.rms().load(Ordering::Relaxed) == 0.0 && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert!((source.rms().load(Ordering::Relaxed) - 0.25).abs() < 1e-3);
+
+    let mixed = |frame: &Vec<f32>| frame.iter().all(|s| (s - 0.25).abs() < 1e-3);
+    while !recorded_frames(&recorded).iter().any(mixed) && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert!(recorded_frames(&recorded).iter().any(mixed));
+
+    // a detached source no longer plays, even with frames queued
+    drop(source);
+    thread::sleep(Duration::from_millis(30));
+    let played = recorded.lock().unwrap().len();
+    for frame in constant_frames(HALF, 3) {
+        _ = tx.send(frame);
+    }
+    thread::sleep(Duration::from_millis(50));
+    assert!(
+        recorded.lock().unwrap()[played..]
+            .iter()
+            .all(|f| f.iter().all(|s| *s == 0.0))
+    );
+    drop(mixer);
+}
+
+#[test]
+fn mixer_handle_switches_devices() {
+    let (first, first_recorded) = RecordingAudioOutput::new();
+    let (second, second_recorded) = RecordingAudioOutput::new();
+    let host = MockAudioHost::new(
+        MockAudioInput::default(),
+        TEST_SAMPLE_RATE as u32,
+        first,
+        TEST_SAMPLE_RATE as u32,
+    );
+    let replacement = MockAudioHost::new(
+        MockAudioInput::default(),
+        TEST_SAMPLE_RATE as u32,
+        second,
+        TEST_SAMPLE_RATE as u32,
+    );
+    let mut mixer = AudioMixerBuilder::new().build(&host).unwrap();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let _source = mixer
+        .add_source(
+            MixerSourceBuilder::new().source(telepathy_audio::adapters::MpscSource::new(rx)),
+        )
+        .unwrap();
+    mixer.switch_device(&replacement, None, None).unwrap();
+    thread::sleep(Duration::from_millis(30));
+    let played = first_recorded.lock().unwrap().len();
+
+    // the attached source keeps playing, now on the replacement device
+    for frame in constant_frames(HALF, 3) {
+        tx.send(frame).unwrap();
+    }
+    let mixed = |frame: &Vec<f32>| frame.iter().all(|s| (s - 0.5).abs() < 1e-3);
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while !recorded_frames(&second_recorded).iter().any(mixed) && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert!(recorded_frames(&second_recorded).iter().any(mixed));
+    assert_eq!(first_recorded.lock().unwrap().len(), played);
+    drop(mixer);
+}
+
+#[test]
+fn add_source_without_a_source_errors() {
+    let host: MockAudioHost<MockAudioInput, RecordingAudioOutput> = MockAudioHost::new(
+        MockAudioInput::default(),
+        TEST_SAMPLE_RATE as u32,
+        RecordingAudioOutput::new().0,
+        TEST_SAMPLE_RATE as u32,
+    );
+    let mixer = AudioMixerBuilder::new().build(&host).unwrap();
+
+    assert!(matches!(
+        mixer.add_source(MixerSourceBuilder::new()),
+        Err(Error::Config(ConfigError::MissingDataSource))
+    ));
+}
@@ -5,11 +5,13 @@ mod common;
 use atomic_float::AtomicF32;
 use bytes::{Bytes, BytesMut};
 use common::{
-    FailingAudioOutput, FailingSource, FullAudioOutput, PartialWriteOutput, PartiallyFullOutput,
-    PatternAudioInput, QueueSource, RecordingAudioOutput, RecordingFullOutput, SineSource,
-    TEST_SAMPLE_RATE, TestAudioInput, TestAudioOutput, bytes_to_i16_samples, make_input_state,
-    make_output_state, raw_frame_from_i16, raw_frame_with_start,
+    DrainingOutput, EchoAudioInput, FailingAudioOutput, FailingSource, FullAudioOutput,
+    PartialWriteOutput, PartiallyFullOutput, PatternAudioInput, QueueSource, RecordingAudioOutput,
+    RecordingFullOutput, SineSource, TEST_SAMPLE_RATE, TestAudioInput, TestAudioOutput,
+    bytes_to_i16_samples, make_input_state, make_output_state, raw_frame_from_i16,
+    raw_frame_with_start,
 };
+use crossbeam::channel::unbounded;
 use nnnoiseless::DenoiseState;
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
@@ -19,10 +21,16 @@ use std::time::Duration;
 use telepathy_audio::Error;
 use telepathy_audio::FRAME_SIZE;
 use telepathy_audio::adapters::{MpscSink, MpscSource};
+use telepathy_audio::codec::{AudioDecoder, AudioEncoder, RawDecoder, RawEncoder};
 use telepathy_audio::constants::MINIMUM_SILENCE_LENGTH as PRODUCTION_MINIMUM_SILENCE_LENGTH;
 use telepathy_audio::internal::buffer_pool::PooledBuffer;
+use telepathy_audio::internal::echo::{DEFAULT_TAIL_FRAMES, EchoCanceller, EchoReference};
+use telepathy_audio::internal::effects::{AudioEffect, EffectChain};
+use telepathy_audio::internal::gain::{AutoGain, AutoGainConfig};
 use telepathy_audio::internal::processor::{input_processor, output_processor};
-use telepathy_audio::internal::state::{InputProcessorState, OutputProcessorState};
+use telepathy_audio::internal::state::{DeviceSwap, InputProcessorState, OutputProcessorState};
+use telepathy_audio::internal::vad::{VoiceGate, VoiceGateConfig};
+use telepathy_audio::io::traits::MISSING_FRAME;
 use telepathy_audio::sea::codec::file::SeaFileHeader;
 use telepathy_audio::sea::decoder::SeaDecoder;
 use telepathy_audio::sea::encoder::{EncoderSettings, SeaEncoder};
@@ -62,8 +70,12 @@ fn input_processor_encodes_frames_with_sea() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
+            None,
+            None,
+            None,
+            None,
             InputProcessorState::default(),
-            Some(encoder),
+            Box::new(encoder),
         )
     });
 
@@ -94,6 +106,58 @@ fn input_processor_encodes_frames_with_sea() {
     }
 }
 
+#[test]
+fn input_processor_follows_shared_residual_bits() {
+    let encode = |residual_bits: Option<f32>| {
+        let (tx, rx) = mpsc::channel::<PooledBuffer>();
+        let encoder =
+            SeaEncoder::new(1, TEST_SAMPLE_RATE as u32, EncoderSettings::default()).unwrap();
+        let mut state = InputProcessorState::default();
+        if let Some(residual_bits) = residual_bits {
+            state = state.with_residual_bits(Arc::new(AtomicF32::new(residual_bits)));
+        }
+
+        input_processor(
+            TestAudioInput::new(FRAME_SIZE * 4),
+            MpscSink::new(tx),
+            TEST_SAMPLE_RATE,
+            TEST_SAMPLE_RATE,
+            None,
+            None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(encoder),
+        )
+        .unwrap();
+        rx.try_iter()
+            .map(|buf| Bytes::copy_from_slice(buf.as_ref()))
+            .collect::<Vec<_>>()
+    };
+
+    let default = encode(None);
+    let finer = encode(Some(5.0));
+    // an invalid value keeps the encoder's own setting
+    let invalid = encode(Some(20.0));
+    assert_eq!(finer.len(), 4);
+    assert!(finer[0].len() > default[0].len());
+    assert_eq!(invalid[0].len(), default[0].len());
+
+    let mut decoder = SeaDecoder::new(SeaFileHeader {
+        version: 1,
+        channels: 1,
+        chunk_size: finer[0].len() as u16,
+        frames_per_chunk: FRAME_SIZE as u16,
+        sample_rate: TEST_SAMPLE_RATE as u32,
+    })
+    .unwrap();
+    let mut decoded = [0_i16; FRAME_SIZE];
+    for frame in finer {
+        decoder.decode_frame(&frame, &mut decoded).unwrap();
+    }
+}
+
 #[test]
 fn output_processor_decodes_frames_with_sea() {
     let mut encoder =
@@ -131,7 +195,9 @@ fn output_processor_decodes_frames_with_sea() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         OutputProcessorState::default(),
-        Some(decoder),
+        Box::new(decoder),
+        None,
+        None,
     )
     .unwrap();
 
@@ -154,6 +220,8 @@ fn output_processor_raw_frame_writes_exact_sample_values() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -174,6 +242,8 @@ fn output_processor_ignores_malformed_raw_frame() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -191,6 +261,8 @@ fn output_processor_source_failures_propagate() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap_err();
@@ -212,6 +284,8 @@ fn output_processor_sink_failures_propagate() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap_err();
@@ -240,8 +314,12 @@ fn run_input_and_measure_rms(input_volume: f32) -> f32 {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -309,8 +387,12 @@ fn input_processor_volume_zero_yields_silence() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -338,8 +420,12 @@ fn input_processor_updates_rms_atomic() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -372,8 +458,12 @@ fn input_processor_drops_frames_after_silence_threshold() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -417,8 +507,12 @@ fn input_processor_resumes_after_silence_when_signal_returns() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -467,8 +561,12 @@ fn input_processor_boundary_silence_length_equals_threshold() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -516,6 +614,8 @@ fn output_processor_counts_dropped_samples_when_full() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         state,
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -538,6 +638,8 @@ fn output_processor_skips_write_samples_when_always_full() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -571,6 +673,8 @@ fn output_processor_partial_full_window_counts_only_dropped() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         state,
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -605,8 +709,12 @@ fn input_processor_passes_all_frames_with_denoiser() {
             input_rate,
             output_rate,
             denoiser,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -651,7 +759,17 @@ fn output_processor_resampling_delivers_all_frames() {
     let input_rate = TEST_SAMPLE_RATE;
     let output_rate = 44_100;
 
-    output_processor(source, output, input_rate, output_rate, state, None).unwrap();
+    output_processor(
+        source,
+        output,
+        input_rate,
+        output_rate,
+        state,
+        Box::new(RawDecoder),
+        None,
+        None,
+    )
+    .unwrap();
 
     let samples = flatten_recorded(&recorded);
     let expected_samples = TEST_FRAMES * FRAME_SIZE * output_rate / input_rate;
@@ -688,6 +806,8 @@ fn output_processor_resampling_48k_to_96k() {
         48_000,
         96_000,
         OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -710,6 +830,8 @@ fn output_processor_resampling_48k_to_8k() {
         48_000,
         8_000,
         OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -738,8 +860,12 @@ fn input_processor_muted_sends_no_frames() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -805,8 +931,12 @@ fn input_processor_unmute_resumes_emission() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             None,
-            state,
             None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
         )
     });
 
@@ -846,7 +976,9 @@ fn output_processor_propagates_decoder_error() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         OutputProcessorState::default(),
-        Some(decoder),
+        Box::new(decoder),
+        None,
+        None,
     )
     .unwrap_err();
 
@@ -874,6 +1006,8 @@ fn output_processor_records_partial_write_loss() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         state,
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -918,6 +1052,8 @@ fn output_processor_deafened_writes_no_samples() {
             TEST_SAMPLE_RATE,
             TEST_SAMPLE_RATE,
             state,
+            Box::new(RawDecoder),
+            None,
             None,
         )
     });
@@ -933,3 +1069,702 @@ fn output_processor_deafened_writes_no_samples() {
         "deafened output processor must not write any samples"
     );
 }
+
+/// Runs [`EchoAudioInput`] through the input processor and returns the mean
+/// power of the last `measure` frames delivered to the sink.
+fn echo_tail_power(cancel: bool, frames: usize, measure: usize) -> f32 {
+    let reference = EchoReference::new();
+    let producer = cancel.then(|| reference.producer(TEST_SAMPLE_RATE as u32));
+    let canceller = cancel
+        .then(|| EchoCanceller::new(&reference, TEST_SAMPLE_RATE as u32, DEFAULT_TAIL_FRAMES));
+    let input = EchoAudioInput::new(producer, FRAME_SIZE * frames, 1_100, 0.5);
+    let (tx, rx) = mpsc::channel::<PooledBuffer>();
+
+    input_processor(
+        input,
+        MpscSink::new(tx),
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        None,
+        canceller,
+        None,
+        None,
+        None,
+        InputProcessorState::default(),
+        Box::new(RawEncoder),
+    )
+    .unwrap();
+
+    let frames: Vec<Vec<i16>> = rx
+        .try_iter()
+        .map(|buf| bytes_to_i16_samples(buf.as_ref()))
+        .collect();
+    assert!(frames.len() >= measure);
+    let tail: Vec<f32> = frames[frames.len() - measure..]
+        .iter()
+        .flatten()
+        .map(|sample| *sample as f32 / i16::MAX as f32)
+        .collect();
+    tail.iter().map(|sample| sample * sample).sum::<f32>() / tail.len() as f32
+}
+
+#[test]
+fn input_processor_cancels_synthetic_echo() {
+    let echo = echo_tail_power(false, 400, 50);
+    let residual = echo_tail_power(true, 400, 50);
+
+    assert!(echo > 1e-3, "echo power {echo}");
+    // at least 20 dB of echo return loss enhancement
+    assert!(residual < echo * 0.01, "echo {echo}, residual {residual}");
+}
+
+#[test]
+fn input_processor_auto_gain_levels_quiet_input_under_volume_ceiling() {
+    // -40 dBFS sine; the default target is -18 dBFS
+    let samples: Vec<f32> = (0..FRAME_SIZE * 400)
+        .map(|idx| {
+            let t = idx as f32 / TEST_SAMPLE_RATE as f32;
+            (t * 440.0 * std::f32::consts::TAU).sin() * 0.0141
+        })
+        .collect();
+    let input_volume = Arc::new(AtomicF32::new(100.0));
+    let gain = Arc::new(AtomicF32::new(1.0));
+    let state = make_input_state(
+        &input_volume,
+        &Arc::new(AtomicF32::new(0.0)),
+        &Arc::new(AtomicBool::new(false)),
+        Arc::new(AtomicF32::new(0.0)),
+    )
+    .with_gain_sender(gain.clone());
+    let (tx, rx) = mpsc::channel::<PooledBuffer>();
+
+    input_processor(
+        PatternAudioInput::new(samples),
+        MpscSink::new(tx),
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        None,
+        None,
+        Some(AutoGain::new(AutoGainConfig::default())),
+        None,
+        None,
+        state,
+        Box::new(RawEncoder),
+    )
+    .unwrap();
+
+    let last = bytes_to_i16_samples(rx.try_iter().last().unwrap().as_ref());
+    let rms = (last.iter().map(|s| (*s as f32).powi(2)).sum::<f32>() / last.len() as f32).sqrt();
+    let level_db = 20.0 * (rms / i16::MAX as f32).log10();
+    assert!((level_db + 18.0).abs() < 1.5, "level {level_db}");
+    // +22 dB is roughly a 12.6x gain
+    let applied = gain.load(Ordering::Relaxed);
+    assert!((11.0..14.5).contains(&applied), "gain {applied}");
+
+    // a lower volume caps the gain
+    let samples = vec![0.0141_f32; FRAME_SIZE * 10];
+    let gain = Arc::new(AtomicF32::new(1.0));
+    let state = make_input_state(
+        &Arc::new(AtomicF32::new(2.0)),
+        &Arc::new(AtomicF32::new(0.0)),
+        &Arc::new(AtomicBool::new(false)),
+        Arc::new(AtomicF32::new(0.0)),
+    )
+    .with_gain_sender(gain.clone());
+    let (tx, _rx) = mpsc::channel::<PooledBuffer>();
+    let mut auto_gain = AutoGain::new(AutoGainConfig::default());
+    let mut warmup = vec![0.0141 * i16::MAX as f32; FRAME_SIZE];
+    for _ in 0..400 {
+        auto_gain.process(&mut warmup, 100.0);
+    }
+
+    input_processor(
+        PatternAudioInput::new(samples),
+        MpscSink::new(tx),
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        None,
+        None,
+        Some(auto_gain),
+        None,
+        None,
+        state,
+        Box::new(RawEncoder),
+    )
+    .unwrap();
+
+    assert!(gain.load(Ordering::Relaxed) <= 2.0 + f32::EPSILON);
+}
+
+/// 440 Hz sine frames at half amplitude.
+fn sine_i16_frames(count: usize) -> Vec<[i16; FRAME_SIZE]> {
+    let mut phase = 0.0_f64;
+    (0..count)
+        .map(|_| {
+            std::array::from_fn(|_| {
+                let sample = (phase.sin() * 16_000.0) as i16;
+                phase += 2.0 * std::f64::consts::PI * 440.0 / TEST_SAMPLE_RATE as f64;
+                sample
+            })
+        })
+        .collect()
+}
+
+fn longest_zero_run(samples: &[f32]) -> usize {
+    samples
+        .split(|sample| *sample != 0.0)
+        .map(<[f32]>::len)
+        .max()
+        .unwrap_or(0)
+}
+
+const LOST_FRAMES: [usize; 3] = [3, 6, 7];
+
+#[test]
+fn output_processor_conceals_missing_raw_frames() {
+    let frames: Vec<Bytes> = sine_i16_frames(10)
+        .iter()
+        .enumerate()
+        .map(|(index, frame)| {
+            if LOST_FRAMES.contains(&index) {
+                MISSING_FRAME
+            } else {
+                raw_frame_from_i16(frame)
+            }
+        })
+        .collect();
+    let (output, recorded) = RecordingAudioOutput::new();
+
+    output_processor(
+        QueueSource::new(frames),
+        output,
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let samples = flatten_recorded(&recorded);
+    assert_eq!(samples.len(), FRAME_SIZE * 10);
+    assert!(longest_zero_run(&samples) < 4);
+    // the concealed frame carries audible signal
+    let concealed = &samples[FRAME_SIZE * 3..FRAME_SIZE * 4];
+    assert!(concealed.iter().any(|sample| sample.abs() > 0.25));
+}
+
+#[test]
+fn output_processor_conceals_missing_sea_frames() {
+    let mut encoder =
+        SeaEncoder::new(1, TEST_SAMPLE_RATE as u32, EncoderSettings::default()).unwrap();
+    let mut frames = Vec::new();
+    for (index, frame) in sine_i16_frames(10).into_iter().enumerate() {
+        let mut encoded = BytesMut::new();
+        encoder.encode_frame(frame, &mut encoded).unwrap();
+        if LOST_FRAMES.contains(&index) {
+            frames.push(MISSING_FRAME);
+        } else {
+            frames.push(encoded.freeze());
+        }
+    }
+
+    let header = SeaFileHeader {
+        version: 1,
+        channels: 1,
+        chunk_size: encoder.chunk_size(),
+        frames_per_chunk: FRAME_SIZE as u16,
+        sample_rate: TEST_SAMPLE_RATE as u32,
+    };
+    let (output, recorded) = RecordingAudioOutput::new();
+
+    output_processor(
+        QueueSource::new(frames),
+        output,
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        OutputProcessorState::default(),
+        Box::new(SeaDecoder::new(header).unwrap()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let samples = flatten_recorded(&recorded);
+    assert_eq!(samples.len(), FRAME_SIZE * 10);
+    assert!(longest_zero_run(&samples) < 4);
+    let concealed = &samples[FRAME_SIZE * 6..FRAME_SIZE * 8];
+    assert!(concealed.iter().any(|sample| sample.abs() > 0.1));
+}
+
+/// Toy codec keeping the high byte of every sample.
+struct HighByteCodec;
+
+impl AudioEncoder for HighByteCodec {
+    fn encode_frame(
+        &mut self,
+        frame: [i16; FRAME_SIZE],
+        output: &mut BytesMut,
+    ) -> Result<(), Error> {
+        output.resize(FRAME_SIZE, 0);
+        for (dst, sample) in output.iter_mut().zip(frame) {
+            *dst = (sample >> 8) as u8;
+        }
+        Ok(())
+    }
+}
+
+impl AudioDecoder for HighByteCodec {
+    fn decode_frame(&mut self, frame: &[u8], output: &mut [i16; FRAME_SIZE]) -> Result<(), Error> {
+        for (dst, byte) in output.iter_mut().zip(frame) {
+            *dst = ((*byte as i8) as i16) << 8;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn processors_round_trip_a_custom_codec() {
+    let input = TestAudioInput::new(FRAME_SIZE * 4);
+    let (tx, rx) = mpsc::channel::<PooledBuffer>();
+
+    input_processor(
+        input,
+        MpscSink::new(tx),
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        None,
+        None,
+        None,
+        None,
+        None,
+        InputProcessorState::default(),
+        Box::new(HighByteCodec),
+    )
+    .unwrap();
+
+    let frames: Vec<Bytes> = rx
+        .try_iter()
+        .map(|buf| Bytes::copy_from_slice(buf.as_ref()))
+        .collect();
+    assert_eq!(frames.len(), 4);
+    assert!(frames.iter().all(|frame| frame.len() == FRAME_SIZE));
+
+    let (output, recorded) = RecordingAudioOutput::new();
+    output_processor(
+        QueueSource::new(frames),
+        output,
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        OutputProcessorState::default(),
+        Box::new(HighByteCodec),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let samples = flatten_recorded(&recorded);
+    assert_eq!(samples.len(), FRAME_SIZE * 4);
+    assert!(samples.iter().any(|sample| sample.abs() > 0.1));
+}
+
+#[test]
+fn output_processor_skips_raw_frames_with_wrong_length() {
+    let frame = raw_frame_from_i16(&[1_000; FRAME_SIZE]);
+    let frames = vec![frame.clone(), Bytes::from_static(&[0; 10]), frame];
+    let (output, recorded) = RecordingAudioOutput::new();
+
+    output_processor(
+        QueueSource::new(frames),
+        output,
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(flatten_recorded(&recorded).len(), FRAME_SIZE * 2);
+}
+
+/// Interleaved stereo with a 440 Hz tone on the left and silence on the right.
+fn left_tone_stereo(frames: usize, sample_rate: usize) -> Vec<f32> {
+    (0..frames)
+        .flat_map(|frame| {
+            let t = frame as f32 / sample_rate as f32;
+            [(t * 440.0 * std::f32::consts::TAU).sin() * 0.5, 0.0]
+        })
+        .collect()
+}
+
+#[test]
+fn input_processor_resamples_stereo_without_mixing_channels() {
+    let input = PatternAudioInput::new(left_tone_stereo(44_100 / 2, 44_100));
+    let (tx, rx) = mpsc::channel::<PooledBuffer>();
+
+    input_processor(
+        input,
+        MpscSink::new(tx),
+        44_100,
+        TEST_SAMPLE_RATE,
+        None,
+        None,
+        None,
+        None,
+        None,
+        InputProcessorState::default().with_channels(2),
+        Box::new(RawEncoder),
+    )
+    .unwrap();
+
+    let frames: Vec<Vec<i16>> = rx
+        .try_iter()
+        .map(|buf| bytes_to_i16_samples(buf.as_ref()))
+        .collect();
+    // half a second is 100 frames of 240 sample pairs at 48 kHz
+    assert!(frames.len().abs_diff(100) <= 2, "{} frames", frames.len());
+    assert!(frames.iter().all(|frame| frame.len() == FRAME_SIZE));
+
+    let samples: Vec<i16> = frames.into_iter().flatten().collect();
+    let left_peak = samples.iter().step_by(2).map(|s| s.abs()).max().unwrap();
+    let right_peak = samples
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .map(|s| s.abs())
+        .max()
+        .unwrap();
+    assert!(left_peak > 15_000, "left peak {left_peak}");
+    assert!(right_peak < 100, "right peak {right_peak}");
+}
+
+#[test]
+fn output_processor_resamples_stereo_without_mixing_channels() {
+    let frames: Vec<Bytes> = left_tone_stereo(FRAME_SIZE / 2 * 20, TEST_SAMPLE_RATE)
+        .chunks(FRAME_SIZE)
+        .map(|frame| {
+            let samples: Vec<i16> = frame.iter().map(|s| (s * i16::MAX as f32) as i16).collect();
+            raw_frame_from_i16(&samples)
+        })
+        .collect();
+    let (output, recorded) = RecordingAudioOutput::new();
+
+    output_processor(
+        QueueSource::new(frames),
+        output,
+        TEST_SAMPLE_RATE,
+        96_000,
+        OutputProcessorState::default().with_channels(2),
+        Box::new(RawDecoder),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let samples = flatten_recorded(&recorded);
+    assert_eq!(samples.len() % 2, 0);
+    assert!(samples.len().abs_diff(FRAME_SIZE * 40) <= FRAME_SIZE * 2);
+    let left_peak = samples
+        .iter()
+        .step_by(2)
+        .fold(0_f32, |acc, s| acc.max(s.abs()));
+    let right_peak = samples
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .fold(0_f32, |acc, s| acc.max(s.abs()));
+    assert!(left_peak > 0.45, "left peak {left_peak}");
+    assert!(right_peak < 0.01, "right peak {right_peak}");
+}
+
+/// Runs `samples` through a voice gated processor and returns how many
+/// frames were sent. The RMS threshold would let every frame pass.
+fn run_voice_gate(samples: Vec<f32>, config: VoiceGateConfig, denoise: bool) -> usize {
+    let (tx, rx) = mpsc::channel::<PooledBuffer>();
+    let state = make_input_state(
+        &Arc::new(AtomicF32::new(1.0)),
+        &Arc::new(AtomicF32::new(0.0)),
+        &Arc::new(AtomicBool::new(false)),
+        Arc::new(AtomicF32::new(0.0)),
+    );
+
+    input_processor(
+        PatternAudioInput::new(samples),
+        MpscSink::new(tx),
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        denoise.then(DenoiseState::new),
+        None,
+        None,
+        Some(VoiceGate::new(config, 1)),
+        None,
+        state,
+        Box::new(RawEncoder),
+    )
+    .unwrap();
+
+    rx.try_iter().count()
+}
+
+#[test]
+fn input_processor_voice_gate_drops_silence_after_hangover() {
+    let config = VoiceGateConfig {
+        threshold: 0.5,
+        hangover_frames: 3,
+    };
+
+    for denoise in [false, true] {
+        let sent = run_voice_gate(vec![0.0; FRAME_SIZE * 20], config, denoise);
+        assert_eq!(sent, 3, "denoise {denoise}");
+    }
+}
+
+#[test]
+fn input_processor_voice_gate_open_sends_every_frame() {
+    let config = VoiceGateConfig {
+        threshold: 0.0,
+        hangover_frames: 0,
+    };
+    let samples: Vec<f32> = sine_i16_frames(20)
+        .into_iter()
+        .flatten()
+        .map(|sample| sample as f32 / i16::MAX as f32)
+        .collect();
+
+    for denoise in [false, true] {
+        let sent = run_voice_gate(samples.clone(), config, denoise);
+        assert_eq!(sent, 20, "denoise {denoise}");
+    }
+}
+
+#[test]
+fn input_processor_continues_on_swapped_device() {
+    // the replacement runs at half the rate, so it is resampled
+    let swap_rate = TEST_SAMPLE_RATE / 2;
+    let (swaps, swap_receiver) = unbounded();
+    swaps
+        .send(DeviceSwap {
+            device: Box::new(PatternAudioInput::new(vec![0.5; FRAME_SIZE * 5])) as Box<_>,
+            sample_rate: swap_rate,
+        })
+        .unwrap();
+    let state = InputProcessorState::default().with_device_swaps(swap_receiver);
+    let (tx, rx) = mpsc::channel::<PooledBuffer>();
+
+    let handle = thread::spawn(move || {
+        input_processor(
+            PatternAudioInput::new(vec![0.1; FRAME_SIZE * 20]),
+            MpscSink::new(tx),
+            TEST_SAMPLE_RATE,
+            TEST_SAMPLE_RATE,
+            None,
+            None,
+            None,
+            None,
+            None,
+            state,
+            Box::new(RawEncoder),
+        )
+    });
+
+    let frames: Vec<Vec<i16>> = rx
+        .iter()
+        .map(|frame| bytes_to_i16_samples(frame.as_ref()))
+        .collect();
+    handle.join().unwrap().unwrap();
+
+    // only the replacement was read, upsampled to twice as many frames
+    assert!(frames.len() >= 8 && frames.len() <= 10, "{}", frames.len());
+    // the first frames carry the resampler delay
+    for frame in &frames[2..] {
+        let peak = frame
+            .iter()
+            .map(|sample| sample.unsigned_abs())
+            .max()
+            .unwrap();
+        assert!(peak > 10_000, "peak {peak}");
+    }
+}
+
+#[test]
+fn output_processor_continues_on_swapped_device() {
+    let swap_rate = TEST_SAMPLE_RATE / 2;
+    let (first, first_recorded) = RecordingAudioOutput::new();
+    let (second, second_recorded) = RecordingAudioOutput::new();
+    let (swaps, swap_receiver) = unbounded();
+    swaps
+        .send(DeviceSwap {
+            device: Box::new(second) as Box<_>,
+            sample_rate: swap_rate,
+        })
+        .unwrap();
+    let state = OutputProcessorState::default().with_device_swaps(swap_receiver);
+    let frames = (0..4)
+        .map(|_| raw_frame_from_i16(&[i16::MAX / 2; FRAME_SIZE]))
+        .collect();
+
+    output_processor(
+        QueueSource::new(frames),
+        first,
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        state,
+        Box::new(RawDecoder),
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert!(first_recorded.lock().unwrap().is_empty());
+    // every frame was resampled to the rate of the replacement
+    assert_eq!(flatten_recorded(&second_recorded).len(), FRAME_SIZE * 2);
+}
+
+/// Scales every sample and remembers the largest sample it was given.
+struct ScaleEffect {
+    factor: f32,
+    peak: Arc<AtomicF32>,
+}
+
+impl AudioEffect for ScaleEffect {
+    fn process(&mut self, frame: &mut [f32; FRAME_SIZE]) {
+        for sample in frame.iter_mut() {
+            self.peak.fetch_max(sample.abs(), Ordering::Relaxed);
+            *sample *= self.factor;
+        }
+    }
+}
+
+#[test]
+fn input_processor_runs_effects_on_normalized_frames() {
+    let peak = Arc::new(AtomicF32::new(0.0));
+    let (chains, chain_receiver) = unbounded();
+    chains
+        .send(EffectChain::new().with(ScaleEffect {
+            factor: 0.5,
+            peak: peak.clone(),
+        }))
+        .unwrap();
+    let state = InputProcessorState::default().with_effect_chains(chain_receiver);
+    let (tx, rx) = mpsc::channel::<PooledBuffer>();
+
+    input_processor(
+        PatternAudioInput::new(vec![0.5; FRAME_SIZE * 4]),
+        MpscSink::new(tx),
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        None,
+        None,
+        None,
+        None,
+        None,
+        state,
+        Box::new(RawEncoder),
+    )
+    .unwrap();
+
+    let frames: Vec<Vec<i16>> = rx
+        .iter()
+        .map(|frame| bytes_to_i16_samples(frame.as_ref()))
+        .collect();
+    assert_eq!(frames.len(), 4);
+    assert!((peak.load(Ordering::Relaxed) - 0.5).abs() < 1e-3);
+    for sample in frames.into_iter().flatten() {
+        assert!((sample - i16::MAX / 4).abs() <= 2, "sample {sample}");
+    }
+}
+
+#[test]
+fn output_processor_runs_effects_before_volume() {
+    let peak = Arc::new(AtomicF32::new(0.0));
+    let (chains, chain_receiver) = unbounded();
+    chains
+        .send(EffectChain::new().with(ScaleEffect {
+            factor: -1.0,
+            peak: peak.clone(),
+        }))
+        .unwrap();
+    let volume = Arc::new(AtomicF32::new(0.5));
+    let state = OutputProcessorState::new(&volume, Arc::default(), &Arc::default(), Arc::default())
+        .with_effect_chains(chain_receiver);
+    let (output, recorded) = RecordingAudioOutput::new();
+    let frames = (0..2)
+        .map(|_| raw_frame_from_i16(&[i16::MAX / 2; FRAME_SIZE]))
+        .collect();
+
+    output_processor(
+        QueueSource::new(frames),
+        output,
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        state,
+        Box::new(RawDecoder),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // the effect saw the decoded level, the device got it inverted & halved
+    assert!((peak.load(Ordering::Relaxed) - 0.5).abs() < 1e-3);
+    let samples = flatten_recorded(&recorded);
+    assert_eq!(samples.len(), FRAME_SIZE * 2);
+    assert!(samples.iter().all(|sample| (sample + 0.25).abs() < 1e-3));
+}
+
+#[test]
+fn output_processor_slows_down_for_a_slow_device() {
+    // the device plays 1000 ppm slower than the frames arrive
+    let (output, _written) = DrainingOutput::new(FRAME_SIZE as f64 * 0.999);
+    let drift = Arc::new(AtomicF32::new(0.0));
+    let queued = Arc::new(AtomicUsize::new(0));
+    let state = OutputProcessorState::default()
+        .with_drift_compensation(true)
+        .with_drift_sender(drift.clone())
+        .with_queued_sender(queued.clone());
+    let frames = (0..400)
+        .map(|_| raw_frame_from_i16(&[1_000; FRAME_SIZE]))
+        .collect();
+
+    output_processor(
+        QueueSource::new(frames),
+        output,
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        state,
+        Box::new(RawDecoder),
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert!(drift.load(Ordering::Relaxed) < 0.0);
+    assert!(queued.load(Ordering::Relaxed) > 0);
+}
+
+#[test]
+fn output_processor_keeps_the_ratio_without_drift_compensation() {
+    let (output, written) = DrainingOutput::new(FRAME_SIZE as f64 * 0.999);
+    let drift = Arc::new(AtomicF32::new(0.0));
+    let state = OutputProcessorState::default().with_drift_sender(drift.clone());
+    let frames = (0..100)
+        .map(|_| raw_frame_from_i16(&[1_000; FRAME_SIZE]))
+        .collect();
+
+    output_processor(
+        QueueSource::new(frames),
+        output,
+        TEST_SAMPLE_RATE,
+        TEST_SAMPLE_RATE,
+        state,
+        Box::new(RawDecoder),
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(drift.load(Ordering::Relaxed), 0.0);
+    assert_eq!(written.load(Ordering::Relaxed), FRAME_SIZE * 100);
+}
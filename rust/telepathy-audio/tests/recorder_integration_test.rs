@@ -0,0 +1,184 @@
+#![cfg(not(target_family = "wasm"))]
+
+mod common;
+
+use common::{RecordingAudioOutput, TEST_SAMPLE_RATE, raw_frame_from_i16};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+use telepathy_audio::devices::{MockAudioHost, MockAudioInput};
+use telepathy_audio::io::{AudioMixerBuilder, MixerSourceBuilder};
+use telepathy_audio::recorder::{
+    Recorder, RecordingConfig, RecordingFormat, RecordingLayout, RecordingTap, RecordingTrack,
+};
+use telepathy_audio::sea::codec::file::SeaFileHeader;
+use telepathy_audio::{ConfigError, Error, FRAME_SIZE};
+
+const HALF: i16 = i16::MAX / 2;
+
+/// A fresh directory for one test's recordings.
+fn recording_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "telepathy-recorder-{}-{}",
+        std::process::id(),
+        test
+    ));
+    _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn wav_samples(bytes: &[u8]) -> Vec<i16> {
+    bytes[44..]
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]))
+        .collect()
+}
+
+/// Pushes `frames` frames of `value` into `track`, paced like a live stream.
+fn feed(track: &mut RecordingTrack, value: i16, frames: usize) {
+    for _ in 0..frames {
+        track.push_i16(&[value; FRAME_SIZE]);
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn mixed_recording_sums_every_track() {
+    let dir = recording_dir("mixed");
+    let path = dir.join("call.wav");
+    let tap = RecordingTap::new();
+    let mut local = tap.track("local", TEST_SAMPLE_RATE as u32, 1);
+    let mut peer = tap.track("peer", TEST_SAMPLE_RATE as u32, 1);
+
+    let recorder = Recorder::start(&tap, &path, RecordingConfig::default()).unwrap();
+    assert!(tap.is_recording());
+    for _ in 0..20 {
+        local.push_i16(&[HALF / 2; FRAME_SIZE]);
+        peer.push_i16(&[HALF / 2; FRAME_SIZE]);
+        thread::sleep(Duration::from_millis(10));
+    }
+    let files = recorder.stop().unwrap();
+    assert!(!tap.is_recording());
+    assert_eq!(files, vec![path.clone()]);
+
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(&bytes[0..4], b"RIFF");
+    let samples = wav_samples(&bytes);
+    assert!(samples.len() >= 10 * FRAME_SIZE);
+    assert!(samples.len().is_multiple_of(FRAME_SIZE));
+    // most frames carry both tracks
+    let summed = samples
+        .chunks_exact(FRAME_SIZE)
+        .filter(|frame| frame.iter().all(|s| (s - HALF).abs() <= 2))
+        .count();
+    assert!(summed >= 10, "only {} summed frames", summed);
+}
+
+#[test]
+fn separate_tracks_are_written_to_their_own_files() {
+    let dir = recording_dir("tracks");
+    let path = dir.join("call.sea");
+    let tap = RecordingTap::new();
+    let mut local = tap.track("local", TEST_SAMPLE_RATE as u32, 1);
+    let mut peer = tap.track("peer one", TEST_SAMPLE_RATE as u32, 1);
+
+    let config = RecordingConfig {
+        format: RecordingFormat::Sea,
+        layout: RecordingLayout::SeparateTracks,
+        ..Default::default()
+    };
+    let recorder = Recorder::start(&tap, &path, config).unwrap();
+    feed(&mut local, HALF, 10);
+    feed(&mut peer, HALF, 10);
+    let files = recorder.stop().unwrap();
+
+    assert_eq!(
+        files,
+        vec![
+            dir.join("call-0-local.sea"),
+            dir.join("call-1-peer_one.sea")
+        ]
+    );
+    let sizes: Vec<usize> = files
+        .iter()
+        .map(|file| {
+            let bytes = std::fs::read(file).unwrap();
+            let header = SeaFileHeader::from_frame(&bytes[..14]).unwrap();
+            assert_eq!(header.channels, 1);
+            assert_eq!((bytes.len() - 14) % header.chunk_size as usize, 0);
+            bytes.len()
+        })
+        .collect();
+    // both tracks cover the whole recording
+    assert_eq!(sizes[0], sizes[1]);
+}
+
+#[test]
+fn a_tap_records_once_at_a_time() {
+    let dir = recording_dir("twice");
+    let tap = RecordingTap::new();
+
+    let recorder = Recorder::start(&tap, dir.join("a.wav"), RecordingConfig::default()).unwrap();
+    assert!(matches!(
+        Recorder::start(&tap, dir.join("b.wav"), RecordingConfig::default()),
+        Err(Error::Config(ConfigError::AlreadyRecording))
+    ));
+    recorder.stop().unwrap();
+
+    // the tap can be recorded again once the first recording stopped
+    Recorder::start(&tap, dir.join("c.wav"), RecordingConfig::default())
+        .unwrap()
+        .stop()
+        .unwrap();
+}
+
+#[test]
+fn recording_to_a_missing_directory_errors() {
+    let tap = RecordingTap::new();
+    let path = recording_dir("missing").join("nope").join("call.wav");
+
+    assert!(matches!(
+        Recorder::start(&tap, path, RecordingConfig::default()),
+        Err(Error::AudioFile(_))
+    ));
+    assert!(!tap.is_recording());
+}
+
+#[test]
+fn mixer_sources_tap_decoded_frames_before_their_volume() {
+    let dir = recording_dir("mixer");
+    let path = dir.join("call.wav");
+    let tap = RecordingTap::new();
+
+    let (output, _) = RecordingAudioOutput::new();
+    let host = MockAudioHost::new(
+        MockAudioInput::default(),
+        TEST_SAMPLE_RATE as u32,
+        output,
+        TEST_SAMPLE_RATE as u32,
+    );
+    let mixer = AudioMixerBuilder::new().build(&host).unwrap();
+
+    let recorder = Recorder::start(&tap, &path, RecordingConfig::default()).unwrap();
+    let (tx, rx) = std::sync::mpsc::channel();
+    let source = mixer
+        .add_source(
+            MixerSourceBuilder::new()
+                .volume(0.1)
+                .recording(&tap, "peer")
+                .source(telepathy_audio::adapters::MpscSource::new(rx)),
+        )
+        .unwrap();
+    for _ in 0..10 {
+        tx.send(raw_frame_from_i16(&[HALF; FRAME_SIZE])).unwrap();
+        thread::sleep(Duration::from_millis(10));
+    }
+    thread::sleep(Duration::from_millis(30));
+    recorder.stop().unwrap();
+    drop(source);
+
+    let samples = wav_samples(&std::fs::read(&path).unwrap());
+    assert!(samples.iter().any(|s| (s - HALF).abs() <= 2));
+    assert!(samples.iter().all(|s| *s == 0 || (s - HALF).abs() <= 2));
+}
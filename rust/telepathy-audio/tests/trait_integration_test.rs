@@ -8,6 +8,7 @@ use common::{
 };
 use std::sync::{Arc, Mutex};
 
+use telepathy_audio::codec::{RawDecoder, RawEncoder};
 use telepathy_audio::internal::buffer_pool::PooledBuffer;
 use telepathy_audio::internal::processor::{input_processor, output_processor};
 use telepathy_audio::internal::state::{InputProcessorState, OutputProcessorState};
@@ -35,8 +36,12 @@ fn callback_sink_receives_frames() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         None,
-        InputProcessorState::default(),
         None,
+        None,
+        None,
+        None,
+        InputProcessorState::default(),
+        Box::new(RawEncoder),
     )
     .unwrap();
 
@@ -67,8 +72,12 @@ fn sink_errors_propagate() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         None,
-        InputProcessorState::default(),
         None,
+        None,
+        None,
+        None,
+        InputProcessorState::default(),
+        Box::new(RawEncoder),
     )
     .unwrap_err();
 
@@ -95,6 +104,8 @@ fn custom_source_drives_output_processor() {
         TEST_SAMPLE_RATE,
         TEST_SAMPLE_RATE,
         OutputProcessorState::default(),
+        Box::new(RawDecoder),
+        None,
         None,
     )
     .unwrap();
@@ -35,6 +35,9 @@ impl Hub {
         let tx_for_message = self.event_tx.clone();
         let tx_for_manager = self.event_tx.clone();
         let tx_for_screenshare = self.event_tx.clone();
+        let tx_for_recording = self.event_tx.clone();
+        let tx_for_device = self.event_tx.clone();
+        let tx_for_peer_statistics = self.event_tx.clone();
 
         NativeCallbacks::new(
             move |contact_id, ringtone, response_tx, mut cancel_rx| {
@@ -126,6 +129,24 @@ impl Hub {
                     let _ = tx.send(Event::ScreenshareStarted { sender });
                 })
             },
+            move |state| {
+                let tx = tx_for_recording.clone();
+                Box::pin(async move {
+                    let _ = tx.send(Event::RecordingState { state });
+                })
+            },
+            move |event| {
+                let tx = tx_for_device.clone();
+                Box::pin(async move {
+                    let _ = tx.send(Event::DeviceEvent { event });
+                })
+            },
+            move |peers| {
+                let tx = tx_for_peer_statistics.clone();
+                Box::pin(async move {
+                    let _ = tx.send(Event::PeerStatistics { peers });
+                })
+            },
         )
     }
 }
@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use telepathy_core::types::{RecordingFormat, RecordingLayout};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ChatAttachment {
@@ -6,6 +7,40 @@ pub struct ChatAttachment {
     pub data_b64: String,
 }
 
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingFormatArg {
+    #[default]
+    Wav,
+    Sea,
+}
+
+impl From<RecordingFormatArg> for RecordingFormat {
+    fn from(value: RecordingFormatArg) -> Self {
+        match value {
+            RecordingFormatArg::Wav => Self::Wav,
+            RecordingFormatArg::Sea => Self::Sea,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingLayoutArg {
+    #[default]
+    Mixed,
+    SeparateTracks,
+}
+
+impl From<RecordingLayoutArg> for RecordingLayout {
+    fn from(value: RecordingLayoutArg) -> Self {
+        match value {
+            RecordingLayoutArg::Mixed => Self::Mixed,
+            RecordingLayoutArg::SeparateTracks => Self::SeparateTracks,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "cmd", content = "args", rename_all = "snake_case")]
 pub enum Command {
@@ -64,6 +99,22 @@ pub enum Command {
     SetDenoise {
         value: bool,
     },
+    SetEchoCancellation {
+        value: bool,
+    },
+    SetAutoGain {
+        value: bool,
+    },
+    SetVoiceGate {
+        value: bool,
+    },
+    SetJitterBufferBounds {
+        min_ms: u32,
+        max_ms: u32,
+    },
+    SetRedundancy {
+        value: bool,
+    },
     SetEfficiencyMode {
         value: bool,
     },
@@ -76,6 +127,14 @@ pub enum Command {
     SetOutputDevice {
         id: Option<String>,
     },
+    StartRecording {
+        path: String,
+        #[serde(default)]
+        format: RecordingFormatArg,
+        #[serde(default)]
+        layout: RecordingLayoutArg,
+    },
+    StopRecording,
     DrainAudioFrameIndices,
     ListDevices,
 }
@@ -1,5 +1,8 @@
 use serde::Serialize;
-use telepathy_core::types::{CallState, ChatMessage, ManagerState, SessionStatus, Statistics};
+use telepathy_core::types::{
+    CallState, ChatMessage, DeviceEvent, ManagerState, PeerStatistics, RecordingState,
+    SessionStatus, Statistics,
+};
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -18,10 +21,22 @@ pub enum Event {
     Statistics {
         input_level: f32,
         output_level: f32,
+        gain: f32,
         latency: usize,
         upload_bandwidth: usize,
         download_bandwidth: usize,
         loss: usize,
+        jitter_delay: usize,
+        packet_loss: f32,
+        jitter: f32,
+        relayed: bool,
+        path_changes: usize,
+        congestion_window: usize,
+        bytes_sent: usize,
+        bytes_received: usize,
+    },
+    PeerStatistics {
+        peers: Vec<PeerStatistics>,
     },
     MessageReceived {
         #[serde(flatten)]
@@ -30,6 +45,12 @@ pub enum Event {
     ScreenshareStarted {
         sender: bool,
     },
+    RecordingState {
+        state: RecordingState,
+    },
+    DeviceEvent {
+        event: DeviceEvent,
+    },
     AcceptCallPrompt {
         request_id: String,
         contact_id: String,
@@ -49,10 +70,19 @@ impl From<Statistics> for Event {
         Self::Statistics {
             input_level: value.input_level,
             output_level: value.output_level,
+            gain: value.gain,
             latency: value.latency,
             upload_bandwidth: value.upload_bandwidth,
             download_bandwidth: value.download_bandwidth,
             loss: value.loss,
+            jitter_delay: value.jitter_delay,
+            packet_loss: value.packet_loss,
+            jitter: value.jitter,
+            relayed: value.relayed,
+            path_changes: value.path_changes,
+            congestion_window: value.congestion_window,
+            bytes_sent: value.bytes_sent,
+            bytes_received: value.bytes_received,
         }
     }
 }
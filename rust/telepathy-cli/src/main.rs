@@ -57,6 +57,10 @@ fn parse_args(args: Vec<String>) -> Result<RunOptions> {
     let mut pkarr_relay = std::env::var("TELEPATHY_PKARR_RELAY").ok();
     let mut system_test_audio = false;
     let mut capture_audio_frame_indices = false;
+    let mut input_file = None;
+    let mut output_file = None;
+    let mut loop_input = false;
+    let mut fast_input = false;
 
     let mut idx = 1usize;
     while idx < args.len() {
@@ -109,6 +113,24 @@ fn parse_args(args: Vec<String>) -> Result<RunOptions> {
                         .ok_or_else(|| startup_failure("missing value for --pkarr-relay"))?,
                 );
             }
+            "--input-file" => {
+                idx += 1;
+                input_file = Some(
+                    args.get(idx)
+                        .cloned()
+                        .ok_or_else(|| startup_failure("missing value for --input-file"))?,
+                );
+            }
+            "--output-file" => {
+                idx += 1;
+                output_file = Some(
+                    args.get(idx)
+                        .cloned()
+                        .ok_or_else(|| startup_failure("missing value for --output-file"))?,
+                );
+            }
+            "--loop-input" => loop_input = true,
+            "--fast-input" => fast_input = true,
             "--system-test-audio" => system_test_audio = true,
             "--capture-audio-frame-indices" => capture_audio_frame_indices = true,
             other => {
@@ -130,6 +152,10 @@ fn parse_args(args: Vec<String>) -> Result<RunOptions> {
         pkarr_relay,
         system_test_audio,
         capture_audio_frame_indices,
+        input_file,
+        output_file,
+        loop_input,
+        fast_input,
     })
 }
 
@@ -309,6 +335,38 @@ mod tests {
         assert!(capture_audio.capture_audio_frame_indices);
     }
 
+    #[test]
+    fn file_audio_flags_are_parsed() {
+        let _guard = EnvVarGuard::clear("TELEPATHY_LISTEN_PORT");
+        let defaults = parse_args(base_args()).unwrap();
+        assert_eq!(defaults.input_file, None);
+        assert_eq!(defaults.output_file, None);
+        assert!(!defaults.loop_input);
+        assert!(!defaults.fast_input);
+
+        let files = parse_args(vec![
+            "telepathy-cli".to_string(),
+            "--input-file".to_string(),
+            "in.wav".to_string(),
+            "--output-file".to_string(),
+            "out.wav".to_string(),
+            "--loop-input".to_string(),
+            "--fast-input".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(files.input_file.as_deref(), Some("in.wav"));
+        assert_eq!(files.output_file.as_deref(), Some("out.wav"));
+        assert!(files.loop_input);
+        assert!(files.fast_input);
+
+        let err = parse_args(vec![
+            "telepathy-cli".to_string(),
+            "--input-file".to_string(),
+        ])
+        .expect_err("missing --input-file value should fail");
+        assert!(err.to_string().contains("missing value for --input-file"));
+    }
+
     #[test]
     fn missing_relay_url_flag_value_fails() {
         let _relay = EnvVarGuard::clear("TELEPATHY_RELAY_URL");
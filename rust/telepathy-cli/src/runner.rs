@@ -7,7 +7,8 @@ use anyhow::{Context, Result};
 use base64::Engine;
 use serde_json::json;
 use telepathy_audio::devices::{
-    AudioHost, CpalAudioHost, MockAudioHost, MockAudioInput, MockAudioOutput,
+    AudioHost, CpalAudioHost, FileAudioHost, FilePacing, MockAudioHost, MockAudioInput,
+    MockAudioOutput,
 };
 use telepathy_core::internal::TelepathyHandle;
 use telepathy_core::native::NativeCallbacks;
@@ -26,6 +27,10 @@ pub struct RunOptions {
     pub pkarr_relay: Option<String>,
     pub system_test_audio: bool,
     pub capture_audio_frame_indices: bool,
+    pub input_file: Option<String>,
+    pub output_file: Option<String>,
+    pub loop_input: bool,
+    pub fast_input: bool,
 }
 
 pub async fn run(opts: RunOptions) -> Result<()> {
@@ -39,11 +44,36 @@ pub async fn run(opts: RunOptions) -> Result<()> {
             None,
         )
         .await
+    } else if opts.input_file.is_some() || opts.output_file.is_some() {
+        let host = file_host(&opts)?;
+        run_with_host(opts, host, None).await
     } else {
         run_with_host(opts, CpalAudioHost::default(), None).await
     }
 }
 
+/// Builds the audio host which plays `--input-file` and writes `--output-file`
+fn file_host(opts: &RunOptions) -> Result<FileAudioHost> {
+    let mut host = FileAudioHost::new()
+        .looping(opts.loop_input)
+        .pacing(if opts.fast_input {
+            FilePacing::AsFastAsPossible
+        } else {
+            FilePacing::RealTime
+        });
+    if let Some(path) = &opts.input_file {
+        host = host.input_file(path).map_err(|error| {
+            crate::startup_failure(format!("failed to read input file {path}: {error}"))
+        })?;
+    }
+    if let Some(path) = &opts.output_file {
+        host = host.output_file(path).map_err(|error| {
+            crate::startup_failure(format!("failed to create output file {path}: {error}"))
+        })?;
+    }
+    Ok(host)
+}
+
 async fn run_with_host<H>(
     opts: RunOptions,
     audio_host: H,
@@ -346,6 +376,41 @@ where
             telepathy.set_denoise(value);
             CommandOutcome::AckOk
         }
+        Command::SetEchoCancellation { value } => {
+            telepathy.set_echo_cancellation(value);
+            CommandOutcome::AckOk
+        }
+        Command::SetAutoGain { value } => {
+            telepathy.set_auto_gain(value);
+            CommandOutcome::AckOk
+        }
+        Command::SetVoiceGate { value } => {
+            telepathy.set_voice_gate(value);
+            CommandOutcome::AckOk
+        }
+        Command::SetJitterBufferBounds { min_ms, max_ms } => {
+            telepathy.set_jitter_buffer_bounds(min_ms, max_ms);
+            CommandOutcome::AckOk
+        }
+        Command::SetRedundancy { value } => {
+            telepathy.set_redundancy(value);
+            CommandOutcome::AckOk
+        }
+        Command::StartRecording {
+            path,
+            format,
+            layout,
+        } => match telepathy
+            .start_recording(path, format.into(), layout.into())
+            .await
+        {
+            Ok(()) => CommandOutcome::AckOk,
+            Err(err) => CommandOutcome::AckErr(err.to_string()),
+        },
+        Command::StopRecording => match telepathy.stop_recording().await {
+            Ok(()) => CommandOutcome::AckOk,
+            Err(err) => CommandOutcome::AckErr(err.to_string()),
+        },
         Command::SetEfficiencyMode { value } => {
             telepathy.set_efficiency_mode(value);
             CommandOutcome::AckOk
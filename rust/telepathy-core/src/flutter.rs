@@ -119,6 +119,10 @@ impl Telepathy {
     }
 
     /// The only entry point into participating in a room.
+    ///
+    /// Rooms ignore the codec config: every member decodes the same stream, so
+    /// rooms always send mono SEA at 5 residual bits, which every client
+    /// (including web) supports. Rooms do not adapt the bitrate to loss.
     pub async fn join_room(
         &self,
         member_strings: Vec<String>,
@@ -241,6 +245,36 @@ impl Telepathy {
         self.handle.set_denoise(denoise)
     }
 
+    /// Echo cancellation is set on the processors; the current call is not reconfigured.
+    #[frb(sync)]
+    pub fn set_echo_cancellation(&self, enabled: bool) {
+        self.handle.set_echo_cancellation(enabled)
+    }
+
+    /// Auto gain is set on the processor; the current call is not reconfigured.
+    #[frb(sync)]
+    pub fn set_auto_gain(&self, enabled: bool) {
+        self.handle.set_auto_gain(enabled)
+    }
+
+    /// The voice gate is set on the processor; the current call is not reconfigured.
+    #[frb(sync)]
+    pub fn set_voice_gate(&self, enabled: bool) {
+        self.handle.set_voice_gate(enabled)
+    }
+
+    /// The bounds are read when a call or room connects.
+    #[frb(sync)]
+    pub fn set_jitter_buffer_bounds(&self, min_ms: u32, max_ms: u32) {
+        self.handle.set_jitter_buffer_bounds(min_ms, max_ms)
+    }
+
+    /// Read when a call or room connects.
+    #[frb(sync)]
+    pub fn set_redundancy(&self, enabled: bool) {
+        self.handle.set_redundancy(enabled)
+    }
+
     #[frb(sync)]
     pub fn set_play_custom_ringtones(&self, play: bool) {
         self.handle.set_play_custom_ringtones(play)
@@ -282,6 +316,22 @@ impl Telepathy {
     pub async fn set_model(&self, model: Option<Vec<u8>>) -> Result<(), DartError> {
         self.handle.set_model(model).await.map_err(DartError::from)
     }
+
+    pub async fn start_recording(
+        &self,
+        path: String,
+        format: RecordingFormat,
+        layout: RecordingLayout,
+    ) -> Result<(), DartError> {
+        self.handle
+            .start_recording(path, format, layout)
+            .await
+            .map_err(DartError::from)
+    }
+
+    pub async fn stop_recording(&self) -> Result<(), DartError> {
+        self.handle.stop_recording().await.map_err(DartError::from)
+    }
 }
 
 #[frb(opaque)]
@@ -313,6 +363,15 @@ pub struct FlutterCallbacks {
     /// Called when a screenshare starts
     #[allow(dead_code)]
     screenshare_started: DartVoid<ScreenshareStartedArgs>,
+
+    /// Alerts the UI when a call recording starts, stops or fails
+    recording_state: DartVoid<RecordingState>,
+
+    /// Alerts the UI when an audio device is added, removed or becomes the default
+    device_event: DartVoid<DeviceEvent>,
+
+    /// Used to report the statistics of each room participant to the frontend
+    peer_statistics: DartVoid<Vec<PeerStatistics>>,
 }
 
 impl FlutterCallbacks {
@@ -328,6 +387,9 @@ impl FlutterCallbacks {
         message_received: impl Fn(ChatMessage) -> DartFnFuture<()> + Send + 'static,
         manager_active: impl Fn(ManagerActiveArgs) -> DartFnFuture<()> + Send + 'static,
         screenshare_started: impl Fn(ScreenshareStartedArgs) -> DartFnFuture<()> + Send + 'static,
+        recording_state: impl Fn(RecordingState) -> DartFnFuture<()> + Send + 'static,
+        device_event: impl Fn(DeviceEvent) -> DartFnFuture<()> + Send + 'static,
+        peer_statistics: impl Fn(Vec<PeerStatistics>) -> DartFnFuture<()> + Send + 'static,
     ) -> Self {
         Self {
             accept_call: Arc::new(Mutex::new(accept_call)),
@@ -339,6 +401,9 @@ impl FlutterCallbacks {
             message_received: Arc::new(Mutex::new(message_received)),
             manager_active: Arc::new(Mutex::new(manager_active)),
             screenshare_started: Arc::new(Mutex::new(screenshare_started)),
+            recording_state: Arc::new(Mutex::new(recording_state)),
+            device_event: Arc::new(Mutex::new(device_event)),
+            peer_statistics: Arc::new(Mutex::new(peer_statistics)),
         }
     }
 }
@@ -346,6 +411,7 @@ impl FlutterCallbacks {
 #[frb(ignore)]
 pub struct FlutterStatisticsCallback {
     inner: DartVoid<Statistics>,
+    peers: DartVoid<Vec<PeerStatistics>>,
 }
 
 async fn notify<A>(void: &DartVoid<A>, args: A) {
@@ -1,6 +1,6 @@
 use crate::flutter::{
-    CallState, ChatMessage, Contact, FlutterCallbacks, FlutterStatisticsCallback, FrontendNotify,
-    SessionStatus, Statistics, invoke, notify,
+    CallState, ChatMessage, Contact, DeviceEvent, FlutterCallbacks, FlutterStatisticsCallback,
+    FrontendNotify, PeerStatistics, RecordingState, SessionStatus, Statistics, invoke, notify,
 };
 use crate::internal::callbacks::{CoreCallbacks, CoreStatisticsCallback};
 use crate::internal::{JoinHandle, spawn_task};
@@ -60,9 +60,18 @@ impl CoreCallbacks for FlutterCallbacks {
         invoke(&self.message_received, chat_message)
     }
 
+    fn recording_state(&self, state: RecordingState) -> impl Future<Output = ()> + Send {
+        notify(&self.recording_state, state)
+    }
+
+    fn device_event(&self, event: DeviceEvent) -> impl Future<Output = ()> + Send {
+        notify(&self.device_event, event)
+    }
+
     fn statistics_callback(&self) -> FlutterStatisticsCallback {
         FlutterStatisticsCallback {
             inner: Arc::clone(&self.statistics),
+            peers: Arc::clone(&self.peer_statistics),
         }
     }
 }
@@ -71,4 +80,8 @@ impl CoreStatisticsCallback for FlutterStatisticsCallback {
     fn post(&self, stats: Statistics) -> impl Future<Output = ()> + Send {
         invoke(&self.inner, stats)
     }
+
+    fn post_peers(&self, peers: Vec<PeerStatistics>) -> impl Future<Output = ()> + Send {
+        invoke(&self.peers, peers)
+    }
 }
@@ -563,6 +563,100 @@ fn wire__crate__types__ChatMessage_time_impl(
         },
     )
 }
+fn wire__crate__types__CodecConfig_channels_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CodecConfig_channels",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CodecConfig>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::types::CodecConfig::channels(&*api_that_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__types__CodecConfig_codec_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CodecConfig_codec",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CodecConfig>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::types::CodecConfig::codec(&*api_that_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__types__CodecConfig_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -631,6 +725,104 @@ fn wire__crate__types__CodecConfig_new_impl(
         },
     )
 }
+fn wire__crate__types__CodecConfig_set_channels_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CodecConfig_set_channels",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CodecConfig>,
+            >>::sse_decode(&mut deserializer);
+            let api_channels = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::types::CodecConfig::set_channels(&*api_that_guard, api_channels);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__types__CodecConfig_set_codec_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CodecConfig_set_codec",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CodecConfig>,
+            >>::sse_decode(&mut deserializer);
+            let api_codec = <crate::types::AudioCodec>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::types::CodecConfig::set_codec(&*api_that_guard, api_codec);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__types__CodecConfig_set_enabled_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1320,42 +1512,479 @@ fn wire__crate__types__Contact_set_output_volume_impl(
                 }
                 let mut api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::types::Contact::set_output_volume(&mut *api_that_guard, api_decibel);
+                    crate::types::Contact::set_output_volume(&mut *api_that_guard, api_decibel);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__flutter__FlutterCallbacks_new_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "FlutterCallbacks_new", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_accept_call = decode_DartFn_Inputs_record_string_opt_list_prim_u_8_strict_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_frontend_notify_Output_bool_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_get_contact = decode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_call_state = decode_DartFn_Inputs_call_state_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_session_status = decode_DartFn_Inputs_record_string_session_status_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_get_contacts = decode_DartFn_Inputs_unit_Output_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_statistics = decode_DartFn_Inputs_statistics_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_message_received = decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChatMessage_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_manager_active = decode_DartFn_Inputs_manager_state_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_screenshare_started = decode_DartFn_Inputs_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_frontend_notify_bool_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_recording_state = decode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_device_event = decode_DartFn_Inputs_device_event_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_peer_statistics = decode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::flutter::FlutterCallbacks::new(api_accept_call, api_get_contact, api_call_state, api_session_status, api_get_contacts, api_statistics, api_message_received, api_manager_active, api_screenshare_started, api_recording_state, api_device_event, api_peer_statistics))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__player__FlutterSoundHandle_cancel_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::player::FlutterSoundHandle::cancel(&*api_that_guard);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__player__FlutterSoundHandle_duration_ms_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_duration_ms",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::player::FlutterSoundHandle::duration_ms(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__player__FlutterSoundHandle_is_looping_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_is_looping",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::player::FlutterSoundHandle::is_looping(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__player__FlutterSoundHandle_is_paused_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_is_paused",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::player::FlutterSoundHandle::is_paused(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__player__FlutterSoundHandle_pause_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_pause",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::player::FlutterSoundHandle::pause(&*api_that_guard);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__player__FlutterSoundHandle_position_ms_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_position_ms",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::player::FlutterSoundHandle::position_ms(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__player__FlutterSoundHandle_resume_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_resume",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::player::FlutterSoundHandle::resume(&*api_that_guard);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__player__FlutterSoundHandle_seek_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_seek",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_position_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::player::FlutterSoundHandle::seek(&*api_that_guard, api_position_ms);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__player__FlutterSoundHandle_set_looping_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "FlutterSoundHandle_set_looping",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_looping = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::player::FlutterSoundHandle::set_looping(&*api_that_guard, api_looping);
                 })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__flutter__FlutterCallbacks_new_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "FlutterCallbacks_new", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_accept_call = decode_DartFn_Inputs_record_string_opt_list_prim_u_8_strict_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_frontend_notify_Output_bool_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
-let api_get_contact = decode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
-let api_call_state = decode_DartFn_Inputs_call_state_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
-let api_session_status = decode_DartFn_Inputs_record_string_session_status_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
-let api_get_contacts = decode_DartFn_Inputs_unit_Output_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
-let api_statistics = decode_DartFn_Inputs_statistics_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
-let api_message_received = decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChatMessage_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
-let api_manager_active = decode_DartFn_Inputs_manager_state_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
-let api_screenshare_started = decode_DartFn_Inputs_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_frontend_notify_bool_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end();
-                transform_result_sse::<_, ()>((move || {
-                     let output_ok = Result::<_,()>::Ok(crate::flutter::FlutterCallbacks::new(api_accept_call, api_get_contact, api_call_state, api_session_status, api_get_contacts, api_statistics, api_message_received, api_manager_active, api_screenshare_started))?;   Ok(output_ok)
-                })()) })
-}
-fn wire__crate__player__FlutterSoundHandle_cancel_impl(
+fn wire__crate__player__FlutterSoundHandle_set_volume_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "FlutterSoundHandle_cancel",
+            debug_name: "FlutterSoundHandle_set_volume",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1372,6 +2001,7 @@ fn wire__crate__player__FlutterSoundHandle_cancel_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FlutterSoundHandle>,
             >>::sse_decode(&mut deserializer);
+            let api_volume = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -1389,7 +2019,7 @@ fn wire__crate__player__FlutterSoundHandle_cancel_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::player::FlutterSoundHandle::cancel(&*api_that_guard);
+                    crate::player::FlutterSoundHandle::set_volume(&*api_that_guard, api_volume);
                 })?;
                 Ok(output_ok)
             })())
@@ -4113,6 +4743,55 @@ fn wire__crate__flutter__Telepathy_send_chat_impl(
         },
     )
 }
+fn wire__crate__flutter__Telepathy_set_auto_gain_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_auto_gain",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::flutter::Telepathy::set_auto_gain(&*api_that_guard, api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__flutter__Telepathy_set_contact_output_volume_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4271,6 +4950,55 @@ fn wire__crate__flutter__Telepathy_set_denoise_impl(
         },
     )
 }
+fn wire__crate__flutter__Telepathy_set_echo_cancellation_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_echo_cancellation",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::flutter::Telepathy::set_echo_cancellation(&*api_that_guard, api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__flutter__Telepathy_set_efficiency_mode_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4489,6 +5217,60 @@ fn wire__crate__flutter__Telepathy_set_input_volume_impl(
         },
     )
 }
+fn wire__crate__flutter__Telepathy_set_jitter_buffer_bounds_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_jitter_buffer_bounds",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_min_ms = <u32>::sse_decode(&mut deserializer);
+            let api_max_ms = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::flutter::Telepathy::set_jitter_buffer_bounds(
+                        &*api_that_guard,
+                        api_min_ms,
+                        api_max_ms,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__flutter__Telepathy_set_model_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -4758,6 +5540,55 @@ fn wire__crate__flutter__Telepathy_set_play_custom_ringtones_impl(
         },
     )
 }
+fn wire__crate__flutter__Telepathy_set_redundancy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_redundancy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::flutter::Telepathy::set_redundancy(&*api_that_guard, api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__flutter__Telepathy_set_rms_threshold_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4849,7 +5680,56 @@ fn wire__crate__flutter__Telepathy_set_send_custom_ringtone_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::flutter::Telepathy::set_send_custom_ringtone(&*api_that_guard, api_send);
+                    crate::flutter::Telepathy::set_send_custom_ringtone(&*api_that_guard, api_send);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__flutter__Telepathy_set_voice_gate_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_voice_gate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::flutter::Telepathy::set_voice_gate(&*api_that_guard, api_enabled);
                 })?;
                 Ok(output_ok)
             })())
@@ -5060,6 +5940,70 @@ fn wire__crate__flutter__Telepathy_start_manager_impl(
         },
     )
 }
+fn wire__crate__flutter__Telepathy_start_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_start_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_format = <crate::types::RecordingFormat>::sse_decode(&mut deserializer);
+            let api_layout = <crate::types::RecordingLayout>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::types::DartError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::flutter::Telepathy::start_recording(
+                            &*api_that_guard,
+                            api_path,
+                            api_format,
+                            api_layout,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__flutter__Telepathy_start_screenshare_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -5212,6 +6156,62 @@ fn wire__crate__flutter__Telepathy_start_session_impl(
         },
     )
 }
+fn wire__crate__flutter__Telepathy_stop_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_stop_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::types::DartError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::flutter::Telepathy::stop_recording(&*api_that_guard).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__flutter__Telepathy_stop_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -5620,6 +6620,76 @@ fn decode_DartFn_Inputs_call_state_Output_unit_AnyhowException(
         ))
     }
 }
+fn decode_DartFn_Inputs_device_event_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::types::DeviceEvent) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::types::DeviceEvent,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::types::DeviceEvent| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_list_peer_statistics_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(Vec<crate::types::PeerStatistics>) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: Vec<crate::types::PeerStatistics>,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: Vec<crate::types::PeerStatistics>| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
 fn decode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
     dart_opaque: flutter_rust_bridge::DartOpaque,
 ) -> impl Fn(Vec<u8>) -> flutter_rust_bridge::DartFnFuture<Option<Contact>> {
@@ -5792,6 +6862,41 @@ fn decode_DartFn_Inputs_record_string_session_status_Output_unit_AnyhowException
         ))
     }
 }
+fn decode_DartFn_Inputs_recording_state_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::types::RecordingState) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::types::RecordingState,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::types::RecordingState| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
 fn decode_DartFn_Inputs_statistics_Output_unit_AnyhowException(
     dart_opaque: flutter_rust_bridge::DartOpaque,
 ) -> impl Fn(crate::types::Statistics) -> flutter_rust_bridge::DartFnFuture<()> {
@@ -6283,6 +7388,19 @@ impl SseDecode for String {
     }
 }
 
+impl SseDecode for crate::types::AudioCodec {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::types::AudioCodec::Sea,
+            1 => crate::types::AudioCodec::Opus,
+            2 => crate::types::AudioCodec::Lossless,
+            _ => unreachable!("Invalid variant for AudioCodec: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::AudioDevice {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6343,6 +7461,50 @@ impl SseDecode for crate::types::DartError {
     }
 }
 
+impl SseDecode for crate::types::DeviceEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_input = <bool>::sse_decode(deserializer);
+                let mut var_device = <crate::AudioDevice>::sse_decode(deserializer);
+                return crate::types::DeviceEvent::Added {
+                    input: var_input,
+                    device: var_device,
+                };
+            }
+            1 => {
+                let mut var_input = <bool>::sse_decode(deserializer);
+                let mut var_device = <crate::AudioDevice>::sse_decode(deserializer);
+                return crate::types::DeviceEvent::Removed {
+                    input: var_input,
+                    device: var_device,
+                };
+            }
+            2 => {
+                let mut var_input = <bool>::sse_decode(deserializer);
+                let mut var_device = <Option<crate::AudioDevice>>::sse_decode(deserializer);
+                return crate::types::DeviceEvent::DefaultChanged {
+                    input: var_input,
+                    device: var_device,
+                };
+            }
+            3 => {
+                let mut var_input = <bool>::sse_decode(deserializer);
+                let mut var_device = <Option<crate::AudioDevice>>::sse_decode(deserializer);
+                return crate::types::DeviceEvent::Switched {
+                    input: var_input,
+                    device: var_device,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6400,6 +7562,18 @@ impl SseDecode for Vec<crate::AudioDevice> {
     }
 }
 
+impl SseDecode for Vec<crate::types::PeerStatistics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::types::PeerStatistics>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6482,18 +7656,29 @@ impl SseDecode for Option<Contact> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<Contact>::sse_decode(deserializer));
+            return Some(<Contact>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<RecordingConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<RecordingConfig>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for Option<RecordingConfig> {
+impl SseDecode for Option<crate::AudioDevice> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<RecordingConfig>::sse_decode(deserializer));
+            return Some(<crate::AudioDevice>::sse_decode(deserializer));
         } else {
             return None;
         }
@@ -6533,6 +7718,26 @@ impl SseDecode for Option<Vec<u8>> {
     }
 }
 
+impl SseDecode for crate::types::PeerStatistics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peer = <String>::sse_decode(deserializer);
+        let mut var_latency = <usize>::sse_decode(deserializer);
+        let mut var_packetLoss = <f32>::sse_decode(deserializer);
+        let mut var_jitter = <f32>::sse_decode(deserializer);
+        let mut var_outputLevel = <f32>::sse_decode(deserializer);
+        let mut var_speaking = <bool>::sse_decode(deserializer);
+        return crate::types::PeerStatistics {
+            peer: var_peer,
+            latency: var_latency,
+            packet_loss: var_packetLoss,
+            jitter: var_jitter,
+            output_level: var_outputLevel,
+            speaking: var_speaking,
+        };
+    }
+}
+
 impl SseDecode for (FrontendNotify, bool) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6598,6 +7803,54 @@ impl SseDecode for (String, crate::types::SessionStatus) {
     }
 }
 
+impl SseDecode for crate::types::RecordingFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::types::RecordingFormat::Wav,
+            1 => crate::types::RecordingFormat::Sea,
+            _ => unreachable!("Invalid variant for RecordingFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::types::RecordingLayout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::types::RecordingLayout::Mixed,
+            1 => crate::types::RecordingLayout::SeparateTracks,
+            _ => unreachable!("Invalid variant for RecordingLayout: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::types::RecordingState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::types::RecordingState::Started(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Vec<String>>::sse_decode(deserializer);
+                return crate::types::RecordingState::Stopped(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::types::RecordingState::Failed(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::types::SessionStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6632,17 +7885,35 @@ impl SseDecode for crate::types::Statistics {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_inputLevel = <f32>::sse_decode(deserializer);
         let mut var_outputLevel = <f32>::sse_decode(deserializer);
+        let mut var_gain = <f32>::sse_decode(deserializer);
         let mut var_latency = <usize>::sse_decode(deserializer);
         let mut var_uploadBandwidth = <usize>::sse_decode(deserializer);
         let mut var_downloadBandwidth = <usize>::sse_decode(deserializer);
         let mut var_loss = <usize>::sse_decode(deserializer);
+        let mut var_jitterDelay = <usize>::sse_decode(deserializer);
+        let mut var_packetLoss = <f32>::sse_decode(deserializer);
+        let mut var_jitter = <f32>::sse_decode(deserializer);
+        let mut var_relayed = <bool>::sse_decode(deserializer);
+        let mut var_pathChanges = <usize>::sse_decode(deserializer);
+        let mut var_congestionWindow = <usize>::sse_decode(deserializer);
+        let mut var_bytesSent = <usize>::sse_decode(deserializer);
+        let mut var_bytesReceived = <usize>::sse_decode(deserializer);
         return crate::types::Statistics {
             input_level: var_inputLevel,
             output_level: var_outputLevel,
+            gain: var_gain,
             latency: var_latency,
             upload_bandwidth: var_uploadBandwidth,
             download_bandwidth: var_downloadBandwidth,
             loss: var_loss,
+            jitter_delay: var_jitterDelay,
+            packet_loss: var_packetLoss,
+            jitter: var_jitter,
+            relayed: var_relayed,
+            path_changes: var_pathChanges,
+            congestion_window: var_congestionWindow,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
         };
     }
 }
@@ -6661,6 +7932,13 @@ impl SseDecode for u32 {
     }
 }
 
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6690,104 +7968,112 @@ fn pde_ffi_dispatcher_primary_impl(
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
         1 => wire__crate__types__Capabilities_default_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__types__CodecConfig_default_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__types__Contact_get_peer_id_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__types__FrontendNotify_notified_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__types__NetworkConfig_default_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__overlay__Overlay_default_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__overlay__Overlay_disable_impl(port, ptr, rust_vec_len, data_len),
-        44 => wire__crate__overlay__Overlay_enable_impl(port, ptr, rust_vec_len, data_len),
-        45 => wire__crate__overlay__Overlay_hide_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__overlay__Overlay_move_overlay_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__overlay__Overlay_new_impl(port, ptr, rust_vec_len, data_len),
-        49 => wire__crate__overlay__Overlay_set_background_color_impl(
+        14 => wire__crate__types__CodecConfig_default_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__types__Contact_get_peer_id_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__types__FrontendNotify_notified_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__types__NetworkConfig_default_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__overlay__Overlay_default_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__overlay__Overlay_disable_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__overlay__Overlay_enable_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__overlay__Overlay_hide_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__overlay__Overlay_move_overlay_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__overlay__Overlay_new_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__overlay__Overlay_set_background_color_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        50 => wire__crate__overlay__Overlay_set_font_color_impl(port, ptr, rust_vec_len, data_len),
-        51 => wire__crate__overlay__Overlay_set_font_height_impl(port, ptr, rust_vec_len, data_len),
-        52 => wire__crate__overlay__Overlay_show_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__flutter__PreparedIdentitySwitch_commit_impl(
+        63 => wire__crate__overlay__Overlay_set_font_color_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__overlay__Overlay_set_font_height_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__overlay__Overlay_show_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__flutter__PreparedIdentitySwitch_commit_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        59 => wire__crate__types__ScreenshareConfig_capabilities_impl(
+        72 => wire__crate__types__ScreenshareConfig_capabilities_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        60 => wire__crate__types__ScreenshareConfig_default_impl(port, ptr, rust_vec_len, data_len),
-        61 => wire__crate__types__ScreenshareConfig_new_impl(port, ptr, rust_vec_len, data_len),
-        62 => wire__crate__types__ScreenshareConfig_recording_config_impl(
+        73 => wire__crate__types__ScreenshareConfig_default_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__types__ScreenshareConfig_new_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__types__ScreenshareConfig_recording_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        64 => wire__crate__types__ScreenshareConfig_update_recording_config_impl(
+        77 => wire__crate__types__ScreenshareConfig_update_recording_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        67 => wire__crate__player__SoundPlayer_play_impl(port, ptr, rust_vec_len, data_len),
-        68 => wire__crate__player__SoundPlayer_update_output_device_impl(
+        80 => wire__crate__player__SoundPlayer_play_impl(port, ptr, rust_vec_len, data_len),
+        81 => wire__crate__player__SoundPlayer_update_output_device_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        71 => wire__crate__flutter__Telepathy_audio_test_impl(port, ptr, rust_vec_len, data_len),
-        73 => wire__crate__flutter__Telepathy_end_call_impl(port, ptr, rust_vec_len, data_len),
-        74 => wire__crate__flutter__Telepathy_join_room_impl(port, ptr, rust_vec_len, data_len),
-        75 => wire__crate__flutter__Telepathy_list_devices_impl(port, ptr, rust_vec_len, data_len),
-        79 => wire__crate__flutter__Telepathy_prepare_identity_switch_impl(
+        84 => wire__crate__flutter__Telepathy_audio_test_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__flutter__Telepathy_end_call_impl(port, ptr, rust_vec_len, data_len),
+        87 => wire__crate__flutter__Telepathy_join_room_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__flutter__Telepathy_list_devices_impl(port, ptr, rust_vec_len, data_len),
+        92 => wire__crate__flutter__Telepathy_prepare_identity_switch_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        80 => {
+        93 => {
             wire__crate__flutter__Telepathy_restart_manager_impl(port, ptr, rust_vec_len, data_len)
         }
-        82 => wire__crate__flutter__Telepathy_send_chat_impl(port, ptr, rust_vec_len, data_len),
-        87 => wire__crate__flutter__Telepathy_set_identity_impl(port, ptr, rust_vec_len, data_len),
-        88 => {
+        95 => wire__crate__flutter__Telepathy_send_chat_impl(port, ptr, rust_vec_len, data_len),
+        102 => wire__crate__flutter__Telepathy_set_identity_impl(port, ptr, rust_vec_len, data_len),
+        103 => {
             wire__crate__flutter__Telepathy_set_input_device_impl(port, ptr, rust_vec_len, data_len)
         }
-        90 => wire__crate__flutter__Telepathy_set_model_impl(port, ptr, rust_vec_len, data_len),
-        92 => wire__crate__flutter__Telepathy_set_output_device_impl(
+        106 => wire__crate__flutter__Telepathy_set_model_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__flutter__Telepathy_set_output_device_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        97 => wire__crate__flutter__Telepathy_shutdown_impl(port, ptr, rust_vec_len, data_len),
-        98 => wire__crate__flutter__Telepathy_start_call_impl(port, ptr, rust_vec_len, data_len),
-        99 => wire__crate__flutter__Telepathy_start_manager_impl(port, ptr, rust_vec_len, data_len),
-        100 => wire__crate__flutter__Telepathy_start_screenshare_impl(
+        115 => wire__crate__flutter__Telepathy_shutdown_impl(port, ptr, rust_vec_len, data_len),
+        116 => wire__crate__flutter__Telepathy_start_call_impl(port, ptr, rust_vec_len, data_len),
+        117 => {
+            wire__crate__flutter__Telepathy_start_manager_impl(port, ptr, rust_vec_len, data_len)
+        }
+        118 => {
+            wire__crate__flutter__Telepathy_start_recording_impl(port, ptr, rust_vec_len, data_len)
+        }
+        119 => wire__crate__flutter__Telepathy_start_screenshare_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        101 => {
+        120 => {
             wire__crate__flutter__Telepathy_start_session_impl(port, ptr, rust_vec_len, data_len)
         }
-        102 => wire__crate__flutter__Telepathy_stop_session_impl(port, ptr, rust_vec_len, data_len),
-        105 => wire__crate__player__load_ringtone_impl(port, ptr, rust_vec_len, data_len),
-        108 => wire__crate__flutter__utils__screenshare_available_impl(
+        121 => {
+            wire__crate__flutter__Telepathy_stop_recording_impl(port, ptr, rust_vec_len, data_len)
+        }
+        122 => wire__crate__flutter__Telepathy_stop_session_impl(port, ptr, rust_vec_len, data_len),
+        125 => wire__crate__player__load_ringtone_impl(port, ptr, rust_vec_len, data_len),
+        128 => wire__crate__flutter__utils__screenshare_available_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        109 => wire__crate__types__statistics_default_impl(port, ptr, rust_vec_len, data_len),
+        129 => wire__crate__types__statistics_default_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -6822,83 +8108,109 @@ fn pde_ffi_dispatcher_sync_impl(
         9 => wire__crate__types__ChatMessage_clear_attachments_impl(ptr, rust_vec_len, data_len),
         10 => wire__crate__types__ChatMessage_is_sender_impl(ptr, rust_vec_len, data_len),
         11 => wire__crate__types__ChatMessage_time_impl(ptr, rust_vec_len, data_len),
-        13 => wire__crate__types__CodecConfig_new_impl(ptr, rust_vec_len, data_len),
-        14 => wire__crate__types__CodecConfig_set_enabled_impl(ptr, rust_vec_len, data_len),
-        15 => wire__crate__types__CodecConfig_set_residual_bits_impl(ptr, rust_vec_len, data_len),
-        16 => wire__crate__types__CodecConfig_set_vbr_impl(ptr, rust_vec_len, data_len),
-        17 => wire__crate__types__CodecConfig_to_values_impl(ptr, rust_vec_len, data_len),
-        18 => wire__crate__types__Contact_from_parts_impl(ptr, rust_vec_len, data_len),
-        20 => wire__crate__types__Contact_id_impl(ptr, rust_vec_len, data_len),
-        21 => wire__crate__types__Contact_id_eq_impl(ptr, rust_vec_len, data_len),
-        22 => wire__crate__types__Contact_new_impl(ptr, rust_vec_len, data_len),
-        23 => wire__crate__types__Contact_nickname_impl(ptr, rust_vec_len, data_len),
-        24 => wire__crate__types__Contact_output_volume_impl(ptr, rust_vec_len, data_len),
-        25 => wire__crate__types__Contact_peer_id_impl(ptr, rust_vec_len, data_len),
-        26 => wire__crate__types__Contact_pub_clone_impl(ptr, rust_vec_len, data_len),
-        27 => wire__crate__types__Contact_set_nickname_impl(ptr, rust_vec_len, data_len),
-        28 => wire__crate__types__Contact_set_output_volume_impl(ptr, rust_vec_len, data_len),
-        29 => wire__crate__flutter__FlutterCallbacks_new_impl(ptr, rust_vec_len, data_len),
-        30 => wire__crate__player__FlutterSoundHandle_cancel_impl(ptr, rust_vec_len, data_len),
-        32 => wire__crate__types__FrontendNotify_notify_impl(ptr, rust_vec_len, data_len),
-        34 => {
+        12 => wire__crate__types__CodecConfig_channels_impl(ptr, rust_vec_len, data_len),
+        13 => wire__crate__types__CodecConfig_codec_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__types__CodecConfig_new_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__types__CodecConfig_set_channels_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__types__CodecConfig_set_codec_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__types__CodecConfig_set_enabled_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__types__CodecConfig_set_residual_bits_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__types__CodecConfig_set_vbr_impl(ptr, rust_vec_len, data_len),
+        21 => wire__crate__types__CodecConfig_to_values_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__types__Contact_from_parts_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__types__Contact_id_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__types__Contact_id_eq_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__types__Contact_new_impl(ptr, rust_vec_len, data_len),
+        27 => wire__crate__types__Contact_nickname_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__types__Contact_output_volume_impl(ptr, rust_vec_len, data_len),
+        29 => wire__crate__types__Contact_peer_id_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__types__Contact_pub_clone_impl(ptr, rust_vec_len, data_len),
+        31 => wire__crate__types__Contact_set_nickname_impl(ptr, rust_vec_len, data_len),
+        32 => wire__crate__types__Contact_set_output_volume_impl(ptr, rust_vec_len, data_len),
+        33 => wire__crate__flutter__FlutterCallbacks_new_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__player__FlutterSoundHandle_cancel_impl(ptr, rust_vec_len, data_len),
+        35 => wire__crate__player__FlutterSoundHandle_duration_ms_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__player__FlutterSoundHandle_is_looping_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__player__FlutterSoundHandle_is_paused_impl(ptr, rust_vec_len, data_len),
+        38 => wire__crate__player__FlutterSoundHandle_pause_impl(ptr, rust_vec_len, data_len),
+        39 => wire__crate__player__FlutterSoundHandle_position_ms_impl(ptr, rust_vec_len, data_len),
+        40 => wire__crate__player__FlutterSoundHandle_resume_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__player__FlutterSoundHandle_seek_impl(ptr, rust_vec_len, data_len),
+        42 => wire__crate__player__FlutterSoundHandle_set_looping_impl(ptr, rust_vec_len, data_len),
+        43 => wire__crate__player__FlutterSoundHandle_set_volume_impl(ptr, rust_vec_len, data_len),
+        45 => wire__crate__types__FrontendNotify_notify_impl(ptr, rust_vec_len, data_len),
+        47 => {
             wire__crate__types__NetworkConfig_get_bind_addresses_impl(ptr, rust_vec_len, data_len)
         }
-        35 => wire__crate__types__NetworkConfig_get_dns_endpoint_impl(ptr, rust_vec_len, data_len),
-        36 => wire__crate__types__NetworkConfig_get_dns_origin_domain_impl(
+        48 => wire__crate__types__NetworkConfig_get_dns_endpoint_impl(ptr, rust_vec_len, data_len),
+        49 => wire__crate__types__NetworkConfig_get_dns_origin_domain_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__types__NetworkConfig_get_listen_port_impl(ptr, rust_vec_len, data_len),
-        38 => wire__crate__types__NetworkConfig_get_pkarr_relay_impl(ptr, rust_vec_len, data_len),
-        39 => wire__crate__types__NetworkConfig_get_relays_impl(ptr, rust_vec_len, data_len),
-        40 => wire__crate__types__NetworkConfig_new_impl(ptr, rust_vec_len, data_len),
-        41 => wire__crate__types__NetworkConfig_update_impl(ptr, rust_vec_len, data_len),
-        48 => wire__crate__overlay__Overlay_screen_resolution_impl(ptr, rust_vec_len, data_len),
-        54 => wire__crate__types__RecordingConfig_bitrate_impl(ptr, rust_vec_len, data_len),
-        55 => wire__crate__types__RecordingConfig_device_impl(ptr, rust_vec_len, data_len),
-        56 => wire__crate__types__RecordingConfig_encoder_impl(ptr, rust_vec_len, data_len),
-        57 => wire__crate__types__RecordingConfig_framerate_impl(ptr, rust_vec_len, data_len),
-        58 => wire__crate__types__RecordingConfig_height_impl(ptr, rust_vec_len, data_len),
-        63 => wire__crate__types__ScreenshareConfig_to_bytes_impl(ptr, rust_vec_len, data_len),
-        65 => wire__crate__player__SoundPlayer_host_impl(ptr, rust_vec_len, data_len),
-        66 => wire__crate__player__SoundPlayer_new_impl(ptr, rust_vec_len, data_len),
-        69 => {
+        50 => wire__crate__types__NetworkConfig_get_listen_port_impl(ptr, rust_vec_len, data_len),
+        51 => wire__crate__types__NetworkConfig_get_pkarr_relay_impl(ptr, rust_vec_len, data_len),
+        52 => wire__crate__types__NetworkConfig_get_relays_impl(ptr, rust_vec_len, data_len),
+        53 => wire__crate__types__NetworkConfig_new_impl(ptr, rust_vec_len, data_len),
+        54 => wire__crate__types__NetworkConfig_update_impl(ptr, rust_vec_len, data_len),
+        61 => wire__crate__overlay__Overlay_screen_resolution_impl(ptr, rust_vec_len, data_len),
+        67 => wire__crate__types__RecordingConfig_bitrate_impl(ptr, rust_vec_len, data_len),
+        68 => wire__crate__types__RecordingConfig_device_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__types__RecordingConfig_encoder_impl(ptr, rust_vec_len, data_len),
+        70 => wire__crate__types__RecordingConfig_framerate_impl(ptr, rust_vec_len, data_len),
+        71 => wire__crate__types__RecordingConfig_height_impl(ptr, rust_vec_len, data_len),
+        76 => wire__crate__types__ScreenshareConfig_to_bytes_impl(ptr, rust_vec_len, data_len),
+        78 => wire__crate__player__SoundPlayer_host_impl(ptr, rust_vec_len, data_len),
+        79 => wire__crate__player__SoundPlayer_new_impl(ptr, rust_vec_len, data_len),
+        82 => {
             wire__crate__player__SoundPlayer_update_output_volume_impl(ptr, rust_vec_len, data_len)
         }
-        70 => wire__crate__flutter__StartOperation_cancel_impl(ptr, rust_vec_len, data_len),
-        72 => wire__crate__flutter__Telepathy_build_chat_impl(ptr, rust_vec_len, data_len),
-        76 => wire__crate__flutter__Telepathy_new_impl(ptr, rust_vec_len, data_len),
-        77 => wire__crate__flutter__Telepathy_new_start_operation_impl(ptr, rust_vec_len, data_len),
-        78 => wire__crate__flutter__Telepathy_pause_statistics_impl(ptr, rust_vec_len, data_len),
-        81 => wire__crate__flutter__Telepathy_resume_statistics_impl(ptr, rust_vec_len, data_len),
-        83 => wire__crate__flutter__Telepathy_set_contact_output_volume_impl(
+        83 => wire__crate__flutter__StartOperation_cancel_impl(ptr, rust_vec_len, data_len),
+        85 => wire__crate__flutter__Telepathy_build_chat_impl(ptr, rust_vec_len, data_len),
+        89 => wire__crate__flutter__Telepathy_new_impl(ptr, rust_vec_len, data_len),
+        90 => wire__crate__flutter__Telepathy_new_start_operation_impl(ptr, rust_vec_len, data_len),
+        91 => wire__crate__flutter__Telepathy_pause_statistics_impl(ptr, rust_vec_len, data_len),
+        94 => wire__crate__flutter__Telepathy_resume_statistics_impl(ptr, rust_vec_len, data_len),
+        96 => wire__crate__flutter__Telepathy_set_auto_gain_impl(ptr, rust_vec_len, data_len),
+        97 => wire__crate__flutter__Telepathy_set_contact_output_volume_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        98 => wire__crate__flutter__Telepathy_set_deafened_impl(ptr, rust_vec_len, data_len),
+        99 => wire__crate__flutter__Telepathy_set_denoise_impl(ptr, rust_vec_len, data_len),
+        100 => {
+            wire__crate__flutter__Telepathy_set_echo_cancellation_impl(ptr, rust_vec_len, data_len)
+        }
+        101 => {
+            wire__crate__flutter__Telepathy_set_efficiency_mode_impl(ptr, rust_vec_len, data_len)
+        }
+        104 => wire__crate__flutter__Telepathy_set_input_volume_impl(ptr, rust_vec_len, data_len),
+        105 => wire__crate__flutter__Telepathy_set_jitter_buffer_bounds_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        84 => wire__crate__flutter__Telepathy_set_deafened_impl(ptr, rust_vec_len, data_len),
-        85 => wire__crate__flutter__Telepathy_set_denoise_impl(ptr, rust_vec_len, data_len),
-        86 => wire__crate__flutter__Telepathy_set_efficiency_mode_impl(ptr, rust_vec_len, data_len),
-        89 => wire__crate__flutter__Telepathy_set_input_volume_impl(ptr, rust_vec_len, data_len),
-        91 => wire__crate__flutter__Telepathy_set_muted_impl(ptr, rust_vec_len, data_len),
-        93 => wire__crate__flutter__Telepathy_set_output_volume_impl(ptr, rust_vec_len, data_len),
-        94 => wire__crate__flutter__Telepathy_set_play_custom_ringtones_impl(
+        107 => wire__crate__flutter__Telepathy_set_muted_impl(ptr, rust_vec_len, data_len),
+        109 => wire__crate__flutter__Telepathy_set_output_volume_impl(ptr, rust_vec_len, data_len),
+        110 => wire__crate__flutter__Telepathy_set_play_custom_ringtones_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        95 => wire__crate__flutter__Telepathy_set_rms_threshold_impl(ptr, rust_vec_len, data_len),
-        96 => wire__crate__flutter__Telepathy_set_send_custom_ringtone_impl(
+        111 => wire__crate__flutter__Telepathy_set_redundancy_impl(ptr, rust_vec_len, data_len),
+        112 => wire__crate__flutter__Telepathy_set_rms_threshold_impl(ptr, rust_vec_len, data_len),
+        113 => wire__crate__flutter__Telepathy_set_send_custom_ringtone_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        103 => wire__crate__flutter__logging__create_log_stream_impl(ptr, rust_vec_len, data_len),
-        104 => wire__crate__flutter__utils__generate_keys_impl(ptr, rust_vec_len, data_len),
-        106 => wire__crate__flutter__utils__room_hash_impl(ptr, rust_vec_len, data_len),
-        107 => wire__crate__flutter__logging__rust_set_up_impl(ptr, rust_vec_len, data_len),
-        110 => wire__crate__flutter__utils__validate_peer_id_impl(ptr, rust_vec_len, data_len),
+        114 => wire__crate__flutter__Telepathy_set_voice_gate_impl(ptr, rust_vec_len, data_len),
+        123 => wire__crate__flutter__logging__create_log_stream_impl(ptr, rust_vec_len, data_len),
+        124 => wire__crate__flutter__utils__generate_keys_impl(ptr, rust_vec_len, data_len),
+        126 => wire__crate__flutter__utils__room_hash_impl(ptr, rust_vec_len, data_len),
+        127 => wire__crate__flutter__logging__rust_set_up_impl(ptr, rust_vec_len, data_len),
+        130 => wire__crate__flutter__utils__validate_peer_id_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -7168,6 +8480,24 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Telepathy>> for Telepathy {
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::types::AudioCodec {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Sea => 0.into_dart(),
+            Self::Opus => 1.into_dart(),
+            Self::Lossless => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::types::AudioCodec {}
+impl flutter_rust_bridge::IntoIntoDart<crate::types::AudioCodec> for crate::types::AudioCodec {
+    fn into_into_dart(self) -> crate::types::AudioCodec {
+        self
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::AudioDevice {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -7226,6 +8556,47 @@ impl flutter_rust_bridge::IntoIntoDart<crate::types::DartError> for crate::types
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::types::DeviceEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::types::DeviceEvent::Added { input, device } => [
+                0.into_dart(),
+                input.into_into_dart().into_dart(),
+                device.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::types::DeviceEvent::Removed { input, device } => [
+                1.into_dart(),
+                input.into_into_dart().into_dart(),
+                device.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::types::DeviceEvent::DefaultChanged { input, device } => [
+                2.into_dart(),
+                input.into_into_dart().into_dart(),
+                device.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::types::DeviceEvent::Switched { input, device } => [
+                3.into_dart(),
+                input.into_into_dart().into_dart(),
+                device.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::types::DeviceEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::types::DeviceEvent> for crate::types::DeviceEvent {
+    fn into_into_dart(self) -> crate::types::DeviceEvent {
+        self
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::types::ManagerState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -7291,6 +8662,94 @@ impl flutter_rust_bridge::IntoIntoDart<crate::types::NetworkConfigUpdateError>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::types::PeerStatistics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer.into_into_dart().into_dart(),
+            self.latency.into_into_dart().into_dart(),
+            self.packet_loss.into_into_dart().into_dart(),
+            self.jitter.into_into_dart().into_dart(),
+            self.output_level.into_into_dart().into_dart(),
+            self.speaking.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::types::PeerStatistics {}
+impl flutter_rust_bridge::IntoIntoDart<crate::types::PeerStatistics>
+    for crate::types::PeerStatistics
+{
+    fn into_into_dart(self) -> crate::types::PeerStatistics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::types::RecordingFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Wav => 0.into_dart(),
+            Self::Sea => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::types::RecordingFormat {}
+impl flutter_rust_bridge::IntoIntoDart<crate::types::RecordingFormat>
+    for crate::types::RecordingFormat
+{
+    fn into_into_dart(self) -> crate::types::RecordingFormat {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::types::RecordingLayout {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mixed => 0.into_dart(),
+            Self::SeparateTracks => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::types::RecordingLayout {}
+impl flutter_rust_bridge::IntoIntoDart<crate::types::RecordingLayout>
+    for crate::types::RecordingLayout
+{
+    fn into_into_dart(self) -> crate::types::RecordingLayout {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::types::RecordingState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::types::RecordingState::Started(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::types::RecordingState::Stopped(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::types::RecordingState::Failed(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::types::RecordingState {}
+impl flutter_rust_bridge::IntoIntoDart<crate::types::RecordingState>
+    for crate::types::RecordingState
+{
+    fn into_into_dart(self) -> crate::types::RecordingState {
+        self
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::types::SessionStatus {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -7327,10 +8786,19 @@ impl flutter_rust_bridge::IntoDart for crate::types::Statistics {
         [
             self.input_level.into_into_dart().into_dart(),
             self.output_level.into_into_dart().into_dart(),
+            self.gain.into_into_dart().into_dart(),
             self.latency.into_into_dart().into_dart(),
             self.upload_bandwidth.into_into_dart().into_dart(),
             self.download_bandwidth.into_into_dart().into_dart(),
             self.loss.into_into_dart().into_dart(),
+            self.jitter_delay.into_into_dart().into_dart(),
+            self.packet_loss.into_into_dart().into_dart(),
+            self.jitter.into_into_dart().into_dart(),
+            self.relayed.into_into_dart().into_dart(),
+            self.path_changes.into_into_dart().into_dart(),
+            self.congestion_window.into_into_dart().into_dart(),
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -7679,6 +9147,23 @@ impl SseEncode for String {
     }
 }
 
+impl SseEncode for crate::types::AudioCodec {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::types::AudioCodec::Sea => 0,
+                crate::types::AudioCodec::Opus => 1,
+                crate::types::AudioCodec::Lossless => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::AudioDevice {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7731,6 +9216,37 @@ impl SseEncode for crate::types::DartError {
     }
 }
 
+impl SseEncode for crate::types::DeviceEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::types::DeviceEvent::Added { input, device } => {
+                <i32>::sse_encode(0, serializer);
+                <bool>::sse_encode(input, serializer);
+                <crate::AudioDevice>::sse_encode(device, serializer);
+            }
+            crate::types::DeviceEvent::Removed { input, device } => {
+                <i32>::sse_encode(1, serializer);
+                <bool>::sse_encode(input, serializer);
+                <crate::AudioDevice>::sse_encode(device, serializer);
+            }
+            crate::types::DeviceEvent::DefaultChanged { input, device } => {
+                <i32>::sse_encode(2, serializer);
+                <bool>::sse_encode(input, serializer);
+                <Option<crate::AudioDevice>>::sse_encode(device, serializer);
+            }
+            crate::types::DeviceEvent::Switched { input, device } => {
+                <i32>::sse_encode(3, serializer);
+                <bool>::sse_encode(input, serializer);
+                <Option<crate::AudioDevice>>::sse_encode(device, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7785,6 +9301,16 @@ impl SseEncode for Vec<crate::AudioDevice> {
     }
 }
 
+impl SseEncode for Vec<crate::types::PeerStatistics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::types::PeerStatistics>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7882,6 +9408,16 @@ impl SseEncode for Option<RecordingConfig> {
     }
 }
 
+impl SseEncode for Option<crate::AudioDevice> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::AudioDevice>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7912,6 +9448,18 @@ impl SseEncode for Option<Vec<u8>> {
     }
 }
 
+impl SseEncode for crate::types::PeerStatistics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.peer, serializer);
+        <usize>::sse_encode(self.latency, serializer);
+        <f32>::sse_encode(self.packet_loss, serializer);
+        <f32>::sse_encode(self.jitter, serializer);
+        <f32>::sse_encode(self.output_level, serializer);
+        <bool>::sse_encode(self.speaking, serializer);
+    }
+}
+
 impl SseEncode for (FrontendNotify, bool) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7970,6 +9518,61 @@ impl SseEncode for (String, crate::types::SessionStatus) {
     }
 }
 
+impl SseEncode for crate::types::RecordingFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::types::RecordingFormat::Wav => 0,
+                crate::types::RecordingFormat::Sea => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::types::RecordingLayout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::types::RecordingLayout::Mixed => 0,
+                crate::types::RecordingLayout::SeparateTracks => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::types::RecordingState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::types::RecordingState::Started(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::types::RecordingState::Stopped(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<String>>::sse_encode(field0, serializer);
+            }
+            crate::types::RecordingState::Failed(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::types::SessionStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8003,10 +9606,19 @@ impl SseEncode for crate::types::Statistics {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <f32>::sse_encode(self.input_level, serializer);
         <f32>::sse_encode(self.output_level, serializer);
+        <f32>::sse_encode(self.gain, serializer);
         <usize>::sse_encode(self.latency, serializer);
         <usize>::sse_encode(self.upload_bandwidth, serializer);
         <usize>::sse_encode(self.download_bandwidth, serializer);
         <usize>::sse_encode(self.loss, serializer);
+        <usize>::sse_encode(self.jitter_delay, serializer);
+        <f32>::sse_encode(self.packet_loss, serializer);
+        <f32>::sse_encode(self.jitter, serializer);
+        <bool>::sse_encode(self.relayed, serializer);
+        <usize>::sse_encode(self.path_changes, serializer);
+        <usize>::sse_encode(self.congestion_window, serializer);
+        <usize>::sse_encode(self.bytes_sent, serializer);
+        <usize>::sse_encode(self.bytes_received, serializer);
     }
 }
 
@@ -8024,6 +9636,13 @@ impl SseEncode for u32 {
     }
 }
 
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1,3 +1,5 @@
+/// adapts the audio bitrate of calls to receiver feedback
+mod bitrate;
 /// callback traits shared by FRB and native frontends
 pub mod callbacks;
 /// networking code for live audio streams
@@ -23,7 +25,12 @@ use crate::internal::state::{
 };
 pub(crate) use crate::internal::utils::{JoinHandle, spawn_task};
 use crate::overlay::Overlay;
-use crate::types::{ChatMessage, CodecConfig, Contact, NetworkConfig, ScreenshareConfig};
+#[cfg(not(target_family = "wasm"))]
+use crate::types::RecordingState;
+use crate::types::{
+    ChatMessage, CodecConfig, Contact, NetworkConfig, RecordingFormat, RecordingLayout,
+    ScreenshareConfig,
+};
 use chrono::Local;
 use iroh::SecretKey;
 use speedy::{LittleEndian, Writable, Writer};
@@ -34,6 +41,8 @@ use std::sync::atomic::Ordering::Relaxed;
 use std::time::Duration;
 use telepathy_audio::RnnModel;
 use telepathy_audio::devices::AudioHost;
+#[cfg(not(target_family = "wasm"))]
+use telepathy_audio::recorder::{Recorder, RecordingConfig};
 use tokio::sync::mpsc::channel;
 use tokio::sync::oneshot;
 use tokio::sync::{Mutex, Notify};
@@ -61,6 +70,10 @@ const END_CALL_SLOT_RELEASE_TIMEOUT: Duration = Duration::from_secs(5);
 /// A timeout used when initializing the call
 const HELLO_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// How often a running recording is checked for a writer that stopped on its own
+#[cfg(not(target_family = "wasm"))]
+const RECORDING_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// How often to keep-alive iroh session streams
 const KEEP_ALIVE: Duration = Duration::from_secs(10);
 /// the protocol identifier for Telepathy sessions
@@ -212,6 +225,8 @@ where
         }
         if let Some(handle) = self.inner.start_manager().await {
             self.handles.lock().await.push(handle);
+            #[cfg(not(target_family = "wasm"))]
+            self.handles.lock().await.extend(self.inner.watch_devices());
         }
     }
 
@@ -849,7 +864,7 @@ where
                     "call.run",
                     call.kind = "audio_test",
                     peer.id = %audio_config.peer,
-                    codec.enabled = audio_config.codec_config().0,
+                    codec.id = ?audio_config.codec_config().0,
                     sample_rate = audio_config.remote_configuration.sample_rate
                 );
                 let result = self
@@ -966,6 +981,38 @@ where
         self.inner.core_state.denoise.store(denoise, Relaxed);
     }
 
+    /// Changing the echo cancellation flag will not affect the current call
+    pub fn set_echo_cancellation(&self, enabled: bool) {
+        self.inner
+            .core_state
+            .echo_cancellation
+            .store(enabled, Relaxed);
+    }
+
+    /// When enabled the input volume becomes the ceiling for the automatic gain.
+    /// Changing the auto gain flag will not affect the current call
+    pub fn set_auto_gain(&self, enabled: bool) {
+        self.inner.core_state.auto_gain.store(enabled, Relaxed);
+    }
+
+    /// When enabled the rms threshold is ignored and frames are sent while speaking.
+    /// Changing the voice gate flag will not affect the current call
+    pub fn set_voice_gate(&self, enabled: bool) {
+        self.inner.core_state.voice_gate.store(enabled, Relaxed);
+    }
+
+    /// Bounds the adaptive jitter buffer delay, in milliseconds.
+    /// Changing the bounds will not affect the current call or room
+    pub fn set_jitter_buffer_bounds(&self, min_ms: u32, max_ms: u32) {
+        self.inner.core_state.set_jitter_bounds(min_ms, max_ms);
+    }
+
+    /// Advertises redundant audio frames, which are sent while the peer
+    /// measures loss. Changing this will not affect the current call or room
+    pub fn set_redundancy(&self, enabled: bool) {
+        self.inner.core_state.redundancy.store(enabled, Relaxed);
+    }
+
     pub fn set_play_custom_ringtones(&self, play: bool) {
         self.inner
             .core_state
@@ -998,12 +1045,16 @@ where
             .store(false, Relaxed);
     }
 
+    /// Sets the input device, an active call or room switches to it
     pub async fn set_input_device(&self, device_id: Option<String>) {
         *self.inner.core_state.input_device.lock().await = device_id;
+        self.inner.core_state.device_changed.notify_one();
     }
 
+    /// Sets the output device, an active call or room switches to it
     pub async fn set_output_device(&self, device_id: Option<String>) {
         *self.inner.core_state.output_device.lock().await = device_id;
+        self.inner.core_state.device_changed.notify_one();
     }
 
     /// Lists the input and output devices
@@ -1033,6 +1084,132 @@ where
         *self.inner.core_state.denoise_model.write().await = model;
         Ok(())
     }
+
+    /// Records the local input and every peer to `path` until `stop_recording` is called.
+    /// Recording continues across calls, silence fills the gaps between them
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn start_recording(
+        &self,
+        path: String,
+        format: RecordingFormat,
+        layout: RecordingLayout,
+    ) -> Result<()> {
+        let config = RecordingConfig {
+            format: format.into(),
+            layout: layout.into(),
+            ..Default::default()
+        };
+
+        {
+            let mut recorder = self
+                .inner
+                .core_state
+                .recorder
+                .lock()
+                .map_err(|_| ErrorKind::Poison("recorder mutex poisoned"))?;
+            // the tap refuses a second recorder while this one runs
+            *recorder = Some(Recorder::start(
+                &self.inner.core_state.recording_tap,
+                &path,
+                config,
+            )?);
+        }
+
+        info!("started recording to {}", path);
+        self.inner
+            .callbacks
+            .recording_state(RecordingState::Started(path))
+            .await;
+        self.watch_recording();
+        Ok(())
+    }
+
+    #[cfg(target_family = "wasm")]
+    pub async fn start_recording(
+        &self,
+        _path: String,
+        _format: RecordingFormat,
+        _layout: RecordingLayout,
+    ) -> Result<()> {
+        Err(ErrorKind::RecordingUnsupported.into())
+    }
+
+    /// Stops the running recording, if there is one, and finishes its files
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn stop_recording(&self) -> Result<()> {
+        let recorder = self
+            .inner
+            .core_state
+            .recorder
+            .lock()
+            .map_err(|_| ErrorKind::Poison("recorder mutex poisoned"))?
+            .take();
+        let Some(recorder) = recorder else {
+            return Ok(());
+        };
+
+        finish_recording(&self.inner, recorder).await
+    }
+
+    /// Reports the recording as soon as its writer stops on its own, e.g. after
+    /// a write error, instead of waiting for `stop_recording`
+    #[cfg(not(target_family = "wasm"))]
+    fn watch_recording(&self) {
+        let core = self.inner.clone();
+        spawn_task(async move {
+            let mut poll = tokio::time::interval(RECORDING_POLL_INTERVAL);
+            loop {
+                poll.tick().await;
+                let recorder = {
+                    let Ok(mut recorder) = core.core_state.recorder.lock() else {
+                        return;
+                    };
+                    match recorder.as_ref() {
+                        // stop_recording took it and reports the end itself
+                        None => return,
+                        Some(running) if running.is_running() => continue,
+                        Some(_) => recorder.take(),
+                    }
+                };
+
+                if let Some(recorder) = recorder
+                    && let Err(error) = finish_recording(&core, recorder).await
+                {
+                    error!("failed to finish recording: {}", error);
+                }
+                return;
+            }
+        });
+    }
+
+    #[cfg(target_family = "wasm")]
+    pub async fn stop_recording(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Joins the recorder's writer and reports how the recording ended
+#[cfg(not(target_family = "wasm"))]
+async fn finish_recording<C, H>(core: &TelepathyCore<C, H>, recorder: Recorder) -> Result<()>
+where
+    C: CoreCallbacks + Send + Sync + 'static,
+    H: AudioHost + Send + Sync + Clone + 'static,
+{
+    // joining the writer flushes the files, keep it off the runtime
+    let state = match tokio::task::spawn_blocking(|| recorder.stop()).await? {
+        Ok(files) => RecordingState::Stopped(
+            files
+                .into_iter()
+                .map(|file| file.to_string_lossy().into_owned())
+                .collect(),
+        ),
+        Err(error) => {
+            error!("recording failed: {}", error);
+            RecordingState::Failed(error.to_string())
+        }
+    };
+    core.callbacks.recording_state(state).await;
+    Ok(())
 }
 
 fn validate_identity_switch_contacts(contacts: &[Contact]) -> Result<()> {
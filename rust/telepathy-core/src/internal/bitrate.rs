@@ -0,0 +1,148 @@
+//! Adaptive bitrate for SEA calls.
+//!
+//! Every [`FEEDBACK_INTERVAL`] the receiver of a call reports the share of
+//! frames it lost and the round trip of the session in a
+//! [`ProtocolMessage::AudioFeedback`](crate::internal::messages::ProtocolMessage::AudioFeedback).
+//! The sender feeds those reports to a [`BitrateController`], which steers the
+//! residual bits of its encoder between [`MIN_RESIDUAL_BITS`] and the value
+//! both peers negotiated.
+//!
+//! Congestion lowers the bitrate right away, while it only climbs back after
+//! several clean reports so a flaky link doesn't oscillate.
+
+use atomic_float::AtomicF32;
+use std::sync::Arc;
+use std::sync::atomic::Ordering::Relaxed;
+use std::time::Duration;
+
+/// How often the receiver reports loss and round trip to the sender.
+pub(crate) const FEEDBACK_INTERVAL: Duration = Duration::from_secs(2);
+/// Fewest residual bits the controller lowers the encoder to.
+const MIN_RESIDUAL_BITS: f32 = 2.0;
+/// Share of lost frames above which the bitrate is lowered.
+const HIGH_LOSS: f32 = 0.05;
+/// Share of lost frames below which the bitrate may be raised.
+const LOW_LOSS: f32 = 0.01;
+/// Round trip above which the bitrate is lowered, in milliseconds.
+const HIGH_RTT_MS: u32 = 300;
+/// Round trip below which the bitrate may be raised, in milliseconds.
+const LOW_RTT_MS: u32 = 150;
+/// Residual bits removed for a congested report.
+const DECREASE_STEP: f32 = 1.0;
+/// Residual bits added after enough clean reports.
+const INCREASE_STEP: f32 = 0.5;
+/// Clean reports in a row needed before the bitrate is raised.
+const CLEAN_REPORTS: u32 = 3;
+
+/// Steers the residual bits of a SEA encoder from receiver feedback.
+pub(crate) struct BitrateController {
+    /// The negotiated residual bits, never exceeded.
+    max: f32,
+    /// The residual bits the encoder currently uses.
+    current: Arc<AtomicF32>,
+    /// Clean reports received since the last change.
+    clean: u32,
+}
+
+impl BitrateController {
+    /// Creates a controller starting at the negotiated `residual_bits`.
+    pub(crate) fn new(residual_bits: f32) -> Self {
+        Self {
+            max: residual_bits,
+            current: Arc::new(AtomicF32::new(residual_bits)),
+            clean: 0,
+        }
+    }
+
+    /// The residual bits shared with the input processor.
+    pub(crate) fn residual_bits(&self) -> &Arc<AtomicF32> {
+        &self.current
+    }
+
+    /// Feeds one receiver report.
+    ///
+    /// Returns the new residual bits when they changed.
+    pub(crate) fn update(&mut self, loss: f32, rtt_ms: u32) -> Option<f32> {
+        let current = self.current.load(Relaxed);
+        let min = MIN_RESIDUAL_BITS.min(self.max);
+
+        let target = if loss > HIGH_LOSS || rtt_ms > HIGH_RTT_MS {
+            self.clean = 0;
+            (current - DECREASE_STEP).max(min)
+        } else if loss < LOW_LOSS && rtt_ms < LOW_RTT_MS {
+            self.clean += 1;
+            if self.clean < CLEAN_REPORTS {
+                return None;
+            }
+            self.clean = 0;
+            (current + INCREASE_STEP).min(self.max)
+        } else {
+            self.clean = 0;
+            return None;
+        };
+
+        if target == current {
+            return None;
+        }
+        self.current.store(target, Relaxed);
+        Some(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feeds `count` identical reports.
+    fn feed(controller: &mut BitrateController, count: usize, loss: f32, rtt_ms: u32) {
+        for _ in 0..count {
+            controller.update(loss, rtt_ms);
+        }
+    }
+
+    #[test]
+    fn lowers_on_loss_or_latency_down_to_the_minimum() {
+        let mut controller = BitrateController::new(5.0);
+
+        assert_eq!(controller.update(0.1, 50), Some(4.0));
+        assert_eq!(controller.update(0.0, 500), Some(3.0));
+        assert_eq!(controller.update(0.1, 500), Some(2.0));
+        assert_eq!(controller.update(0.1, 500), None);
+        assert_eq!(controller.residual_bits().load(Relaxed), MIN_RESIDUAL_BITS);
+    }
+
+    #[test]
+    fn raises_after_clean_reports_up_to_the_negotiated_bits() {
+        let mut controller = BitrateController::new(3.0);
+        feed(&mut controller, 2, 0.2, 50);
+        assert_eq!(controller.residual_bits().load(Relaxed), 2.0);
+
+        assert_eq!(controller.update(0.0, 50), None);
+        assert_eq!(controller.update(0.0, 50), None);
+        assert_eq!(controller.update(0.0, 50), Some(2.5));
+
+        feed(&mut controller, 30, 0.0, 50);
+        assert_eq!(controller.residual_bits().load(Relaxed), 3.0);
+    }
+
+    #[test]
+    fn moderate_reports_hold_the_bitrate_and_reset_the_clean_streak() {
+        let mut controller = BitrateController::new(4.0);
+        controller.update(0.1, 50);
+
+        feed(&mut controller, 2, 0.0, 50);
+        assert_eq!(controller.update(0.02, 50), None);
+        assert_eq!(controller.update(0.0, 200), None);
+        feed(&mut controller, 2, 0.0, 50);
+        assert_eq!(controller.residual_bits().load(Relaxed), 3.0);
+    }
+
+    #[test]
+    fn never_leaves_negotiated_bits_below_the_minimum() {
+        let mut controller = BitrateController::new(1.5);
+
+        assert_eq!(controller.update(0.5, 1_000), None);
+        feed(&mut controller, 10, 0.0, 10);
+        assert_eq!(controller.residual_bits().load(Relaxed), 1.5);
+    }
+}
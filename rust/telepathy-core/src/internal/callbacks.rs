@@ -1,6 +1,7 @@
 use crate::internal::utils::JoinHandle;
 use crate::types::{
-    CallState, ChatMessage, Contact, FrontendNotify, ManagerState, SessionStatus, Statistics,
+    CallState, ChatMessage, Contact, DeviceEvent, FrontendNotify, ManagerState, PeerStatistics,
+    RecordingState, SessionStatus, Statistics,
 };
 #[cfg(feature = "integration-testing")]
 use async_trait::async_trait;
@@ -48,6 +49,10 @@ pub trait CoreCallbacks {
 
     fn message_received(&self, chat_message: ChatMessage) -> impl Future<Output = ()> + Send;
 
+    fn recording_state(&self, state: RecordingState) -> impl Future<Output = ()> + Send;
+
+    fn device_event(&self, event: DeviceEvent) -> impl Future<Output = ()> + Send;
+
     fn statistics_callback(&self) -> Self::StatisticsCallback;
 }
 
@@ -55,4 +60,6 @@ pub trait CoreCallbacks {
 #[cfg_attr(feature = "integration-testing", async_trait)]
 pub trait CoreStatisticsCallback {
     fn post(&self, stats: Statistics) -> impl Future<Output = ()> + Send;
+
+    fn post_peers(&self, peers: Vec<PeerStatistics>) -> impl Future<Output = ()> + Send;
 }
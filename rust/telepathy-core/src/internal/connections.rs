@@ -1,16 +1,19 @@
 use crate::internal::KEEP_ALIVE;
 use crate::internal::error::Error;
+use atomic_float::AtomicF32;
 use bytes::Bytes;
 use iroh::endpoint::Connection;
 use kanal::{AsyncReceiver, Sender};
 use std::collections::{BTreeMap, HashSet};
+use std::mem;
 use std::sync::atomic::Ordering::Relaxed;
-use std::sync::atomic::{AtomicU32, AtomicUsize};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use telepathy_audio::FRAME_SIZE;
 use telepathy_audio::internal::NETWORK_FRAME;
 use telepathy_audio::internal::buffer_pool::{BufferPool, PooledBuffer, PooledBytes};
+use telepathy_audio::io::traits::MISSING_FRAME;
 use tokio::select;
 #[cfg(not(target_family = "wasm"))]
 use tokio::time::{Instant, timeout};
@@ -24,21 +27,199 @@ pub(crate) type SharedConnections = Arc<RoomConnectionRegistry>;
 
 /// 4 bytes for sequence number
 const HEADER_SIZE: usize = 4;
+/// Playout delay used until jitter has been measured, in frames.
 const JITTER_LATENCY_FRAMES: u32 = 5;
 const MAX_BUFFERED_FRAMES: u32 = 32;
+/// Default lower bound of the adaptive playout delay.
+pub(crate) const DEFAULT_JITTER_MIN_MS: u32 = 20;
+/// Default upper bound of the adaptive playout delay.
+pub(crate) const DEFAULT_JITTER_MAX_MS: u32 = 200;
+/// The playout delay covers this many times the measured jitter.
+const JITTER_DEPTH_FACTOR: f64 = 4.0;
+/// Weight of each new inter-arrival jitter sample (RFC 3550).
+const JITTER_SMOOTHING: f64 = 1.0 / 16.0;
 /// Pre-computed exact capacity for prepared audio packets
 const PACKET_BUFFER_CAPACITY: usize = HEADER_SIZE + NETWORK_FRAME;
 /// Pool size for timestamp buffers
 const PACKET_POOL_SIZE: usize = 8;
 const KEEP_ALIVE_TAG: u8 = 1;
 const KEEP_ALIVE_PACKET_SIZE: usize = HEADER_SIZE + 1;
+/// Tags a datagram asking the peer to start (1) or stop (0) sending redundant frames.
+const REDUNDANCY_TAG: u8 = 2;
+const REDUNDANCY_PACKET_SIZE: usize = 2;
+/// 2 bytes for the length of the primary payload in redundant packets
+const REDUNDANCY_HEADER_SIZE: usize = 2;
+/// Redundant packets stay within the datagram size every QUIC path supports.
+/// Frames too large to share a datagram, like uncompressed audio, go out alone.
+const MAX_REDUNDANT_PACKET_SIZE: usize = 1_100;
+/// Played frames the receiver measures loss over before asking for a change.
+const REDUNDANCY_WINDOW_FRAMES: usize = 250;
+/// Share of lost frames above which the receiver asks for redundant frames.
+const REDUNDANCY_ENABLE_LOSS: f32 = 0.03;
+/// Share of lost frames below which redundant frames are no longer needed.
+const REDUNDANCY_DISABLE_LOSS: f32 = 0.01;
+
+/// Forward error correction state of one audio connection.
+///
+/// When both peers negotiated it in their [`AudioExtension`](crate::internal::messages::AudioExtension),
+/// audio packets carry the length of their payload so a copy of the previous
+/// frame can follow it. The receiver measures loss and tells the sender when
+/// to include that copy, which is reflected in `active`.
+#[derive(Clone, Default)]
+pub(crate) struct Redundancy {
+    negotiated: bool,
+    active: Arc<AtomicBool>,
+}
+
+impl Redundancy {
+    pub(crate) fn new(negotiated: bool) -> Self {
+        Self {
+            negotiated,
+            active: Default::default(),
+        }
+    }
+
+    /// The framing of audio packets, with `previous` if the peer asked for it.
+    fn framing<'a>(&self, previous: &'a [u8]) -> Framing<'a> {
+        if !self.negotiated {
+            Framing::Plain
+        } else if self.active.load(Relaxed) {
+            Framing::Redundant(previous)
+        } else {
+            Framing::Redundant(&[])
+        }
+    }
+}
+
+/// How an audio payload is laid out after its sequence number.
+#[derive(Clone, Copy)]
+enum Framing<'a> {
+    /// Only the payload.
+    Plain,
+    /// The payload length, the payload and the previous payload, which is
+    /// empty when there is none.
+    Redundant(&'a [u8]),
+}
+
+/// Measures loss on the receiving side to switch the sender's redundancy.
+#[derive(Default)]
+struct LossMonitor {
+    frames: usize,
+    lost: usize,
+    requested: bool,
+}
+
+impl LossMonitor {
+    /// Counts a frame that was missing or had to be rebuilt.
+    fn lost(&mut self) {
+        self.lost += 1;
+    }
+
+    /// Counts a played frame.
+    ///
+    /// At the end of each window returns whether redundancy should be
+    /// requested. The request is repeated every window since it travels as a
+    /// datagram too.
+    fn played(&mut self) -> Option<bool> {
+        self.frames += 1;
+        if self.frames < REDUNDANCY_WINDOW_FRAMES {
+            return None;
+        }
+
+        let loss = self.lost as f32 / self.frames as f32;
+        self.frames = 0;
+        self.lost = 0;
+
+        self.requested = if self.requested {
+            loss >= REDUNDANCY_DISABLE_LOSS
+        } else {
+            loss >= REDUNDANCY_ENABLE_LOSS
+        };
+        Some(self.requested)
+    }
+}
+
+/// Network telemetry of one audio receiver.
+///
+/// Frames played and lost are reported back to the sender, while datagrams
+/// received against the sequence numbers they span and the inter-arrival
+/// jitter feed the call statistics.
+#[derive(Default)]
+pub(crate) struct ReceiveCounters {
+    played: AtomicUsize,
+    lost: AtomicUsize,
+    received: AtomicUsize,
+    expected: AtomicUsize,
+    /// Smoothed inter-arrival jitter in milliseconds.
+    jitter: AtomicF32,
+}
+
+impl ReceiveCounters {
+    /// Takes the share of frames lost since the last call, if any were played.
+    ///
+    /// Frames rebuilt from redundant copies count as lost, since they were
+    /// lost on the network.
+    pub(crate) fn take_loss(&self) -> Option<f32> {
+        let played = self.played.swap(0, Relaxed);
+        let lost = self.lost.swap(0, Relaxed);
+        (played > 0).then(|| (lost as f32 / played as f32).min(1.0))
+    }
+
+    /// Takes the share of datagrams missing from the received sequence since
+    /// the last call.
+    ///
+    /// Late datagrams still count as received, only gaps are lost.
+    pub(crate) fn take_packet_loss(&self) -> f32 {
+        let received = self.received.swap(0, Relaxed);
+        let expected = self.expected.swap(0, Relaxed);
+        if expected == 0 {
+            return 0.0;
+        }
+        expected.saturating_sub(received) as f32 / expected as f32
+    }
+
+    /// The smoothed inter-arrival jitter in milliseconds.
+    pub(crate) fn jitter(&self) -> f32 {
+        self.jitter.load(Relaxed)
+    }
+}
+
+/// Counts the sequence numbers spanned by received audio datagrams.
+#[derive(Default)]
+struct SequenceGaps {
+    highest: Option<u32>,
+}
+
+impl SequenceGaps {
+    /// Returns how many sequence numbers the arrival of `seq` adds.
+    ///
+    /// Late and duplicate datagrams add none. Jumps too large to be loss
+    /// belong to a restarted stream and count as a single datagram.
+    fn expected(&mut self, seq: u32) -> usize {
+        let Some(highest) = self.highest else {
+            self.highest = Some(seq);
+            return 1;
+        };
+        if !seq_before(highest, seq) {
+            return 0;
+        }
+
+        self.highest = Some(seq);
+        let gap = seq.wrapping_sub(highest);
+        if gap > MAX_BUFFERED_FRAMES {
+            1
+        } else {
+            gap as usize
+        }
+    }
+}
 
 /// Tracks room audio transport connections for the shared uplink path.
 ///
 /// Connections are keyed by [`Connection::stable_id`] so duplicate joins and
 /// leaves can remove the exact socket even after a peer reconnects.
 pub(crate) struct RoomConnectionRegistry {
-    pending: Mutex<Vec<Connection>>,
+    pending: Mutex<Vec<(Connection, Redundancy)>>,
     removed: Mutex<HashSet<usize>>,
 }
 
@@ -52,8 +233,8 @@ impl Default for RoomConnectionRegistry {
 }
 
 impl RoomConnectionRegistry {
-    pub(crate) fn push(&self, connection: Connection) {
-        self.pending.lock().unwrap().push(connection);
+    pub(crate) fn push(&self, connection: Connection, redundancy: Redundancy) {
+        self.pending.lock().unwrap().push((connection, redundancy));
     }
 
     pub(crate) fn remove(&self, connection: &Connection) {
@@ -62,7 +243,7 @@ impl RoomConnectionRegistry {
         self.pending
             .lock()
             .unwrap()
-            .retain(|pending| pending.stable_id() != connection_id);
+            .retain(|(pending, _)| pending.stable_id() != connection_id);
     }
 
     fn is_removed(&self, connection: &Connection) -> bool {
@@ -72,7 +253,7 @@ impl RoomConnectionRegistry {
             .contains(&connection.stable_id())
     }
 
-    fn drain_pending(&self) -> Vec<Connection> {
+    fn drain_pending(&self) -> Vec<(Connection, Redundancy)> {
         self.pending.lock().unwrap().drain(..).collect()
     }
 }
@@ -90,14 +271,25 @@ pub(crate) struct ConstConnection {
     packet_buffers: Arc<BufferPool>,
 
     sequence_number: Arc<AtomicU32>,
+
+    redundancy: Redundancy,
+
+    /// The last audio payload sent, for redundant packets.
+    previous: Vec<u8>,
 }
 
 impl ConstConnection {
-    pub(crate) fn new(connection: Connection, sequence_number: Arc<AtomicU32>) -> Self {
+    pub(crate) fn new(
+        connection: Connection,
+        sequence_number: Arc<AtomicU32>,
+        redundancy: Redundancy,
+    ) -> Self {
         Self {
             connection,
             packet_buffers: Arc::new(BufferPool::new(PACKET_POOL_SIZE, PACKET_BUFFER_CAPACITY)),
             sequence_number,
+            redundancy,
+            previous: Vec::new(),
         }
     }
 }
@@ -116,7 +308,13 @@ impl TelepathyConnection for ConstConnection {
             packet.as_ref(),
             sequence_number,
             is_keep_alive,
+            self.redundancy.framing(&self.previous),
         );
+        // a keepalive ends the talkspurt, the next frame has no predecessor
+        self.previous.clear();
+        if !is_keep_alive && self.redundancy.negotiated {
+            self.previous.extend_from_slice(packet);
+        }
         // Clone the inner Bytes (O(1) refcount increment) for send, allowing
         // automatic buffer recovery when pooled_bytes is dropped after send completes.
         let ok = self
@@ -131,7 +329,7 @@ impl TelepathyConnection for ConstConnection {
 pub(crate) struct DynamicConnection {
     new_connections: SharedConnections,
 
-    connections: Vec<Connection>,
+    connections: Vec<(Connection, Redundancy)>,
 
     /// Reused buffers via `BufferPool` for zero-reallocation packet construction.
     /// Buffers are automatically returned to the pool when `PooledBytes` is dropped.
@@ -147,18 +345,21 @@ pub(crate) struct DynamicConnection {
     /// anchor, jump-starting playback at that point rather than waiting for
     /// the original sequence origin.
     sequence_number: Arc<AtomicU32>,
+
+    /// The last audio payload sent, for redundant packets.
+    previous: Vec<u8>,
 }
 
 impl TelepathyConnection for DynamicConnection {
     fn send(&mut self, packet: &Bytes) -> usize {
-        for connection in self.new_connections.drain_pending() {
+        for (connection, redundancy) in self.new_connections.drain_pending() {
             if !self.new_connections.is_removed(&connection) {
-                self.connections.push(connection);
+                self.connections.push((connection, redundancy));
             }
         }
 
         self.connections
-            .retain(|connection| !self.new_connections.is_removed(connection));
+            .retain(|(connection, _)| !self.new_connections.is_removed(connection));
 
         // send the bytes to all connections, dropping any that error
         let mut i = 0;
@@ -171,13 +372,14 @@ impl TelepathyConnection for DynamicConnection {
 
             let send_result = {
                 // limit the &mut borrow to this block
-                let socket = &mut self.connections[i];
+                let (socket, redundancy) = &mut self.connections[i];
 
                 let pooled_bytes = prepare_packet(
                     pooled_buffer,
                     packet.as_ref(),
                     sequence_number,
                     is_keep_alive,
+                    redundancy.framing(&self.previous),
                 );
                 // Clone for send (O(1)), buffer recovered automatically via Drop
                 socket.send_datagram((*pooled_bytes).clone())
@@ -196,8 +398,12 @@ impl TelepathyConnection for DynamicConnection {
             }
         }
 
-        if successful_sends > 0 && !is_keep_alive {
+        if is_keep_alive {
+            self.previous.clear();
+        } else if successful_sends > 0 {
             self.sequence_number.fetch_add(1, Relaxed);
+            self.previous.clear();
+            self.previous.extend_from_slice(packet);
         }
         successful_sends
     }
@@ -210,11 +416,47 @@ impl DynamicConnection {
             connections: Vec::new(),
             packet_buffers: Arc::new(BufferPool::new(PACKET_POOL_SIZE, PACKET_BUFFER_CAPACITY)),
             sequence_number,
+            previous: Vec::new(),
         }
     }
 }
 
-#[derive(Default)]
+/// Bounds of the adaptive playout delay of an [`AudioJitterBuffer`], in frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct JitterBounds {
+    min_frames: u32,
+    max_frames: u32,
+}
+
+impl JitterBounds {
+    /// Converts delays in milliseconds to whole frames at `sample_rate`.
+    ///
+    /// The bounds are kept in order, above zero and below [`MAX_BUFFERED_FRAMES`].
+    pub(crate) fn from_millis(min_ms: u32, max_ms: u32, sample_rate: u32) -> Self {
+        let frames = |ms: u32| {
+            let frames = (ms as u64 * sample_rate as u64).div_ceil(FRAME_SIZE as u64 * 1_000);
+            frames.min(MAX_BUFFERED_FRAMES as u64) as u32
+        };
+        let min_frames = frames(min_ms).max(1);
+        let max_frames = frames(max_ms).max(min_frames);
+        Self {
+            min_frames,
+            max_frames,
+        }
+    }
+
+    fn clamp(&self, frames: u32) -> u32 {
+        frames.clamp(self.min_frames, self.max_frames)
+    }
+}
+
+/// Orders received packets and releases them at their playout deadline.
+///
+/// The playout delay adapts to the network. Inter-arrival jitter is tracked
+/// like RFC 3550 and every talkspurt starts with a delay of
+/// [`JITTER_DEPTH_FACTOR`] times the jitter, within the configured bounds. A
+/// packet arriving after its deadline delays the rest of the talkspurt by one
+/// frame, so a worsening link does not have to wait for the next pause.
 struct AudioJitterBuffer {
     packets: BTreeMap<u32, Bytes>,
 
@@ -232,16 +474,59 @@ struct AudioJitterBuffer {
 
     /// The sample rate of the audio source used to calculate frame durations
     sample_rate: u32,
+
+    /// Bounds of the playout delay.
+    bounds: JitterBounds,
+
+    /// Playout delay of the current talkspurt, in frames.
+    depth: u32,
+
+    /// Smoothed inter-arrival jitter in seconds, once measured.
+    jitter: Option<f64>,
+
+    /// Sequence number and arrival time of the last audio packet.
+    last_arrival: Option<(u32, Instant)>,
+
+    /// Whether the packet after a missing one is released without waiting
+    /// for its deadline, so the decoder can rebuild the missing frame from it.
+    release_next: bool,
 }
 
 impl AudioJitterBuffer {
     fn new(sample_rate: u32) -> Self {
+        let bounds =
+            JitterBounds::from_millis(DEFAULT_JITTER_MIN_MS, DEFAULT_JITTER_MAX_MS, sample_rate);
         Self {
+            packets: BTreeMap::new(),
+            next_seq: None,
+            anchor_seq: 0,
+            anchor_deadline: None,
+            min_seq: None,
             sample_rate,
-            ..Default::default()
+            bounds,
+            depth: bounds.clamp(JITTER_LATENCY_FRAMES),
+            jitter: None,
+            last_arrival: None,
+            release_next: false,
         }
     }
 
+    fn with_bounds(mut self, bounds: JitterBounds) -> Self {
+        self.bounds = bounds;
+        self.depth = bounds.clamp(self.depth);
+        self
+    }
+
+    /// The playout delay of the current talkspurt.
+    fn delay(&self) -> Duration {
+        self.frame_duration(self.depth)
+    }
+
+    /// The smoothed inter-arrival jitter, zero until measured.
+    fn jitter(&self) -> Duration {
+        Duration::from_secs_f64(self.jitter.unwrap_or_default())
+    }
+
     fn advance_min_seq(&mut self, candidate: u32) {
         if self
             .min_seq
@@ -261,29 +546,34 @@ impl AudioJitterBuffer {
         self.packets.clear();
         self.next_seq = None;
         self.anchor_deadline = None;
+        self.last_arrival = None;
 
         // The keepalive carries the next audio sequence number, not its own sequence.
         self.advance_min_seq(sequence_floor);
     }
 
     fn insert_audio(&mut self, seq: u32, payload: Bytes, now: Instant) -> bool {
+        // Late packets are the jitter that matters most, measure them too.
+        self.measure_arrival(seq, now);
+
         if let Some(min_seq) = self.min_seq
             && seq_before(seq, min_seq)
         {
+            // During a talkspurt this packet missed its deadline.
+            self.extend_talkspurt();
             return false;
         }
 
         // First packet in a talkspurt. Start playout after the jitter delay.
         if self.next_seq.is_none() {
-            self.next_seq = Some(seq);
-            self.anchor_seq = seq;
-            self.anchor_deadline = Some(now + self.frame_duration(JITTER_LATENCY_FRAMES));
+            self.start_talkspurt(seq, now);
         }
 
         let next_seq = self.next_seq.unwrap();
 
         // Already played or skipped.
         if seq_before(seq, next_seq) {
+            self.extend_talkspurt();
             return false;
         }
 
@@ -293,14 +583,76 @@ impl AudioJitterBuffer {
         // Restart instead of letting one packet force hundreds of fake losses.
         if ahead > MAX_BUFFERED_FRAMES {
             self.packets.clear();
-            self.next_seq = Some(seq);
-            self.anchor_seq = seq;
-            self.anchor_deadline = Some(now + self.frame_duration(JITTER_LATENCY_FRAMES));
+            self.start_talkspurt(seq, now);
         }
 
         self.packets.insert(seq, payload).is_none()
     }
 
+    /// Whether `seq` is buffered for playout.
+    fn contains(&self, seq: u32) -> bool {
+        self.packets.contains_key(&seq)
+    }
+
+    /// Fills in `seq` from a redundant copy if it is missing and not due yet.
+    ///
+    /// Unlike [`Self::insert_audio`] this neither starts a talkspurt nor
+    /// measures jitter, the copy arrived with a later packet. Returns true
+    /// when the frame was rebuilt.
+    fn recover(&mut self, seq: u32, payload: Bytes) -> bool {
+        let Some(next_seq) = self.next_seq else {
+            return false;
+        };
+        if seq_before(seq, next_seq) || self.packets.contains_key(&seq) {
+            return false;
+        }
+        self.packets.insert(seq, payload);
+        true
+    }
+
+    /// Updates the jitter estimate with the arrival of `seq`.
+    fn measure_arrival(&mut self, seq: u32, now: Instant) {
+        if let Some((last_seq, last_arrival)) = self.last_arrival.replace((seq, now)) {
+            let frames = seq.wrapping_sub(last_seq) as i32 as f64;
+            let expected = frames * self.frame_duration(1).as_secs_f64();
+            let actual = now.saturating_duration_since(last_arrival).as_secs_f64();
+            let deviation = (actual - expected).abs();
+
+            // longer gaps are pauses between talkspurts, not jitter
+            if deviation <= self.frame_duration(MAX_BUFFERED_FRAMES).as_secs_f64() {
+                let jitter = self.jitter.get_or_insert(deviation);
+                *jitter += (deviation - *jitter) * JITTER_SMOOTHING;
+            }
+        }
+    }
+
+    /// Anchors playout of a talkspurt starting at `seq` after a delay
+    /// covering the measured jitter.
+    fn start_talkspurt(&mut self, seq: u32, now: Instant) {
+        if let Some(jitter) = self.jitter {
+            let frames = jitter * JITTER_DEPTH_FACTOR / self.frame_duration(1).as_secs_f64();
+            self.depth = self.bounds.clamp(frames.ceil() as u32);
+        }
+
+        self.next_seq = Some(seq);
+        self.anchor_seq = seq;
+        self.anchor_deadline = Some(now + self.delay());
+        self.release_next = false;
+    }
+
+    /// Delays the rest of the talkspurt by one frame, up to the upper bound.
+    ///
+    /// Does nothing between talkspurts.
+    fn extend_talkspurt(&mut self) {
+        let frame = self.frame_duration(1);
+        if self.depth < self.bounds.max_frames
+            && let Some(anchor_deadline) = &mut self.anchor_deadline
+        {
+            *anchor_deadline += frame;
+            self.depth += 1;
+        }
+    }
+
     fn deadline_for(&self, seq: u32) -> Option<Instant> {
         let anchor_deadline = self.anchor_deadline?;
         let offset_frames = seq.wrapping_sub(self.anchor_seq);
@@ -321,12 +673,15 @@ impl AudioJitterBuffer {
     /// Returns:
     /// - None: not time to play yet
     /// - Some(Some(payload)): play this packet
-    /// - Some(None): packet was missing, count/drop it
+    /// - Some(None): packet was missing, count it and let the output conceal it
+    ///
+    /// A packet following a missing one is due right away when it is already
+    /// buffered, codecs with in-band FEC rebuild the missing frame from it.
     fn pop_due(&mut self, now: Instant) -> Option<Option<Bytes>> {
         let next_seq = self.next_seq?;
         let deadline = self.deadline_for(next_seq)?;
 
-        if now < deadline {
+        if now < deadline && !mem::take(&mut self.release_next) {
             return None;
         }
 
@@ -335,6 +690,7 @@ impl AudioJitterBuffer {
 
         self.next_seq = Some(following);
         self.advance_min_seq(following);
+        self.release_next = payload.is_none() && self.packets.contains_key(&following);
 
         // If the buffer is empty after a miss or the last emitted packet,
         // go idle. This avoids counting silence as infinite packet loss.
@@ -389,30 +745,63 @@ pub(crate) async fn audio_input<S: TelepathyConnection>(
 }
 
 /// Receives audio data from the socket and sends it to the output processor
+///
+/// The jitter buffer's playout delay adapts within `bounds` and is published
+/// to `jitter_delay` in milliseconds.
+///
+/// With negotiated `redundancy`, missing frames are rebuilt from the copies
+/// carried by later packets, and the peer is asked to send those copies while
+/// the measured loss is high.
+///
+/// Network telemetry of the received audio is recorded in `counters`.
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn audio_output(
     sender: Sender<Bytes>,
     connection: Connection,
     cancel: CancellationToken,
     loss: Arc<AtomicUsize>,
+    jitter_delay: Arc<AtomicUsize>,
     sample_rate: u32,
+    bounds: JitterBounds,
+    redundancy: Redundancy,
+    counters: Arc<ReceiveCounters>,
 ) -> Result<(), Error> {
-    let mut jitter = AudioJitterBuffer::new(sample_rate);
+    let mut jitter = AudioJitterBuffer::new(sample_rate).with_bounds(bounds);
+    jitter_delay.store(jitter.delay().as_millis() as usize, Relaxed);
+    let mut monitor = LossMonitor::default();
+    let mut gaps = SequenceGaps::default();
 
     'outer: loop {
         // First, emit everything whose playout deadline has arrived.
         while let Some(payload) = jitter.pop_due(Instant::now()) {
-            match payload {
-                Some(payload) => {
-                    if sender.try_send(payload).is_err() {
-                        info!("audio_output ended with closed channel");
-                        break 'outer Ok(());
-                    }
-                }
-                None => {
-                    // We know a packet was missing because a later packet was buffered.
-                    loss.fetch_add(FRAME_SIZE, Relaxed);
+            let payload = payload.unwrap_or_else(|| {
+                // We know a packet was missing because a later packet was buffered.
+                // The output processor conceals the marker instead of playing a gap.
+                loss.fetch_add(FRAME_SIZE, Relaxed);
+                counters.lost.fetch_add(1, Relaxed);
+                monitor.lost();
+                MISSING_FRAME
+            });
+            counters.played.fetch_add(1, Relaxed);
+
+            if redundancy.negotiated
+                && let Some(requested) = monitor.played()
+            {
+                debug!(requested, "audio_output requesting peer redundancy");
+                let request = Bytes::from_static(if requested {
+                    &[REDUNDANCY_TAG, 1]
+                } else {
+                    &[REDUNDANCY_TAG, 0]
+                });
+                if let Err(error) = connection.send_datagram(request) {
+                    warn!("audio_output failed to request redundancy: {error}");
                 }
             }
+
+            if sender.try_send(payload).is_err() {
+                info!("audio_output ended with closed channel");
+                break 'outer Ok(());
+            }
         }
 
         let maybe_message = if let Some(deadline) = jitter.next_deadline() {
@@ -451,14 +840,53 @@ pub(crate) async fn audio_output(
                     let sequence_floor = message.get_u32();
                     debug!("audio_output received keep alive");
                     jitter.reset_after_keepalive(sequence_floor);
+                } else if redundancy.negotiated
+                    && len == REDUNDANCY_PACKET_SIZE
+                    && message[0] == REDUNDANCY_TAG
+                {
+                    debug!(
+                        active = message[1] != 0,
+                        "audio_output received redundancy request"
+                    );
+                    redundancy.active.store(message[1] != 0, Relaxed);
                 } else if len < HEADER_SIZE {
                     warn!("audio_output received unexpected message len={len}");
                 } else {
                     let sequence_number = message.get_u32();
-                    let inserted = jitter.insert_audio(sequence_number, message, Instant::now());
-                    if !inserted {
-                        loss.fetch_add(FRAME_SIZE, Relaxed);
+                    counters.received.fetch_add(1, Relaxed);
+                    counters
+                        .expected
+                        .fetch_add(gaps.expected(sequence_number), Relaxed);
+
+                    let (payload, previous) = if redundancy.negotiated {
+                        let Some(split) = split_redundant(message) else {
+                            warn!("audio_output received malformed redundant packet len={len}");
+                            continue;
+                        };
+                        split
+                    } else {
+                        (message, Bytes::new())
+                    };
+
+                    // a frame rebuilt earlier may still arrive late, it is not lost
+                    if !jitter.contains(sequence_number) {
+                        let inserted =
+                            jitter.insert_audio(sequence_number, payload, Instant::now());
+                        if !inserted {
+                            loss.fetch_add(FRAME_SIZE, Relaxed);
+                        }
                     }
+
+                    if !previous.is_empty()
+                        && jitter.recover(sequence_number.wrapping_sub(1), previous)
+                    {
+                        counters.lost.fetch_add(1, Relaxed);
+                        monitor.lost();
+                    }
+                    jitter_delay.store(jitter.delay().as_millis() as usize, Relaxed);
+                    counters
+                        .jitter
+                        .store(jitter.jitter().as_secs_f32() * 1_000.0, Relaxed);
                 }
             }
             Err(error) => {
@@ -478,14 +906,17 @@ fn prepare_packet(
     payload: &[u8],
     sequence_number: u32,
     is_keep_alive: bool,
+    framing: Framing,
 ) -> PooledBytes {
     let buf = buffer.inner_mut();
     buf.clear();
 
-    let total_size = if is_keep_alive {
-        KEEP_ALIVE_PACKET_SIZE
-    } else {
-        HEADER_SIZE + payload.len()
+    let total_size = match framing {
+        _ if is_keep_alive => KEEP_ALIVE_PACKET_SIZE,
+        Framing::Plain => HEADER_SIZE + payload.len(),
+        Framing::Redundant(previous) => {
+            HEADER_SIZE + REDUNDANCY_HEADER_SIZE + payload.len() + previous.len()
+        }
     };
 
     if buf.capacity() < total_size {
@@ -501,13 +932,36 @@ fn prepare_packet(
     } else {
         // Write sequence number. bytes::BufMut::put_u32 uses big-endian/network order.
         buf.put_u32(sequence_number);
-        buf.extend_from_slice(payload);
+
+        if let Framing::Redundant(previous) = framing {
+            buf.put_u16(payload.len() as u16);
+            buf.extend_from_slice(payload);
+            if total_size <= MAX_REDUNDANT_PACKET_SIZE {
+                buf.extend_from_slice(previous);
+            }
+        } else {
+            buf.extend_from_slice(payload);
+        }
     }
 
     // Convert to PooledBytes for automatic buffer recovery via Drop
     buffer.freeze()
 }
 
+/// Splits the body of a redundant packet into its payload and the payload of
+/// the previous sequence number, which is empty when it was not included.
+fn split_redundant(mut message: Bytes) -> Option<(Bytes, Bytes)> {
+    if message.len() < REDUNDANCY_HEADER_SIZE {
+        return None;
+    }
+    let len = message.get_u16() as usize;
+    if len > message.len() {
+        return None;
+    }
+    let payload = message.split_to(len);
+    Some((payload, message))
+}
+
 fn is_keep_alive_payload(payload: &[u8]) -> bool {
     payload == [KEEP_ALIVE_TAG]
 }
@@ -549,4 +1003,286 @@ mod tests {
 
         assert!(jitter.insert_audio(81, Bytes::from_static(b"fresh call"), Instant::now()));
     }
+
+    #[test]
+    fn missing_frame_is_reported_between_buffered_packets() {
+        let mut jitter = AudioJitterBuffer::new(48_000);
+        let now = Instant::now();
+
+        assert!(jitter.insert_audio(10, Bytes::from_static(b"first"), now));
+        assert!(jitter.insert_audio(12, Bytes::from_static(b"third"), now));
+
+        let later = now + jitter.frame_duration(JITTER_LATENCY_FRAMES + 3);
+        assert_eq!(
+            jitter.pop_due(later),
+            Some(Some(Bytes::from_static(b"first")))
+        );
+        // the gap is surfaced so the output processor can conceal it
+        assert_eq!(jitter.pop_due(later), Some(None));
+        assert_eq!(
+            jitter.pop_due(later),
+            Some(Some(Bytes::from_static(b"third")))
+        );
+        assert_eq!(jitter.pop_due(later), None);
+    }
+
+    #[test]
+    fn packet_after_a_missing_one_is_released_early() {
+        let mut jitter = AudioJitterBuffer::new(48_000);
+        let now = Instant::now();
+
+        jitter.insert_audio(10, Bytes::from_static(b"first"), now);
+        jitter.insert_audio(12, Bytes::from_static(b"third"), now);
+        jitter.insert_audio(13, Bytes::from_static(b"fourth"), now);
+
+        // only the missing frame is due, its successor comes along for FEC
+        let missing_due = now + jitter.delay() + jitter.frame_duration(1);
+        assert_eq!(
+            jitter.pop_due(missing_due),
+            Some(Some(Bytes::from_static(b"first")))
+        );
+        assert_eq!(jitter.pop_due(missing_due), Some(None));
+        assert_eq!(
+            jitter.pop_due(missing_due),
+            Some(Some(Bytes::from_static(b"third")))
+        );
+        // the rest of the talkspurt keeps its schedule
+        assert_eq!(jitter.pop_due(missing_due), None);
+    }
+
+    /// Inserts `count` packets from `start`, the n-th arriving `offset(n)` after `now`.
+    fn arrive(
+        jitter: &mut AudioJitterBuffer,
+        start: u32,
+        count: u32,
+        now: Instant,
+        offset: impl Fn(u32) -> Duration,
+    ) {
+        for n in 0..count {
+            jitter.insert_audio(start + n, Bytes::from_static(b"audio"), now + offset(n));
+        }
+    }
+
+    #[test]
+    fn steady_arrivals_shrink_the_delay_to_the_lower_bound() {
+        let mut jitter = AudioJitterBuffer::new(48_000);
+        let now = Instant::now();
+        let frame = jitter.frame_duration(1);
+        assert_eq!(jitter.delay(), jitter.frame_duration(JITTER_LATENCY_FRAMES));
+
+        arrive(&mut jitter, 0, 20, now, |n| frame * n);
+        // the delay only changes when the next talkspurt starts
+        assert_eq!(jitter.delay(), jitter.frame_duration(JITTER_LATENCY_FRAMES));
+
+        jitter.reset_after_keepalive(20);
+        assert!(jitter.insert_audio(20, Bytes::from_static(b"audio"), now + frame * 100));
+        assert_eq!(jitter.delay(), Duration::from_millis(20));
+    }
+
+    #[test]
+    fn jittery_arrivals_grow_the_delay_up_to_the_upper_bound() {
+        let bounds = JitterBounds::from_millis(20, 100, 48_000);
+        let mut wide = AudioJitterBuffer::new(48_000);
+        let mut narrow = AudioJitterBuffer::new(48_000).with_bounds(bounds);
+        let now = Instant::now();
+        let frame = wide.frame_duration(1);
+        // every other packet is held up by five frames
+        let offset = |n: u32| frame * n + frame * 5 * (n % 2);
+
+        arrive(&mut wide, 0, 50, now, offset);
+        arrive(&mut narrow, 0, 50, now, offset);
+        wide.reset_after_keepalive(50);
+        narrow.reset_after_keepalive(50);
+        wide.insert_audio(50, Bytes::from_static(b"audio"), now + frame * 100);
+        narrow.insert_audio(50, Bytes::from_static(b"audio"), now + frame * 100);
+
+        assert!(
+            wide.delay() > Duration::from_millis(100),
+            "{:?}",
+            wide.delay()
+        );
+        assert!(wide.delay() <= Duration::from_millis(DEFAULT_JITTER_MAX_MS as u64));
+        assert_eq!(narrow.delay(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn late_packet_delays_the_rest_of_the_talkspurt() {
+        let mut jitter = AudioJitterBuffer::new(48_000);
+        let now = Instant::now();
+
+        assert!(jitter.insert_audio(10, Bytes::from_static(b"first"), now));
+        assert!(jitter.insert_audio(12, Bytes::from_static(b"third"), now));
+        let third_deadline = jitter.deadline_for(12).unwrap();
+
+        let due = now + jitter.frame_duration(JITTER_LATENCY_FRAMES + 1);
+        assert_eq!(
+            jitter.pop_due(due),
+            Some(Some(Bytes::from_static(b"first")))
+        );
+        assert_eq!(jitter.pop_due(due), Some(None));
+
+        // the skipped packet still counts as lost but buys one more frame
+        assert!(!jitter.insert_audio(11, Bytes::from_static(b"second"), due));
+        assert_eq!(
+            jitter.delay(),
+            jitter.frame_duration(JITTER_LATENCY_FRAMES + 1)
+        );
+        assert_eq!(
+            jitter.deadline_for(12),
+            Some(third_deadline + jitter.frame_duration(1))
+        );
+    }
+
+    #[test]
+    fn redundant_copy_rebuilds_only_missing_frames() {
+        let mut jitter = AudioJitterBuffer::new(48_000);
+        let now = Instant::now();
+
+        // nothing to rebuild between talkspurts
+        assert!(!jitter.recover(9, Bytes::from_static(b"zeroth")));
+
+        assert!(jitter.insert_audio(10, Bytes::from_static(b"first"), now));
+        assert!(jitter.insert_audio(12, Bytes::from_static(b"third"), now));
+        assert!(!jitter.recover(9, Bytes::from_static(b"zeroth")));
+        assert!(!jitter.recover(10, Bytes::from_static(b"copy")));
+        assert!(jitter.recover(11, Bytes::from_static(b"second")));
+        assert!(jitter.contains(11));
+
+        let later = now + jitter.frame_duration(JITTER_LATENCY_FRAMES + 3);
+        for payload in [&b"first"[..], b"second", b"third"] {
+            assert_eq!(
+                jitter.pop_due(later),
+                Some(Some(Bytes::from_static(payload)))
+            );
+        }
+        assert_eq!(jitter.pop_due(later), None);
+    }
+
+    fn packet(payload: &[u8], framing: Framing) -> Bytes {
+        let pool = Arc::new(BufferPool::new(1, PACKET_BUFFER_CAPACITY));
+        let packet = prepare_packet(BufferPool::acquire(&pool), payload, 7, false, framing);
+        (*packet).clone()
+    }
+
+    #[test]
+    fn redundant_packets_carry_the_previous_payload() {
+        let mut plain = packet(b"audio", Framing::Plain);
+        assert_eq!(plain.get_u32(), 7);
+        assert_eq!(plain, Bytes::from_static(b"audio"));
+
+        let mut redundant = packet(b"audio", Framing::Redundant(b"previous"));
+        assert_eq!(redundant.get_u32(), 7);
+        assert_eq!(
+            split_redundant(redundant),
+            Some((
+                Bytes::from_static(b"audio"),
+                Bytes::from_static(b"previous")
+            ))
+        );
+
+        let mut first = packet(b"audio", Framing::Redundant(&[]));
+        first.advance(HEADER_SIZE);
+        assert_eq!(
+            split_redundant(first),
+            Some((Bytes::from_static(b"audio"), Bytes::new()))
+        );
+
+        assert_eq!(split_redundant(Bytes::from_static(&[0])), None);
+        assert_eq!(split_redundant(Bytes::from_static(&[0, 2, 1])), None);
+    }
+
+    #[test]
+    fn oversized_frames_are_sent_without_redundancy() {
+        let frame = vec![1; NETWORK_FRAME];
+
+        let mut large = packet(&frame, Framing::Redundant(&frame));
+        large.advance(HEADER_SIZE);
+        let (payload, previous) = split_redundant(large).unwrap();
+        assert_eq!(payload.as_ref(), frame.as_slice());
+        assert!(previous.is_empty());
+    }
+
+    #[test]
+    fn loss_monitor_switches_with_hysteresis() {
+        let mut monitor = LossMonitor::default();
+        let window = |monitor: &mut LossMonitor, lost: usize| {
+            let mut request = None;
+            for frame in 0..REDUNDANCY_WINDOW_FRAMES {
+                if frame < lost {
+                    monitor.lost();
+                }
+                request = monitor.played();
+            }
+            request
+        };
+
+        assert_eq!(window(&mut monitor, 2), Some(false));
+        assert_eq!(window(&mut monitor, 10), Some(true));
+        // between the thresholds the request is kept
+        assert_eq!(window(&mut monitor, 5), Some(true));
+        assert_eq!(window(&mut monitor, 1), Some(false));
+        assert_eq!(window(&mut monitor, 5), Some(false));
+    }
+
+    #[test]
+    fn receive_counters_report_loss_once_per_window() {
+        let counters = ReceiveCounters::default();
+        assert_eq!(counters.take_loss(), None);
+
+        counters.played.fetch_add(40, Relaxed);
+        counters.lost.fetch_add(2, Relaxed);
+        assert_eq!(counters.take_loss(), Some(0.05));
+        assert_eq!(counters.take_loss(), None);
+    }
+
+    #[test]
+    fn sequence_gaps_count_missing_datagrams_but_not_reordering() {
+        let mut gaps = SequenceGaps::default();
+
+        // 10..=15 spans six sequence numbers, late and duplicate ones add none
+        let expected: usize = [10, 11, 14, 12, 13, 13, 15]
+            .into_iter()
+            .map(|seq| gaps.expected(seq))
+            .sum();
+        assert_eq!(expected, 6);
+        // a restarted stream is not loss
+        assert_eq!(gaps.expected(16 + MAX_BUFFERED_FRAMES), 1);
+
+        let mut gaps = SequenceGaps::default();
+        gaps.expected(u32::MAX);
+        assert_eq!(gaps.expected(1), 2);
+    }
+
+    #[test]
+    fn packet_loss_is_taken_per_interval() {
+        let counters = ReceiveCounters::default();
+        assert_eq!(counters.take_packet_loss(), 0.0);
+
+        counters.received.fetch_add(9, Relaxed);
+        counters.expected.fetch_add(10, Relaxed);
+        assert_eq!(counters.take_packet_loss(), 0.1);
+
+        // duplicates never report negative loss
+        counters.received.fetch_add(3, Relaxed);
+        counters.expected.fetch_add(2, Relaxed);
+        assert_eq!(counters.take_packet_loss(), 0.0);
+    }
+
+    #[test]
+    fn bounds_round_up_to_whole_frames_and_stay_ordered() {
+        assert_eq!(
+            JitterBounds::from_millis(25, 10, 48_000),
+            JitterBounds {
+                min_frames: 3,
+                max_frames: 3
+            }
+        );
+        assert_eq!(
+            JitterBounds::from_millis(0, 10_000, 48_000),
+            JitterBounds {
+                min_frames: 1,
+                max_frames: MAX_BUFFERED_FRAMES
+            }
+        );
+    }
 }
@@ -2,24 +2,28 @@
 //! negotiates incoming or outgoing calls, then transitions into direct [`call_handshake`]
 //! or room [`room_handshake`] handling.
 
+use crate::internal::bitrate::{BitrateController, FEEDBACK_INTERVAL};
 use crate::internal::callbacks::CoreCallbacks;
 use crate::internal::connections::{
-    ConstConnection, DynamicConnection, SharedConnections, audio_input, audio_output,
+    ConstConnection, DynamicConnection, ReceiveCounters, Redundancy, SharedConnections,
+    audio_input, audio_output,
 };
 use crate::internal::error::{
     AudioStreamError, CALL_END_ALREADY_ACTIVE, CALL_END_GENERIC, CallEndMessage, Error, ErrorKind,
     peer_busy_message, peer_goodbye_reason_message, peer_no_response_message,
     peer_not_accepted_message, peer_unexpected_message,
 };
-use crate::internal::helpers::OutputHelper;
+use crate::internal::helpers::{
+    FailoverStreams, InputHelper, OutputHelper, StreamDevices, StreamErrorReporter,
+};
 use crate::internal::helpers::{RoomTaskOutcome, join_room_task_bounded};
 use crate::internal::messages::{
     AudioHeader, GoodbyeReason, ProtocolMessage, RoomControl, RoomJoinAdmission, RoomMessage,
     StartScreenshare,
 };
 use crate::internal::state::{
-    CallSlot, CallSlotAcquireResult, CallSlotSnapshot, CallSlotState, CoreState, RuntimeSnapshot,
-    StatisticsCollectorState, room_hash_for_peers,
+    CallSlot, CallSlotAcquireResult, CallSlotSnapshot, CallSlotState, CoreState,
+    PeerStatisticsState, RuntimeSnapshot, StatisticsCollectorState, room_hash_for_peers,
 };
 use crate::internal::utils::{JoinHandle, spawn_task};
 #[cfg(target_os = "ios")]
@@ -47,7 +51,9 @@ use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Duration;
 #[cfg(target_family = "wasm")]
 use telepathy_audio::WebAudioWrapper;
+use telepathy_audio::codec::CodecId;
 use telepathy_audio::devices::AudioHost;
+use telepathy_audio::io::{AudioMixerHandle, AudioOutputHandle, MixerSourceHandle};
 use tokio::select;
 #[cfg(target_family = "wasm")]
 use tokio::sync::Mutex;
@@ -334,6 +340,33 @@ where
         ))
     }
 
+    /// Forwards device hot-plug and default-device changes to the frontend
+    /// until the manager stops.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn watch_devices(&self) -> Option<JoinHandle<()>> {
+        let mut events = match self.host.watch_devices() {
+            Ok(events) => events,
+            Err(error) => {
+                warn!(event = "device_watch_failed", error = %error);
+                return None;
+            }
+        };
+
+        let stop = self.core_state.stop_manager.clone();
+        let callbacks = Arc::clone(&self.callbacks);
+        Some(spawn_task(async move {
+            loop {
+                select! {
+                    _ = stop.cancelled() => break,
+                    event = events.recv() => match event {
+                        Some(event) => callbacks.device_event(event.into()).await,
+                        None => break,
+                    },
+                }
+            }
+        }))
+    }
+
     /// Builds the iroh endpoint, handles session start requests and incoming connections.
     #[instrument(
         name = "manager.run",
@@ -1338,7 +1371,11 @@ where
     ) -> Result<HelloResponse> {
         let is_in_room = args.room_hash.is_some();
         match message {
-            ProtocolMessage::HelloAck { audio_header } => {
+            ProtocolMessage::HelloAck {
+                audio_header,
+                audio_extension,
+            } => {
+                let audio_header = audio_header.with_extension(audio_extension);
                 if !audio_header.is_valid() {
                     warn!(event = "invalid_audio_header_rejected");
                     write_message(io.send, &ProtocolMessage::Reject).await?;
@@ -1410,7 +1447,12 @@ where
             ProtocolMessage::KeepAlive => Ok(HelloResponse::Continue),
             // Simultaneous dial: both sides sent Hello before receiving the other's. The lower peer-id
             // yields and accepts the incoming Hello as if it were the callee.
-            ProtocolMessage::Hello { audio_header, .. } => {
+            ProtocolMessage::Hello {
+                audio_header,
+                audio_extension,
+                ..
+            } => {
+                let audio_header = audio_header.with_extension(audio_extension);
                 // We are the lower peer -> we lose the tiebreaker -> accept their Hello here
                 // (mirrors negotiate_incoming_call's HelloAck path). Otherwise, we win and keep
                 // waiting for their HelloAck.
@@ -1424,9 +1466,7 @@ where
                         call_state.remote_configuration = audio_header;
                         write_message(
                             io.send,
-                            &ProtocolMessage::HelloAck {
-                                audio_header: call_state.local_configuration.clone(),
-                            },
+                            &ProtocolMessage::hello_ack(&call_state.local_configuration),
                         )
                         .await?;
 
@@ -1672,9 +1712,7 @@ where
                         }
                         write_message(
                             io.send,
-                            &ProtocolMessage::HelloAck {
-                                audio_header: call_state.local_configuration.clone(),
-                            },
+                            &ProtocolMessage::hello_ack(&call_state.local_configuration),
                         )
                         .await?;
 
@@ -1816,11 +1854,11 @@ where
             };
         write_message(
             io.send,
-            &ProtocolMessage::Hello {
-                ringtone: other_ringtone,
-                audio_header: call_state.local_configuration.clone(),
-                room_hash: args.room_hash,
-            },
+            &ProtocolMessage::hello(
+                other_ringtone,
+                &call_state.local_configuration,
+                args.room_hash,
+            ),
         )
         .await?;
         info!(event = "outgoing_negotiation_waiting_hello_ack", peer.id = %peer);
@@ -2005,7 +2043,13 @@ where
                 let peer_room_hash;
 
                 match result? {
-                    ProtocolMessage::Hello { ringtone, audio_header, room_hash } => {
+                    ProtocolMessage::Hello {
+                        ringtone,
+                        audio_header,
+                        room_hash,
+                        audio_extension,
+                    } => {
+                        let audio_header = audio_header.with_extension(audio_extension);
                         if !audio_header.is_valid() {
                             warn!(event = "invalid_audio_header_rejected");
                             write_message(io.send, &ProtocolMessage::Reject).await?;
@@ -2225,7 +2269,7 @@ where
         skip_all,
         fields(
             peer.id = %call_state.peer,
-            codec.enabled = call_state.codec_config().0,
+            codec.id = ?call_state.codec_config().0,
             sample_rate = call_state.remote_configuration.sample_rate
         )
     )]
@@ -2242,18 +2286,34 @@ where
 
         // shared statistics values
         let statistics_state = StatisticsCollectorState::new(optional.as_ref().map(|o| o.state));
-        // reference for use in networking tasks
+        // references for use in networking tasks
         let loss = statistics_state.loss.clone();
+        let jitter_delay = statistics_state.jitter_delay.clone();
+        let counters = statistics_state.receive.clone();
 
         // the two clients agree on these codec options
         let codec_config = call_state.codec_config();
+        let channels = call_state.channels();
+        // SEA calls adapt their residual bits to the feedback of the receiver,
+        // older clients end the call on an unknown message so both must read it
+        let bitrate =
+            (optional.is_some() && codec_config.0 == CodecId::Sea && call_state.audio_feedback())
+                .then(|| BitrateController::new(codec_config.2));
 
         let (stream_error_sender, mut stream_error_receiver) = unbounded_channel();
+        // failed streams move to other devices with a fresh error callback
+        let reporter = StreamErrorReporter::new(stream_error_sender.clone(), end_call.clone());
+        let mut devices = StreamDevices {
+            input: self.core_state.input_device.lock().await.clone(),
+            output: self.core_state.output_device.lock().await.clone(),
+        };
 
         // Setup input (stream is managed internally)
         let mut input_helper = self
             .setup_input(
                 codec_config,
+                bitrate.as_ref().map(BitrateController::residual_bits),
+                channels,
                 &statistics_state,
                 end_call,
                 stream_error_sender.clone(),
@@ -2266,6 +2326,7 @@ where
                 call_state.peer,
                 call_state.remote_configuration.sample_rate as f64,
                 codec_config.0,
+                channels,
                 &statistics_state,
                 end_call.clone(),
                 stream_error_sender,
@@ -2281,22 +2342,43 @@ where
         ));
 
         let call_result = if let Some(o) = optional {
+            let redundancy = Redundancy::new(call_state.redundancy());
             let input_handle = spawn_task(audio_input(
                 input_helper.receiver(),
-                ConstConnection::new(o.connection.clone(), self.core_state.audio_sequence.clone()),
+                ConstConnection::new(
+                    o.connection.clone(),
+                    self.core_state.audio_sequence.clone(),
+                    redundancy.clone(),
+                ),
                 stop_io.clone(),
             ));
 
+            let sample_rate = call_state.remote_configuration.sample_rate;
             let output_handle = spawn_task(audio_output(
                 output_helper.sender(),
                 o.connection.clone(),
                 stop_io.clone(),
                 loss,
-                call_state.remote_configuration.sample_rate,
+                jitter_delay,
+                sample_rate,
+                self.core_state.jitter_bounds(sample_rate),
+                redundancy,
+                counters.clone(),
             ));
 
-            let controller_future =
-                self.call_controller(o, call_state.peer, end_call, &mut stream_error_receiver);
+            let controller_future = self.call_controller(
+                o,
+                call_state.peer,
+                end_call,
+                CallFeedback { bitrate, counters },
+                &mut stream_error_receiver,
+                FailoverStreams {
+                    input: &mut input_helper,
+                    output: Some(&mut output_helper),
+                    devices: &mut devices,
+                    reporter: &reporter,
+                },
+            );
 
             info!(event = "call_controller_starting");
 
@@ -2374,10 +2456,14 @@ where
         o: OptionalCallArgs<'_>,
         peer: PublicKey,
         end_call: &Arc<Notify>,
+        mut feedback: CallFeedback,
         stream_errors: &mut UnboundedReceiver<AudioStreamError>,
+        #[cfg_attr(target_family = "wasm", allow(unused_mut, unused_variables))]
+        mut streams: CallStreams<'_, H>,
     ) -> Result<CallControllerOutcome> {
         let identity = self.peer_id().await;
         let mut stream_errors_open = true;
+        let mut feedback_interval = interval(FEEDBACK_INTERVAL);
 
         CONNECTED.store(true, Relaxed);
         // Race Connected delivery against the two authoritative teardown signals
@@ -2408,6 +2494,15 @@ where
 
                 error = stream_errors.recv(), if stream_errors_open => {
                     if let Some(error) = error {
+                        // keep the call alive on another device when one works
+                        #[cfg(not(target_family = "wasm"))]
+                        let error = match self
+                            .recover_streams(error, stream_errors, &mut streams)
+                            .await
+                        {
+                            Ok(()) => continue,
+                            Err(error) => error,
+                        };
                         let message = CallEndMessage::from_stream_error(&error).into_string();
                         _ = write_message(
                             o.control_send,
@@ -2420,6 +2515,11 @@ where
                     }
                     stream_errors_open = false;
                 }
+                // moves the streams to a newly set device
+                _ = self.core_state.device_changed.notified() => {
+                    #[cfg(not(target_family = "wasm"))]
+                    self.apply_device_selection(&mut streams).await;
+                }
                 // ends the call
                 _ = end_call.notified() => {
                     write_message(o.control_send, &ProtocolMessage::goodbye()).await?;
@@ -2440,6 +2540,14 @@ where
                         }.in_current_span());
                     }
                 }
+                // reports the received audio so the peer can adapt its bitrate
+                _ = feedback_interval.tick(), if feedback.bitrate.is_some() => {
+                    if let Some(loss) = feedback.counters.take_loss() {
+                        let rtt_ms = o.state.network.latency.load(Relaxed) as u32;
+                        let message = ProtocolMessage::AudioFeedback { loss, rtt_ms };
+                        write_message(o.control_send, &message).await?;
+                    }
+                }
                 // receives and handles messages from the callee
                 result = read_message(o.control_recv) => {
                     let message: ProtocolMessage = result?;
@@ -2477,6 +2585,13 @@ where
                             }
 
                         }
+                        ProtocolMessage::AudioFeedback { loss, rtt_ms } => {
+                            if let Some(bitrate) = feedback.bitrate.as_mut()
+                                && let Some(residual_bits) = bitrate.update(loss, rtt_ms)
+                            {
+                                info!(event = "call_bitrate_adapted", residual_bits, loss, rtt_ms);
+                            }
+                        }
                         _ => error!(event = "call_controller_unexpected_message", ?message),
                     }
                 },
@@ -2687,6 +2802,7 @@ where
                         room_generation,
                         input_handle: None,
                         connections: HashMap::new(),
+                        mixer: None,
                         statistics_handle: None,
                         terminal_error: None,
                         outcome: RoomControllerOutcome::Silent,
@@ -2703,10 +2819,20 @@ where
         // shared statistics
         let statistics_state = StatisticsCollectorState::new(None);
         // tracks connection state for peers keyed by transport stable id
-        let mut connections: HashMap<usize, RoomConnection<H::OutputStream>> = HashMap::new();
+        let mut connections: HashMap<usize, RoomConnection> = HashMap::new();
+        // plays every peer through one output stream, opened at the first join
+        let mut mixer: Option<AudioMixerHandle<H::OutputStream>> = None;
         let mut peer_connections: HashMap<PublicKey, usize> = HashMap::new();
         let (stream_error_sender, mut stream_error_receiver) = unbounded_channel();
         let mut stream_errors_open = true;
+        // failed streams move to other devices with a fresh error callback
+        #[cfg(not(target_family = "wasm"))]
+        let reporter = StreamErrorReporter::new(stream_error_sender.clone(), end_call.clone());
+        #[cfg(not(target_family = "wasm"))]
+        let mut devices = StreamDevices {
+            input: self.core_state.input_device.lock().await.clone(),
+            output: self.core_state.output_device.lock().await.clone(),
+        };
         let mut terminal_error = None;
         // Completion channel for per-peer output tasks. Each spawned
         // `audio_output` signals its `connection_id` here on return so the
@@ -2732,6 +2858,7 @@ where
                             room_generation,
                             input_handle: None,
                             connections: HashMap::new(),
+                            mixer: None,
                             statistics_handle: None,
                             terminal_error: None,
                             outcome: RoomControllerOutcome::Silent,
@@ -2750,6 +2877,7 @@ where
                             room_generation,
                             input_handle: None,
                             connections: HashMap::new(),
+                            mixer: None,
                             statistics_handle: None,
                             terminal_error: None,
                             outcome: RoomControllerOutcome::Silent,
@@ -2758,7 +2886,13 @@ where
                     .await;
             }
             result = self.setup_input(
-                (true, true, 5_f32), // hard coded room codec options
+                // hard coded room codec options, every member decodes the same stream
+                // so rooms stay on mono sea which all clients (including web) support
+                (CodecId::Sea, true, 5_f32),
+                // no bitrate controller, the one stream serves every member so it
+                // cannot follow the feedback of a single receiver
+                None,
+                1,
                 &statistics_state,
                 &end_call,
                 stream_error_sender.clone(),
@@ -2780,6 +2914,7 @@ where
                             room_generation,
                             input_handle: None,
                             connections: HashMap::new(),
+                            mixer: None,
                             statistics_handle: None,
                             terminal_error: Some(error),
                             outcome: RoomControllerOutcome::Notify { message },
@@ -2802,6 +2937,7 @@ where
                         room_generation,
                         input_handle: None,
                         connections: HashMap::new(),
+                        mixer: None,
                         statistics_handle: None,
                         terminal_error: None,
                         outcome: RoomControllerOutcome::Silent,
@@ -2824,6 +2960,7 @@ where
                         room_generation,
                         input_handle: None,
                         connections: HashMap::new(),
+                        mixer: None,
                         statistics_handle: None,
                         terminal_error: None,
                         outcome: RoomControllerOutcome::Silent,
@@ -2843,6 +2980,7 @@ where
                         room_generation,
                         input_handle: None,
                         connections: HashMap::new(),
+                        mixer: None,
                         statistics_handle: None,
                         terminal_error: Some(error.into_error_kind().into()),
                         outcome: RoomControllerOutcome::Notify { message },
@@ -2894,6 +3032,7 @@ where
                         room_generation,
                         input_handle: input_handle.take(),
                         connections,
+                        mixer,
                         statistics_handle: Some(statistics_handle),
                         terminal_error: None,
                         outcome: RoomControllerOutcome::Silent,
@@ -2923,6 +3062,7 @@ where
                         room_generation,
                         input_handle: input_handle.take(),
                         connections,
+                        mixer,
                         statistics_handle: Some(statistics_handle),
                         terminal_error: None,
                         outcome: RoomControllerOutcome::Silent,
@@ -2944,6 +3084,24 @@ where
                 }
                 error = stream_error_receiver.recv(), if stream_errors_open => {
                     if let Some(error) = error {
+                        // keep the room alive on another device when one works
+                        #[cfg(not(target_family = "wasm"))]
+                        let error = match self
+                            .recover_streams(
+                                error,
+                                &mut stream_error_receiver,
+                                &mut FailoverStreams {
+                                    input: &mut input_helper,
+                                    output: mixer.as_mut(),
+                                    devices: &mut devices,
+                                    reporter: &reporter,
+                                },
+                            )
+                            .await
+                        {
+                            Ok(()) => continue,
+                            Err(error) => error,
+                        };
                         let message = CallEndMessage::from_stream_error(&error).into_string();
                         let remote_reason = error.remote_reason();
                         // Each installed handshake owns the framed control writer, so terminal
@@ -2965,6 +3123,17 @@ where
                     }
                     stream_errors_open = false;
                 }
+                // moves the streams to a newly set device
+                _ = self.core_state.device_changed.notified() => {
+                    #[cfg(not(target_family = "wasm"))]
+                    self.apply_device_selection(&mut FailoverStreams {
+                        input: &mut input_helper,
+                        output: mixer.as_mut(),
+                        devices: &mut devices,
+                        reporter: &reporter,
+                    })
+                    .await;
+                }
                 message = receiver.recv() => {
                     match message {
                         Some(RoomMessage::Join {
@@ -2974,17 +3143,20 @@ where
                             terminal_sender,
                             admission_sender,
                         }) => {
-                            if let Some(session) = self.session_states.read().await.get(&state.peer) {
+                            let network = if let Some(session) =
+                                self.session_states.read().await.get(&state.peer)
+                            {
                                 if session.id != session_id {
                                     warn!(event = "room_join_stale_session", peer.id = %state.peer);
                                     let _ = admission_sender.send(RoomJoinAdmission::Aborted);
                                     continue;
                                 }
+                                session.network.clone()
                             } else {
                                 warn!(event = "room_join_missing_session", peer.id = %state.peer);
                                 let _ = admission_sender.send(RoomJoinAdmission::Aborted);
                                 continue;
-                            }
+                            };
 
                             let connection_id = connection.stable_id();
                             info!(
@@ -3003,11 +3175,20 @@ where
                                 continue;
                             }
 
+                            let peer_statistics = PeerStatisticsState {
+                                network,
+                                receive: Default::default(),
+                                output_rms: Default::default(),
+                                jitter_delay: Default::default(),
+                            };
                             let setup_output_result = select! {
-                                result = self.setup_output(
+                                result = self.setup_mixer_source(
+                                    &mut mixer,
                                     state.peer,
                                     state.remote_configuration.sample_rate as f64,
-                                    true,
+                                    // rooms never negotiate a codec, see the room input
+                                    CodecId::Sea,
+                                    &peer_statistics.output_rms,
                                     &statistics_state,
                                     end_call.clone(),
                                     stream_error_sender.clone(),
@@ -3068,7 +3249,8 @@ where
                                     break;
                                 }
                             }
-                            connection_sender.push(connection.clone());
+                            let redundancy = Redundancy::new(state.redundancy());
+                            connection_sender.push(connection.clone(), redundancy.clone());
                             // begin sending. The wrapper reports completion to
                             // `output_completion_rx` so the controller can
                             // retire the entry instead of leaving the handle
@@ -3081,14 +3263,23 @@ where
                             let output_stop_io = stop_io.child_token();
                             let output_cancel = output_stop_io.clone();
                             let output_loss = statistics_state.loss.clone();
+                            // every peer has its own jitter buffer, the collector reports the deepest
+                            let output_jitter_delay = peer_statistics.jitter_delay.clone();
+                            let output_counters = peer_statistics.receive.clone();
                             let output_sample_rate = state.remote_configuration.sample_rate;
+                            let output_jitter_bounds =
+                                self.core_state.jitter_bounds(output_sample_rate);
                             let handle = spawn_task(async move {
                                 let result = audio_output(
                                     output_sender,
                                     output_connection,
                                     output_stop_io,
                                     output_loss,
+                                    output_jitter_delay,
                                     output_sample_rate,
+                                    output_jitter_bounds,
+                                    redundancy,
+                                    output_counters,
                                 )
                                 .await;
                                 let _ = completion_tx.send((connection_id, result));
@@ -3114,6 +3305,7 @@ where
                                     peer_connections.insert(state.peer, previous_connection_id);
                                 } else {
                                     peer_connections.remove(&state.peer);
+                                    statistics_state.remove_peer(&state.peer);
                                 }
                                 if let Some(mut installed) = connections.remove(&connection_id) {
                                     connection_sender.remove(&installed.connection);
@@ -3138,6 +3330,7 @@ where
                                 }
                                 continue;
                             }
+                            statistics_state.insert_peer(state.peer, peer_statistics);
                             if let Some(old_connection_id) = previous_connection_id
                                 && old_connection_id != connection_id
                                 && let Some(old_connection) = connections.remove(&old_connection_id)
@@ -3205,6 +3398,7 @@ where
                                         break;
                                     }
                                     peer_connections.remove(&peer);
+                                    statistics_state.remove_peer(&peer);
                                     if let Some(connection) = connections.remove(&connection_id) {
                                         connection_sender.remove(&connection.connection);
                                         connection.cancel.cancel();
@@ -3263,6 +3457,7 @@ where
                         && let Some(connection) = connections.remove(&connection_id)
                     {
                         connection_sender.remove(&connection.connection);
+                        retire_peer_statistics(&peer_connections, &statistics_state, connection_id);
                         let mut handle = connection.handle;
                         match join_room_task_bounded(
                             &mut handle,
@@ -3316,6 +3511,7 @@ where
                 room_generation,
                 input_handle: input_handle.take(),
                 connections,
+                mixer,
                 statistics_handle: Some(statistics_handle),
                 terminal_error,
                 outcome,
@@ -3352,9 +3548,9 @@ where
     }
 }
 
-pub(crate) struct RoomConnection<O> {
+pub(crate) struct RoomConnection {
     pub(crate) connection: Connection,
-    _output: OutputHelper<O>,
+    _output: OutputHelper<MixerSourceHandle>,
     pub(crate) handle: JoinHandle<Result<()>>,
     cancel: CancellationToken,
     terminal_sender: UnboundedSender<RoomControl>,
@@ -3375,7 +3571,8 @@ pub(crate) struct RoomControllerCleanup<O> {
     pub(crate) room_owner: CallSlotSnapshot,
     pub(crate) room_generation: u64,
     pub(crate) input_handle: Option<JoinHandle<Result<()>>>,
-    pub(crate) connections: HashMap<usize, RoomConnection<O>>,
+    pub(crate) connections: HashMap<usize, RoomConnection>,
+    pub(crate) mixer: Option<AudioMixerHandle<O>>,
     pub(crate) statistics_handle: Option<JoinHandle<()>>,
     pub(crate) terminal_error: Option<Error>,
     pub(crate) outcome: RoomControllerOutcome,
@@ -3413,6 +3610,22 @@ enum CallControllerOutcome {
     Notify { message: String, remote: bool },
 }
 
+/// The streams of a direct call
+type CallStreams<'a, H> = FailoverStreams<
+    'a,
+    InputHelper<<H as AudioHost>::InputStream>,
+    OutputHelper<AudioOutputHandle<<H as AudioHost>::OutputStream>>,
+>;
+
+/// Audio feedback exchanged by the controller of a call
+struct CallFeedback {
+    /// Steers the local encoder, `None` unless the call uses SEA and both
+    /// clients read feedback
+    bitrate: Option<BitrateController>,
+    /// Frames played and lost by the local receiver
+    counters: Arc<ReceiveCounters>,
+}
+
 pub(crate) struct OptionalCallArgs<'a> {
     connection: &'a Connection,
     control_send: &'a mut FramedWrite<SendStream, LengthDelimitedCodec>,
@@ -3731,6 +3944,21 @@ fn abort_pending_room_joins(receiver: &mut Receiver<RoomMessage>) {
     }
 }
 
+/// Stops reporting statistics for the peer whose active room connection
+/// completed. The peer keeps its connection id so its leave is still reported,
+/// connections replaced by a newer one do not own the peer's statistics.
+fn retire_peer_statistics(
+    peer_connections: &HashMap<PublicKey, usize>,
+    statistics_state: &StatisticsCollectorState,
+    connection_id: usize,
+) -> Option<PublicKey> {
+    let peer = peer_connections
+        .iter()
+        .find_map(|(peer, id)| (*id == connection_id).then_some(*peer))?;
+    statistics_state.remove_peer(&peer);
+    Some(peer)
+}
+
 struct PendingRoomJoinGuard(Receiver<RoomMessage>);
 
 impl PendingRoomJoinGuard {
@@ -4701,22 +4929,28 @@ fn ringtone_is_within_limit(ringtone: &Option<Vec<u8>>) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::internal::messages::AudioHeader;
+    use crate::internal::messages::{AudioExtension, AudioHeader};
     use iroh::SecretKey;
     use speedy::{Readable, Writable};
 
     #[test]
     fn oversized_hello_ringtone_is_rejected_before_prompting() {
-        let message = ProtocolMessage::Hello {
-            ringtone: Some(vec![0; MAX_RINGTONE_LENGTH + 1]),
-            audio_header: AudioHeader {
-                sample_rate: 48_000,
-                codec_enabled: true,
-                vbr: false,
-                residual_bits: 4.0,
-            },
-            room_hash: None,
-        };
+        let message = ProtocolMessage::hello(
+            Some(vec![0; MAX_RINGTONE_LENGTH + 1]),
+            &AudioHeader::new(
+                48_000,
+                false,
+                4.0,
+                AudioExtension {
+                    codec_id: CodecId::Sea.into(),
+                    codecs: vec![CodecId::Raw.into(), CodecId::Sea.into()],
+                    channels: 1,
+                    redundancy: true,
+                    audio_feedback: true,
+                },
+            ),
+            None,
+        );
         let encoded = message.write_to_vec().unwrap();
         let ProtocolMessage::Hello { ringtone, .. } =
             ProtocolMessage::read_from_buffer(&encoded).unwrap()
@@ -5247,6 +5481,35 @@ mod tests {
         assert!(!predecessor.can_restore_room_predecessor());
     }
 
+    #[test]
+    fn completed_room_connections_retire_their_peer_statistics() {
+        let statistics_state = StatisticsCollectorState::new(None);
+        let peer = SecretKey::generate().public();
+        statistics_state.insert_peer(
+            peer,
+            PeerStatisticsState {
+                network: Default::default(),
+                receive: Default::default(),
+                output_rms: Default::default(),
+                jitter_delay: Default::default(),
+            },
+        );
+        let peer_connections = HashMap::from([(peer, 2)]);
+
+        // a replaced connection completing leaves the current statistics
+        assert_eq!(
+            retire_peer_statistics(&peer_connections, &statistics_state, 1),
+            None
+        );
+        assert!(statistics_state.peers.lock().unwrap().contains_key(&peer));
+
+        assert_eq!(
+            retire_peer_statistics(&peer_connections, &statistics_state, 2),
+            Some(peer)
+        );
+        assert!(statistics_state.peers.lock().unwrap().is_empty());
+    }
+
     fn parked_prompt() -> (JoinHandle<bool>, Arc<Notify>) {
         let cancel = Arc::new(Notify::new());
         let waiter = Arc::clone(&cancel);
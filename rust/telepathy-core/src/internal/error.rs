@@ -68,6 +68,8 @@ pub enum ErrorKind {
     AttachmentsTooLarge,
     MpscSend,
     InvalidModel,
+    #[cfg(target_family = "wasm")]
+    RecordingUnsupported,
 }
 
 impl From<std::io::Error> for Error {
@@ -262,6 +264,9 @@ impl Display for Error {
                 ErrorKind::AttachmentsTooLarge => "Attachments too large".to_string(),
                 ErrorKind::MpscSend => "Channel closed (mpsc send failed)".to_string(),
                 ErrorKind::InvalidModel => "Invalid RNN model".to_string(),
+                #[cfg(target_family = "wasm")]
+                ErrorKind::RecordingUnsupported =>
+                    "Call recording is not supported on this platform".to_string(),
             }
         )
     }
@@ -317,6 +322,12 @@ impl AudioStreamError {
         }
     }
 
+    /// whether the error was reported by an input stream
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn is_input(&self) -> bool {
+        matches!(self.direction, AudioStreamDirection::Input)
+    }
+
     pub(crate) fn remote_reason(&self) -> GoodbyeReason {
         GoodbyeReason::AudioDeviceError
     }
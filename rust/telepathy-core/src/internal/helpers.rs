@@ -4,7 +4,7 @@ use crate::internal::core::{
     TelepathyCore,
 };
 use crate::internal::error::{AudioStreamError, Error, ErrorKind};
-use crate::internal::messages::{AudioHeader, RoomMessage};
+use crate::internal::messages::{AudioExtension, AudioHeader, RoomMessage};
 #[cfg(not(target_family = "wasm"))]
 use crate::internal::messages::{ProtocolMessage, StartScreenshare};
 #[cfg(not(target_family = "wasm"))]
@@ -15,8 +15,11 @@ use crate::internal::utils::deactivate_audio_session;
 use crate::internal::utils::{JoinHandle, KanalSink, KanalSource};
 use crate::internal::{ALPN, MAX_RINGTONE_LENGTH, Result};
 #[cfg(not(target_family = "wasm"))]
+use crate::types::DeviceEvent;
+#[cfg(not(target_family = "wasm"))]
 use crate::types::FrontendNotify;
 use crate::types::{ManagerState, SessionStatus};
+use atomic_float::AtomicF32;
 use bytes::Bytes;
 use iroh::address_lookup::PkarrPublisher;
 use iroh::endpoint::{default_relay_mode, presets};
@@ -30,10 +33,16 @@ use std::sync::atomic::Ordering::Relaxed;
 use std::time::Duration;
 #[cfg(target_family = "wasm")]
 use telepathy_audio::WebAudioWrapper;
+use telepathy_audio::codec::CodecId;
+use telepathy_audio::codec::{LosslessDecoder, LosslessEncoder};
+#[cfg(not(target_family = "wasm"))]
+use telepathy_audio::codec::{OpusDecoder, OpusEncoder, OpusSettings};
 use telepathy_audio::devices::AudioHost;
 use telepathy_audio::internal::buffer_pool::PooledBuffer;
 use telepathy_audio::io::{
-    AudioInputBuilder, AudioInputHandle, AudioOutputBuilder, AudioOutputHandle, CodecBitrateMode,
+    AudioInputBuilder, AudioInputHandle, AudioMixerBuilder, AudioMixerHandle, AudioOutputBuilder,
+    AudioOutputHandle, AutoGainConfig, CodecBitrateMode, MixerSourceBuilder, MixerSourceHandle,
+    StreamErrorCallback, VoiceGateConfig,
 };
 #[cfg(not(target_family = "wasm"))]
 use tokio::fs::File;
@@ -41,6 +50,8 @@ use tokio::fs::File;
 use tokio::io::AsyncReadExt;
 use tokio::select;
 use tokio::sync::Notify;
+#[cfg(not(target_family = "wasm"))]
+use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::sync::mpsc::{Sender, UnboundedSender};
 #[cfg(not(target_family = "wasm"))]
 use tokio::time::timeout;
@@ -314,51 +325,84 @@ where
     }
 
     /// helper method to set up audio input stack using the telepathy-audio library
+    ///
+    /// SEA encoders follow `residual_bits` when given, starting from the
+    /// residual bits in `codec_options`.
     pub(crate) async fn setup_input(
         &self,
-        codec_options: (bool, bool, f32),
+        codec_options: (CodecId, bool, f32),
+        residual_bits: Option<&Arc<AtomicF32>>,
+        channels: u16,
         statistics_state: &StatisticsCollectorState,
         end_call: &Arc<Notify>,
         stream_error: UnboundedSender<AudioStreamError>,
     ) -> Result<InputHelper<H::InputStream>> {
-        let (codec_enabled, vbr, residual_bits) = codec_options;
+        let (codec, vbr, initial_residual_bits) = codec_options;
         // Channel for receiving processed audio data
         let (sender, receiver) = kanal::unbounded_async();
-        let input_end_call = end_call.clone();
+        let reporter = StreamErrorReporter::new(stream_error, end_call.clone());
 
         let input_device_id = self.core_state.input_device.lock().await.clone();
 
         let mut builder = AudioInputBuilder::new()
             .device(input_device_id)
+            .channels(channels)
             .input_volume_shared(self.core_state.get_input_volume())
             .rms_threshold_shared(self.core_state.get_rms_threshold())
             .muted_shared(&self.core_state.muted)
             .rms_shared(&statistics_state.input_rms)
-            .on_error(move |error| {
-                error!(error = %error, "input_stream_error");
-                report_stream_error(
-                    &stream_error,
-                    &input_end_call,
-                    AudioStreamError::input(error.to_string()),
-                );
-            })
+            .gain_shared(&statistics_state.input_gain)
+            .on_error(reporter.input())
             .sink(KanalSink::new(sender));
 
-        if codec_enabled {
-            builder = builder.codec(
+        builder = match codec {
+            CodecId::Raw => builder,
+            CodecId::Sea => builder.codec(
                 if vbr {
                     CodecBitrateMode::Vbr
                 } else {
                     CodecBitrateMode::Cbr
                 },
-                residual_bits,
-            )
+                initial_residual_bits,
+            ),
+            #[cfg(not(target_family = "wasm"))]
+            CodecId::Opus => builder.encoder(move |sample_rate, channels| {
+                OpusEncoder::new(
+                    sample_rate,
+                    channels,
+                    OpusSettings {
+                        vbr,
+                        ..Default::default()
+                    },
+                )
+            }),
+            #[cfg(target_family = "wasm")]
+            CodecId::Opus => return Err(unsupported_codec(codec)),
+            CodecId::Lossless => builder.encoder(|_, _| Ok(LosslessEncoder::new())),
+        };
+
+        if let Some(residual_bits) = residual_bits {
+            builder = builder.residual_bits_shared(residual_bits);
         }
 
         if self.core_state.denoise.load(Relaxed) {
             builder = builder.denoise(self.core_state.denoise_model.read().await.clone());
         }
 
+        if self.core_state.echo_cancellation.load(Relaxed) {
+            builder = builder.echo_cancellation(&self.core_state.echo_reference);
+        }
+
+        builder = builder.recording(&self.core_state.recording_tap, "local");
+
+        if self.core_state.auto_gain.load(Relaxed) {
+            builder = builder.auto_gain(AutoGainConfig::default());
+        }
+
+        if self.core_state.voice_gate.load(Relaxed) {
+            builder = builder.voice_gate(VoiceGateConfig::default());
+        }
+
         #[cfg(target_family = "wasm")]
         {
             let wrapper = self
@@ -379,37 +423,275 @@ where
         &self,
         peer: PublicKey,
         remote_sample_rate: f64,
-        codec_enabled: bool,
+        codec: CodecId,
+        channels: u16,
         statistics_state: &StatisticsCollectorState,
         end_call: Arc<Notify>,
         stream_error: UnboundedSender<AudioStreamError>,
-    ) -> Result<OutputHelper<H::OutputStream>> {
+    ) -> Result<OutputHelper<AudioOutputHandle<H::OutputStream>>> {
         let device_id = self.core_state.output_device.lock().await.clone();
         // Create the input channel
         let (sender, receiver) = kanal::unbounded();
         // Get the shared volume multiplier
         let output_volume = self.core_state.output_volume_for_peer(peer)?;
         // Create the audio output using the builder
-        let handle = AudioOutputBuilder::new()
+        let mut builder = AudioOutputBuilder::new()
             .source(KanalSource::new(receiver))
             .device(device_id)
             .sample_rate(remote_sample_rate as u32)
+            .channels(channels)
             .output_volume_shared(&output_volume)
             .deafened_shared(&self.core_state.deafened)
             .rms_shared(&statistics_state.output_rms)
             .loss_shared(&statistics_state.loss)
-            .codec(codec_enabled)
-            .on_error(move |error| {
-                error!(error = %error, "output_stream_error");
-                report_stream_error(
-                    &stream_error,
-                    &end_call,
-                    AudioStreamError::output(error.to_string()),
-                );
-            })
-            .build(&self.host)?;
+            .on_error(StreamErrorReporter::new(stream_error, end_call).output());
+
+        builder = match codec {
+            CodecId::Raw => builder,
+            CodecId::Sea => builder.codec(true),
+            #[cfg(not(target_family = "wasm"))]
+            CodecId::Opus => builder.decoder(OpusDecoder::new),
+            #[cfg(target_family = "wasm")]
+            CodecId::Opus => return Err(unsupported_codec(codec)),
+            CodecId::Lossless => builder.decoder(|_, _| Ok(LosslessDecoder::new())),
+        };
+
+        if self.core_state.echo_cancellation.load(Relaxed) {
+            builder = builder.echo_reference(&self.core_state.echo_reference);
+        }
+
+        builder = builder.recording(&self.core_state.recording_tap, peer.to_string());
 
-        Ok(OutputHelper::new(handle, sender))
+        Ok(OutputHelper::new(builder.build(&self.host)?, sender))
+    }
+
+    /// helper method to attach a room peer to the shared output mixer
+    ///
+    /// The mixer is opened on the first call so rooms without peers hold no
+    /// output device.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn setup_mixer_source(
+        &self,
+        mixer: &mut Option<AudioMixerHandle<H::OutputStream>>,
+        peer: PublicKey,
+        remote_sample_rate: f64,
+        codec: CodecId,
+        output_rms: &Arc<AtomicF32>,
+        statistics_state: &StatisticsCollectorState,
+        end_call: Arc<Notify>,
+        stream_error: UnboundedSender<AudioStreamError>,
+    ) -> Result<OutputHelper<MixerSourceHandle>> {
+        let mixer = match mixer {
+            Some(mixer) => mixer,
+            None => mixer.insert(
+                self.setup_mixer(statistics_state, end_call, stream_error)
+                    .await?,
+            ),
+        };
+
+        // Create the input channel
+        let (sender, receiver) = kanal::unbounded();
+        // Get the shared volume multiplier
+        let output_volume = self.core_state.output_volume_for_peer(peer)?;
+        let mut builder = MixerSourceBuilder::new()
+            .source(KanalSource::new(receiver))
+            .sample_rate(remote_sample_rate as u32)
+            .output_volume_shared(&output_volume)
+            .rms_shared(output_rms)
+            .recording(&self.core_state.recording_tap, peer.to_string());
+
+        builder = match codec {
+            CodecId::Raw => builder,
+            CodecId::Sea => builder.codec(true),
+            #[cfg(not(target_family = "wasm"))]
+            CodecId::Opus => builder.decoder(OpusDecoder::new),
+            #[cfg(target_family = "wasm")]
+            CodecId::Opus => return Err(unsupported_codec(codec)),
+            CodecId::Lossless => builder.decoder(|_, _| Ok(LosslessDecoder::new())),
+        };
+
+        Ok(OutputHelper::new(mixer.add_source(builder)?, sender))
+    }
+
+    /// helper method to open the output mixer shared by every room peer
+    async fn setup_mixer(
+        &self,
+        statistics_state: &StatisticsCollectorState,
+        end_call: Arc<Notify>,
+        stream_error: UnboundedSender<AudioStreamError>,
+    ) -> Result<AudioMixerHandle<H::OutputStream>> {
+        let device_id = self.core_state.output_device.lock().await.clone();
+        // the per-peer volumes of the sources already include the output volume
+        let mut builder = AudioMixerBuilder::new()
+            .device(device_id)
+            .deafened_shared(&self.core_state.deafened)
+            .rms_shared(&statistics_state.output_rms)
+            .loss_shared(&statistics_state.loss)
+            .on_error(StreamErrorReporter::new(stream_error, end_call).output());
+
+        if self.core_state.echo_cancellation.load(Relaxed) {
+            builder = builder.echo_reference(&self.core_state.echo_reference);
+        }
+
+        Ok(builder.build(&self.host)?)
+    }
+
+    /// Moves the streams failed by `error`, and by any errors queued behind it,
+    /// to other devices. Returns the error of a stream no device works for.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) async fn recover_streams<I, O>(
+        &self,
+        error: AudioStreamError,
+        stream_errors: &mut UnboundedReceiver<AudioStreamError>,
+        streams: &mut FailoverStreams<'_, I, O>,
+    ) -> std::result::Result<(), AudioStreamError>
+    where
+        I: SwitchDevice<H>,
+        O: SwitchDevice<H>,
+    {
+        let mut pending = vec![error];
+        // every device is tried once, so devices failing on open cannot loop
+        let mut tried_inputs = Vec::new();
+        let mut tried_outputs = Vec::new();
+
+        while let Some(error) = pending.pop() {
+            // a failed device can report more than once, one error per stream is enough
+            pending.retain(|queued| queued.is_input() != error.is_input());
+            while let Ok(queued) = stream_errors.try_recv() {
+                if queued.is_input() != error.is_input() && pending.is_empty() {
+                    pending.push(queued);
+                }
+            }
+
+            let moved = if error.is_input() {
+                self.fail_over(
+                    &mut *streams.input,
+                    &mut streams.devices.input,
+                    &mut tried_inputs,
+                    streams.reporter,
+                )
+                .await
+            } else if let Some(output) = streams.output.as_deref_mut() {
+                self.fail_over(
+                    output,
+                    &mut streams.devices.output,
+                    &mut tried_outputs,
+                    streams.reporter,
+                )
+                .await
+            } else {
+                true
+            };
+            if !moved {
+                return Err(error);
+            }
+
+            // errors of the new devices, reported while they were opened
+            while let Ok(queued) = stream_errors.try_recv() {
+                pending.push(queued);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves the streams to the devices currently set in `CoreState`. A device
+    /// which does not open is logged and its stream stays where it is.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) async fn apply_device_selection<I, O>(&self, streams: &mut FailoverStreams<'_, I, O>)
+    where
+        I: SwitchDevice<H>,
+        O: SwitchDevice<H>,
+    {
+        let input = self.core_state.input_device.lock().await.clone();
+        select_device(
+            &self.host,
+            &mut *streams.input,
+            &mut streams.devices.input,
+            input,
+            streams.reporter,
+        );
+
+        let output = self.core_state.output_device.lock().await.clone();
+        if let Some(stream) = streams.output.as_deref_mut() {
+            select_device(
+                &self.host,
+                stream,
+                &mut streams.devices.output,
+                output,
+                streams.reporter,
+            );
+        } else {
+            // the room mixer opens on the selected device once a peer joins
+            streams.devices.output = output;
+        }
+    }
+
+    /// Moves a stream whose device failed to the system default, or else to
+    /// the first listed device which opens. `device` holds the failed device
+    /// and is updated to the new one, devices in `tried` are skipped. The
+    /// frontend is told about the switch.
+    #[cfg(not(target_family = "wasm"))]
+    async fn fail_over<S: SwitchDevice<H>>(
+        &self,
+        stream: &mut S,
+        device: &mut Option<String>,
+        tried: &mut Vec<Option<String>>,
+        reporter: &StreamErrorReporter,
+    ) -> bool {
+        let listed = if S::INPUT {
+            self.host.list_input_devices()
+        } else {
+            self.host.list_output_devices()
+        };
+        let listed = listed.unwrap_or_else(|error| {
+            warn!(event = "device_failover_list_failed", input = S::INPUT, error = %error);
+            Vec::new()
+        });
+
+        tried.push(device.clone());
+        let default = (!tried.contains(&None)).then_some(None);
+        let candidates: Vec<_> = default
+            .into_iter()
+            .chain(
+                listed
+                    .into_iter()
+                    .filter(|info| {
+                        !tried
+                            .iter()
+                            .any(|id| id.as_deref() == Some(info.id.as_str()))
+                    })
+                    .map(Some),
+            )
+            .collect();
+
+        for candidate in candidates {
+            let device_id = candidate.as_ref().map(|info| info.id.clone());
+            tried.push(device_id.clone());
+            match stream.switch_device(&self.host, device_id.as_deref(), reporter) {
+                Ok(()) => {
+                    info!(event = "device_failover", input = S::INPUT, device.id = ?device_id);
+                    *device = device_id;
+                    self.callbacks
+                        .device_event(DeviceEvent::Switched {
+                            input: S::INPUT,
+                            device: candidate.map(Into::into),
+                        })
+                        .await;
+                    return true;
+                }
+                Err(error) => {
+                    warn!(
+                        event = "device_failover_candidate_failed",
+                        input = S::INPUT,
+                        device.id = ?device_id,
+                        error = %error
+                    );
+                }
+            }
+        }
+
+        false
     }
 
     /// helper method to set up EarlyCallState
@@ -426,34 +708,57 @@ where
             return Ok(state);
         }
 
-        // rnnoise requires a 48kHz sample rate
-        let sample_rate = if self.core_state.denoise.load(Relaxed) {
-            48_000
-        } else {
-            cfg_if::cfg_if! {
-                if #[cfg(target_family = "wasm")] {
-                     self
-                        .web_input
-                        .lock()
-                        .await
-                        .as_ref()
-                        .expect("web audio wrapper was not initialized")
-                        .sample_rate as u32
-                } else {
-                    let device_id = self.core_state.input_device.lock().await;
-                    self.host.input_sample_rate(device_id.as_deref())?
+        // rnnoise requires a 48kHz sample rate, also when it only detects voice
+        let sample_rate =
+            if self.core_state.denoise.load(Relaxed) || self.core_state.voice_gate.load(Relaxed) {
+                48_000
+            } else {
+                cfg_if::cfg_if! {
+                    if #[cfg(target_family = "wasm")] {
+                         self
+                            .web_input
+                            .lock()
+                            .await
+                            .as_ref()
+                            .expect("web audio wrapper was not initialized")
+                            .sample_rate as u32
+                    } else {
+                        let device_id = self.core_state.input_device.lock().await;
+                        self.host.input_sample_rate(device_id.as_deref())?
+                    }
                 }
-            }
+            };
+
+        // web audio captures mono
+        let channels = if cfg!(target_family = "wasm") {
+            1
+        } else {
+            self.core_state.codec_config.channels()
         };
 
         Ok(EarlyCallState {
             peer,
-            local_configuration: AudioHeader {
+            local_configuration: AudioHeader::new(
                 sample_rate,
-                codec_enabled: self.core_state.codec_config.enabled.load(Relaxed),
-                vbr: self.core_state.codec_config.vbr.load(Relaxed),
-                residual_bits: self.core_state.codec_config.residual_bits.load(Relaxed) as f64,
-            },
+                self.core_state.codec_config.vbr.load(Relaxed),
+                self.core_state.codec_config.residual_bits.load(Relaxed) as f64,
+                AudioExtension {
+                    codec_id: if self.core_state.codec_config.enabled.load(Relaxed) {
+                        CodecId::from(self.core_state.codec_config.codec())
+                    } else {
+                        CodecId::Raw
+                    }
+                    .into(),
+                    codecs: CodecId::ALL
+                        .into_iter()
+                        .filter(|codec| codec.is_supported(sample_rate, channels))
+                        .map(u8::from)
+                        .collect(),
+                    channels,
+                    redundancy: self.core_state.redundancy.load(Relaxed),
+                    audio_feedback: true,
+                },
+            ),
             remote_configuration: AudioHeader::default(),
         })
     }
@@ -719,6 +1024,7 @@ where
             room_generation,
             input_handle,
             connections,
+            mixer,
             statistics_handle,
             terminal_error,
             outcome,
@@ -750,6 +1056,8 @@ where
         {
             record_room_terminal_error(&mut terminal_error, error);
         }
+        // every source is detached by now, stop the mixer and close the device
+        drop(mixer);
         debug!(event = "room_processing_teardown_done");
         // Clear `room_state` only if it's still the currently installed generation.
         {
@@ -950,19 +1258,54 @@ pub(crate) async fn join_room_task_bounded(
     }
 }
 
-fn report_stream_error(
-    sender: &UnboundedSender<AudioStreamError>,
-    end_call: &Notify,
-    error: AudioStreamError,
-) {
-    let sent = sender.send(error).is_ok();
-    if !sent {
-        end_call.notify_one();
+/// Reports the stream errors of a call or room to its controller
+#[derive(Clone)]
+pub(crate) struct StreamErrorReporter {
+    sender: UnboundedSender<AudioStreamError>,
+    end_call: Arc<Notify>,
+}
+
+impl StreamErrorReporter {
+    pub(crate) fn new(sender: UnboundedSender<AudioStreamError>, end_call: Arc<Notify>) -> Self {
+        Self { sender, end_call }
+    }
+
+    /// Builds the error callback of an input stream
+    pub(crate) fn input(&self) -> StreamErrorCallback {
+        let reporter = self.clone();
+        Box::new(move |error| {
+            error!(error = %error, "input_stream_error");
+            reporter.report(AudioStreamError::input(error.to_string()));
+        })
+    }
+
+    /// Builds the error callback of an output stream
+    pub(crate) fn output(&self) -> StreamErrorCallback {
+        let reporter = self.clone();
+        Box::new(move |error| {
+            error!(error = %error, "output_stream_error");
+            reporter.report(AudioStreamError::output(error.to_string()));
+        })
     }
+
+    fn report(&self, error: AudioStreamError) {
+        let sent = self.sender.send(error).is_ok();
+        if !sent {
+            self.end_call.notify_one();
+        }
+    }
+}
+
+/// opus is never advertised on wasm, so negotiation cannot select it there
+#[cfg(target_family = "wasm")]
+fn unsupported_codec(codec: CodecId) -> Error {
+    telepathy_audio::Error::Config(telepathy_audio::ConfigError::UnsupportedCodec(codec)).into()
 }
 
-pub(crate) struct OutputHelper<O> {
-    _handle: AudioOutputHandle<O>,
+/// Keeps an output alive while its sender feeds it, `T` is the output's handle
+pub(crate) struct OutputHelper<T> {
+    #[cfg_attr(target_family = "wasm", allow(dead_code))]
+    handle: T,
     sender: Option<kanal::Sender<Bytes>>,
 }
 
@@ -973,11 +1316,11 @@ pub(crate) struct RoomNegotiationSnapshot {
     pub(crate) room_generation: u64,
 }
 
-impl<O> OutputHelper<O> {
+impl<T> OutputHelper<T> {
     /// Creates a new OutputHelper and stores the handle in the shared storage
-    pub(crate) fn new(handle: AudioOutputHandle<O>, sender: kanal::Sender<Bytes>) -> Self {
+    pub(crate) fn new(handle: T, sender: kanal::Sender<Bytes>) -> Self {
         Self {
-            _handle: handle,
+            handle,
             sender: Some(sender),
         }
     }
@@ -988,7 +1331,8 @@ impl<O> OutputHelper<O> {
 }
 
 pub(crate) struct InputHelper<I> {
-    _handle: AudioInputHandle<I>,
+    #[cfg_attr(target_family = "wasm", allow(dead_code))]
+    handle: AudioInputHandle<I>,
     receiver: Option<kanal::AsyncReceiver<PooledBuffer>>,
 }
 
@@ -999,7 +1343,7 @@ impl<I> InputHelper<I> {
         receiver: kanal::AsyncReceiver<PooledBuffer>,
     ) -> Self {
         Self {
-            _handle: handle,
+            handle,
             receiver: Some(receiver),
         }
     }
@@ -1008,3 +1352,117 @@ impl<I> InputHelper<I> {
         self.receiver.take().expect("receiver already taken")
     }
 }
+
+/// A running stream of a call or room which can move to another device
+#[cfg(not(target_family = "wasm"))]
+pub(crate) trait SwitchDevice<H: AudioHost> {
+    /// whether the stream records audio
+    const INPUT: bool;
+
+    /// Opens `device_id` and moves the stream to it, `None` is the system default
+    fn switch_device(
+        &mut self,
+        host: &H,
+        device_id: Option<&str>,
+        reporter: &StreamErrorReporter,
+    ) -> Result<()>;
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<H: AudioHost> SwitchDevice<H> for InputHelper<H::InputStream> {
+    const INPUT: bool = true;
+
+    fn switch_device(
+        &mut self,
+        host: &H,
+        device_id: Option<&str>,
+        reporter: &StreamErrorReporter,
+    ) -> Result<()> {
+        Ok(self
+            .handle
+            .switch_device(host, device_id, Some(reporter.input()))?)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<H: AudioHost> SwitchDevice<H> for OutputHelper<AudioOutputHandle<H::OutputStream>> {
+    const INPUT: bool = false;
+
+    fn switch_device(
+        &mut self,
+        host: &H,
+        device_id: Option<&str>,
+        reporter: &StreamErrorReporter,
+    ) -> Result<()> {
+        Ok(self
+            .handle
+            .switch_device(host, device_id, Some(reporter.output()))?)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<H: AudioHost> SwitchDevice<H> for AudioMixerHandle<H::OutputStream> {
+    const INPUT: bool = false;
+
+    fn switch_device(
+        &mut self,
+        host: &H,
+        device_id: Option<&str>,
+        reporter: &StreamErrorReporter,
+    ) -> Result<()> {
+        Ok(AudioMixerHandle::switch_device(
+            self,
+            host,
+            device_id,
+            Some(reporter.output()),
+        )?)
+    }
+}
+
+/// Moves `stream` to the `selected` device unless it already runs there
+#[cfg(not(target_family = "wasm"))]
+fn select_device<H: AudioHost, S: SwitchDevice<H>>(
+    host: &H,
+    stream: &mut S,
+    device: &mut Option<String>,
+    selected: Option<String>,
+    reporter: &StreamErrorReporter,
+) {
+    if *device == selected {
+        return;
+    }
+
+    match stream.switch_device(host, selected.as_deref(), reporter) {
+        Ok(()) => {
+            info!(event = "device_selected", input = S::INPUT, device.id = ?selected);
+            *device = selected;
+        }
+        Err(error) => {
+            warn!(
+                event = "device_select_failed",
+                input = S::INPUT,
+                device.id = ?selected,
+                error = %error
+            );
+        }
+    }
+}
+
+/// The devices the streams of a call or room run on, `None` is the system default
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+#[derive(Default)]
+pub(crate) struct StreamDevices {
+    pub(crate) input: Option<String>,
+    pub(crate) output: Option<String>,
+}
+
+/// The streams of a call or room, moved to other devices when theirs fail or
+/// another device is set
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+pub(crate) struct FailoverStreams<'a, I, O> {
+    pub(crate) input: &'a mut I,
+    /// `None` while a room has not opened its output
+    pub(crate) output: Option<&'a mut O>,
+    pub(crate) devices: &'a mut StreamDevices,
+    pub(crate) reporter: &'a StreamErrorReporter,
+}
@@ -4,6 +4,7 @@ use iroh::PublicKey;
 use iroh::endpoint::Connection;
 use serde::Serialize;
 use speedy::{Readable, Writable};
+use telepathy_audio::codec::{CodecId, supports_channels};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 use uuid::Uuid;
@@ -42,9 +43,15 @@ pub(crate) enum ProtocolMessage {
         ringtone: Option<Vec<u8>>,
         audio_header: AudioHeader,
         room_hash: Option<u64>,
+        /// missing from the `Hello` of older clients
+        #[speedy(default_on_eof)]
+        audio_extension: Option<AudioExtension>,
     },
     HelloAck {
         audio_header: AudioHeader,
+        /// missing from the `HelloAck` of older clients
+        #[speedy(default_on_eof)]
+        audio_extension: Option<AudioExtension>,
     },
     Reject,
     Busy,
@@ -59,6 +66,11 @@ pub(crate) enum ProtocolMessage {
     ScreenshareHeader {
         encoder_name: String,
     },
+    /// Loss and round trip measured by the receiver of a call's audio
+    AudioFeedback {
+        loss: f32,
+        rtt_ms: u32,
+    },
 }
 
 impl ProtocolMessage {
@@ -73,22 +85,143 @@ impl ProtocolMessage {
             reason: GoodbyeReason::None,
         }
     }
+
+    pub(crate) fn hello(
+        ringtone: Option<Vec<u8>>,
+        audio_header: &AudioHeader,
+        room_hash: Option<u64>,
+    ) -> Self {
+        Self::Hello {
+            ringtone,
+            audio_header: audio_header.clone(),
+            room_hash,
+            audio_extension: audio_header.extension.clone(),
+        }
+    }
+
+    pub(crate) fn hello_ack(audio_header: &AudioHeader) -> Self {
+        Self::HelloAck {
+            audio_header: audio_header.clone(),
+            audio_extension: audio_header.extension.clone(),
+        }
+    }
 }
 
+/// The audio configuration a client sends in its `Hello` & `HelloAck`.
+///
+/// The serialized header keeps the layout of the first protocol version.
+/// Settings added since travel in an [`AudioExtension`] at the end of the
+/// message, which older clients ignore.
 #[derive(Readable, Writable, Debug, Clone, Default)]
 pub(crate) struct AudioHeader {
     pub(crate) sample_rate: u32,
+    /// whether the preferred codec falls back to SEA rather than raw, the
+    /// only codecs older clients know
     pub(crate) codec_enabled: bool,
     pub(crate) vbr: bool,
     pub(crate) residual_bits: f64,
+    /// sent at the end of the handshake message, `None` for older clients
+    #[speedy(skip)]
+    pub(crate) extension: Option<AudioExtension>,
+}
+
+/// Audio settings added after the first protocol version.
+///
+/// Fields added later must be read with `#[speedy(default_on_eof)]` so
+/// extensions from clients that predate them still parse.
+#[derive(Readable, Writable, Debug, Clone, Default)]
+pub(crate) struct AudioExtension {
+    /// [`CodecId`] this client prefers
+    pub(crate) codec_id: u8,
+    /// every [`CodecId`] this client can use at `sample_rate` & `channels`;
+    /// unknown ids are ignored
+    pub(crate) codecs: Vec<u8>,
+    /// the number of interleaved channels this client sends, both clients
+    /// use the smaller count
+    pub(crate) channels: u16,
+    /// whether this client can send & rebuild redundant audio frames, used
+    /// when both clients advertise it
+    pub(crate) redundancy: bool,
+    /// whether this client reads [`ProtocolMessage::AudioFeedback`], which
+    /// is only sent when both clients advertise it
+    pub(crate) audio_feedback: bool,
 }
 
 impl AudioHeader {
+    /// Builds a header advertising `extension`, older clients read the
+    /// codec from `codec_enabled`.
+    pub(crate) fn new(
+        sample_rate: u32,
+        vbr: bool,
+        residual_bits: f64,
+        extension: AudioExtension,
+    ) -> Self {
+        let codec = CodecId::try_from(extension.codec_id).unwrap_or_default();
+        Self {
+            sample_rate,
+            codec_enabled: std::iter::successors(Some(codec), |codec| codec.fallback())
+                .any(|codec| codec == CodecId::Sea),
+            vbr,
+            residual_bits,
+            extension: Some(extension),
+        }
+    }
+
+    /// Attaches the extension received after the header.
+    pub(crate) fn with_extension(mut self, extension: Option<AudioExtension>) -> Self {
+        self.extension = extension;
+        self
+    }
+
     pub(crate) fn is_valid(&self) -> bool {
         self.sample_rate < 128_000
             && self.sample_rate > 8_000
             && self.residual_bits <= 8_f64
             && self.residual_bits >= 2_f64
+            && self.extension.as_ref().is_none_or(|extension| {
+                CodecId::try_from(extension.codec_id).is_ok()
+                    && supports_channels(extension.channels)
+            })
+    }
+
+    /// the advertised codec, older clients prefer SEA or raw
+    pub(crate) fn codec(&self) -> CodecId {
+        match &self.extension {
+            Some(extension) => CodecId::try_from(extension.codec_id).unwrap_or_default(),
+            None if self.codec_enabled => CodecId::Sea,
+            None => CodecId::Raw,
+        }
+    }
+
+    /// whether this client advertised `codec`, raw is always supported and
+    /// older clients also support SEA
+    pub(crate) fn supports(&self, codec: CodecId) -> bool {
+        codec == CodecId::Raw
+            || match &self.extension {
+                Some(extension) => extension.codecs.contains(&u8::from(codec)),
+                None => codec == CodecId::Sea,
+            }
+    }
+
+    /// the number of channels this client sends, older clients send mono
+    pub(crate) fn channels(&self) -> u16 {
+        self.extension
+            .as_ref()
+            .map_or(1, |extension| extension.channels)
+    }
+
+    /// whether this client can send & rebuild redundant audio frames
+    pub(crate) fn redundancy(&self) -> bool {
+        self.extension
+            .as_ref()
+            .is_some_and(|extension| extension.redundancy)
+    }
+
+    /// whether this client reads audio feedback, older clients fail on it
+    pub(crate) fn audio_feedback(&self) -> bool {
+        self.extension
+            .as_ref()
+            .is_some_and(|extension| extension.audio_feedback)
     }
 }
 
@@ -158,3 +291,136 @@ impl StartScreenshare {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// the handshake messages of the first protocol version
+    #[derive(Readable, Writable, Debug)]
+    enum LegacyMessage {
+        Hello {
+            ringtone: Option<Vec<u8>>,
+            audio_header: LegacyAudioHeader,
+            room_hash: Option<u64>,
+        },
+        HelloAck {
+            audio_header: LegacyAudioHeader,
+        },
+    }
+
+    #[derive(Readable, Writable, Debug, PartialEq)]
+    struct LegacyAudioHeader {
+        sample_rate: u32,
+        codec_enabled: bool,
+        vbr: bool,
+        residual_bits: f64,
+    }
+
+    fn header(codec: CodecId) -> AudioHeader {
+        AudioHeader::new(
+            48_000,
+            true,
+            5.0,
+            AudioExtension {
+                codec_id: codec.into(),
+                codecs: CodecId::ALL.into_iter().map(u8::from).collect(),
+                channels: 2,
+                redundancy: true,
+                audio_feedback: true,
+            },
+        )
+    }
+
+    #[test]
+    fn older_clients_read_the_handshake() {
+        let hello = ProtocolMessage::hello(Some(vec![1, 2, 3]), &header(CodecId::Opus), Some(7))
+            .write_to_vec()
+            .unwrap();
+        let LegacyMessage::Hello {
+            ringtone,
+            audio_header,
+            room_hash,
+        } = LegacyMessage::read_from_buffer(&hello).unwrap()
+        else {
+            panic!("expected Hello message");
+        };
+        assert_eq!(ringtone, Some(vec![1, 2, 3]));
+        assert_eq!(room_hash, Some(7));
+        assert_eq!(
+            audio_header,
+            LegacyAudioHeader {
+                sample_rate: 48_000,
+                codec_enabled: true,
+                vbr: true,
+                residual_bits: 5.0,
+            }
+        );
+
+        // lossless falls back to raw, which older clients know as disabled
+        let hello_ack = ProtocolMessage::hello_ack(&header(CodecId::Lossless))
+            .write_to_vec()
+            .unwrap();
+        let LegacyMessage::HelloAck { audio_header } =
+            LegacyMessage::read_from_buffer(&hello_ack).unwrap()
+        else {
+            panic!("expected HelloAck message");
+        };
+        assert!(!audio_header.codec_enabled);
+    }
+
+    #[test]
+    fn the_handshake_of_older_clients_has_no_extension() {
+        let hello = LegacyMessage::Hello {
+            ringtone: None,
+            audio_header: LegacyAudioHeader {
+                sample_rate: 48_000,
+                codec_enabled: true,
+                vbr: false,
+                residual_bits: 5.0,
+            },
+            room_hash: Some(7),
+        }
+        .write_to_vec()
+        .unwrap();
+        let ProtocolMessage::Hello {
+            audio_header,
+            room_hash,
+            audio_extension,
+            ..
+        } = ProtocolMessage::read_from_buffer(&hello).unwrap()
+        else {
+            panic!("expected Hello message");
+        };
+        assert_eq!(room_hash, Some(7));
+        let audio_header = audio_header.with_extension(audio_extension);
+        assert!(audio_header.extension.is_none());
+        assert!(audio_header.is_valid());
+        assert_eq!(audio_header.codec(), CodecId::Sea);
+        assert!(audio_header.supports(CodecId::Sea));
+        assert!(!audio_header.supports(CodecId::Opus));
+        assert_eq!(audio_header.channels(), 1);
+        assert!(!audio_header.redundancy());
+        assert!(!audio_header.audio_feedback());
+    }
+
+    #[test]
+    fn the_extension_round_trips() {
+        let hello_ack = ProtocolMessage::hello_ack(&header(CodecId::Opus))
+            .write_to_vec()
+            .unwrap();
+        let ProtocolMessage::HelloAck {
+            audio_header,
+            audio_extension,
+        } = ProtocolMessage::read_from_buffer(&hello_ack).unwrap()
+        else {
+            panic!("expected HelloAck message");
+        };
+        let audio_header = audio_header.with_extension(audio_extension);
+        assert_eq!(audio_header.codec(), CodecId::Opus);
+        assert!(audio_header.supports(CodecId::Lossless));
+        assert_eq!(audio_header.channels(), 2);
+        assert!(audio_header.redundancy());
+        assert!(audio_header.audio_feedback());
+    }
+}
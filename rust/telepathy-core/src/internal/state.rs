@@ -1,5 +1,8 @@
 use crate::internal::Result;
 use crate::internal::callbacks::CoreCallbacks;
+use crate::internal::connections::{
+    DEFAULT_JITTER_MAX_MS, DEFAULT_JITTER_MIN_MS, JitterBounds, ReceiveCounters,
+};
 use crate::internal::error::ErrorKind;
 use crate::internal::messages::{AudioHeader, ProtocolMessage, RoomMessage};
 use crate::types::{CodecConfig, Contact, NetworkConfig, ScreenshareConfig, SessionStatus};
@@ -13,17 +16,22 @@ use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize};
 use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Duration;
 use telepathy_audio::RnnModel;
+use telepathy_audio::codec::CodecId;
 use telepathy_audio::internal::utils::db_to_multiplier;
+use telepathy_audio::io::EchoReference;
+#[cfg(not(target_family = "wasm"))]
+use telepathy_audio::recorder::Recorder;
+use telepathy_audio::recorder::RecordingTap;
 use tokio::select;
 use tokio::sync::mpsc::Sender;
 use tokio::sync::{Mutex, Notify, RwLock};
 #[cfg(not(target_family = "wasm"))]
-use tokio::time::interval;
+use tokio::time::{Instant, interval};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 #[cfg(target_family = "wasm")]
-use wasmtimer::tokio::interval;
+use wasmtimer::{std::Instant, tokio::interval};
 
 type SharedDeviceId = Arc<Mutex<Option<String>>>;
 
@@ -467,12 +475,34 @@ pub struct CoreState {
     /// The rnnoise model
     pub(crate) denoise_model: Arc<RwLock<RnnModel>>,
 
+    /// Enables acoustic echo cancellation
+    pub(crate) echo_cancellation: Arc<AtomicBool>,
+
+    /// Enables automatic input gain, capped by the input volume
+    pub(crate) auto_gain: Arc<AtomicBool>,
+
+    /// Gates the input on voice activity instead of the rms threshold
+    pub(crate) voice_gate: Arc<AtomicBool>,
+
+    /// Far-end reference shared by the output streams and the echo canceller
+    pub(crate) echo_reference: EchoReference,
+
+    /// Taps every audio stream for call recording
+    pub(crate) recording_tap: RecordingTap,
+
+    /// The running call recording, if there is one
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) recorder: Arc<StdMutex<Option<Recorder>>>,
+
     /// Manually set the input device
     pub(crate) input_device: SharedDeviceId,
 
     /// Manually set the output device
     pub(crate) output_device: SharedDeviceId,
 
+    /// notifies the active call or room when a device is set
+    pub(crate) device_changed: Arc<Notify>,
+
     /// The current iroh secret key
     pub identity: Arc<RwLock<Option<SecretKey>>>,
 
@@ -501,6 +531,9 @@ pub struct CoreState {
     /// Enables sending your custom ringtone
     pub(crate) send_custom_ringtone: Arc<AtomicBool>,
 
+    /// Advertises redundant audio frames for lossy connections
+    pub(crate) redundancy: Arc<AtomicBool>,
+
     /// Decreases the statistics update rate
     pub(crate) efficiency_mode: Arc<AtomicBool>,
 
@@ -544,6 +577,12 @@ pub struct CoreState {
     /// The output volume in decibels
     output_volume: Arc<AtomicF32>,
 
+    /// Lower bound of the adaptive jitter buffer delay in milliseconds
+    jitter_min_ms: Arc<AtomicU32>,
+
+    /// Upper bound of the adaptive jitter buffer delay in milliseconds
+    jitter_max_ms: Arc<AtomicU32>,
+
     /// Output samples are multiplied by this number, per-peer
     peer_output_volumes: Arc<StdMutex<HashMap<PublicKey, PeerVolume>>>,
 
@@ -843,6 +882,9 @@ impl CoreState {
             applied_runtime_revision: Arc::new(AtomicU64::new(u64::MAX)),
             failed_runtime_revision: Arc::new(AtomicU64::new(u64::MAX)),
             input_multiplier: Arc::new(AtomicF32::new(1.0)),
+            jitter_min_ms: Arc::new(AtomicU32::new(DEFAULT_JITTER_MIN_MS)),
+            jitter_max_ms: Arc::new(AtomicU32::new(DEFAULT_JITTER_MAX_MS)),
+            redundancy: Arc::new(AtomicBool::new(true)),
             ..Self::default()
         }
     }
@@ -864,6 +906,20 @@ impl CoreState {
         &self.rms_threshold
     }
 
+    pub(crate) fn set_jitter_bounds(&self, min_ms: u32, max_ms: u32) {
+        self.jitter_min_ms.store(min_ms, Relaxed);
+        self.jitter_max_ms.store(max_ms, Relaxed);
+    }
+
+    /// the adaptive jitter buffer bounds for a stream at `sample_rate`
+    pub(crate) fn jitter_bounds(&self, sample_rate: u32) -> JitterBounds {
+        JitterBounds::from_millis(
+            self.jitter_min_ms.load(Relaxed),
+            self.jitter_max_ms.load(Relaxed),
+            sample_rate,
+        )
+    }
+
     /// returns the volume multiplier to share with the output processor
     pub(crate) fn output_volume_for_peer(&self, peer: PublicKey) -> Result<Arc<AtomicF32>> {
         Ok(self.get_peer_volume(peer)?.multiplier)
@@ -969,10 +1025,14 @@ impl RoomState {
 pub(crate) struct StatisticsCollectorState {
     pub(crate) input_rms: Arc<AtomicF32>,
     pub(crate) output_rms: Arc<AtomicF32>,
-    pub(crate) latency: Arc<AtomicUsize>,
-    pub(crate) upload_bandwidth: Arc<AtomicUsize>,
-    pub(crate) download_bandwidth: Arc<AtomicUsize>,
+    /// the linear gain automatic gain control applies to the input
+    pub(crate) input_gain: Arc<AtomicF32>,
+    pub(crate) network: Arc<ConnectionStatistics>,
+    pub(crate) receive: Arc<ReceiveCounters>,
     pub(crate) loss: Arc<AtomicUsize>,
+    pub(crate) jitter_delay: Arc<AtomicUsize>,
+    /// statistics sources of the peers admitted to a room, empty during calls
+    pub(crate) peers: Arc<StdMutex<HashMap<PublicKey, PeerStatisticsState>>>,
 }
 
 impl StatisticsCollectorState {
@@ -980,18 +1040,99 @@ impl StatisticsCollectorState {
         Self {
             input_rms: Arc::new(Default::default()),
             output_rms: Arc::new(Default::default()),
-            latency: state.map(|s| s.latency.clone()).unwrap_or_default(),
-            upload_bandwidth: state
-                .map(|s| s.upload_bandwidth.clone())
-                .unwrap_or_default(),
-            download_bandwidth: state
-                .map(|s| s.download_bandwidth.clone())
-                .unwrap_or_default(),
+            input_gain: Arc::new(AtomicF32::new(1.0)),
+            network: state.map(|s| s.network.clone()).unwrap_or_default(),
+            receive: Arc::new(Default::default()),
             loss: Arc::new(Default::default()),
+            jitter_delay: Arc::new(Default::default()),
+            peers: Arc::new(Default::default()),
         }
     }
+
+    /// Starts reporting statistics for a peer admitted to the room, replacing
+    /// those of a previous connection
+    pub(crate) fn insert_peer(&self, peer: PublicKey, state: PeerStatisticsState) {
+        self.peers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(peer, state);
+    }
+
+    /// Stops reporting statistics for a peer that left the room
+    pub(crate) fn remove_peer(&self, peer: &PublicKey) {
+        self.peers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(peer);
+    }
+}
+
+/// Statistics sources of a single peer admitted to a room
+pub(crate) struct PeerStatisticsState {
+    /// transport statistics of the peer's session
+    pub(crate) network: Arc<ConnectionStatistics>,
+
+    /// telemetry of the audio received from the peer
+    pub(crate) receive: Arc<ReceiveCounters>,
+
+    /// RMS of the peer's mixer source
+    pub(crate) output_rms: Arc<AtomicF32>,
+
+    /// playout delay of the peer's jitter buffer in milliseconds
+    pub(crate) jitter_delay: Arc<AtomicUsize>,
 }
 
+/// Transport statistics of a session connection, kept up to date by
+/// [`SessionState::connection_monitor`] from iroh's connection stats
+#[derive(Default)]
+pub(crate) struct ConnectionStatistics {
+    /// round trip time of the primary path in milliseconds
+    pub(crate) latency: AtomicUsize,
+
+    /// bytes per second sent over the connection
+    pub(crate) upload_bandwidth: AtomicUsize,
+
+    /// bytes per second received over the connection
+    pub(crate) download_bandwidth: AtomicUsize,
+
+    /// total bytes sent over the connection
+    pub(crate) bytes_sent: AtomicUsize,
+
+    /// total bytes received over the connection
+    pub(crate) bytes_received: AtomicUsize,
+
+    /// congestion window of the primary path in bytes
+    pub(crate) congestion_window: AtomicUsize,
+
+    /// whether the primary path goes through a relay
+    pub(crate) relayed: AtomicBool,
+
+    /// how often the primary path switched between direct and relayed
+    pub(crate) path_changes: AtomicUsize,
+}
+
+impl ConnectionStatistics {
+    /// Records the totals of one monitor tick and derives the bandwidth from
+    /// the previous totals, `elapsed` ago.
+    fn update_bytes(&self, sent: u64, received: u64, elapsed: Duration) {
+        let seconds = elapsed.as_secs_f64().max(f64::EPSILON);
+        let previous_sent = self.bytes_sent.swap(sent as usize, Relaxed);
+        let previous_received = self.bytes_received.swap(received as usize, Relaxed);
+        let upload = (sent as usize).saturating_sub(previous_sent) as f64 / seconds;
+        let download = (received as usize).saturating_sub(previous_received) as f64 / seconds;
+        self.upload_bandwidth.store(upload as usize, Relaxed);
+        self.download_bandwidth.store(download as usize, Relaxed);
+    }
+}
+
+/// The order codecs are picked in when the peers prefer different ones.
+///
+/// Compressed codecs come first, so a peer asking for a smaller stream gets
+/// one. SEA must precede lossless: older clients use SEA whenever either
+/// peer asks for it.
+const CODEC_PREFERENCE: [CodecId; 4] =
+    [CodecId::Opus, CodecId::Sea, CodecId::Lossless, CodecId::Raw];
+
 /// state used early in the call before it starts
 #[derive(Clone)]
 pub(crate) struct EarlyCallState {
@@ -1001,13 +1142,46 @@ pub(crate) struct EarlyCallState {
 }
 
 impl EarlyCallState {
-    pub(crate) fn codec_config(&self) -> (bool, bool, f32) {
-        let codec_enabled =
-            self.remote_configuration.codec_enabled || self.local_configuration.codec_enabled;
+    pub(crate) fn codec_config(&self) -> (CodecId, bool, f32) {
+        // both peers must pick the same codec, the first preference either
+        // peer asked for wins and falls back (e.g. opus to sea) until both
+        // peers support it
+        let requested = [
+            self.local_configuration.codec(),
+            self.remote_configuration.codec(),
+        ];
+        let preferred = CODEC_PREFERENCE
+            .into_iter()
+            .find(|codec| requested.contains(codec))
+            .unwrap_or_default();
+        let codec = std::iter::successors(Some(preferred), |codec| codec.fallback())
+            .find(|codec| {
+                self.local_configuration.supports(*codec)
+                    && self.remote_configuration.supports(*codec)
+            })
+            .unwrap_or_default();
         let vbr = self.remote_configuration.vbr || self.local_configuration.vbr;
         let residual_bits = (self.remote_configuration.residual_bits as f32)
             .min(self.local_configuration.residual_bits as f32);
-        (codec_enabled, vbr, residual_bits)
+        (codec, vbr, residual_bits)
+    }
+
+    /// the number of channels both clients send, the smaller advertised count
+    pub(crate) fn channels(&self) -> u16 {
+        self.local_configuration
+            .channels()
+            .min(self.remote_configuration.channels())
+            .max(1)
+    }
+
+    /// whether audio packets carry redundant frames, both clients must support it
+    pub(crate) fn redundancy(&self) -> bool {
+        self.local_configuration.redundancy() && self.remote_configuration.redundancy()
+    }
+
+    /// whether the clients exchange audio feedback, both clients must support it
+    pub(crate) fn audio_feedback(&self) -> bool {
+        self.local_configuration.audio_feedback() && self.remote_configuration.audio_feedback()
     }
 }
 
@@ -1028,14 +1202,8 @@ pub struct SessionState {
     /// a reusable sender for messages while a call is active
     pub(crate) message_sender: Sender<ProtocolMessage>,
 
-    /// a shared latency value for the session from iroh rtt
-    pub(crate) latency: Arc<AtomicUsize>,
-
-    /// a shared upload bandwidth value for the session
-    pub(crate) upload_bandwidth: Arc<AtomicUsize>,
-
-    /// a shared download bandwidth value for the session
-    pub(crate) download_bandwidth: Arc<AtomicUsize>,
+    /// shared transport statistics for the session from iroh's connection stats
+    pub(crate) network: Arc<ConnectionStatistics>,
 
     pub(crate) end_call: Arc<Notify>,
 
@@ -1065,9 +1233,7 @@ impl SessionState {
             reconcile_room_call: Notify::new(),
             stop_session: Default::default(),
             message_sender: message_sender.clone(),
-            latency: Default::default(),
-            upload_bandwidth: Default::default(),
-            download_bandwidth: Default::default(),
+            network: Default::default(),
             end_call: Default::default(),
             start_screenshare: Default::default(),
             stop_screenshare: Default::default(),
@@ -1182,6 +1348,8 @@ impl SessionState {
     {
         let mut interval = interval(Duration::from_secs(1));
         interval.tick().await;
+        let mut last_tick = Instant::now();
+        let mut relayed_path = None;
 
         loop {
             select! {
@@ -1191,9 +1359,11 @@ impl SessionState {
                         break;
                     }
 
-                    // track overall bandwidth across all connections
-                    self.upload_bandwidth.store(connection.stats().udp_tx.bytes as usize, Relaxed);
-                    self.download_bandwidth.store(connection.stats().udp_rx.bytes as usize, Relaxed);
+                    // track overall bandwidth across all paths
+                    let stats = connection.stats();
+                    let now = Instant::now();
+                    self.network.update_bytes(stats.udp_tx.bytes, stats.udp_rx.bytes, now - last_tick);
+                    last_tick = now;
 
                     let paths = connection.paths();
                     let mut max_data = u64::MIN;
@@ -1212,12 +1382,20 @@ impl SessionState {
                     }
 
                     if let Some(primary_connection) = primary_connection {
-                        self.latency.store(primary_connection.rtt().as_millis() as usize, Relaxed);
+                        let relayed = primary_connection.is_relay();
+                        if relayed_path.replace(relayed).is_some_and(|previous| previous != relayed) {
+                            info!(event = "connection_path_changed", relayed, peer.id = %peer);
+                            self.network.path_changes.fetch_add(1, Relaxed);
+                        }
+                        self.network.relayed.store(relayed, Relaxed);
+                        self.network.latency.store(primary_connection.rtt().as_millis() as usize, Relaxed);
+                        let path_stats = primary_connection.stats();
+                        self.network.congestion_window.store(path_stats.cwnd as usize, Relaxed);
 
                         callbacks
                             .session_status(
                                 SessionStatus::Connected {
-                                    relayed: primary_connection.is_relay(),
+                                    relayed,
                                     remote_address: match primary_connection.remote_addr() {
                                         TransportAddr::Ip(socket) => socket.ip().to_string(),
                                         TransportAddr::Relay(relay_url) => relay_identifier(relay_url),
@@ -1307,6 +1485,242 @@ mod core_state_tests {
     }
 }
 
+#[cfg(test)]
+mod connection_statistics_tests {
+    use super::ConnectionStatistics;
+    use std::sync::atomic::Ordering::Relaxed;
+    use std::time::Duration;
+
+    #[test]
+    fn bandwidth_is_derived_from_the_byte_totals() {
+        let statistics = ConnectionStatistics::default();
+
+        statistics.update_bytes(1_000, 4_000, Duration::from_secs(1));
+        statistics.update_bytes(3_000, 5_000, Duration::from_millis(500));
+
+        assert_eq!(statistics.bytes_sent.load(Relaxed), 3_000);
+        assert_eq!(statistics.bytes_received.load(Relaxed), 5_000);
+        assert_eq!(statistics.upload_bandwidth.load(Relaxed), 4_000);
+        assert_eq!(statistics.download_bandwidth.load(Relaxed), 2_000);
+    }
+}
+
+#[cfg(test)]
+mod early_call_state_tests {
+    use super::EarlyCallState;
+    use crate::internal::messages::{AudioExtension, AudioHeader};
+    use iroh::SecretKey;
+    use telepathy_audio::codec::CodecId;
+
+    fn header(codec: CodecId, residual_bits: f64) -> AudioHeader {
+        AudioHeader::new(
+            48_000,
+            false,
+            residual_bits,
+            AudioExtension {
+                codec_id: codec.into(),
+                codecs: CodecId::ALL.into_iter().map(u8::from).collect(),
+                channels: 1,
+                redundancy: true,
+                audio_feedback: true,
+            },
+        )
+    }
+
+    /// a header from a client that predates [`AudioExtension`]
+    fn legacy_header(codec_enabled: bool) -> AudioHeader {
+        AudioHeader {
+            sample_rate: 48_000,
+            codec_enabled,
+            vbr: false,
+            residual_bits: 5.0,
+            extension: None,
+        }
+    }
+
+    fn extension(header: &mut AudioHeader) -> &mut AudioExtension {
+        header.extension.as_mut().unwrap()
+    }
+
+    /// negotiates from both sides and checks they agree
+    fn negotiate(local: AudioHeader, remote: AudioHeader) -> (CodecId, bool, f32) {
+        let caller = EarlyCallState {
+            peer: SecretKey::generate().public(),
+            local_configuration: local.clone(),
+            remote_configuration: remote.clone(),
+        };
+        let callee = EarlyCallState {
+            peer: SecretKey::generate().public(),
+            local_configuration: remote,
+            remote_configuration: local,
+        };
+
+        assert_eq!(callee.codec_config(), caller.codec_config());
+        caller.codec_config()
+    }
+
+    #[test]
+    fn codec_config_is_symmetric_and_prefers_compressed_codecs() {
+        let local = header(CodecId::Raw, 5.0);
+        let remote = header(CodecId::Sea, 3.0);
+        assert_eq!(negotiate(local, remote), (CodecId::Sea, false, 3.0));
+    }
+
+    #[test]
+    fn opus_is_used_when_both_peers_support_it() {
+        let local = header(CodecId::Opus, 5.0);
+        let remote = header(CodecId::Sea, 5.0);
+        assert_eq!(negotiate(local, remote).0, CodecId::Opus);
+    }
+
+    #[test]
+    fn opus_falls_back_to_sea_when_the_peer_does_not_advertise_it() {
+        let local = header(CodecId::Opus, 5.0);
+        let mut remote = header(CodecId::Opus, 5.0);
+        extension(&mut remote).codecs = vec![CodecId::Raw.into(), CodecId::Sea.into()];
+        assert_eq!(negotiate(local.clone(), remote).0, CodecId::Sea);
+
+        // a peer that advertises nothing can still take raw audio
+        let mut remote = header(CodecId::Raw, 5.0);
+        extension(&mut remote).codecs.clear();
+        assert_eq!(negotiate(local, remote).0, CodecId::Raw);
+    }
+
+    #[test]
+    fn the_preference_list_decides_between_requested_codecs() {
+        let lossless = header(CodecId::Lossless, 5.0);
+        for (other, expected) in [
+            (CodecId::Opus, CodecId::Opus),
+            (CodecId::Sea, CodecId::Sea),
+            (CodecId::Raw, CodecId::Lossless),
+        ] {
+            assert_eq!(negotiate(lossless.clone(), header(other, 5.0)).0, expected);
+        }
+    }
+
+    #[test]
+    fn lossless_falls_back_to_raw_to_stay_lossless() {
+        let local = header(CodecId::Lossless, 5.0);
+        let mut remote = header(CodecId::Raw, 5.0);
+        extension(&mut remote)
+            .codecs
+            .retain(|id| *id != u8::from(CodecId::Lossless));
+        assert_eq!(negotiate(local, remote).0, CodecId::Raw);
+    }
+
+    #[test]
+    fn legacy_peers_negotiate_the_codec_they_pick() {
+        for codec in CodecId::ALL {
+            for codec_enabled in [false, true] {
+                let local = header(codec, 5.0);
+                // older clients use SEA when either peer enables the codec
+                let expected = if local.codec_enabled || codec_enabled {
+                    CodecId::Sea
+                } else {
+                    CodecId::Raw
+                };
+                assert_eq!(
+                    negotiate(local, legacy_header(codec_enabled)).0,
+                    expected,
+                    "{codec:?} with codec_enabled {codec_enabled}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn channels_negotiate_down_to_the_smaller_count() {
+        let mut local = header(CodecId::Sea, 5.0);
+        extension(&mut local).channels = 2;
+        let remote = header(CodecId::Sea, 5.0);
+
+        let state = EarlyCallState {
+            peer: SecretKey::generate().public(),
+            local_configuration: local.clone(),
+            remote_configuration: remote,
+        };
+        assert_eq!(state.channels(), 1);
+
+        let state = EarlyCallState {
+            remote_configuration: local.clone(),
+            ..state
+        };
+        assert_eq!(state.channels(), 2);
+
+        // older clients send mono
+        let state = EarlyCallState {
+            remote_configuration: legacy_header(true),
+            ..state
+        };
+        assert_eq!(state.channels(), 1);
+
+        // an unsupported channel count is rejected with the header
+        extension(&mut local).channels = 5;
+        assert!(!local.is_valid());
+    }
+
+    #[test]
+    fn redundancy_requires_both_peers() {
+        let local = header(CodecId::Sea, 5.0);
+        let mut remote = header(CodecId::Sea, 5.0);
+
+        let state = EarlyCallState {
+            peer: SecretKey::generate().public(),
+            local_configuration: local,
+            remote_configuration: remote.clone(),
+        };
+        assert!(state.redundancy());
+
+        extension(&mut remote).redundancy = false;
+        let state = EarlyCallState {
+            remote_configuration: remote,
+            ..state
+        };
+        assert!(!state.redundancy());
+
+        let state = EarlyCallState {
+            remote_configuration: legacy_header(true),
+            ..state
+        };
+        assert!(!state.redundancy());
+    }
+
+    #[test]
+    fn audio_feedback_requires_both_peers() {
+        let local = header(CodecId::Sea, 5.0);
+        let mut remote = header(CodecId::Sea, 5.0);
+
+        let state = EarlyCallState {
+            peer: SecretKey::generate().public(),
+            local_configuration: local,
+            remote_configuration: remote.clone(),
+        };
+        assert!(state.audio_feedback());
+
+        extension(&mut remote).audio_feedback = false;
+        let state = EarlyCallState {
+            remote_configuration: remote,
+            ..state
+        };
+        assert!(!state.audio_feedback());
+
+        let state = EarlyCallState {
+            remote_configuration: legacy_header(true),
+            ..state
+        };
+        assert!(!state.audio_feedback());
+    }
+
+    #[test]
+    fn unknown_codec_ids_are_invalid() {
+        let mut header = header(CodecId::Sea, 5.0);
+        assert!(header.is_valid());
+        extension(&mut header).codec_id = u8::MAX;
+        assert!(!header.is_valid());
+        assert_eq!(header.codec(), CodecId::Raw);
+    }
+}
+
 #[cfg(test)]
 mod call_slot_tests {
     use super::{CallSlot, CallSlotAcquireResult, CallSlotState, relay_identifier};
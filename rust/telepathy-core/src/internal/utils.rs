@@ -1,16 +1,18 @@
 use crate::internal::callbacks::CoreStatisticsCallback;
 use crate::internal::error::{AudioStreamError, Error, ErrorKind};
 use crate::internal::messages::ProtocolMessage;
-use crate::internal::state::StatisticsCollectorState;
-use crate::overlay::{CONNECTED, LATENCY, LOSS};
-use crate::types::Statistics;
+use crate::internal::state::{PeerStatisticsState, StatisticsCollectorState};
+use crate::overlay::{CONNECTED, JITTER, LATENCY, PACKET_LOSS, RELAYED};
+use crate::types::{PeerStatistics, Statistics};
 use bytes::Bytes;
 #[cfg(feature = "flutter")]
 pub use flutter_rust_bridge::JoinHandle;
 use futures_util::{SinkExt, StreamExt};
+use iroh::PublicKey;
 use iroh::endpoint::{RecvStream, SendStream};
 use kanal::AsyncReceiver;
 use speedy::{Readable, Writable};
+use std::collections::HashMap;
 use std::future::Future;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
@@ -33,6 +35,9 @@ use wasmtimer::tokio::interval;
 
 type Result<T> = std::result::Result<T, Error>;
 
+/// RMS above which a room participant counts as speaking, about -40 dBFS
+const SPEAKING_RMS: f32 = 0.01;
+
 /// An `AudioDataSink` backed by a `kanal` channel.
 pub(crate) struct KanalSink {
     sender: kanal::Sender<PooledBuffer>,
@@ -84,6 +89,42 @@ pub(crate) fn level_from_window(local_max: f32, max: &mut f32) -> f32 {
     }
 }
 
+/// Processes the statistics of every admitted room participant, sorted by peer id
+///
+/// `output_max` holds the max output RMS of each participant and forgets those
+/// who left.
+fn peer_statistics(
+    peers: &HashMap<PublicKey, PeerStatisticsState>,
+    output_max: &mut HashMap<PublicKey, f32>,
+) -> Vec<PeerStatistics> {
+    output_max.retain(|peer, _| peers.contains_key(peer));
+    let mut statistics: Vec<_> = peers
+        .iter()
+        .map(|(peer, state)| {
+            let rms = state.output_rms.swap(0_f32, Relaxed);
+            PeerStatistics {
+                peer: peer.to_string(),
+                latency: state.network.latency.load(Relaxed),
+                packet_loss: state.receive.take_packet_loss(),
+                jitter: state.receive.jitter(),
+                output_level: level_from_window(rms, output_max.entry(*peer).or_default()),
+                speaking: rms > SPEAKING_RMS,
+            }
+        })
+        .collect();
+    statistics.sort_by(|a, b| a.peer.cmp(&b.peer));
+    statistics
+}
+
+/// The deepest playout delay of a call's or room's jitter buffers, rooms run
+/// one per participant
+fn deepest_jitter_delay(call: usize, peers: &HashMap<PublicKey, PeerStatisticsState>) -> usize {
+    peers
+        .values()
+        .map(|peer| peer.jitter_delay.load(Relaxed))
+        .fold(call, usize::max)
+}
+
 /// Writes a message to the stream
 pub(crate) async fn write_message(
     transport: &mut FramedWrite<SendStream, LengthDelimitedCodec>,
@@ -124,6 +165,10 @@ pub(crate) async fn statistics_collector<C: CoreStatisticsCallback>(
     let mut input_max = 0_f32;
     // max output RMS
     let mut output_max = 0_f32;
+    // max output RMS of each room participant
+    let mut peer_output_max = HashMap::new();
+    // whether the last posted peer statistics had entries
+    let mut peers_posted = false;
 
     loop {
         select! {
@@ -131,34 +176,79 @@ pub(crate) async fn statistics_collector<C: CoreStatisticsCallback>(
                 break;
             }
             _ = update_interval.tick() => {
-                let latency = state.latency.load(Relaxed);
+                let (peers, jitter_delay) = {
+                    let peer_states = state.peers.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                    (
+                        peer_statistics(&peer_states, &mut peer_output_max),
+                        deepest_jitter_delay(state.jitter_delay.load(Relaxed), &peer_states),
+                    )
+                };
+                let network = &state.network;
                 let loss = state.loss.swap(0, Relaxed);
+                // rooms report their worst participant's connection
+                let (latency, packet_loss, jitter) = peers.iter().fold(
+                    (
+                        network.latency.load(Relaxed),
+                        state.receive.take_packet_loss(),
+                        state.receive.jitter(),
+                    ),
+                    |(latency, packet_loss, jitter), peer| {
+                        (
+                            latency.max(peer.latency),
+                            packet_loss.max(peer.packet_loss),
+                            jitter.max(peer.jitter),
+                        )
+                    },
+                );
+                let relayed = network.relayed.load(Relaxed);
                 // update overlay statistics
                 LATENCY.store(latency, Relaxed);
-                LOSS.store(loss, Relaxed);
+                PACKET_LOSS.store(packet_loss, Relaxed);
+                JITTER.store(jitter, Relaxed);
+                RELAYED.store(relayed, Relaxed);
                 // only post statistics if not paused
                 if !statistics_paused.load(Relaxed) {
                     callback.post(Statistics {
                         input_level: level_from_window(state.input_rms.swap(0_f32, Relaxed), &mut input_max),
                         output_level: level_from_window(state.output_rms.swap(0_f32, Relaxed), &mut output_max),
+                        gain: state.input_gain.load(Relaxed),
                         latency,
-                        upload_bandwidth: state.upload_bandwidth.load(Relaxed),
-                        download_bandwidth: state.download_bandwidth.load(Relaxed),
+                        upload_bandwidth: network.upload_bandwidth.load(Relaxed),
+                        download_bandwidth: network.download_bandwidth.load(Relaxed),
                         loss,
+                        jitter_delay,
+                        packet_loss,
+                        jitter,
+                        relayed,
+                        path_changes: network.path_changes.load(Relaxed),
+                        congestion_window: network.congestion_window.load(Relaxed),
+                        bytes_sent: network.bytes_sent.load(Relaxed),
+                        bytes_received: network.bytes_received.load(Relaxed),
                     }).await;
+                    // post once more after the last participant left to clear them
+                    if !peers.is_empty() || peers_posted {
+                        peers_posted = !peers.is_empty();
+                        callback.post_peers(peers).await;
+                    }
                 }
             }
             _ = reset_interval.tick() => {
                 input_max /= 2_f32;
                 output_max /= 2_f32;
+                peer_output_max.values_mut().for_each(|max| *max /= 2_f32);
             }
         }
     }
 
     // zero out the statistics when the collector ends
     callback.post(Statistics::default()).await;
+    if peers_posted {
+        callback.post_peers(Vec::new()).await;
+    }
     LATENCY.store(0, Relaxed);
-    LOSS.store(0, Relaxed);
+    PACKET_LOSS.store(0.0, Relaxed);
+    JITTER.store(0.0, Relaxed);
+    RELAYED.store(false, Relaxed);
     CONNECTED.store(false, Relaxed);
     debug!("statistics collector returning");
 }
@@ -274,3 +364,88 @@ pub(crate) fn deactivate_audio_session() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::state::ConnectionStatistics;
+    use atomic_float::AtomicF32;
+    use iroh::SecretKey;
+
+    fn peer_state(latency: usize, output_rms: f32) -> PeerStatisticsState {
+        let network = Arc::new(ConnectionStatistics::default());
+        network.latency.store(latency, Relaxed);
+        PeerStatisticsState {
+            network,
+            receive: Arc::new(Default::default()),
+            output_rms: Arc::new(AtomicF32::new(output_rms)),
+            jitter_delay: Arc::new(Default::default()),
+        }
+    }
+
+    #[test]
+    fn peer_statistics_report_each_participant_sorted_by_peer() {
+        let (quiet, loud) = (
+            SecretKey::generate().public(),
+            SecretKey::generate().public(),
+        );
+        let peers = HashMap::from([(quiet, peer_state(40, 0.001)), (loud, peer_state(90, 0.2))]);
+        let mut output_max = HashMap::new();
+
+        let statistics = peer_statistics(&peers, &mut output_max);
+
+        assert_eq!(statistics.len(), 2);
+        assert!(statistics[0].peer < statistics[1].peer);
+        let loud_statistics = statistics
+            .iter()
+            .find(|s| s.peer == loud.to_string())
+            .unwrap();
+        let quiet_statistics = statistics
+            .iter()
+            .find(|s| s.peer == quiet.to_string())
+            .unwrap();
+        assert_eq!(loud_statistics.latency, 90);
+        assert!(loud_statistics.speaking);
+        assert_eq!(loud_statistics.output_level, 1.0);
+        assert_eq!(quiet_statistics.latency, 40);
+        assert!(!quiet_statistics.speaking);
+        // the window RMS is consumed
+        assert_eq!(peers[&loud].output_rms.load(Relaxed), 0.0);
+    }
+
+    #[test]
+    fn peer_statistics_forget_the_level_of_departed_participants() {
+        let (staying, leaving) = (
+            SecretKey::generate().public(),
+            SecretKey::generate().public(),
+        );
+        let mut peers =
+            HashMap::from([(staying, peer_state(0, 0.1)), (leaving, peer_state(0, 0.1))]);
+        let mut output_max = HashMap::new();
+        peer_statistics(&peers, &mut output_max);
+        assert_eq!(output_max.len(), 2);
+
+        peers.remove(&leaving);
+        peers[&staying].output_rms.store(0.05, Relaxed);
+        let statistics = peer_statistics(&peers, &mut output_max);
+
+        assert_eq!(statistics.len(), 1);
+        assert_eq!(output_max.len(), 1);
+        assert_eq!(statistics[0].output_level, 0.5);
+    }
+
+    #[test]
+    fn rooms_report_the_deepest_jitter_buffer() {
+        let (steady, jittery) = (
+            SecretKey::generate().public(),
+            SecretKey::generate().public(),
+        );
+        let peers = HashMap::from([(steady, peer_state(0, 0.0)), (jittery, peer_state(0, 0.0))]);
+        peers[&steady].jitter_delay.store(20, Relaxed);
+        peers[&jittery].jitter_delay.store(80, Relaxed);
+
+        assert_eq!(deepest_jitter_delay(0, &peers), 80);
+        // calls have no participants and report their own buffer
+        assert_eq!(deepest_jitter_delay(40, &HashMap::new()), 40);
+    }
+}
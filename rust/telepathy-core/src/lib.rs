@@ -1,3 +1,4 @@
+use serde::Serialize;
 use telepathy_audio::devices::AudioDeviceInfo;
 
 #[cfg(feature = "flutter")]
@@ -12,6 +13,7 @@ pub mod overlay;
 pub mod player;
 pub mod types;
 
+#[derive(Debug, Clone, Serialize)]
 pub struct AudioDevice {
     pub name: String,
     pub id: String,
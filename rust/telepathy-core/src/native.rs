@@ -3,7 +3,8 @@ use crate::internal::TelepathyHandle;
 use crate::internal::callbacks::{CoreCallbacks, CoreStatisticsCallback};
 use crate::internal::{JoinHandle, spawn_task};
 use crate::types::{
-    CallState, ChatMessage, Contact, FrontendNotify, ManagerState, SessionStatus, Statistics,
+    CallState, ChatMessage, Contact, DeviceEvent, FrontendNotify, ManagerState, PeerStatistics,
+    RecordingFormat, RecordingLayout, RecordingState, SessionStatus, Statistics,
 };
 use iroh::PublicKey;
 use std::future::Future;
@@ -172,6 +173,26 @@ impl NativeTelepathy {
         self.handle.set_denoise(denoise);
     }
 
+    pub fn set_echo_cancellation(&self, enabled: bool) {
+        self.handle.set_echo_cancellation(enabled);
+    }
+
+    pub fn set_auto_gain(&self, enabled: bool) {
+        self.handle.set_auto_gain(enabled);
+    }
+
+    pub fn set_voice_gate(&self, enabled: bool) {
+        self.handle.set_voice_gate(enabled);
+    }
+
+    pub fn set_jitter_buffer_bounds(&self, min_ms: u32, max_ms: u32) {
+        self.handle.set_jitter_buffer_bounds(min_ms, max_ms);
+    }
+
+    pub fn set_redundancy(&self, enabled: bool) {
+        self.handle.set_redundancy(enabled);
+    }
+
     pub fn set_play_custom_ringtones(&self, play: bool) {
         self.handle.set_play_custom_ringtones(play);
     }
@@ -210,18 +231,42 @@ impl NativeTelepathy {
             .await
             .map_err(|e| e.to_string())
     }
+
+    pub async fn start_recording(
+        &self,
+        path: String,
+        format: RecordingFormat,
+        layout: RecordingLayout,
+    ) -> Result<(), String> {
+        self.handle
+            .start_recording(path, format, layout)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn stop_recording(&self) -> Result<(), String> {
+        self.handle
+            .stop_recording()
+            .await
+            .map_err(|e| e.to_string())
+    }
 }
 
 /// Statistics callback adapter for non-FRB clients.
 #[derive(Clone)]
 pub struct NativeStatisticsCallback {
     inner: NativeVoid<Statistics>,
+    peers: NativeVoid<Vec<PeerStatistics>>,
 }
 
 impl CoreStatisticsCallback for NativeStatisticsCallback {
     async fn post(&self, stats: Statistics) {
         (self.inner)(stats).await
     }
+
+    async fn post_peers(&self, peers: Vec<PeerStatistics>) {
+        (self.peers)(peers).await
+    }
 }
 
 /// Rust-native callback surface for `telepathy-core`.
@@ -243,6 +288,9 @@ pub struct NativeCallbacks {
     message_received: NativeVoid<ChatMessage>,
     manager_active: NativeVoid<ManagerState>,
     screenshare_started: NativeVoid<(FrontendNotify, bool)>,
+    recording_state: NativeVoid<RecordingState>,
+    device_event: NativeVoid<DeviceEvent>,
+    peer_statistics: NativeVoid<Vec<PeerStatistics>>,
 }
 
 impl NativeCallbacks {
@@ -260,6 +308,9 @@ impl NativeCallbacks {
         message_received: impl Fn(ChatMessage) -> NativeFuture<()> + Send + Sync + 'static,
         manager_active: impl Fn(ManagerState) -> NativeFuture<()> + Send + Sync + 'static,
         screenshare_started: impl Fn((FrontendNotify, bool)) -> NativeFuture<()> + Send + Sync + 'static,
+        recording_state: impl Fn(RecordingState) -> NativeFuture<()> + Send + Sync + 'static,
+        device_event: impl Fn(DeviceEvent) -> NativeFuture<()> + Send + Sync + 'static,
+        peer_statistics: impl Fn(Vec<PeerStatistics>) -> NativeFuture<()> + Send + Sync + 'static,
     ) -> Self {
         Self {
             accept_call: Arc::new(accept_call),
@@ -271,6 +322,9 @@ impl NativeCallbacks {
             message_received: Arc::new(message_received),
             manager_active: Arc::new(manager_active),
             screenshare_started: Arc::new(screenshare_started),
+            recording_state: Arc::new(recording_state),
+            device_event: Arc::new(device_event),
+            peer_statistics: Arc::new(peer_statistics),
         }
     }
 }
@@ -331,9 +385,18 @@ impl CoreCallbacks for NativeCallbacks {
         (self.message_received)(chat_message).await
     }
 
+    async fn recording_state(&self, state: RecordingState) {
+        (self.recording_state)(state).await
+    }
+
+    async fn device_event(&self, event: DeviceEvent) {
+        (self.device_event)(event).await
+    }
+
     fn statistics_callback(&self) -> NativeStatisticsCallback {
         NativeStatisticsCallback {
             inner: Arc::clone(&self.statistics),
+            peers: Arc::clone(&self.peer_statistics),
         }
     }
 }
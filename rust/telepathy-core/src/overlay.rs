@@ -9,6 +9,7 @@ use std::time::Duration;
 
 #[cfg(windows)]
 use crate::internal::spawn_task;
+use atomic_float::AtomicF32;
 #[cfg(windows)]
 use kanal::Sender;
 use lazy_static::lazy_static;
@@ -44,7 +45,9 @@ mod windows;
 
 lazy_static! {
     pub(crate) static ref LATENCY: Arc<AtomicUsize> = Default::default();
-    pub(crate) static ref LOSS: Arc<AtomicUsize> = Default::default();
+    pub(crate) static ref PACKET_LOSS: Arc<AtomicF32> = Default::default();
+    pub(crate) static ref JITTER: Arc<AtomicF32> = Default::default();
+    pub(crate) static ref RELAYED: Arc<AtomicBool> = Default::default();
     pub(crate) static ref CONNECTED: Arc<AtomicBool> = Default::default();
     static ref FONT_HEIGHT: Arc<AtomicI32> = Default::default();
     static ref BACKGROUND_COLOR: Arc<AtomicU32> = Default::default();
@@ -4,7 +4,9 @@ use std::sync::atomic::Ordering::Relaxed;
 
 use crate::overlay::color::{BAD_COLOR, percent_to_color};
 use crate::overlay::error::Result;
-use crate::overlay::{BACKGROUND_COLOR, CONNECTED, FONT_COLOR, FONT_HEIGHT, LATENCY, LOSS};
+use crate::overlay::{
+    BACKGROUND_COLOR, CONNECTED, FONT_COLOR, FONT_HEIGHT, JITTER, LATENCY, PACKET_LOSS, RELAYED,
+};
 use tracing::{error, info};
 use widestring::U16CString;
 use windows::Win32::Foundation::{
@@ -230,10 +232,30 @@ unsafe fn draw_overlay(hwnd: HWND) {
             string_format,
         );
 
-        let loss = LOSS.load(Relaxed);
-        let color = percent_to_color((loss as f64 / 1000.0).min(1_f64));
+        let loss = PACKET_LOSS.load(Relaxed) as f64;
+        let color = percent_to_color((loss / 0.1).min(1_f64));
+        bounding = draw_text(
+            &format!("{:.1}%", loss * 100.0),
+            (bounding.X + bounding.Width, 0.0),
+            color.argb(),
+            graphics,
+            font,
+            string_format,
+        );
+
+        bounding = draw_text(
+            "Jitter:",
+            (bounding.X + bounding.Width + 30.0, 0.0),
+            FONT_COLOR.load(Relaxed),
+            graphics,
+            font,
+            string_format,
+        );
+
+        let jitter = JITTER.load(Relaxed) as f64;
+        let color = percent_to_color((jitter / 50.0).min(1_f64));
         bounding = draw_text(
-            &loss.to_string(),
+            &format!("{jitter:.0}"),
             (bounding.X + bounding.Width, 0.0),
             color.argb(),
             graphics,
@@ -241,6 +263,17 @@ unsafe fn draw_overlay(hwnd: HWND) {
             string_format,
         );
 
+        if RELAYED.load(Relaxed) {
+            bounding = draw_text(
+                "Relayed",
+                (bounding.X + bounding.Width + 30.0, 0.0),
+                FONT_COLOR.load(Relaxed),
+                graphics,
+                font,
+                string_format,
+            );
+        }
+
         if !CONNECTED.load(Relaxed) {
             _ = draw_text(
                 "Disconnected",
@@ -8,6 +8,7 @@ use crate::types::DartError;
 #[cfg(not(target_family = "wasm"))]
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 use telepathy_audio::Host;
 use telepathy_audio::player::{AudioPlayer, SoundHandle, wav_to_sea};
 #[cfg(not(target_family = "wasm"))]
@@ -49,8 +50,8 @@ impl SoundPlayer {
 
     /// Plays audio from the provided bytes.
     ///
-    /// Supports both WAV files (with standard 44-byte header) and SEA codec files.
-    /// The format is auto-detected based on header validation.
+    /// Supports both WAV files and SEA codec files. Files starting with
+    /// `RIFF` are parsed as WAV, anything else as SEA.
     ///
     /// # Arguments
     ///
@@ -58,12 +59,13 @@ impl SoundPlayer {
     ///
     /// # Returns
     ///
-    /// A `FlutterSoundHandle` that can be used to cancel playback.
+    /// A `FlutterSoundHandle` that can be used to pause, seek, loop or cancel playback.
     ///
     /// # Errors
     ///
     /// Returns `DartError` if:
     /// - The file is too short (< 14 bytes)
+    /// - The WAV chunks or SEA header are malformed
     /// - No output device is available
     /// - Stream configuration cannot be obtained
     /// - Stream creation fails
@@ -100,7 +102,8 @@ impl SoundPlayer {
 /// Flutter-compatible handle for controlling active sound playback.
 ///
 /// This handle wraps the library's `SoundHandle` and provides Flutter
-/// Rust Bridge attributes for Dart interop.
+/// Rust Bridge attributes for Dart interop. Positions are exchanged in
+/// milliseconds.
 #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(opaque))]
 pub struct FlutterSoundHandle(SoundHandle);
 
@@ -112,6 +115,60 @@ impl FlutterSoundHandle {
     pub fn cancel(&self) {
         self.0.cancel();
     }
+
+    /// Pauses playback without closing the output stream.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn pause(&self) {
+        self.0.pause();
+    }
+
+    /// Resumes paused playback.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn resume(&self) {
+        self.0.resume();
+    }
+
+    /// Returns true while playback is paused.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn is_paused(&self) -> bool {
+        self.0.is_paused()
+    }
+
+    /// Jumps to a position in milliseconds, clamped to the duration.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn seek(&self, position_ms: u64) {
+        self.0.seek(Duration::from_millis(position_ms));
+    }
+
+    /// Enables or disables looping.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn set_looping(&self, looping: bool) {
+        self.0.set_looping(looping);
+    }
+
+    /// Returns true while the sound loops.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn is_looping(&self) -> bool {
+        self.0.is_looping()
+    }
+
+    /// Sets the volume of this sound in decibels, on top of the player volume.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn set_volume(&self, volume: f32) {
+        self.0.set_volume(volume);
+    }
+
+    /// The playback position in milliseconds.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn position_ms(&self) -> u64 {
+        self.0.position().as_millis() as u64
+    }
+
+    /// The duration of the sound in milliseconds.
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn duration_ms(&self) -> u64 {
+        self.0.duration().as_millis() as u64
+    }
 }
 
 /// Loads a ringtone from a WAV file and converts it to SEA format.
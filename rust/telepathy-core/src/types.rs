@@ -1,3 +1,4 @@
+use crate::AudioDevice;
 use crate::internal::error::{Error, ErrorKind};
 use crate::internal::messages::Attachment;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
@@ -19,7 +20,9 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::RwLock as StdRwLock;
 use std::sync::atomic::Ordering::Relaxed;
-use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU32};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU16, AtomicU32};
+use telepathy_audio::codec::{CodecId, supports_channels};
+use telepathy_audio::devices::DeviceDirection;
 use tokio::sync::{Notify, RwLock};
 use url::Url;
 use uuid::Uuid;
@@ -150,6 +153,37 @@ pub enum CallState {
     CallEnded(String, bool),
 }
 
+/// reported through `CoreCallbacks::recording_state`
+#[derive(Debug, Serialize, Clone)]
+pub enum RecordingState {
+    /// recording to the given path
+    Started(String),
+    /// the recording ended after writing these files
+    Stopped(Vec<String>),
+    /// the recording ended with an error
+    Failed(String),
+}
+
+/// reported through `CoreCallbacks::device_event`
+#[derive(Debug, Serialize, Clone)]
+pub enum DeviceEvent {
+    /// a device was connected
+    Added { input: bool, device: AudioDevice },
+    /// a device was disconnected
+    Removed { input: bool, device: AudioDevice },
+    /// the system default device changed, `None` when there is no default
+    DefaultChanged {
+        input: bool,
+        device: Option<AudioDevice>,
+    },
+    /// a call or room moved to another device after its device failed,
+    /// `None` is the system default
+    Switched {
+        input: bool,
+        device: Option<AudioDevice>,
+    },
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub enum SessionStatus {
     Connecting,
@@ -201,7 +235,6 @@ impl ChatMessage {
 }
 
 /// processed statistics for the frontend
-// TODO add packet loss & other connection stats
 #[derive(Default, Serialize)]
 pub struct Statistics {
     /// a percentage of the max input volume in the window
@@ -210,17 +243,67 @@ pub struct Statistics {
     /// a percentage of the max output volume in the window
     pub output_level: f32,
 
-    /// the current call latency
+    /// the linear gain applied by automatic gain control, 1.0 when it is disabled
+    pub gain: f32,
+
+    /// the round trip time of the connection in milliseconds
     pub latency: usize,
 
-    /// the approximate upload bandwidth used by the current call
+    /// the bytes per second sent over the connection
     pub upload_bandwidth: usize,
 
-    /// the approximate download bandwidth used by the current call
+    /// the bytes per second received over the connection
     pub download_bandwidth: usize,
 
     /// the number of output samples that were lost in the interval
     pub loss: usize,
+
+    /// the playout delay of the adaptive jitter buffer in milliseconds,
+    /// the deepest participant's in rooms
+    pub jitter_delay: usize,
+
+    /// the share of audio datagrams missing from the received sequence in the interval
+    pub packet_loss: f32,
+
+    /// the inter-arrival jitter of received audio in milliseconds
+    pub jitter: f32,
+
+    /// whether the connection goes through a relay instead of a direct path
+    pub relayed: bool,
+
+    /// how often the connection switched between a direct and a relayed path
+    pub path_changes: usize,
+
+    /// the congestion window of the connection in bytes
+    pub congestion_window: usize,
+
+    /// the total bytes sent over the connection
+    pub bytes_sent: usize,
+
+    /// the total bytes received over the connection
+    pub bytes_received: usize,
+}
+
+/// processed statistics of a single room participant for the frontend
+#[derive(Debug, Serialize, Clone)]
+pub struct PeerStatistics {
+    /// the peer id of the participant
+    pub peer: String,
+
+    /// the round trip time of the connection to the participant in milliseconds
+    pub latency: usize,
+
+    /// the share of the participant's audio datagrams missing in the interval
+    pub packet_loss: f32,
+
+    /// the inter-arrival jitter of the participant's audio in milliseconds
+    pub jitter: f32,
+
+    /// a percentage of the participant's max output volume in the window
+    pub output_level: f32,
+
+    /// whether the participant was audible in the interval
+    pub speaking: bool,
 }
 
 /// a shared notifier that can be passed to frontend code
@@ -625,17 +708,103 @@ impl From<ScreenshareConfigDisk> for ScreenshareConfig {
     }
 }
 
+/// the compressed codec offered to peers when the codec is enabled
+#[derive(Debug, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AudioCodec {
+    #[default]
+    Sea,
+    /// falls back to sea when the peer does not support opus
+    Opus,
+    /// bit exact audio, smaller than raw
+    Lossless,
+}
+
+impl From<AudioCodec> for CodecId {
+    fn from(codec: AudioCodec) -> Self {
+        match codec {
+            AudioCodec::Sea => CodecId::Sea,
+            AudioCodec::Opus => CodecId::Opus,
+            AudioCodec::Lossless => CodecId::Lossless,
+        }
+    }
+}
+
+/// the file format of a call recording
+#[derive(Debug, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RecordingFormat {
+    /// 16-bit PCM wav
+    #[default]
+    Wav,
+    /// sea codec, playable like any other sea sound
+    Sea,
+}
+
+impl From<RecordingFormat> for telepathy_audio::recorder::RecordingFormat {
+    fn from(format: RecordingFormat) -> Self {
+        match format {
+            RecordingFormat::Wav => Self::Wav,
+            RecordingFormat::Sea => Self::Sea,
+        }
+    }
+}
+
+/// whether a call recording mixes everyone into one file
+#[derive(Debug, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RecordingLayout {
+    #[default]
+    Mixed,
+    /// one file for the local user and one for each peer
+    SeparateTracks,
+}
+
+impl From<telepathy_audio::devices::DeviceEvent> for DeviceEvent {
+    fn from(value: telepathy_audio::devices::DeviceEvent) -> Self {
+        use telepathy_audio::devices::DeviceEvent as AudioDeviceEvent;
+
+        match value {
+            AudioDeviceEvent::Added { direction, device } => Self::Added {
+                input: direction == DeviceDirection::Input,
+                device: device.into(),
+            },
+            AudioDeviceEvent::Removed { direction, device } => Self::Removed {
+                input: direction == DeviceDirection::Input,
+                device: device.into(),
+            },
+            AudioDeviceEvent::DefaultChanged { direction, device } => Self::DefaultChanged {
+                input: direction == DeviceDirection::Input,
+                device: device.map(Into::into),
+            },
+        }
+    }
+}
+
+impl From<RecordingLayout> for telepathy_audio::recorder::RecordingLayout {
+    fn from(layout: RecordingLayout) -> Self {
+        match layout {
+            RecordingLayout::Mixed => Self::Mixed,
+            RecordingLayout::SeparateTracks => Self::SeparateTracks,
+        }
+    }
+}
+
 #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(opaque))]
 #[derive(Clone, Default)]
 pub struct CodecConfig {
     /// whether to use the codec
     pub(crate) enabled: Arc<AtomicBool>,
 
+    /// which codec to use when enabled, an [`AudioCodec`]
+    pub(crate) codec: Arc<AtomicU8>,
+
     /// whether to use variable bitrate
     pub(crate) vbr: Arc<AtomicBool>,
 
     /// the compression level
     pub(crate) residual_bits: Arc<AtomicF32>,
+
+    /// the number of audio channels to capture & send
+    pub(crate) channels: Arc<AtomicU16>,
 }
 
 impl CodecConfig {
@@ -643,8 +812,10 @@ impl CodecConfig {
     pub fn new(enabled: bool, vbr: bool, residual_bits: f32) -> Self {
         Self {
             enabled: Arc::new(AtomicBool::new(enabled)),
+            codec: Default::default(),
             vbr: Arc::new(AtomicBool::new(vbr)),
             residual_bits: Arc::new(AtomicF32::new(residual_bits)),
+            channels: Arc::new(AtomicU16::new(1)),
         }
     }
 
@@ -653,6 +824,36 @@ impl CodecConfig {
         self.enabled.store(enabled, Relaxed);
     }
 
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn set_codec(&self, codec: AudioCodec) {
+        self.codec.store(codec as u8, Relaxed);
+    }
+
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn codec(&self) -> AudioCodec {
+        match self.codec.load(Relaxed) {
+            1 => AudioCodec::Opus,
+            2 => AudioCodec::Lossless,
+            _ => AudioCodec::Sea,
+        }
+    }
+
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn set_channels(&self, channels: u16) {
+        self.channels.store(channels, Relaxed);
+    }
+
+    /// the configured channel count, mono when it is not supported
+    #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+    pub fn channels(&self) -> u16 {
+        let channels = self.channels.load(Relaxed);
+        if supports_channels(channels) {
+            channels
+        } else {
+            1
+        }
+    }
+
     #[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
     pub fn set_vbr(&self, vbr: bool) {
         self.vbr.store(vbr, Relaxed);
@@ -893,7 +1094,8 @@ fn poison_field_error(
 
 #[cfg(test)]
 mod tests {
-    use super::{NetworkConfig, NetworkConfigField};
+    use super::{AudioCodec, CodecConfig, NetworkConfig, NetworkConfigField};
+    use telepathy_audio::codec::CodecId;
 
     const VALID_RELAY_A: &str = "https://relay-us.iroh.example/";
     const VALID_RELAY_B: &str = "https://relay-eu.iroh.example/";
@@ -905,6 +1107,34 @@ mod tests {
         items.iter().map(|s| s.to_string()).collect()
     }
 
+    #[test]
+    fn codec_config_defaults_to_sea_and_selects_other_codecs() {
+        let config = CodecConfig::new(true, false, 5.0);
+        assert_eq!(config.codec(), AudioCodec::Sea);
+
+        config.set_codec(AudioCodec::Opus);
+        assert_eq!(config.codec(), AudioCodec::Opus);
+        assert_eq!(CodecId::from(config.codec()), CodecId::Opus);
+        // clones share the selection like the other codec settings
+        assert_eq!(config.clone().codec(), AudioCodec::Opus);
+
+        config.set_codec(AudioCodec::Lossless);
+        assert_eq!(CodecId::from(config.codec()), CodecId::Lossless);
+    }
+
+    #[test]
+    fn codec_config_defaults_to_mono_and_ignores_unsupported_channels() {
+        let config = CodecConfig::new(true, false, 5.0);
+        assert_eq!(config.channels(), 1);
+        assert_eq!(CodecConfig::default().channels(), 1);
+
+        config.set_channels(2);
+        assert_eq!(config.clone().channels(), 2);
+
+        config.set_channels(5);
+        assert_eq!(config.channels(), 1);
+    }
+
     #[test]
     fn default_constructor_leaves_optionals_unset_and_listens_on_unspecified_v4() {
         let config = NetworkConfig::default();
@@ -256,13 +256,14 @@ async fn room_input_stream_error_removes_only_failing_peer() {
     room_members.sort();
     let input_error_probe = StreamErrorProbe::new();
     let output_error_probe = StreamErrorProbe::new();
+    let host_a = CallbackCapturingAudioHost::new(input_error_probe.clone(), output_error_probe);
 
     let client_a = build_client(
         relay_map,
         key_a,
         vec![contact_b.clone(), contact_c.clone()],
         &codec_config,
-        CallbackCapturingAudioHost::new(input_error_probe.clone(), output_error_probe),
+        host_a.clone(),
         call_states_a.clone(),
     )
     .await;
@@ -328,6 +329,8 @@ async fn room_input_stream_error_removes_only_failing_peer() {
     wait_for_room_join_count(&call_states_c, &peer_b, 1).await;
 
     input_error_probe.wait_captured().await;
+    // without another device to move to, the stream error ends the room
+    host_a.block_device_opens();
     input_error_probe.trigger(simulated_stream_error(
         "simulated input device disconnected",
     ));
@@ -383,13 +386,15 @@ async fn room_stream_error_surfaces_local_message(trigger_input: bool) {
     let room_members = sorted_room_members(&contact_a, &contact_b);
     let input_error_probe = StreamErrorProbe::new();
     let output_error_probe = StreamErrorProbe::new();
+    let host_a =
+        CallbackCapturingAudioHost::new(input_error_probe.clone(), output_error_probe.clone());
 
     let client_a = build_client(
         relay_map,
         key_a,
         vec![contact_b.clone()],
         &codec_config,
-        CallbackCapturingAudioHost::new(input_error_probe.clone(), output_error_probe.clone()),
+        host_a.clone(),
         call_states_a.clone(),
     )
     .await;
@@ -434,6 +439,8 @@ async fn room_stream_error_surfaces_local_message(trigger_input: bool) {
     let (probe, expected_message, simulated_message) =
         stream_error_scenario(trigger_input, &input_error_probe, &output_error_probe);
     probe.wait_captured().await;
+    // without another device to move to, the stream error ends the call
+    host_a.block_device_opens();
     probe.trigger(simulated_stream_error(simulated_message));
 
     // Terminal contract: local `CallEnded`, remote `RoomLeave`, local slot release,
@@ -483,6 +490,8 @@ async fn normal_call_stream_error_surfaces_local_message(trigger_input: bool) {
     let call_states_b = Arc::new(Mutex::new(Vec::new()));
     let input_error_probe = StreamErrorProbe::new();
     let output_error_probe = StreamErrorProbe::new();
+    let host_a =
+        CallbackCapturingAudioHost::new(input_error_probe.clone(), output_error_probe.clone());
     let accept_probe_b = PendingAcceptProbe::default();
 
     let client_a = build_client(
@@ -490,7 +499,7 @@ async fn normal_call_stream_error_surfaces_local_message(trigger_input: bool) {
         key_a,
         vec![contact_b.clone()],
         &codec_config,
-        CallbackCapturingAudioHost::new(input_error_probe.clone(), output_error_probe.clone()),
+        host_a.clone(),
         call_states_a.clone(),
     )
     .await;
@@ -533,6 +542,8 @@ async fn normal_call_stream_error_surfaces_local_message(trigger_input: bool) {
     let (probe, expected_message, simulated_message) =
         stream_error_scenario(trigger_input, &input_error_probe, &output_error_probe);
     probe.wait_captured().await;
+    // without another device to move to, the stream error ends the call
+    host_a.block_device_opens();
     probe.trigger(simulated_stream_error(simulated_message));
 
     wait_for_call_ended_contains(&call_states_a, expected_message, false, "alice").await;
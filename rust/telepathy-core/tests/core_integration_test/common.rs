@@ -11,13 +11,15 @@ use telepathy_audio::devices::{MockAudioInput, MockAudioOutput};
 use telepathy_audio::internal::traits::{AudioInput, AudioOutput};
 use telepathy_audio::io::StreamErrorCallback;
 use telepathy_audio::{CpalError, CpalErrorKind};
+use telepathy_core::AudioDevice;
 use telepathy_core::internal::TelepathyHandle;
 use telepathy_core::internal::callbacks::{MockCoreCallbacks, MockCoreStatisticsCallback};
 use telepathy_core::internal::state::CallSlotState;
 use telepathy_core::overlay::Overlay;
 use telepathy_core::types::Contact;
 use telepathy_core::types::{
-    CallState, CodecConfig, ManagerState, NetworkConfig, ScreenshareConfig, SessionStatus,
+    CallState, CodecConfig, DeviceEvent, ManagerState, NetworkConfig, ScreenshareConfig,
+    SessionStatus,
 };
 use tokio::select;
 use tokio::sync::{Notify, watch};
@@ -50,6 +52,7 @@ where
     pub(super) is_active: Arc<AtomicBool>,
     pub(super) contact_lookup_probe: ContactLookupProbe,
     pub(super) session_status_probe: SessionStatusProbe,
+    pub(super) device_event_probe: DeviceEventProbe,
 }
 
 impl<H> ClientHarness<H>
@@ -287,6 +290,49 @@ pub(super) struct StreamErrorProbe {
     ready: Arc<Notify>,
 }
 
+/// Records the device events delivered to the frontend.
+#[derive(Clone, Default)]
+pub(super) struct DeviceEventProbe {
+    events: Arc<Mutex<Vec<DeviceEvent>>>,
+    changed: Arc<Notify>,
+}
+
+impl DeviceEventProbe {
+    fn record(&self, event: DeviceEvent) {
+        self.events.lock().unwrap().push(event);
+        self.changed.notify_one();
+    }
+
+    /// Waits for a stream to move to another device and returns that device,
+    /// `None` being the system default.
+    pub(super) async fn wait_for_switch(&self, input: bool) -> Option<AudioDevice> {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(60);
+        loop {
+            let switched = self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .find_map(|event| match event {
+                    DeviceEvent::Switched {
+                        input: switched_input,
+                        device,
+                    } if *switched_input == input => Some(device.clone()),
+                    _ => None,
+                });
+            if let Some(device) = switched {
+                return device;
+            }
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "timed out waiting for a device switch (input {input}); events={:?}",
+                self.events.lock().unwrap()
+            );
+            select! { _ = self.changed.notified() => {}, _ = sleep(Duration::from_millis(100)) => {} }
+        }
+    }
+}
+
 /// Blocks `input_sample_rate` after recording its device selection. Tests use
 /// this to close the control stream before a setup failure resumes.
 #[derive(Clone, Default)]
@@ -545,6 +591,13 @@ impl CallbackCapturingAudioHost {
         }
     }
 
+    /// Makes every later `open_input` / `open_output` fail, so a failed
+    /// stream has no device to move to.
+    pub(super) fn block_device_opens(&self) {
+        self.fail_input_synchronously.store(true, Relaxed);
+        self.fail_output_synchronously.store(true, Relaxed);
+    }
+
     pub(super) fn with_device_selection_probe(mut self, probe: DeviceSelectionProbe) -> Self {
         self.device_selection_probe = probe;
         self
@@ -693,6 +746,7 @@ impl AudioHost for CallbackCapturingAudioHost {
     fn open_input(
         &self,
         device_id: Option<&str>,
+        _channels: u16,
         error_callback: Option<StreamErrorCallback>,
     ) -> Result<
         (impl AudioInput + Send + 'static, u32, Self::InputStream),
@@ -723,6 +777,7 @@ impl AudioHost for CallbackCapturingAudioHost {
     fn open_output(
         &self,
         device_id: Option<&str>,
+        _channels: u16,
         error_callback: Option<StreamErrorCallback>,
     ) -> Result<
         (impl AudioOutput + Send + 'static, u32, Self::OutputStream),
@@ -747,6 +802,18 @@ impl AudioHost for CallbackCapturingAudioHost {
         self.output_error_probe.capture(error_callback);
         Ok((MockAudioOutput, DEFAULT_SAMPLE_RATE, ()))
     }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn watch_devices(
+        &self,
+    ) -> Result<
+        tokio::sync::mpsc::UnboundedReceiver<telepathy_audio::devices::DeviceEvent>,
+        telepathy_audio::devices::DeviceError,
+    > {
+        // the device set never changes, so the watcher ends immediately
+        let (_, receiver) = tokio::sync::mpsc::unbounded_channel();
+        Ok(receiver)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1153,6 +1220,7 @@ where
         is_active,
         contact_lookup_probe: Default::default(),
         session_status_probe,
+        device_event_probe: Default::default(),
     }
 }
 
@@ -1217,6 +1285,7 @@ where
         is_active,
         contact_lookup_probe: Default::default(),
         session_status_probe,
+        device_event_probe: Default::default(),
     }
 }
 
@@ -1281,6 +1350,7 @@ where
         is_active,
         contact_lookup_probe: Default::default(),
         session_status_probe,
+        device_event_probe: Default::default(),
     }
 }
 
@@ -1342,6 +1412,7 @@ where
     let is_relayed = Arc::new(AtomicBool::new(false));
     let contact_lookup_probe = ContactLookupProbe::default();
     let session_status_probe = SessionStatusProbe::default();
+    let device_event_probe = DeviceEventProbe::default();
     let mock = construct_mock_callbacks_with_contact_lookup(
         contacts,
         initial_contacts,
@@ -1355,6 +1426,7 @@ where
         None,
         Some(contact_lookup_probe.clone()),
         Some(session_status_probe.clone()),
+        Some(device_event_probe.clone()),
     );
 
     let mut telepathy: MockTelepathyHandle<H> = TelepathyHandle::new(
@@ -1374,6 +1446,7 @@ where
         is_active,
         contact_lookup_probe,
         session_status_probe,
+        device_event_probe,
     }
 }
 
@@ -1411,6 +1484,7 @@ pub(super) fn construct_mock_callbacks(
         call_ended_park,
         None,
         session_status_probe,
+        None,
     )
 }
 
@@ -1428,6 +1502,7 @@ fn construct_mock_callbacks_with_contact_lookup(
     call_ended_park: Option<CallEndedPark>,
     contact_lookup_probe: Option<ContactLookupProbe>,
     session_status_probe: Option<SessionStatusProbe>,
+    device_event_probe: Option<DeviceEventProbe>,
 ) -> MockCoreCallbacks {
     let mut mock = MockCoreCallbacks::new();
 
@@ -1623,11 +1698,21 @@ fn construct_mock_callbacks_with_contact_lookup(
         })
     });
 
+    mock.expect_device_event().returning(move |event| {
+        info!("got device event: {event:?}");
+        if let Some(probe) = &device_event_probe {
+            probe.record(event);
+        }
+        Box::pin(async move {})
+    });
+
     mock.expect_statistics_callback().returning(|| {
         let mut mock = MockCoreStatisticsCallback::new();
 
         mock.expect_post()
             .returning(move |_| Box::pin(async move {}));
+        mock.expect_post_peers()
+            .returning(move |_| Box::pin(async move {}));
 
         mock
     });
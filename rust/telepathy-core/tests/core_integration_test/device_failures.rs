@@ -4,8 +4,10 @@ use super::common::{
     PendingAcceptProbe, STALE_INPUT_DEVICE_ID, STALE_OUTPUT_DEVICE_ID, StreamErrorProbe,
     assert_call_slot_idle, assert_no_call_ended_contains, build_client,
     build_client_with_accept_probe, build_client_with_options, call_state_snapshot,
-    init_test_tracing, shared_relay_map, sorted_room_members, wait_for_call_ended_contains,
-    wait_for_connected, wait_for_sessions, wait_for_slot_idle,
+    init_test_tracing, shared_relay_map, simulated_stream_error, sorted_room_members,
+    stream_error_scenario, wait_for_call_ended_contains, wait_for_connected,
+    wait_for_no_extra_room_leave, wait_for_room_join_count, wait_for_sessions, wait_for_slot_idle,
+    wait_for_slot_room_call,
 };
 
 use iroh::SecretKey;
@@ -1094,3 +1096,403 @@ async fn stale_output_device_fails_audio_test_and_allows_retry() {
 
     client.telepathy.shutdown().await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn call_input_stream_error_fails_over_to_another_device() {
+    call_stream_error_fails_over(true).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn call_output_stream_error_fails_over_to_another_device() {
+    call_stream_error_fails_over(false).await;
+}
+
+async fn call_stream_error_fails_over(trigger_input: bool) {
+    init_test_tracing();
+    let relay_map = shared_relay_map();
+    let codec_config = CodecConfig::new(true, true, 5.0);
+
+    let key_a = SecretKey::generate();
+    let key_b = SecretKey::generate();
+    let contact_a = Contact::new("failover-client-a".to_string(), key_a.public().to_string())
+        .expect("contact a invalid");
+    let contact_b = Contact::new("failover-client-b".to_string(), key_b.public().to_string())
+        .expect("contact b invalid");
+
+    let call_states_a = Arc::new(Mutex::new(Vec::new()));
+    let call_states_b = Arc::new(Mutex::new(Vec::new()));
+    let input_error_probe = StreamErrorProbe::new();
+    let output_error_probe = StreamErrorProbe::new();
+    let host_a =
+        CallbackCapturingAudioHost::new(input_error_probe.clone(), output_error_probe.clone());
+    let accept_probe_b = PendingAcceptProbe::default();
+
+    let client_a = build_client(
+        relay_map,
+        key_a,
+        vec![contact_b.clone()],
+        &codec_config,
+        host_a.clone(),
+        call_states_a.clone(),
+    )
+    .await;
+    let client_b = build_client_with_accept_probe(
+        relay_map,
+        key_b,
+        vec![contact_a.clone()],
+        &codec_config,
+        MockAudioHost::new(
+            MockAudioInput::default(),
+            DEFAULT_SAMPLE_RATE,
+            MockAudioOutput,
+            DEFAULT_SAMPLE_RATE,
+        ),
+        call_states_b.clone(),
+        accept_probe_b.clone(),
+    )
+    .await;
+
+    client_a.telepathy.start_session(&contact_b).await;
+    client_b.telepathy.start_session(&contact_a).await;
+    wait_for_sessions(&client_a, &contact_b, &client_b, &contact_a).await;
+
+    client_a
+        .telepathy
+        .start_call(&contact_b)
+        .await
+        .expect("alice should start the outgoing call");
+    accept_probe_b.wait_opened().await;
+    client_b
+        .telepathy
+        .start_call(&contact_a)
+        .await
+        .expect("bob should accept the call");
+
+    wait_for_connected(&call_states_a, "alice").await;
+    wait_for_connected(&call_states_b, "bob").await;
+
+    let (probe, expected_message, simulated_message) =
+        stream_error_scenario(trigger_input, &input_error_probe, &output_error_probe);
+    probe.wait_captured().await;
+    probe.trigger(simulated_stream_error(simulated_message));
+
+    // the failed default device is skipped, so the stream moves to the listed device
+    let device = client_a
+        .device_event_probe
+        .wait_for_switch(trigger_input)
+        .await;
+    assert_eq!(
+        device.map(|device| device.id).as_deref(),
+        Some(MOCK_DEVICE_ID)
+    );
+    sleep(Duration::from_secs(1)).await;
+    for (label, states) in [("alice", &call_states_a), ("bob", &call_states_b)] {
+        let states = call_state_snapshot(states);
+        assert!(
+            states
+                .iter()
+                .all(|state| !matches!(state, CallState::CallEnded(_, _))),
+            "{label} should stay in the call after the failover; states were {states:?}"
+        );
+    }
+
+    // the replacement reports its own errors, the call ends once no device is left
+    probe.wait_captured().await;
+    host_a.block_device_opens();
+    probe.trigger(simulated_stream_error(simulated_message));
+    wait_for_call_ended_contains(&call_states_a, expected_message, false, "alice").await;
+    wait_for_call_ended_contains(&call_states_b, "Audio device error", true, "bob").await;
+
+    client_a.telepathy.shutdown().await;
+    client_b.telepathy.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn room_output_stream_error_fails_over_to_another_device() {
+    init_test_tracing();
+    let relay_map = shared_relay_map();
+    let codec_config = CodecConfig::new(true, true, 5.0);
+
+    let key_a = SecretKey::generate();
+    let key_b = SecretKey::generate();
+    let contact_a = Contact::new(
+        "room-failover-client-a".to_string(),
+        key_a.public().to_string(),
+    )
+    .expect("contact a invalid");
+    let contact_b = Contact::new(
+        "room-failover-client-b".to_string(),
+        key_b.public().to_string(),
+    )
+    .expect("contact b invalid");
+
+    let peer_a = contact_a.get_peer_id().to_string();
+    let peer_b = contact_b.get_peer_id().to_string();
+    let call_states_a = Arc::new(Mutex::new(Vec::new()));
+    let call_states_b = Arc::new(Mutex::new(Vec::new()));
+    let room_members = sorted_room_members(&contact_a, &contact_b);
+    let output_error_probe = StreamErrorProbe::new();
+
+    let client_a = build_client(
+        relay_map,
+        key_a,
+        vec![contact_b.clone()],
+        &codec_config,
+        CallbackCapturingAudioHost::new(StreamErrorProbe::new(), output_error_probe.clone()),
+        call_states_a.clone(),
+    )
+    .await;
+    let client_b = build_client(
+        relay_map,
+        key_b,
+        vec![contact_a.clone()],
+        &codec_config,
+        MockAudioHost::new(
+            MockAudioInput::default(),
+            DEFAULT_SAMPLE_RATE,
+            MockAudioOutput,
+            DEFAULT_SAMPLE_RATE,
+        ),
+        call_states_b.clone(),
+    )
+    .await;
+
+    client_a.telepathy.start_session(&contact_b).await;
+    client_b.telepathy.start_session(&contact_a).await;
+    wait_for_sessions(&client_a, &contact_b, &client_b, &contact_a).await;
+
+    client_a
+        .telepathy
+        .join_room(room_members.clone())
+        .await
+        .expect("client a should join room");
+    client_b
+        .telepathy
+        .join_room(room_members)
+        .await
+        .expect("client b should join room");
+    wait_for_room_join_count(&call_states_a, &peer_b, 1).await;
+    wait_for_room_join_count(&call_states_b, &peer_a, 1).await;
+
+    output_error_probe.wait_captured().await;
+    output_error_probe.trigger(simulated_stream_error(
+        "simulated output device disconnected",
+    ));
+
+    // the shared mixer moves to the listed device and every peer keeps playing
+    let device = client_a.device_event_probe.wait_for_switch(false).await;
+    assert_eq!(
+        device.map(|device| device.id).as_deref(),
+        Some(MOCK_DEVICE_ID)
+    );
+    wait_for_no_extra_room_leave(&call_states_b, &peer_a, 0, Duration::from_secs(1)).await;
+    wait_for_slot_room_call(&client_a, "room client a after the failover").await;
+    assert_no_call_ended_contains(&call_states_a, "Speaker error", "room client a");
+
+    client_a.telepathy.shutdown().await;
+    client_b.telepathy.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn call_switches_to_devices_set_mid_call() {
+    init_test_tracing();
+    let relay_map = shared_relay_map();
+    let codec_config = CodecConfig::new(true, true, 5.0);
+
+    let key_a = SecretKey::generate();
+    let key_b = SecretKey::generate();
+    let contact_a = Contact::new(
+        "set-device-call-client-a".to_string(),
+        key_a.public().to_string(),
+    )
+    .expect("contact a invalid");
+    let contact_b = Contact::new(
+        "set-device-call-client-b".to_string(),
+        key_b.public().to_string(),
+    )
+    .expect("contact b invalid");
+
+    let call_states_a = Arc::new(Mutex::new(Vec::new()));
+    let call_states_b = Arc::new(Mutex::new(Vec::new()));
+    let device_probe = DeviceSelectionProbe::default();
+    let accept_probe_b = PendingAcceptProbe::default();
+
+    let client_a = build_client(
+        relay_map,
+        key_a,
+        vec![contact_b.clone()],
+        &codec_config,
+        CallbackCapturingAudioHost::new(StreamErrorProbe::new(), StreamErrorProbe::new())
+            .with_device_selection_probe(device_probe.clone()),
+        call_states_a.clone(),
+    )
+    .await;
+    let client_b = build_client_with_accept_probe(
+        relay_map,
+        key_b,
+        vec![contact_a.clone()],
+        &codec_config,
+        MockAudioHost::new(
+            MockAudioInput::default(),
+            DEFAULT_SAMPLE_RATE,
+            MockAudioOutput,
+            DEFAULT_SAMPLE_RATE,
+        ),
+        call_states_b.clone(),
+        accept_probe_b.clone(),
+    )
+    .await;
+
+    client_a.telepathy.start_session(&contact_b).await;
+    client_b.telepathy.start_session(&contact_a).await;
+    wait_for_sessions(&client_a, &contact_b, &client_b, &contact_a).await;
+
+    client_a
+        .telepathy
+        .start_call(&contact_b)
+        .await
+        .expect("alice should start the outgoing call");
+    accept_probe_b.wait_opened().await;
+    client_b
+        .telepathy
+        .start_call(&contact_a)
+        .await
+        .expect("bob should accept the call");
+
+    wait_for_connected(&call_states_a, "alice").await;
+    wait_for_connected(&call_states_b, "bob").await;
+
+    // a device which does not open leaves the stream where it is
+    client_a
+        .telepathy
+        .set_output_device(Some(STALE_OUTPUT_DEVICE_ID.to_string()))
+        .await;
+    device_probe
+        .wait_for(
+            DeviceSelectionOperation::OpenOutput,
+            STALE_OUTPUT_DEVICE_ID,
+            1,
+        )
+        .await;
+
+    client_a
+        .telepathy
+        .set_input_device(Some(MOCK_DEVICE_ID.to_string()))
+        .await;
+    device_probe
+        .wait_for(DeviceSelectionOperation::OpenInput, MOCK_DEVICE_ID, 1)
+        .await;
+    client_a
+        .telepathy
+        .set_output_device(Some(MOCK_DEVICE_ID.to_string()))
+        .await;
+    device_probe
+        .wait_for(DeviceSelectionOperation::OpenOutput, MOCK_DEVICE_ID, 1)
+        .await;
+
+    sleep(Duration::from_secs(1)).await;
+    for (label, states) in [("alice", &call_states_a), ("bob", &call_states_b)] {
+        let states = call_state_snapshot(states);
+        assert!(
+            states
+                .iter()
+                .all(|state| !matches!(state, CallState::CallEnded(_, _))),
+            "{label} should stay in the call across device changes; states were {states:?}"
+        );
+    }
+
+    client_a.telepathy.shutdown().await;
+    client_b.telepathy.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn room_switches_to_devices_set_mid_room() {
+    init_test_tracing();
+    let relay_map = shared_relay_map();
+    let codec_config = CodecConfig::new(true, true, 5.0);
+
+    let key_a = SecretKey::generate();
+    let key_b = SecretKey::generate();
+    let contact_a = Contact::new(
+        "set-device-room-client-a".to_string(),
+        key_a.public().to_string(),
+    )
+    .expect("contact a invalid");
+    let contact_b = Contact::new(
+        "set-device-room-client-b".to_string(),
+        key_b.public().to_string(),
+    )
+    .expect("contact b invalid");
+
+    let peer_a = contact_a.get_peer_id().to_string();
+    let peer_b = contact_b.get_peer_id().to_string();
+    let call_states_a = Arc::new(Mutex::new(Vec::new()));
+    let call_states_b = Arc::new(Mutex::new(Vec::new()));
+    let room_members = sorted_room_members(&contact_a, &contact_b);
+    let device_probe = DeviceSelectionProbe::default();
+
+    let client_a = build_client(
+        relay_map,
+        key_a,
+        vec![contact_b.clone()],
+        &codec_config,
+        CallbackCapturingAudioHost::new(StreamErrorProbe::new(), StreamErrorProbe::new())
+            .with_device_selection_probe(device_probe.clone()),
+        call_states_a.clone(),
+    )
+    .await;
+    let client_b = build_client(
+        relay_map,
+        key_b,
+        vec![contact_a.clone()],
+        &codec_config,
+        MockAudioHost::new(
+            MockAudioInput::default(),
+            DEFAULT_SAMPLE_RATE,
+            MockAudioOutput,
+            DEFAULT_SAMPLE_RATE,
+        ),
+        call_states_b.clone(),
+    )
+    .await;
+
+    client_a.telepathy.start_session(&contact_b).await;
+    client_b.telepathy.start_session(&contact_a).await;
+    wait_for_sessions(&client_a, &contact_b, &client_b, &contact_a).await;
+
+    client_a
+        .telepathy
+        .join_room(room_members.clone())
+        .await
+        .expect("client a should join room");
+    client_b
+        .telepathy
+        .join_room(room_members)
+        .await
+        .expect("client b should join room");
+    wait_for_room_join_count(&call_states_a, &peer_b, 1).await;
+    wait_for_room_join_count(&call_states_b, &peer_a, 1).await;
+
+    // the input and the shared mixer move while every peer stays connected
+    client_a
+        .telepathy
+        .set_input_device(Some(MOCK_DEVICE_ID.to_string()))
+        .await;
+    device_probe
+        .wait_for(DeviceSelectionOperation::OpenInput, MOCK_DEVICE_ID, 1)
+        .await;
+    client_a
+        .telepathy
+        .set_output_device(Some(MOCK_DEVICE_ID.to_string()))
+        .await;
+    device_probe
+        .wait_for(DeviceSelectionOperation::OpenOutput, MOCK_DEVICE_ID, 1)
+        .await;
+
+    wait_for_no_extra_room_leave(&call_states_b, &peer_a, 0, Duration::from_secs(1)).await;
+    wait_for_slot_room_call(&client_a, "room client a after the device changes").await;
+    assert_no_call_ended_contains(&call_states_a, "", "room client a");
+
+    client_a.telepathy.shutdown().await;
+    client_b.telepathy.shutdown().await;
+}
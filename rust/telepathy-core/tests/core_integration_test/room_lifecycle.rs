@@ -529,9 +529,10 @@ async fn cancelled_room_interrupts_gated_peer_output_setup() {
     let call_states_b = Arc::new(Mutex::new(Vec::new()));
     let room_members = sorted_room_members(&contact_a, &contact_b);
 
-    // Gate A's `setup_output` at the host's `open_output` call (the production
-    // path through `AudioOutputBuilder::build`). `CoreState::output_device` is
-    // `pub(crate)`, so the test must drive the gate via host behavior.
+    // Gate A's `setup_mixer_source` at the host's `open_output` call (the
+    // production path through `AudioMixerBuilder::build`).
+    // `CoreState::output_device` is `pub(crate)`, so the test must drive the
+    // gate via host behavior.
     let device_probe = DeviceSelectionProbe::default();
     let output_gate = OutputOpenGate::default();
     let host_a = CallbackCapturingAudioHost::new(StreamErrorProbe::new(), StreamErrorProbe::new())
@@ -572,7 +573,7 @@ async fn cancelled_room_interrupts_gated_peer_output_setup() {
         .await;
 
     let operation_a = CancellationToken::new();
-    // Cancel A's room operation once its per-peer `setup_output` reaches the
+    // Cancel A's room operation once its first `setup_mixer_source` reaches the
     // host (recorded as `OpenOutput`), then release the gate so the cancelled
     // call can return. Without the cancellation race A's room controller
     // strands on the gated `open_output`; with the race, cancelling interrupts
@@ -602,8 +603,8 @@ async fn cancelled_room_interrupts_gated_peer_output_setup() {
         .expect("client b should join room");
 
     // B observing A's RoomJoin means the room handshake completed both ways; A
-    // has therefore received B's Join and its `setup_output` is blocked inside
-    // the gated `open_output`.
+    // has therefore received B's Join and its `setup_mixer_source` is blocked
+    // inside the gated `open_output`.
     wait_for_room_join_count(&call_states_b, &peer_a, 1).await;
 
     operation_a.cancel();
@@ -278,6 +278,8 @@ class _FakeCodecConfig implements CodecConfig {
   bool _enabled = true;
   bool _vbr = true;
   double _residualBits = 5.0;
+  AudioCodec _codec = AudioCodec.sea;
+  int _channels = 1;
 
   @override
   void dispose() {}
@@ -285,6 +287,22 @@ class _FakeCodecConfig implements CodecConfig {
   @override
   bool get isDisposed => false;
 
+  @override
+  int channels() => _channels;
+
+  @override
+  AudioCodec codec() => _codec;
+
+  @override
+  void setChannels({required int channels}) {
+    _channels = channels;
+  }
+
+  @override
+  void setCodec({required AudioCodec codec}) {
+    _codec = codec;
+  }
+
   @override
   void setEnabled({required bool enabled}) {
     _enabled = enabled;
@@ -390,6 +408,9 @@ class _FakeTelepathy implements Telepathy {
   @override
   Future<void> sendChat({required ChatMessage message}) async {}
 
+  @override
+  void setAutoGain({required bool enabled}) {}
+
   @override
   void setContactOutputVolume({required Contact contact}) {}
 
@@ -399,6 +420,9 @@ class _FakeTelepathy implements Telepathy {
   @override
   void setDenoise({required bool denoise}) {}
 
+  @override
+  void setEchoCancellation({required bool enabled}) {}
+
   @override
   void setEfficiencyMode({required bool enabled}) {}
 
@@ -411,6 +435,9 @@ class _FakeTelepathy implements Telepathy {
   @override
   void setInputVolume({required double decibel}) {}
 
+  @override
+  void setJitterBufferBounds({required int minMs, required int maxMs}) {}
+
   @override
   Future<void> setModel({Uint8List? model}) async {}
 
@@ -426,12 +453,18 @@ class _FakeTelepathy implements Telepathy {
   @override
   void setPlayCustomRingtones({required bool play}) {}
 
+  @override
+  void setRedundancy({required bool enabled}) {}
+
   @override
   void setRmsThreshold({required double decimal}) {}
 
   @override
   void setSendCustomRingtone({required bool send}) {}
 
+  @override
+  void setVoiceGate({required bool enabled}) {}
+
   @override
   Future<void> shutdown() async {}
 
@@ -444,12 +477,22 @@ class _FakeTelepathy implements Telepathy {
   @override
   Future<void> startManager() async {}
 
+  @override
+  Future<void> startRecording({
+    required String path,
+    required RecordingFormat format,
+    required RecordingLayout layout,
+  }) async {}
+
   @override
   Future<void> startScreenshare({required Contact contact}) async {}
 
   @override
   Future<void> startSession({required Contact contact}) async {}
 
+  @override
+  Future<void> stopRecording() async {}
+
   @override
   Future<void> stopSession({required Contact contact}) async {}
 }
@@ -597,6 +597,9 @@ class _FakeTelepathy implements Telepathy {
   @override
   Future<void> sendChat({required ChatMessage message}) async {}
 
+  @override
+  void setAutoGain({required bool enabled}) {}
+
   @override
   void setContactOutputVolume({required Contact contact}) {}
 
@@ -606,6 +609,9 @@ class _FakeTelepathy implements Telepathy {
   @override
   void setDenoise({required bool denoise}) {}
 
+  @override
+  void setEchoCancellation({required bool enabled}) {}
+
   @override
   void setEfficiencyMode({required bool enabled}) {}
 
@@ -618,6 +624,9 @@ class _FakeTelepathy implements Telepathy {
   @override
   void setInputVolume({required double decibel}) {}
 
+  @override
+  void setJitterBufferBounds({required int minMs, required int maxMs}) {}
+
   @override
   Future<void> setModel({Uint8List? model}) async {}
 
@@ -633,12 +642,18 @@ class _FakeTelepathy implements Telepathy {
   @override
   void setPlayCustomRingtones({required bool play}) {}
 
+  @override
+  void setRedundancy({required bool enabled}) {}
+
   @override
   void setRmsThreshold({required double decimal}) {}
 
   @override
   void setSendCustomRingtone({required bool send}) {}
 
+  @override
+  void setVoiceGate({required bool enabled}) {}
+
   @override
   Future<void> shutdown() async {}
 
@@ -651,12 +666,22 @@ class _FakeTelepathy implements Telepathy {
   @override
   Future<void> startManager() async {}
 
+  @override
+  Future<void> startRecording({
+    required String path,
+    required RecordingFormat format,
+    required RecordingLayout layout,
+  }) async {}
+
   @override
   Future<void> startScreenshare({required Contact contact}) async {}
 
   @override
   Future<void> startSession({required Contact contact}) async {}
 
+  @override
+  Future<void> stopRecording() async {}
+
   @override
   Future<void> stopSession({required Contact contact}) async {}
 }
@@ -55,8 +55,35 @@ class _FakeSoundHandle implements FlutterSoundHandle {
   @override
   void dispose() {}
 
+  @override
+  BigInt durationMs() => BigInt.zero;
+
   @override
   bool get isDisposed => false;
+
+  @override
+  bool isLooping() => false;
+
+  @override
+  bool isPaused() => false;
+
+  @override
+  void pause() {}
+
+  @override
+  BigInt positionMs() => BigInt.zero;
+
+  @override
+  void resume() {}
+
+  @override
+  void seek({required BigInt positionMs}) {}
+
+  @override
+  void setLooping({required bool looping}) {}
+
+  @override
+  void setVolume({required double volume}) {}
 }
 
 class _FakeSoundPlayer implements SoundPlayer {